@@ -0,0 +1,54 @@
+//! Parsing a large `GET /v1/designs` page into owned and borrowed models.
+//!
+//! Run with `cargo bench --bench list_designs`.
+
+use canva_connect::models::borrowed::ListDesignsResponseRef;
+use canva_connect::models::GetListDesignResponse;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_json::json;
+
+fn list_body(designs: usize) -> Vec<u8> {
+    let items: Vec<_> = (0..designs)
+        .map(|i| {
+            let id = format!("DAF{i:08}");
+            json!({
+                "id": id,
+                "title": format!("Quarterly report {i}"),
+                "owner": { "user_id": "oUnPjZ2k2yuhftbWF7873o", "team_id": "oBpVhLW22VrqtwKgaayRbP" },
+                "thumbnail": {
+                    "width": 595,
+                    "height": 335,
+                    "url": format!("https://document-export.canva.com/{id}/thumbnail.png"),
+                },
+                "urls": {
+                    "edit_url": format!("https://www.canva.com/api/design/{id}/edit"),
+                    "view_url": format!("https://www.canva.com/api/design/{id}/view"),
+                },
+                "created_at": 1_700_000_000,
+                "updated_at": 1_700_086_400,
+                "page_count": 3,
+            })
+        })
+        .collect();
+    json!({ "items": items, "continuation": "next" })
+        .to_string()
+        .into_bytes()
+}
+
+fn parse_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_designs");
+    for designs in [100, 1_000] {
+        let body = list_body(designs);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        group.bench_with_input(BenchmarkId::new("owned", designs), &body, |b, body| {
+            b.iter(|| serde_json::from_slice::<GetListDesignResponse>(body))
+        });
+        group.bench_with_input(BenchmarkId::new("borrowed", designs), &body, |b, body| {
+            b.iter(|| ListDesignsResponseRef::from_slice(body))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_list);
+criterion_main!(benches);
@@ -157,7 +157,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 break;
             }
-            DesignAutofillStatus::InProgress => {
+            DesignAutofillStatus::InProgress | DesignAutofillStatus::Unknown => {
                 attempts += 1;
                 if attempts >= max_attempts {
                     println!("⏰ Timeout waiting for job to complete");
@@ -105,6 +105,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         description,
                         required,
                     } => ("Chart", label, description, required),
+                    canva_connect::models::DataField::Unknown => {
+                        println!("      Type: unsupported");
+                        continue;
+                    }
                 };
 
                 println!("      Type: {field_type}");
@@ -26,7 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // First, we need a design to comment on
     println!("\n1. Finding a design to comment on...");
-    let designs = client.designs().list(None, None, None, None).await?;
+    let designs = client.designs().list_with(&Default::default()).await?;
 
     if designs.items.is_empty() {
         println!("❌ No designs found. Create a design first to test comments.");
@@ -20,6 +20,7 @@
 
 use canva_connect::{
     auth::AccessToken,
+    endpoints::designs::ListDesignsRequest,
     models::{CreateDesignRequest, DesignTypeInput, OwnershipType, PresetDesignTypeName},
     Client,
 };
@@ -51,7 +52,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // List existing designs
     println!("📋 Listing your designs...");
     match designs_api
-        .list(None, None, Some(OwnershipType::Owned), None)
+        .list_with(
+            &ListDesignsRequest::builder()
+                .ownership(OwnershipType::Owned)
+                .build(),
+        )
         .await
     {
         Ok(designs_response) => {
@@ -28,7 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // First, we need a design to export
     println!("\n1. Finding a design to export...");
-    let designs = client.designs().list(None, None, None, None).await?;
+    let designs = client.designs().list_with(&Default::default()).await?;
 
     if designs.items.is_empty() {
         println!("❌ No designs found. Create a design first to test exports.");
@@ -158,11 +158,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         match job_status.job.status {
             JobStatus::Success => {
                 println!("✅ Export completed successfully!");
-                if let Some(result) = &job_status.job.result {
-                    println!("   Download URLs:");
-                    for (i, url) in result.urls.iter().enumerate() {
-                        println!("   {}. {}", i + 1, url.url);
-                    }
+                println!("   Download URLs:");
+                for export_url in job_status.job.export_urls() {
+                    println!("   {}. {}", export_url.page, export_url.url);
                 }
                 break;
             }
@@ -173,7 +171,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 break;
             }
-            JobStatus::InProgress => {
+            JobStatus::InProgress | JobStatus::Unknown => {
                 attempts += 1;
                 if attempts >= max_attempts {
                     println!("⏰ Timeout waiting for export to complete");
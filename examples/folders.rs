@@ -182,6 +182,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             image.updated_at.format("%Y-%m-%d %H:%M:%S UTC")
                         );
                     }
+                    FolderItemSummary::Unknown => println!("   ❔ (unsupported item type)"),
                 }
                 println!();
             }
@@ -234,6 +235,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let suitable_item = root_items.items.iter().find(|item| {
                 match item {
                     FolderItemSummary::Folder { folder } => folder.id != main_folder.id,
+                    FolderItemSummary::Unknown => false,
                     _ => true, // Designs and images are always suitable to move
                 }
             });
@@ -251,6 +253,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         design.id.clone(),
                     ),
                     FolderItemSummary::Image { image } => (image.name.clone(), image.id.clone()),
+                    FolderItemSummary::Unknown => unreachable!("unknown items are skipped above"),
                 };
 
                 println!("📦 Found item to move: {item_name} (ID: {item_id})");
@@ -294,7 +294,8 @@ async fn wait_for_url_upload_completion(
                     "Upload failed: {error_msg}"
                 )));
             }
-            canva_connect::models::JobStatus::InProgress => {
+            canva_connect::models::JobStatus::InProgress
+            | canva_connect::models::JobStatus::Unknown => {
                 // Show progress indicator
                 let dots = ".".repeat((attempt % 4) + 1);
                 print!("\r   ⏳ Processing{dots:<4}");
@@ -88,6 +88,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         canva_connect::endpoints::user::Capability::Resize => {
                             println!("   📐 Resize - Access to design resize APIs (Canva Pro+)");
                         }
+                        canva_connect::endpoints::user::Capability::Unknown => {
+                            println!("   ❔ A capability this example does not know about");
+                        }
                     }
                 }
             }
@@ -6,10 +6,20 @@
 //! ## Features
 //!
 //! - **Token Storage**: Thread-safe token storage with automatic expiry management
+//! - **Persistent Storage**: Save tokens to an encrypted file or the OS keyring with [`TokenStorage`], or to your own store with [`TokenSet::to_json`]
 //! - **Auto-refresh**: Automatic token refresh when access tokens expire
 //! - **Token Introspection**: Check token validity and metadata
 //! - **Token Revocation**: Revoke access and refresh tokens
 //! - **Thread Safety**: All operations are safe for concurrent use
+//! - **Redirect Handling**: Catch the OAuth redirect on a loopback URI with `callback_server` (requires the `oauth-flow` feature)
+//! - **Interactive Login**: `OAuthClient::authorize_interactive` runs the whole browser flow in one call (requires the `oauth-flow` feature)
+//! - **Headless Login**: [`OAuthClient::authorize_manual`] lets the user paste the redirect URL back, for servers without a browser
+//! - **Client Integration**: [`Client::with_oauth`](crate::Client::with_oauth) authenticates API calls through [`TokenProvider`]
+//!
+//! Tokens always act on behalf of a user. The Connect API's token endpoint
+//! only accepts the `authorization_code` and `refresh_token` grants, so there
+//! is no machine-to-machine (`client_credentials`) login; a server has to be
+//! authorized once by a user and keep refreshing that user's tokens.
 //!
 //! ## Basic Usage
 //!
@@ -73,7 +83,18 @@
 //! # }
 //! ```
 
+#[cfg(feature = "oauth-flow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth-flow")))]
+pub mod callback_server;
+pub mod manual;
 pub mod scopes;
+pub mod storage;
+
+pub use scopes::ScopeSet;
+
+#[cfg(feature = "keyring")]
+pub use storage::KeyringTokenStorage;
+pub use storage::{FileTokenStorage, TokenStorage};
 
 use crate::error::{Error, Result};
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
@@ -85,6 +106,11 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// How long [`OAuthClient::authorize_interactive`] waits for the user to approve access
+#[cfg(feature = "oauth-flow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth-flow")))]
+pub const DEFAULT_AUTHORIZE_TIMEOUT: Duration = Duration::from_secs(300);
+
 /// OAuth 2.0 access token for authenticating with the Canva Connect API
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccessToken {
@@ -128,6 +154,46 @@ impl From<&str> for AccessToken {
     }
 }
 
+/// Source of access tokens for a [`Client`](crate::Client)
+///
+/// The client asks the provider for a token before every request, and calls
+/// [`refresh`](TokenProvider::refresh) once when the API answers
+/// `401 Unauthorized`, retrying the request with the new token.
+///
+/// Implemented for [`AccessToken`] (a static token that is never refreshed)
+/// and [`OAuthClient`] (tokens from its [`TokenStore`], refreshed with the
+/// stored refresh token).
+#[async_trait::async_trait]
+pub trait TokenProvider: fmt::Debug + Send + Sync {
+    /// Get a token to authenticate the next request
+    async fn access_token(&self) -> Result<AccessToken>;
+
+    /// Get a new token after the current one was rejected
+    async fn refresh(&self) -> Result<AccessToken>;
+
+    /// Scopes granted to the current token, or `None` if they are unknown
+    ///
+    /// Used by [`ClientBuilder::scope_preflight`](crate::ClientBuilder::scope_preflight)
+    /// to reject requests the token cannot make. The default returns `None`,
+    /// which skips the check.
+    async fn granted_scopes(&self) -> Result<Option<ScopeSet>> {
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenProvider for AccessToken {
+    async fn access_token(&self) -> Result<AccessToken> {
+        Ok(self.clone())
+    }
+
+    async fn refresh(&self) -> Result<AccessToken> {
+        Err(Error::Auth(
+            "Static access token cannot be refreshed".to_string(),
+        ))
+    }
+}
+
 /// OAuth 2.0 scopes for the Canva Connect API
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Scope {
@@ -169,9 +235,26 @@ pub enum Scope {
     ProfileRead,
 }
 
-impl fmt::Display for Scope {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let scope_str = match self {
+impl Scope {
+    /// Every scope, in declaration order
+    pub const ALL: [Scope; 12] = [
+        Scope::AssetRead,
+        Scope::AssetWrite,
+        Scope::BrandTemplateMetaRead,
+        Scope::BrandTemplateContentRead,
+        Scope::CommentRead,
+        Scope::CommentWrite,
+        Scope::DesignMetaRead,
+        Scope::DesignContentRead,
+        Scope::DesignContentWrite,
+        Scope::FolderRead,
+        Scope::FolderWrite,
+        Scope::ProfileRead,
+    ];
+
+    /// The scope's wire name, e.g. `"design:meta:read"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
             Scope::AssetRead => "asset:read",
             Scope::AssetWrite => "asset:write",
             Scope::BrandTemplateMetaRead => "brandtemplate:meta:read",
@@ -184,8 +267,32 @@ impl fmt::Display for Scope {
             Scope::FolderRead => "folder:read",
             Scope::FolderWrite => "folder:write",
             Scope::ProfileRead => "profile:read",
-        };
-        write!(f, "{scope_str}")
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Scope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| Error::Auth(format!("Unknown OAuth scope: {s}")))
+    }
+}
+
+impl TryFrom<&str> for Scope {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
     }
 }
 
@@ -295,7 +402,7 @@ impl OAuthConfig {
             .append_pair("client_id", &self.client_id)
             .append_pair("redirect_uri", &self.redirect_uri)
             .append_pair("response_type", "code")
-            .append_pair("scope", &self.scopes_string())
+            .append_pair("scope", &self.scope_set().to_string())
             .append_pair("code_challenge", &pkce.code_challenge)
             .append_pair("code_challenge_method", "S256");
 
@@ -306,13 +413,9 @@ impl OAuthConfig {
         Ok(url.to_string())
     }
 
-    /// Convert scopes to a space-separated string
-    pub fn scopes_string(&self) -> String {
-        self.scopes
-            .iter()
-            .map(|scope| scope.to_string())
-            .collect::<Vec<_>>()
-            .join(" ")
+    /// The requested scopes as a [`ScopeSet`], in configuration order
+    pub fn scope_set(&self) -> ScopeSet {
+        self.scopes.iter().cloned().collect()
     }
 }
 
@@ -339,7 +442,18 @@ pub struct TokenExchangeResponse {
 }
 
 /// Represents a complete OAuth 2.0 token set with expiry information
-#[derive(Debug, Clone)]
+///
+/// `expires_at` is an [`Instant`], which only means something inside the
+/// running process. The serde implementations, [`to_json`](Self::to_json)
+/// and [`from_json`](Self::from_json) store the expiry as a wall-clock
+/// RFC 3339 timestamp instead, so a saved token set can be restored after a
+/// restart and still expire on time:
+///
+/// ```json
+/// {"access_token":"...","refresh_token":"...","expires_at":"2024-05-01T12:00:00Z","scope":"design:meta:read"}
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "TokenSetJson", from = "TokenSetJson")]
 pub struct TokenSet {
     pub access_token: String,
     pub refresh_token: Option<String>,
@@ -347,6 +461,51 @@ pub struct TokenSet {
     pub scope: Option<String>,
 }
 
+/// Serialized form of a [`TokenSet`], with a wall-clock expiry
+#[derive(Serialize, Deserialize)]
+struct TokenSetJson {
+    access_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+impl From<TokenSet> for TokenSetJson {
+    fn from(token_set: TokenSet) -> Self {
+        Self {
+            expires_at: token_set.expires_at_utc(),
+            access_token: token_set.access_token,
+            refresh_token: token_set.refresh_token,
+            scope: token_set.scope,
+        }
+    }
+}
+
+impl From<TokenSetJson> for TokenSet {
+    fn from(json: TokenSetJson) -> Self {
+        Self {
+            access_token: json.access_token,
+            refresh_token: json.refresh_token,
+            expires_at: json.expires_at.map(instant_at),
+            scope: json.scope,
+        }
+    }
+}
+
+/// The [`Instant`] matching a wall-clock time; times in the past map to now
+pub(crate) fn instant_at(at: chrono::DateTime<chrono::Utc>) -> Instant {
+    // Cap far-future expiries so adding them to an Instant cannot overflow
+    const MAX_REMAINING: Duration = Duration::from_secs(10 * 365 * 24 * 60 * 60);
+    let remaining = (at - chrono::Utc::now())
+        .to_std()
+        .unwrap_or(Duration::ZERO)
+        .min(MAX_REMAINING);
+    Instant::now() + remaining
+}
+
 impl TokenSet {
     /// Create a new token set from a token exchange response
     pub fn from_exchange_response(response: TokenExchangeResponse) -> Self {
@@ -376,16 +535,74 @@ impl TokenSet {
             .unwrap_or(false)
     }
 
+    /// The expiry as a wall-clock time, if the token expires
+    pub fn expires_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.expires_at.map(|expires_at| {
+            let remaining = expires_at.saturating_duration_since(Instant::now());
+            chrono::TimeDelta::from_std(remaining)
+                .ok()
+                .and_then(|remaining| chrono::Utc::now().checked_add_signed(remaining))
+                .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC)
+        })
+    }
+
+    /// Serialize to JSON for saving, with the expiry as a wall-clock timestamp
+    ///
+    /// The JSON holds the refresh token in plain text; keep it somewhere
+    /// private, or use a [`TokenStorage`] that encrypts it.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Restore a token set saved with [`to_json`](Self::to_json)
+    ///
+    /// A token whose expiry has passed comes back already expired.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
     /// Get the access token as an AccessToken instance
     pub fn access_token(&self) -> AccessToken {
         AccessToken::new(&self.access_token)
     }
+
+    /// Scopes granted to the token, if the token response listed them
+    ///
+    /// Scopes this crate does not know about are skipped.
+    pub fn granted_scopes(&self) -> Option<ScopeSet> {
+        self.scope.as_deref().map(ScopeSet::parse_lossy)
+    }
+
+    /// The token response these tokens could have come from
+    fn to_exchange_response(&self) -> TokenExchangeResponse {
+        TokenExchangeResponse {
+            access_token: self.access_token.clone(),
+            token_type: "Bearer".to_string(),
+            expires_in: self.expires_at.map(|expires_at| {
+                expires_at
+                    .saturating_duration_since(Instant::now())
+                    .as_secs()
+            }),
+            refresh_token: self.refresh_token.clone(),
+            scope: self.scope.clone(),
+        }
+    }
 }
 
 /// Thread-safe token storage for OAuth 2.0 tokens
+///
+/// Tokens are kept in memory. A store created with
+/// [`with_storage`](Self::with_storage) also writes every change to a
+/// persistent [`TokenStorage`] backend; call [`load`](Self::load) at startup
+/// to pick up tokens saved by a previous run.
 #[derive(Debug, Clone)]
 pub struct TokenStore {
     tokens: Arc<RwLock<Option<TokenSet>>>,
+    storage: Option<Arc<dyn TokenStorage>>,
+    persist_lock: Arc<tokio::sync::Mutex<()>>,
+    // Held for the whole of a token refresh, so clients sharing the store
+    // refresh one at a time
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
 }
 
 impl TokenStore {
@@ -393,13 +610,58 @@ impl TokenStore {
     pub fn new() -> Self {
         Self {
             tokens: Arc::new(RwLock::new(None)),
+            storage: None,
+            persist_lock: Arc::default(),
+            refresh_lock: Arc::default(),
+        }
+    }
+
+    /// Create an empty token store that persists tokens to `storage`
+    pub fn with_storage(storage: impl TokenStorage + 'static) -> Self {
+        Self {
+            tokens: Arc::new(RwLock::new(None)),
+            storage: Some(Arc::new(storage)),
+            persist_lock: Arc::default(),
+            refresh_lock: Arc::default(),
+        }
+    }
+
+    /// Load tokens from the persistent backend into memory
+    ///
+    /// Returns the loaded token set, or `None` if the backend has none or
+    /// this store has no backend.
+    pub async fn load(&self) -> Result<Option<TokenSet>> {
+        let Some(storage) = &self.storage else {
+            return Ok(None);
+        };
+        let loaded = storage.load().await?;
+        if let Some(token_set) = &loaded {
+            *self.tokens.write().await = Some(token_set.clone());
         }
+        Ok(loaded)
     }
 
     /// Store a token set
+    ///
+    /// Failures to persist the tokens are ignored; use
+    /// [`try_store`](Self::try_store) to handle them.
     pub async fn store(&self, token_set: TokenSet) {
-        let mut tokens = self.tokens.write().await;
-        *tokens = Some(token_set);
+        let _ = self.try_store(token_set).await;
+    }
+
+    /// Store a token set, reporting failures to persist it
+    ///
+    /// The in-memory tokens are updated even when persisting fails.
+    pub async fn try_store(&self, token_set: TokenSet) -> Result<()> {
+        let Some(storage) = &self.storage else {
+            *self.tokens.write().await = Some(token_set);
+            return Ok(());
+        };
+        // The persist lock keeps concurrent saves in order without holding the
+        // token lock across I/O, so readers never wait on the backend
+        let _persist = self.persist_lock.lock().await;
+        *self.tokens.write().await = Some(token_set.clone());
+        storage.save(&token_set).await
     }
 
     /// Get the current token set
@@ -429,9 +691,28 @@ impl TokenStore {
     }
 
     /// Clear all stored tokens
+    ///
+    /// Failures to clear the persistent backend are ignored; use
+    /// [`try_clear`](Self::try_clear) to handle them.
     pub async fn clear(&self) {
-        let mut tokens = self.tokens.write().await;
-        *tokens = None;
+        let _ = self.try_clear().await;
+    }
+
+    /// Clear all stored tokens, reporting failures to clear the persistent backend
+    pub async fn try_clear(&self) -> Result<()> {
+        let Some(storage) = &self.storage else {
+            *self.tokens.write().await = None;
+            return Ok(());
+        };
+        let _persist = self.persist_lock.lock().await;
+        *self.tokens.write().await = None;
+        storage.clear().await
+    }
+}
+
+impl<S: TokenStorage + 'static> From<S> for TokenStore {
+    fn from(storage: S) -> Self {
+        Self::with_storage(storage)
     }
 }
 
@@ -483,27 +764,78 @@ pub struct OAuthClient {
     config: OAuthConfig,
     http_client: reqwest::Client,
     token_store: TokenStore,
+    api_base_url: String,
+    on_store_error: Option<StoreErrorHandler>,
+}
+
+/// Callback for tokens that could not be persisted, see [`OAuthClient::on_store_error`]
+#[derive(Clone)]
+struct StoreErrorHandler(Arc<dyn Fn(&Error) + Send + Sync>);
+
+impl fmt::Debug for StoreErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StoreErrorHandler")
+    }
 }
 
 impl OAuthClient {
     /// Create a new OAuth client
     pub fn new(config: OAuthConfig) -> Self {
-        Self {
-            config,
-            http_client: reqwest::Client::new(),
-            token_store: TokenStore::new(),
-        }
+        Self::with_token_store(config, TokenStore::new())
     }
 
     /// Create a new OAuth client with a custom token store
-    pub fn with_token_store(config: OAuthConfig, token_store: TokenStore) -> Self {
+    ///
+    /// Accepts a [`TokenStore`], to share tokens between clients, or any
+    /// [`TokenStorage`] backend, such as [`FileTokenStorage`], to persist them.
+    /// Call [`TokenStore::load`] to restore previously saved tokens.
+    pub fn with_token_store(config: OAuthConfig, token_store: impl Into<TokenStore>) -> Self {
         Self {
             config,
             http_client: reqwest::Client::new(),
-            token_store,
+            token_store: token_store.into(),
+            api_base_url: crate::BASE_URL.to_string(),
+            on_store_error: None,
+        }
+    }
+
+    /// Send token requests to a different API base URL (defaults to [`crate::BASE_URL`])
+    pub fn with_api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        self.api_base_url = api_base_url.into();
+        self
+    }
+
+    /// Call `handler` when new tokens could not be saved to the token
+    /// store's persistent backend
+    ///
+    /// A failed save does not fail the exchange or refresh that issued the
+    /// tokens: they are kept in memory and returned, since the old refresh
+    /// token may already be revoked. Use the handler to warn the user that
+    /// they will have to sign in again after a restart. With the
+    /// `observability` feature the failure is also logged.
+    pub fn on_store_error(mut self, handler: impl Fn(&Error) + Send + Sync + 'static) -> Self {
+        self.on_store_error = Some(StoreErrorHandler(Arc::new(handler)));
+        self
+    }
+
+    /// Store newly issued tokens, reporting a failure to persist them
+    /// instead of returning it
+    async fn save_tokens(&self, token_response: &TokenExchangeResponse) {
+        let token_set = TokenSet::from_exchange_response(token_response.clone());
+        if let Err(error) = self.token_store.try_store(token_set).await {
+            #[cfg(feature = "observability")]
+            tracing::warn!(%error, "Failed to persist OAuth tokens");
+            if let Some(handler) = &self.on_store_error {
+                (handler.0)(&error);
+            }
         }
     }
 
+    /// URL of an OAuth endpoint such as `token` or `revoke`
+    fn oauth_url(&self, endpoint: &str) -> String {
+        format!("{}/v1/oauth/{endpoint}", self.api_base_url)
+    }
+
     /// Get the authorization URL (with PKCE enabled by default)
     pub fn authorization_url(&self, state: Option<&str>) -> Result<(String, PkceParams)> {
         let pkce = PkceParams::new();
@@ -520,26 +852,120 @@ impl OAuthClient {
         self.config.authorization_url_with_pkce(state, pkce)
     }
 
-    /// Exchange authorization code for access token (PKCE required for Canva Connect API)
+    /// Run the whole authorization code flow for a desktop or command-line app
     ///
-    /// Note: This method is deprecated. Use `exchange_code_with_pkce` instead as PKCE is required.
-    #[deprecated(
-        note = "PKCE is required for Canva Connect API. Use exchange_code_with_pkce instead."
-    )]
-    pub async fn exchange_code(&self, _code: &str) -> Result<TokenExchangeResponse> {
-        Err(Error::Auth(
-            "PKCE is required for Canva Connect API. Use exchange_code_with_pkce instead."
-                .to_string(),
-        ))
+    /// Generates PKCE parameters and a random `state`, starts a
+    /// [`callback_server`] on the configured redirect URI, opens the
+    /// authorization page in the default browser and exchanges the returned
+    /// code. The tokens are stored in the token store and returned.
+    ///
+    /// The redirect URI must be a loopback `http` URI registered with your
+    /// Canva app, e.g. `http://127.0.0.1:8080/callback`. If no browser can be
+    /// opened, `no_browser` is called with the authorization URL so the app
+    /// can show it to the user. Gives up after [`DEFAULT_AUTHORIZE_TIMEOUT`].
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{auth::{OAuthClient, OAuthConfig, Scope}, Client};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = OAuthClient::new(OAuthConfig::new(
+    ///     "client-id",
+    ///     "client-secret",
+    ///     "http://127.0.0.1:8080/callback",
+    ///     vec![Scope::DesignMetaRead],
+    /// ));
+    /// oauth
+    ///     .authorize_interactive(|url| eprintln!("Open this URL to authorize access:\n{url}"))
+    ///     .await?;
+    /// let client = Client::with_oauth(oauth).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "oauth-flow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth-flow")))]
+    pub async fn authorize_interactive<F>(&self, no_browser: F) -> Result<TokenSet>
+    where
+        F: FnOnce(&str),
+    {
+        self.authorize_with(
+            |url| {
+                if webbrowser::open(url).is_err() {
+                    no_browser(url);
+                }
+            },
+            DEFAULT_AUTHORIZE_TIMEOUT,
+        )
+        .await
+    }
+
+    /// Like [`authorize_interactive`](Self::authorize_interactive), showing
+    /// the authorization URL with `open` and waiting up to `timeout`
+    ///
+    /// `open` is called once the callback server is listening.
+    #[cfg(feature = "oauth-flow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth-flow")))]
+    pub async fn authorize_with<F>(&self, open: F, timeout: Duration) -> Result<TokenSet>
+    where
+        F: FnOnce(&str),
+    {
+        let state = uuid::Uuid::new_v4().to_string();
+        let (url, pkce) = self.authorization_url(Some(&state))?;
+        let server =
+            callback_server::CallbackServer::bind(&self.config.redirect_uri, &state).await?;
+        open(&url);
+        let code = server.wait(timeout).await?;
+        let response = self.exchange_code_with_pkce(&code, &pkce).await?;
+        Ok(TokenSet::from_exchange_response(response))
+    }
+
+    /// Run the authorization code flow by having the user paste the result back
+    ///
+    /// For headless servers, where no browser can be opened and nothing can
+    /// listen on the redirect URI. Generates PKCE parameters and a random
+    /// `state`, then calls `prompt` with the authorization URL. `prompt`
+    /// shows the URL to the user and returns what they pasted: the URL they
+    /// were redirected to or the bare code, as accepted by
+    /// [`manual::parse_authorization_response`]. The code is exchanged and
+    /// the tokens stored and returned.
+    ///
+    /// [`manual::stdin_prompt`] prompts on the terminal. The redirect URI
+    /// can be any URI registered with your Canva app.
+    pub async fn authorize_manual<F, Fut>(&self, prompt: F) -> Result<TokenSet>
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: std::future::Future<Output = Result<String>>,
+    {
+        let state = uuid::Uuid::new_v4().to_string();
+        let (url, pkce) = self.authorization_url(Some(&state))?;
+        let pasted = prompt(url).await?;
+        let code = manual::parse_authorization_response(&pasted, &state)?;
+        let response = self.exchange_code_with_pkce(&code, &pkce).await?;
+        Ok(TokenSet::from_exchange_response(response))
     }
 
     /// Exchange authorization code for access token with PKCE and store it
     ///
     /// PKCE is required for the Canva Connect API.
+    ///
+    /// Authorization codes are single use, so the exchange runs on a separate
+    /// task: dropping the returned future does not lose the issued tokens,
+    /// which are still stored in the token store.
     pub async fn exchange_code_with_pkce(
         &self,
         code: &str,
         pkce: &PkceParams,
+    ) -> Result<TokenExchangeResponse> {
+        let client = self.clone();
+        let code = code.to_string();
+        let pkce = pkce.clone();
+        detach(async move { client.perform_code_exchange(&code, &pkce).await }).await
+    }
+
+    async fn perform_code_exchange(
+        &self,
+        code: &str,
+        pkce: &PkceParams,
     ) -> Result<TokenExchangeResponse> {
         let form_data = vec![
             ("client_id", self.config.client_id.as_str()),
@@ -552,7 +978,7 @@ impl OAuthClient {
 
         let response = self
             .http_client
-            .post("https://api.canva.com/rest/v1/oauth/token")
+            .post(self.oauth_url("token"))
             .form(&form_data)
             .send()
             .await?;
@@ -560,9 +986,7 @@ impl OAuthClient {
         if response.status().is_success() {
             let token_response: TokenExchangeResponse = response.json().await?;
 
-            // Store the tokens
-            let token_set = TokenSet::from_exchange_response(token_response.clone());
-            self.token_store.store(token_set).await;
+            self.save_tokens(&token_response).await;
 
             Ok(token_response)
         } else {
@@ -572,15 +996,19 @@ impl OAuthClient {
     }
 
     /// Get a valid access token, refreshing if necessary
+    ///
+    /// When several tasks find the token expired at once, only one of them
+    /// refreshes it; the others wait for and use its result.
     pub async fn get_access_token(&self) -> Result<AccessToken> {
         // First, try to get a valid non-expired token
-        if let Some(token) = self.token_store.get_valid_access_token().await {
-            return Ok(token);
+        let tokens = self.token_store.get().await;
+        if let Some(token_set) = tokens.as_ref().filter(|tokens| !tokens.is_expired()) {
+            return Ok(token_set.access_token());
         }
 
         // If no valid token, try to refresh
-        if self.token_store.has_refresh_token().await {
-            self.refresh_token().await?;
+        if let Some(stale) = tokens.and_then(|tokens| tokens.refresh_token) {
+            self.refresh_from(Some(stale)).await?;
             return self
                 .token_store
                 .get_valid_access_token()
@@ -596,7 +1024,80 @@ impl OAuthClient {
     }
 
     /// Refresh the access token using the refresh token
+    ///
+    /// Canva rotates refresh tokens on every use, so the refresh runs on a
+    /// separate task: dropping the returned future does not lose the new
+    /// refresh token, which is still stored in the token store.
+    ///
+    /// Refreshes through the same [`TokenStore`] run one at a time. A call
+    /// that had to wait for another refresh returns the tokens that refresh
+    /// stored instead of spending the rotated refresh token again.
     pub async fn refresh_token(&self) -> Result<TokenExchangeResponse> {
+        let stale = self
+            .token_store
+            .get()
+            .await
+            .and_then(|tokens| tokens.refresh_token);
+        self.refresh_from(stale).await
+    }
+
+    /// Refresh unless another refresh already replaced the `stale` refresh token
+    async fn refresh_from(&self, stale: Option<String>) -> Result<TokenExchangeResponse> {
+        let client = self.clone();
+        detach(async move {
+            let _refresh = client.token_store.refresh_lock.lock().await;
+            if let Some(current) = client.token_store.get().await {
+                if stale.is_some() && current.refresh_token != stale {
+                    return Ok(current.to_exchange_response());
+                }
+            }
+            client.perform_refresh().await
+        })
+        .await
+    }
+
+    /// Keep the access token fresh by refreshing it `lead` before it expires
+    ///
+    /// Spawns a task that sleeps until `lead` before the stored token
+    /// expires and then refreshes it, so requests rarely wait on a refresh.
+    /// Tokens without an expiry or refresh token are left alone; the task
+    /// checks again when the store changes them. A failed refresh is retried
+    /// after [`REFRESH_AHEAD_RETRY_DELAY`].
+    ///
+    /// The task runs until the returned handle is dropped. Pick a `lead`
+    /// well below the token lifetime, or every check will refresh.
+    pub fn spawn_refresh_ahead(&self, lead: Duration) -> RefreshAheadHandle {
+        let client = self.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let delay = client
+                    .token_store
+                    .get()
+                    .await
+                    .filter(|tokens| tokens.refresh_token.is_some())
+                    .and_then(|tokens| tokens.expires_at)
+                    .map(|expires_at| {
+                        expires_at
+                            .saturating_duration_since(Instant::now())
+                            .saturating_sub(lead)
+                    });
+                match delay {
+                    Some(Duration::ZERO) => {
+                        // Failures are retried; success pushes expiry out of reach
+                        let _ = client.refresh_token().await;
+                        tokio::time::sleep(REFRESH_AHEAD_RETRY_DELAY).await;
+                    }
+                    Some(delay) => {
+                        tokio::time::sleep(delay.min(REFRESH_AHEAD_RECHECK_INTERVAL)).await
+                    }
+                    None => tokio::time::sleep(REFRESH_AHEAD_RECHECK_INTERVAL).await,
+                }
+            }
+        });
+        RefreshAheadHandle { task }
+    }
+
+    async fn perform_refresh(&self) -> Result<TokenExchangeResponse> {
         let current_tokens = self
             .token_store
             .get()
@@ -616,7 +1117,7 @@ impl OAuthClient {
 
         let response = self
             .http_client
-            .post("https://api.canva.com/rest/v1/oauth/token")
+            .post(self.oauth_url("token"))
             .json(&request)
             .send()
             .await?;
@@ -624,9 +1125,7 @@ impl OAuthClient {
         if response.status().is_success() {
             let token_response: TokenExchangeResponse = response.json().await?;
 
-            // Store the new tokens
-            let token_set = TokenSet::from_exchange_response(token_response.clone());
-            self.token_store.store(token_set).await;
+            self.save_tokens(&token_response).await;
 
             Ok(token_response)
         } else {
@@ -645,7 +1144,7 @@ impl OAuthClient {
 
         let response = self
             .http_client
-            .post("https://api.canva.com/rest/v1/oauth/introspect")
+            .post(self.oauth_url("introspect"))
             .json(&request)
             .send()
             .await?;
@@ -672,7 +1171,7 @@ impl OAuthClient {
 
         let response = self
             .http_client
-            .post("https://api.canva.com/rest/v1/oauth/revoke")
+            .post(self.oauth_url("revoke"))
             .json(&request)
             .send()
             .await?;
@@ -681,7 +1180,7 @@ impl OAuthClient {
             // Clear stored tokens if we revoked the current access token
             if let Some(current_tokens) = self.token_store.get().await {
                 if current_tokens.access_token == token {
-                    self.token_store.clear().await;
+                    self.token_store.try_clear().await?;
                 }
             }
             Ok(())
@@ -709,6 +1208,71 @@ impl OAuthClient {
     }
 }
 
+/// How long [`OAuthClient::spawn_refresh_ahead`] waits after a refresh before checking again
+pub const REFRESH_AHEAD_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+// Longest sleep between checks of the token store, so replaced tokens are noticed
+const REFRESH_AHEAD_RECHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Background token refresh started by [`OAuthClient::spawn_refresh_ahead`]
+///
+/// The refresh task stops when this handle is dropped.
+#[derive(Debug)]
+pub struct RefreshAheadHandle {
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl RefreshAheadHandle {
+    /// Stop refreshing; same as dropping the handle
+    pub fn stop(self) {}
+}
+
+impl Drop for RefreshAheadHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Run a token request to completion even if the caller stops waiting for it
+async fn detach<T: Send + 'static>(
+    request: impl std::future::Future<Output = Result<T>> + Send + 'static,
+) -> Result<T> {
+    tokio::spawn(request)
+        .await
+        .map_err(|e| Error::Generic(format!("Token request task failed: {e}")))?
+}
+
+#[async_trait::async_trait]
+impl TokenProvider for OAuthClient {
+    async fn access_token(&self) -> Result<AccessToken> {
+        self.get_access_token().await
+    }
+
+    async fn refresh(&self) -> Result<AccessToken> {
+        let response = self.refresh_token().await?;
+        Ok(AccessToken::new(response.access_token))
+    }
+
+    /// Scopes from the token response, or from the introspection endpoint
+    /// when the response did not list them
+    async fn granted_scopes(&self) -> Result<Option<ScopeSet>> {
+        if let Some(scopes) = self
+            .token_store
+            .get()
+            .await
+            .and_then(|token_set| token_set.granted_scopes())
+        {
+            return Ok(Some(scopes));
+        }
+        let token = self.get_access_token().await?;
+        let introspection = self.introspect_token(token.as_str()).await?;
+        if !introspection.active {
+            return Err(Error::Auth("Access token is no longer active".to_string()));
+        }
+        Ok(introspection.scope.as_deref().map(ScopeSet::parse_lossy))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -830,4 +1394,359 @@ mod tests {
         assert!(pkce.code_verifier.len() >= 43);
         assert!(pkce.code_verifier.len() <= 128);
     }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_authorize_manual_exchanges_pasted_code() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/oauth/token")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+                mockito::Matcher::UrlEncoded("code".into(), "pasted-code".into()),
+            ]))
+            .with_body(
+                r#"{"access_token":"user-token","token_type":"Bearer","expires_in":3600,"refresh_token":"refresh"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = OAuthConfig::new(
+            "test-client-id",
+            "test-secret",
+            "https://example.com/callback",
+            vec![Scope::DesignMetaRead],
+        );
+        let client = OAuthClient::new(config).with_api_base_url(server.url());
+
+        let tokens = client
+            .authorize_manual(|url| async move {
+                let url = url::Url::parse(&url).expect("valid authorization URL");
+                let (_, state) = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "state")
+                    .expect("state parameter");
+                Ok(format!(
+                    "https://example.com/callback?code=pasted-code&state={state}"
+                ))
+            })
+            .await
+            .expect("Authorization should succeed");
+
+        assert_eq!(tokens.access_token().as_str(), "user-token");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_exchange_returns_tokens_when_persisting_fails() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/v1/oauth/token")
+            .with_body(r#"{"access_token":"m2m-token","token_type":"Bearer","expires_in":3600}"#)
+            .create_async()
+            .await;
+        // A path under a regular file can never be written
+        let blocker = std::env::temp_dir().join(format!("canva-blocker-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&blocker, b"").expect("create blocker file");
+        let storage = FileTokenStorage::new(blocker.join("tokens.json"), [3; 32]);
+        let failures = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let seen = Arc::clone(&failures);
+
+        let client = OAuthClient::with_token_store(
+            OAuthConfig::new("id", "secret", "http://127.0.0.1:8080/callback", vec![]),
+            storage,
+        )
+        .with_api_base_url(server.url())
+        .on_store_error(move |_| {
+            seen.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+
+        let response = client
+            .exchange_code_with_pkce("auth-code", &PkceParams::new())
+            .await
+            .expect("Exchange should succeed");
+
+        assert_eq!(response.access_token, "m2m-token");
+        assert_eq!(failures.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(
+            client.get_access_token().await.expect("Token in memory"),
+            AccessToken::new("m2m-token")
+        );
+        let _ = std::fs::remove_file(&blocker);
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_token_store_persists_to_storage() {
+        let path = std::env::temp_dir().join(format!("canva-store-{}.json", uuid::Uuid::new_v4()));
+        let store = TokenStore::with_storage(FileTokenStorage::new(&path, [3; 32]));
+        store
+            .try_store(TokenSet {
+                access_token: "persisted".to_string(),
+                refresh_token: None,
+                expires_at: None,
+                scope: None,
+            })
+            .await
+            .expect("Tokens should persist");
+
+        let restarted = OAuthClient::with_token_store(
+            OAuthConfig::new("id", "secret", "uri", vec![]),
+            FileTokenStorage::new(&path, [3; 32]),
+        );
+        assert!(restarted.token_store().get().await.is_none());
+        restarted
+            .token_store()
+            .load()
+            .await
+            .expect("Tokens should load");
+        assert_eq!(
+            restarted
+                .get_access_token()
+                .await
+                .expect("Token should be valid"),
+            AccessToken::new("persisted")
+        );
+
+        restarted
+            .token_store()
+            .try_clear()
+            .await
+            .expect("Tokens should clear");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_granted_scopes_compare_with_config() {
+        let config = OAuthConfig::new(
+            "id",
+            "secret",
+            "uri",
+            vec![Scope::DesignMetaRead, Scope::AssetWrite],
+        );
+        let token_set = TokenSet::from_exchange_response(TokenExchangeResponse {
+            access_token: "token".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: None,
+            refresh_token: None,
+            scope: Some("design:meta:read asset:read future:scope".to_string()),
+        });
+
+        let granted = token_set.granted_scopes().unwrap_or_default();
+        assert_eq!(granted.to_string(), "design:meta:read asset:read");
+        assert_eq!(
+            granted.missing(&config.scope_set()),
+            ScopeSet::from(vec![Scope::AssetWrite])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_token_set_json_keeps_wall_clock_expiry() {
+        let token_set = TokenSet::from_exchange_response(TokenExchangeResponse {
+            access_token: "access".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: Some(3600),
+            refresh_token: Some("refresh".to_string()),
+            scope: None,
+        });
+
+        let json = token_set.to_json().expect("serializes");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let expires_at = value["expires_at"].as_str().expect("RFC 3339 expiry");
+        let expires_at = chrono::DateTime::parse_from_rfc3339(expires_at).expect("valid timestamp");
+        let remaining = expires_at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+        assert!((3590..=3600).contains(&remaining.num_seconds()));
+        assert!(value.get("scope").is_none());
+
+        let restored = TokenSet::from_json(&json).expect("deserializes");
+        assert_eq!(restored.refresh_token.as_deref(), Some("refresh"));
+        assert!(!restored.expires_within(Duration::from_secs(3500)));
+        assert!(restored.expires_within(Duration::from_secs(3700)));
+
+        let expired =
+            TokenSet::from_json(r#"{"access_token":"a","expires_at":"2020-01-01T00:00:00Z"}"#)
+                .expect("deserializes");
+        assert!(expired.is_expired());
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_cancelled_refresh_still_stores_rotated_tokens() {
+        use futures::FutureExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let refresh = server
+            .mock("POST", "/v1/oauth/token")
+            .with_body(
+                r#"{"access_token":"new-access","token_type":"Bearer","expires_in":3600,"refresh_token":"new-refresh"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = OAuthClient::new(OAuthConfig::new("id", "secret", "uri", vec![]))
+            .with_api_base_url(server.url());
+        client
+            .token_store()
+            .store(TokenSet {
+                access_token: "old-access".to_string(),
+                refresh_token: Some("old-refresh".to_string()),
+                expires_at: None,
+                scope: None,
+            })
+            .await;
+
+        // Drop the refresh future right after it starts
+        assert!(client.refresh_token().now_or_never().is_none());
+
+        for _ in 0..100 {
+            let stored = client.token_store().get().await.expect("tokens present");
+            if stored.refresh_token.as_deref() == Some("new-refresh") {
+                assert_eq!(stored.access_token, "new-access");
+                refresh.assert_async().await;
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("rotated tokens were not stored after the refresh was cancelled");
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_concurrent_callers_share_one_refresh() {
+        let mut server = mockito::Server::new_async().await;
+        let refresh = server
+            .mock("POST", "/v1/oauth/token")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"refresh_token":"old-refresh"}"#.to_string(),
+            ))
+            .with_body(
+                r#"{"access_token":"new-access","token_type":"Bearer","expires_in":3600,"refresh_token":"new-refresh"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = OAuthClient::new(OAuthConfig::new("id", "secret", "uri", vec![]))
+            .with_api_base_url(server.url());
+        client
+            .token_store()
+            .store(TokenSet {
+                access_token: "old-access".to_string(),
+                refresh_token: Some("old-refresh".to_string()),
+                expires_at: Some(Instant::now()),
+                scope: None,
+            })
+            .await;
+
+        let tokens = futures::future::join_all((0..8).map(|_| client.get_access_token())).await;
+        for token in tokens {
+            assert_eq!(token.expect("access token").as_str(), "new-access");
+        }
+        let waited = client.refresh_from(Some("old-refresh".to_string())).await;
+        assert_eq!(
+            waited.expect("stored tokens").refresh_token.as_deref(),
+            Some("new-refresh")
+        );
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_refresh_ahead_refreshes_before_expiry() {
+        let mut server = mockito::Server::new_async().await;
+        let refresh = server
+            .mock("POST", "/v1/oauth/token")
+            .with_body(
+                r#"{"access_token":"new-access","token_type":"Bearer","expires_in":3600,"refresh_token":"new-refresh"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = OAuthClient::new(OAuthConfig::new("id", "secret", "uri", vec![]))
+            .with_api_base_url(server.url());
+        client
+            .token_store()
+            .store(TokenSet {
+                access_token: "old-access".to_string(),
+                refresh_token: Some("old-refresh".to_string()),
+                expires_at: Some(Instant::now() + Duration::from_secs(120)),
+                scope: None,
+            })
+            .await;
+
+        // Still valid, but inside the five minute lead
+        let handle = client.spawn_refresh_ahead(Duration::from_secs(300));
+        for _ in 0..100 {
+            let stored = client.token_store().get().await.expect("tokens present");
+            if stored.access_token == "new-access" {
+                handle.stop();
+                refresh.assert_async().await;
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("token was not refreshed ahead of expiry");
+    }
+
+    #[cfg(feature = "oauth-flow")]
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_authorize_with_completes_flow() {
+        let mut server = mockito::Server::new_async().await;
+        let token = server
+            .mock("POST", "/v1/oauth/token")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+                mockito::Matcher::UrlEncoded("code".into(), "code-123".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"{"access_token":"at","refresh_token":"rt","token_type":"Bearer","expires_in":3600}"#,
+            )
+            .create_async()
+            .await;
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("free port")
+            .port();
+        let redirect_uri = format!("http://127.0.0.1:{port}/callback");
+        let client = OAuthClient::new(OAuthConfig::new(
+            "client-id",
+            "secret",
+            redirect_uri.clone(),
+            vec![Scope::DesignMetaRead],
+        ))
+        .with_api_base_url(server.url());
+
+        let token_set = client
+            .authorize_with(
+                |url| {
+                    let url = url::Url::parse(url).expect("authorization URL");
+                    let state = url
+                        .query_pairs()
+                        .find(|(key, _)| key == "state")
+                        .map(|(_, value)| value.into_owned())
+                        .expect("state");
+                    tokio::spawn(async move {
+                        reqwest::get(format!("{redirect_uri}?code=code-123&state={state}"))
+                            .await
+                            .ok();
+                    });
+                },
+                Duration::from_secs(5),
+            )
+            .await
+            .expect("authorization");
+
+        assert_eq!(token_set.access_token, "at");
+        assert_eq!(token_set.refresh_token.as_deref(), Some("rt"));
+        assert_eq!(
+            client.get_access_token().await.expect("stored").as_str(),
+            "at"
+        );
+        token.assert_async().await;
+    }
 }
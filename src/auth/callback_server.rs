@@ -0,0 +1,345 @@
+//! Local HTTP server that receives the OAuth redirect.
+//!
+//! Requires the `oauth-flow` feature. Desktop and command-line apps register a
+//! loopback redirect URI such as `http://127.0.0.1:8080/callback`; after the
+//! user approves access, Canva redirects the browser there with an
+//! authorization code. [`listen_for_callback`] waits for the request carrying
+//! the expected `state`, turning any other away, and returns its code.
+//!
+//! Bind with [`CallbackServer::bind`] before showing the authorization URL if
+//! the browser might come back quickly, then [`wait`](CallbackServer::wait)
+//! for the code.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::auth::{callback_server::CallbackServer, OAuthClient, OAuthConfig, Scope};
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let redirect_uri = "http://127.0.0.1:8080/callback";
+//! let client = OAuthClient::new(OAuthConfig::new(
+//!     "client-id",
+//!     "client-secret",
+//!     redirect_uri,
+//!     vec![Scope::DesignMetaRead],
+//! ));
+//!
+//! let state = uuid::Uuid::new_v4().to_string();
+//! let (auth_url, pkce) = client.authorization_url(Some(&state))?;
+//! let server = CallbackServer::bind(redirect_uri, &state).await?;
+//! println!("Open {auth_url}");
+//!
+//! let code = server.wait(Duration::from_secs(300)).await?;
+//! client.exchange_code_with_pkce(&code, &pkce).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+const SUCCESS_PAGE: &str = "<h1>Authorization successful</h1>\
+<p>You can close this window and return to the application.</p>";
+
+/// Serve the OAuth redirect on `redirect_uri` and return the authorization code
+///
+/// Requests whose `state` does not match `expected_state` get a `400` and
+/// are otherwise ignored. Fails if the callback carries an `error` or if no
+/// matching callback arrives within `timeout`.
+pub async fn listen_for_callback(
+    redirect_uri: &str,
+    expected_state: &str,
+    timeout: Duration,
+) -> Result<String> {
+    CallbackServer::bind(redirect_uri, expected_state)
+        .await?
+        .wait(timeout)
+        .await
+}
+
+/// A listening OAuth callback server
+///
+/// The server stops when [`wait`](Self::wait) returns or the value is dropped.
+#[derive(Debug)]
+pub struct CallbackServer {
+    local_addr: SocketAddr,
+    outcome: oneshot::Receiver<Result<String>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl CallbackServer {
+    /// Start listening on the host and port of `redirect_uri`
+    ///
+    /// The redirect URI must use `http`; only requests to its path are
+    /// treated as the callback.
+    pub async fn bind(redirect_uri: &str, expected_state: &str) -> Result<Self> {
+        let url = url::Url::parse(redirect_uri)?;
+        if url.scheme() != "http" {
+            return Err(Error::Config(format!(
+                "OAuth callback server needs an http redirect URI, got {redirect_uri}"
+            )));
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| Error::Config(format!("Redirect URI {redirect_uri} has no host")))?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string();
+        let port = url.port_or_known_default().unwrap_or(80);
+
+        let listener = tokio::net::TcpListener::bind((host.as_str(), port)).await?;
+        let local_addr = listener.local_addr()?;
+        let builder = Server::from_tcp(listener.into_std()?)
+            .map_err(|e| Error::Generic(format!("Failed to start OAuth callback server: {e}")))?;
+
+        let (outcome_tx, outcome) = oneshot::channel();
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        let handler = Arc::new(Handler {
+            path: url.path().to_string(),
+            expected_state: expected_state.to_string(),
+            outcome: Mutex::new(Some(outcome_tx)),
+        });
+        let make_service = make_service_fn(move |_conn| {
+            let handler = handler.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let handler = handler.clone();
+                    async move { Ok::<_, Infallible>(handler.handle(&request)) }
+                }))
+            }
+        });
+        let server = builder.serve(make_service).with_graceful_shutdown(async {
+            shutdown_rx.await.ok();
+        });
+        tokio::spawn(server);
+
+        Ok(Self {
+            local_addr,
+            outcome,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Wait up to `timeout` for the callback and return the authorization code
+    pub async fn wait(mut self, timeout: Duration) -> Result<String> {
+        match tokio::time::timeout(timeout, &mut self.outcome).await {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(_)) => Err(Error::Auth(
+                "OAuth callback server stopped unexpectedly".to_string(),
+            )),
+            Err(_) => Err(Error::Auth(format!(
+                "No OAuth callback received within {}s",
+                timeout.as_secs()
+            ))),
+        }
+    }
+}
+
+impl Drop for CallbackServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Handler {
+    path: String,
+    expected_state: String,
+    outcome: Mutex<Option<oneshot::Sender<Result<String>>>>,
+}
+
+impl Handler {
+    fn handle(&self, request: &Request<Body>) -> Response<Body> {
+        if request.method() != Method::GET || request.uri().path() != self.path {
+            return page(StatusCode::NOT_FOUND, "<h1>Not found</h1>".to_string());
+        }
+        let params: Vec<(String, String)> =
+            url::form_urlencoded::parse(request.uri().query().unwrap_or("").as_bytes())
+                .into_owned()
+                .collect();
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        // Only a request carrying our state can complete the flow; anything
+        // else, such as a forged redirect, is turned away and we keep waiting
+        if param("state") != Some(self.expected_state.as_str()) {
+            return page(
+                StatusCode::BAD_REQUEST,
+                "<h1>Authorization failed</h1><p>The request state did not match.</p>".to_string(),
+            );
+        }
+
+        let (outcome, response) = if let Some(error) = param("error") {
+            let description = param("error_description").unwrap_or("No description given");
+            (
+                Err(Error::Auth(format!(
+                    "Authorization failed: {error}: {description}"
+                ))),
+                page(
+                    StatusCode::BAD_REQUEST,
+                    format!(
+                        "<h1>Authorization failed</h1><p>{}: {}</p>",
+                        escape_html(error),
+                        escape_html(description)
+                    ),
+                ),
+            )
+        } else if let Some(code) = param("code") {
+            (
+                Ok(code.to_string()),
+                page(StatusCode::OK, SUCCESS_PAGE.to_string()),
+            )
+        } else {
+            // Not a callback; keep waiting for the real one
+            return page(
+                StatusCode::BAD_REQUEST,
+                "<h1>Invalid callback</h1><p>The authorization code is missing.</p>".to_string(),
+            );
+        };
+
+        let sender = self
+            .outcome
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        match sender {
+            Some(sender) => {
+                sender.send(outcome).ok();
+                response
+            }
+            None => page(
+                StatusCode::GONE,
+                "<h1>Authorization already completed</h1>".to_string(),
+            ),
+        }
+    }
+}
+
+fn page(status: StatusCode, body: String) -> Response<Body> {
+    let html = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Canva authorization</title></head>\
+<body style=\"font-family: sans-serif; text-align: center; padding: 50px\">{body}</body></html>"
+    );
+    let mut response = Response::new(Body::from(html));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    response
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    async fn server(state: &str) -> (CallbackServer, String) {
+        let server = CallbackServer::bind("http://127.0.0.1:0/callback", state)
+            .await
+            .expect("bind");
+        let base = format!("http://{}", server.local_addr());
+        (server, base)
+    }
+
+    #[tokio::test]
+    async fn test_returns_code_for_matching_state() {
+        let (server, base) = server("s1").await;
+
+        let response = reqwest::get(format!("{base}/favicon.ico"))
+            .await
+            .expect("request");
+        assert_eq!(response.status(), 404);
+        let response = reqwest::get(format!("{base}/callback?code=abc&state=s1"))
+            .await
+            .expect("request");
+        assert_eq!(response.status(), 200);
+
+        let code = server.wait(Duration::from_secs(5)).await.expect("code");
+        assert_eq!(code, "abc");
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_state_does_not_end_the_wait() {
+        let (server, base) = server("s1").await;
+
+        for forged in [
+            "code=evil&state=forged",
+            "code=evil",
+            "error=access_denied&state=forged",
+            "error=access_denied",
+        ] {
+            let response = reqwest::get(format!("{base}/callback?{forged}"))
+                .await
+                .expect("request");
+            assert_eq!(response.status(), 400, "{forged}");
+        }
+        let response = reqwest::get(format!("{base}/callback?code=abc&state=s1"))
+            .await
+            .expect("request");
+        assert_eq!(response.status(), 200);
+
+        let code = server.wait(Duration::from_secs(5)).await.expect("code");
+        assert_eq!(code, "abc");
+    }
+
+    #[tokio::test]
+    async fn test_reports_denied_authorization() {
+        let (server, base) = server("s1").await;
+
+        let response = reqwest::get(format!(
+            "{base}/callback?error=access_denied&error_description=%3Cb%3Eno%3C%2Fb%3E&state=s1"
+        ))
+        .await
+        .expect("request");
+        let body = response.text().await.expect("body");
+        assert!(body.contains("&lt;b&gt;no&lt;/b&gt;"));
+
+        let result = server.wait(Duration::from_secs(5)).await;
+        assert!(matches!(result, Err(Error::Auth(message)) if message.contains("access_denied")));
+    }
+
+    #[tokio::test]
+    async fn test_times_out_without_callback() {
+        let (server, _) = server("s1").await;
+
+        let result = server.wait(Duration::from_millis(50)).await;
+
+        assert!(
+            matches!(result, Err(Error::Auth(message)) if message.contains("No OAuth callback"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_https_redirect_uri() {
+        let result = CallbackServer::bind("https://127.0.0.1:8080/callback", "s1").await;
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+}
@@ -0,0 +1,126 @@
+//! Authorizing without a local callback server.
+//!
+//! A program on a remote server cannot open a browser, and the browser the
+//! user signs in with cannot reach a loopback redirect URI on that server.
+//! [`OAuthClient::authorize_manual`] handles that case: it shows the user
+//! the authorization URL through a prompt function and waits for them to
+//! paste back what they got. After approving access the browser is sent to
+//! the redirect URI; even if that page fails to load, its address bar holds
+//! the code. The user can paste either the whole redirect URL or just the
+//! code.
+//!
+//! [`parse_authorization_response`] reads the pasted text. A redirect URL
+//! must carry the `state` of the authorization request, so a URL from an
+//! older or forged request is rejected.
+//!
+//! ```rust,no_run
+//! use canva_connect::auth::{manual::stdin_prompt, OAuthClient, OAuthConfig, Scope};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let oauth = OAuthClient::new(OAuthConfig::new(
+//!     "client-id",
+//!     "client-secret",
+//!     "https://example.com/canva/callback",
+//!     vec![Scope::DesignMetaRead],
+//! ));
+//! oauth.authorize_manual(stdin_prompt).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`OAuthClient::authorize_manual`]: super::OAuthClient::authorize_manual
+
+use crate::error::{Error, Result};
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+/// Read the authorization code from what the user pasted
+///
+/// `input` is either the full URL the browser was redirected to, its query
+/// string, or the bare code. Redirect URLs and query strings must carry
+/// `expected_state` and fail with [`Error::Auth`] if the user denied access
+/// or the state does not match. Surrounding whitespace is ignored.
+pub fn parse_authorization_response(input: &str, expected_state: &str) -> Result<String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(Error::Auth("No authorization code was entered".to_string()));
+    }
+    let query = match url::Url::parse(input) {
+        Ok(url) => url.query().unwrap_or_default().to_string(),
+        Err(_) if input.contains('=') => input.trim_start_matches('?').to_string(),
+        Err(_) => return Ok(input.to_string()),
+    };
+
+    let params: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+    let param = |name: &str| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    if let Some(error) = param("error") {
+        let description = param("error_description").unwrap_or("No description given");
+        return Err(Error::Auth(format!(
+            "Authorization failed: {error}: {description}"
+        )));
+    }
+    if param("state") != Some(expected_state) {
+        return Err(Error::Auth(
+            "Pasted redirect URL does not match the authorization request".to_string(),
+        ));
+    }
+    param("code")
+        .map(str::to_string)
+        .ok_or_else(|| Error::Auth("Pasted redirect URL has no authorization code".to_string()))
+}
+
+/// Prompt on the terminal: print `url` to standard error and read one line from standard input
+///
+/// Pass this to [`OAuthClient::authorize_manual`](super::OAuthClient::authorize_manual)
+/// for a command-line tool run over SSH.
+pub async fn stdin_prompt(url: String) -> Result<String> {
+    eprintln!("Open this URL in a browser to authorize access to Canva:\n{url}");
+    eprintln!("Then paste the URL you were redirected to, or the code, and press Enter:");
+    let mut line = String::new();
+    BufReader::new(tokio::io::stdin())
+        .read_line(&mut line)
+        .await?;
+    Ok(line)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_redirect_url_query_string_and_bare_code() {
+        let url = "https://example.com/callback?code=abc%2F1&state=s1\n";
+        assert_eq!(
+            parse_authorization_response(url, "s1").expect("valid URL"),
+            "abc/1"
+        );
+        assert_eq!(
+            parse_authorization_response("?state=s1&code=abc", "s1").expect("valid query"),
+            "abc"
+        );
+        assert_eq!(
+            parse_authorization_response("  abc  ", "s1").expect("bare code"),
+            "abc"
+        );
+    }
+
+    #[test]
+    fn test_rejects_mismatched_state_and_denied_access() {
+        let forged = parse_authorization_response("https://example.com/cb?code=abc&state=s2", "s1");
+        assert!(matches!(forged, Err(Error::Auth(message)) if message.contains("does not match")));
+
+        let denied = parse_authorization_response(
+            "https://example.com/cb?error=access_denied&state=s1",
+            "s1",
+        );
+        assert!(matches!(denied, Err(Error::Auth(message)) if message.contains("access_denied")));
+    }
+}
@@ -1,7 +1,14 @@
 //! OAuth scopes documentation for the Canva Connect API.
 //!
 //! This module documents all available OAuth scopes that can be requested
-//! when authenticating with the Canva Connect API.
+//! when authenticating with the Canva Connect API, and provides [`ScopeSet`]
+//! for working with the space-separated scope lists used on the wire.
+
+use super::Scope;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 /// All available OAuth scopes for the Canva Connect API
 ///
@@ -151,3 +158,243 @@ pub mod oauth_scopes {
     /// Profile-related scopes
     pub const PROFILE_READ: &str = "profile:read";
 }
+
+/// A deduplicated set of [`Scope`]s that keeps insertion order
+///
+/// Parses from and displays as the space-separated format used in OAuth
+/// requests and token responses, and serializes the same way.
+///
+/// ```rust
+/// use canva_connect::auth::{Scope, ScopeSet};
+///
+/// let requested: ScopeSet = "design:meta:read asset:read".parse().unwrap();
+/// let granted: ScopeSet = "design:meta:read".parse().unwrap();
+///
+/// assert_eq!(granted.missing(&requested).to_string(), "asset:read");
+/// assert!(requested.contains(Scope::AssetRead));
+/// ```
+#[derive(Debug, Clone, Default, Eq)]
+pub struct ScopeSet {
+    scopes: Vec<Scope>,
+}
+
+impl ScopeSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a space-separated scope list, skipping scopes this crate does not know
+    ///
+    /// Use this for scopes granted by the server, which may include scopes
+    /// added to the API after this crate was released.
+    pub fn parse_lossy(s: &str) -> Self {
+        s.split_whitespace()
+            .filter_map(|scope| scope.parse().ok())
+            .collect()
+    }
+
+    /// Add a scope, returning `false` if it was already present
+    pub fn insert(&mut self, scope: Scope) -> bool {
+        if self.contains(scope.clone()) {
+            return false;
+        }
+        self.scopes.push(scope);
+        true
+    }
+
+    /// Whether the set contains `scope`
+    pub fn contains(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    /// Whether every scope in `other` is in this set
+    pub fn contains_all(&self, other: &ScopeSet) -> bool {
+        other.iter().all(|scope| self.scopes.contains(scope))
+    }
+
+    /// Scopes in either set, this set's first
+    pub fn union(&self, other: &ScopeSet) -> ScopeSet {
+        self.iter().chain(other.iter()).cloned().collect()
+    }
+
+    /// Scopes in `required` that this set lacks
+    pub fn missing(&self, required: &ScopeSet) -> ScopeSet {
+        required
+            .iter()
+            .filter(|scope| !self.scopes.contains(scope))
+            .cloned()
+            .collect()
+    }
+
+    /// Iterate over the scopes in insertion order
+    pub fn iter(&self) -> std::slice::Iter<'_, Scope> {
+        self.scopes.iter()
+    }
+
+    /// Number of scopes in the set
+    pub fn len(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Whether the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.scopes.is_empty()
+    }
+}
+
+/// Sets are equal when they contain the same scopes, in any order
+impl PartialEq for ScopeSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.contains_all(other)
+    }
+}
+
+impl FromIterator<Scope> for ScopeSet {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        let mut set = ScopeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Scope> for ScopeSet {
+    fn extend<I: IntoIterator<Item = Scope>>(&mut self, iter: I) {
+        for scope in iter {
+            self.insert(scope);
+        }
+    }
+}
+
+impl From<Vec<Scope>> for ScopeSet {
+    fn from(scopes: Vec<Scope>) -> Self {
+        scopes.into_iter().collect()
+    }
+}
+
+impl IntoIterator for ScopeSet {
+    type Item = Scope;
+    type IntoIter = std::vec::IntoIter<Scope>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scopes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ScopeSet {
+    type Item = &'a Scope;
+    type IntoIter = std::slice::Iter<'a, Scope>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scopes.iter()
+    }
+}
+
+impl fmt::Display for ScopeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, scope) in self.scopes.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(scope.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ScopeSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.split_whitespace().map(Scope::from_str).collect()
+    }
+}
+
+impl TryFrom<&str> for ScopeSet {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl Serialize for ScopeSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScopeSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_round_trips_through_str() {
+        for scope in Scope::ALL {
+            assert_eq!(scope.as_str().parse::<Scope>().expect("known scope"), scope);
+        }
+        assert!(Scope::try_from("design:everything").is_err());
+    }
+
+    #[test]
+    fn test_parse_dedupes_and_keeps_order() {
+        let set: ScopeSet = "  folder:read asset:read\tfolder:read "
+            .parse()
+            .expect("valid scopes");
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.to_string(), "folder:read asset:read");
+        assert!("".parse::<ScopeSet>().expect("empty is valid").is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_scope() {
+        assert!("asset:read made:up".parse::<ScopeSet>().is_err());
+        assert_eq!(
+            ScopeSet::parse_lossy("asset:read made:up"),
+            ScopeSet::from(vec![Scope::AssetRead])
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let granted = ScopeSet::from(vec![Scope::AssetRead, Scope::DesignMetaRead]);
+        let required = ScopeSet::from(vec![Scope::DesignMetaRead, Scope::FolderWrite]);
+
+        assert!(granted.contains(Scope::AssetRead));
+        assert!(!granted.contains_all(&required));
+        assert_eq!(
+            granted.missing(&required),
+            ScopeSet::from(vec![Scope::FolderWrite])
+        );
+        assert_eq!(
+            granted.union(&required).to_string(),
+            "asset:read design:meta:read folder:write"
+        );
+    }
+
+    #[test]
+    fn test_equality_ignores_order() {
+        let a = ScopeSet::from(vec![Scope::AssetRead, Scope::FolderRead]);
+        let b = ScopeSet::from(vec![Scope::FolderRead, Scope::AssetRead]);
+        assert_eq!(a, b);
+        assert_ne!(a, ScopeSet::from(vec![Scope::AssetRead]));
+    }
+
+    #[test]
+    fn test_serde_uses_wire_format() {
+        let set = ScopeSet::from(vec![Scope::CommentRead, Scope::CommentWrite]);
+        let json = serde_json::to_string(&set).expect("serializable");
+        assert_eq!(json, r#""comment:read comment:write""#);
+        let parsed: ScopeSet = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(parsed, set);
+        assert!(serde_json::from_str::<ScopeSet>(r#""nope""#).is_err());
+    }
+}
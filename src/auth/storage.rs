@@ -0,0 +1,451 @@
+//! Persistent storage backends for OAuth tokens.
+//!
+//! A [`TokenStore`](super::TokenStore) keeps tokens in memory. Give it a
+//! [`TokenStorage`] backend and every stored or cleared token set is also
+//! written to that backend, so command-line tools keep their tokens across
+//! restarts.
+//!
+//! - [`FileTokenStorage`] - AES-256-GCM encrypted JSON file
+//! - `KeyringTokenStorage` - the operating system keyring (requires the
+//!   `keyring` feature)
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::auth::{FileTokenStorage, OAuthClient, OAuthConfig, TokenStore};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let config = OAuthConfig::new("id", "secret", "uri", vec![]);
+//! let passphrase = std::env::var("CANVA_TOKEN_PASSPHRASE")?;
+//! let storage = FileTokenStorage::with_passphrase("tokens.json", &passphrase);
+//! let store = TokenStore::with_storage(storage);
+//!
+//! // Pick up tokens saved by a previous run
+//! store.load().await?;
+//!
+//! let client = OAuthClient::with_token_store(config, store);
+//! let token = client.get_access_token().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::TokenSet;
+use crate::error::{Error, Result};
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A place to persist OAuth tokens between runs
+#[async_trait::async_trait]
+pub trait TokenStorage: fmt::Debug + Send + Sync {
+    /// Load the saved token set, or `None` if nothing has been saved
+    async fn load(&self) -> Result<Option<TokenSet>>;
+
+    /// Save a token set, replacing any previous one
+    async fn save(&self, token_set: &TokenSet) -> Result<()>;
+
+    /// Remove the saved token set
+    async fn clear(&self) -> Result<()>;
+}
+
+/// On-disk layout of an encrypted token file
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedFile {
+    version: u32,
+    /// Argon2id salt, present when the key was derived from a passphrase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    salt: Option<String>,
+    nonce: String,
+    ciphertext: String,
+}
+
+const FILE_VERSION: u32 = 1;
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+
+/// Where a [`FileTokenStorage`] gets its encryption key
+#[derive(Clone)]
+enum KeySource {
+    Key([u8; 32]),
+    Passphrase(String),
+}
+
+/// Token storage in an AES-256-GCM encrypted JSON file
+///
+/// The file is written atomically and, on Unix, readable only by its owner.
+/// Loading a file written with a different key fails with [`Error::Auth`].
+#[derive(Clone)]
+pub struct FileTokenStorage {
+    path: PathBuf,
+    key: KeySource,
+}
+
+impl FileTokenStorage {
+    /// Store tokens at `path`, encrypted with a 256-bit key
+    pub fn new(path: impl Into<PathBuf>, key: [u8; 32]) -> Self {
+        Self {
+            path: path.into(),
+            key: KeySource::Key(key),
+        }
+    }
+
+    /// Store tokens at `path`, encrypted with a key derived from `passphrase`
+    ///
+    /// The key is derived with Argon2id and a random salt, stored in the
+    /// file and replaced on every save, so guessing the passphrase from a
+    /// stolen file is slow. Deriving the key takes a noticeable moment on
+    /// each load and save.
+    pub fn with_passphrase(path: impl Into<PathBuf>, passphrase: &str) -> Self {
+        Self {
+            path: path.into(),
+            key: KeySource::Passphrase(passphrase.to_string()),
+        }
+    }
+
+    /// Path of the token file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The cipher for a file with `salt`, deriving the key from the passphrase if there is one
+    async fn cipher(&self, salt: Option<Vec<u8>>) -> Result<Aes256Gcm> {
+        let key = match (&self.key, salt) {
+            (KeySource::Key(key), None) => *key,
+            (KeySource::Passphrase(passphrase), Some(salt)) => {
+                let passphrase = passphrase.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut key = [0u8; 32];
+                    Argon2::default()
+                        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+                        .map(|()| key)
+                        .map_err(|e| Error::Auth(format!("Failed to derive token key: {e}")))
+                })
+                .await
+                .map_err(|e| Error::Generic(format!("Key derivation task failed: {e}")))??
+            }
+            (KeySource::Key(_), Some(_)) => {
+                return Err(Error::Auth(
+                    "Stored tokens are encrypted with a passphrase, not a key".to_string(),
+                ))
+            }
+            (KeySource::Passphrase(_), None) => {
+                return Err(Error::Auth(
+                    "Stored tokens are encrypted with a key, not a passphrase".to_string(),
+                ))
+            }
+        };
+        Ok(Aes256Gcm::new(&key.into()))
+    }
+
+    async fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedFile> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let salt = matches!(self.key, KeySource::Passphrase(_)).then(|| {
+            let mut salt = vec![0u8; SALT_LEN];
+            rand::thread_rng().fill_bytes(&mut salt);
+            salt
+        });
+        let ciphertext = self
+            .cipher(salt.clone())
+            .await?
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| Error::Auth("Failed to encrypt stored tokens".to_string()))?;
+
+        Ok(EncryptedFile {
+            version: FILE_VERSION,
+            salt: salt.map(|salt| STANDARD.encode(salt)),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    async fn decrypt(&self, file: &EncryptedFile) -> Result<Vec<u8>> {
+        if file.version != FILE_VERSION {
+            return Err(Error::Auth(format!(
+                "Unsupported token file version {}",
+                file.version
+            )));
+        }
+        let invalid = || Error::Auth("Stored tokens are corrupted".to_string());
+        let salt = file
+            .salt
+            .as_ref()
+            .map(|salt| STANDARD.decode(salt).map_err(|_| invalid()))
+            .transpose()?;
+        let nonce = STANDARD.decode(&file.nonce).map_err(|_| invalid())?;
+        let ciphertext = STANDARD.decode(&file.ciphertext).map_err(|_| invalid())?;
+        if nonce.len() != NONCE_LEN {
+            return Err(invalid());
+        }
+
+        self.cipher(salt)
+            .await?
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| {
+                Error::Auth(
+                    "Failed to decrypt stored tokens: wrong key or corrupted file".to_string(),
+                )
+            })
+    }
+}
+
+impl fmt::Debug for FileTokenStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileTokenStorage")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStorage for FileTokenStorage {
+    async fn load(&self) -> Result<Option<TokenSet>> {
+        let contents = match tokio::fs::read(&self.path).await {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let file: EncryptedFile = serde_json::from_slice(&contents)?;
+        let plaintext = self.decrypt(&file).await?;
+        Ok(Some(serde_json::from_slice(&plaintext)?))
+    }
+
+    async fn save(&self, token_set: &TokenSet) -> Result<()> {
+        let plaintext = serde_json::to_vec(token_set)?;
+        let contents = serde_json::to_vec_pretty(&self.encrypt(&plaintext).await?)?;
+
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // Write to a sibling file and rename, so a crash never leaves a torn file
+        let mut temp_name = self.path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = self.path.with_file_name(temp_name);
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&temp_path).await?;
+        tokio::io::AsyncWriteExt::write_all(&mut file, &contents).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        tokio::fs::rename(&temp_path, &self.path).await?;
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<()> {
+        match tokio::fs::remove_file(&self.path).await {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Token storage in the operating system keyring
+///
+/// Uses the macOS Keychain, the Windows Credential Manager or the Linux
+/// kernel keyring. Requires the `keyring` feature.
+#[cfg(feature = "keyring")]
+#[derive(Debug, Clone)]
+pub struct KeyringTokenStorage {
+    service: String,
+    user: String,
+}
+
+#[cfg(feature = "keyring")]
+impl KeyringTokenStorage {
+    /// Store tokens under the given keyring service and user names
+    pub fn new(service: impl Into<String>, user: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            user: user.into(),
+        }
+    }
+
+    async fn with_entry<T, F>(&self, operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(keyring::Entry) -> keyring::Result<T> + Send + 'static,
+    {
+        let service = self.service.clone();
+        let user = self.user.clone();
+        // Keyring backends make blocking system calls
+        tokio::task::spawn_blocking(move || {
+            keyring::Entry::new(&service, &user).and_then(operation)
+        })
+        .await
+        .map_err(|e| Error::Generic(format!("Keyring task failed: {e}")))?
+        .map_err(|e| Error::Auth(format!("Keyring error: {e}")))
+    }
+}
+
+#[cfg(feature = "keyring")]
+#[async_trait::async_trait]
+impl TokenStorage for KeyringTokenStorage {
+    async fn load(&self) -> Result<Option<TokenSet>> {
+        let secret = self
+            .with_entry(|entry| match entry.get_password() {
+                Ok(secret) => Ok(Some(secret)),
+                Err(keyring::Error::NoEntry) => Ok(None),
+                Err(error) => Err(error),
+            })
+            .await?;
+        match secret {
+            Some(secret) => Ok(Some(TokenSet::from_json(&secret)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, token_set: &TokenSet) -> Result<()> {
+        let secret = token_set.to_json()?;
+        self.with_entry(move |entry| entry.set_password(&secret))
+            .await
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.with_entry(|entry| match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            result => result,
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn token_set() -> TokenSet {
+        TokenSet {
+            access_token: "access".to_string(),
+            refresh_token: Some("refresh".to_string()),
+            expires_at: Some(Instant::now() + Duration::from_secs(3600)),
+            scope: Some("design:meta:read".to_string()),
+        }
+    }
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir().join(format!("canva-tokens-{}.json", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_file_storage_round_trip() {
+        let path = temp_path();
+        let storage = FileTokenStorage::with_passphrase(&path, "passphrase");
+
+        assert!(storage.load().await.expect("load").is_none());
+        storage.save(&token_set()).await.expect("save");
+
+        let on_disk = std::fs::read_to_string(&path).expect("file written");
+        assert!(!on_disk.contains("access"));
+        assert!(!on_disk.contains("refresh"));
+
+        let loaded = storage.load().await.expect("load").expect("tokens saved");
+        assert_eq!(loaded.access_token, "access");
+        assert_eq!(loaded.refresh_token.as_deref(), Some("refresh"));
+        assert_eq!(loaded.scope.as_deref(), Some("design:meta:read"));
+        assert!(!loaded.expires_within(Duration::from_secs(3500)));
+        assert!(loaded.expires_within(Duration::from_secs(3700)));
+
+        storage.clear().await.expect("clear");
+        assert!(!path.exists());
+        storage.clear().await.expect("clearing twice is fine");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_file_storage_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = temp_path();
+        let storage = FileTokenStorage::new(&path, [7; 32]);
+        storage.save(&token_set()).await.expect("save");
+
+        let mode = std::fs::metadata(&path)
+            .expect("metadata")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_file_storage_rejects_wrong_key() {
+        let path = temp_path();
+        FileTokenStorage::new(&path, [1; 32])
+            .save(&token_set())
+            .await
+            .expect("save");
+
+        let result = FileTokenStorage::new(&path, [2; 32]).load().await;
+        assert!(matches!(result, Err(Error::Auth(_))));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_passphrase_key_is_salted_per_save() {
+        let path = temp_path();
+        let storage = FileTokenStorage::with_passphrase(&path, "passphrase");
+        let read_salt = || {
+            let file: EncryptedFile =
+                serde_json::from_slice(&std::fs::read(&path).expect("file written"))
+                    .expect("layout");
+            file.salt.expect("passphrase files carry a salt")
+        };
+
+        storage.save(&token_set()).await.expect("save");
+        let first = read_salt();
+        storage.save(&token_set()).await.expect("save");
+        assert_ne!(read_salt(), first);
+
+        let wrong = FileTokenStorage::with_passphrase(&path, "wrong passphrase");
+        assert!(matches!(wrong.load().await, Err(Error::Auth(_))));
+        let key = FileTokenStorage::new(&path, [0; 32]);
+        assert!(matches!(key.load().await, Err(Error::Auth(_))));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_expired_tokens_stay_expired() {
+        let mut expired = token_set();
+        expired.expires_at = Some(Instant::now());
+
+        let plaintext = serde_json::to_vec(&expired).expect("serialize");
+        let restored: TokenSet = serde_json::from_slice(&plaintext).expect("deserialize");
+        assert!(restored.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_file_storage_uses_token_set_json() {
+        let path = temp_path();
+        let storage = FileTokenStorage::new(&path, [3; 32]);
+        storage.save(&token_set()).await.expect("save");
+
+        let file: EncryptedFile =
+            serde_json::from_slice(&std::fs::read(&path).expect("file written")).expect("layout");
+        let plaintext = storage.decrypt(&file).await.expect("decrypt");
+        let value: serde_json::Value = serde_json::from_slice(&plaintext).expect("JSON");
+        let expires_at = value["expires_at"].as_str().expect("RFC 3339 expiry");
+        assert!(chrono::DateTime::parse_from_rfc3339(expires_at).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_debug_hides_key() {
+        let storage = FileTokenStorage::new("tokens.json", [42; 32]);
+        let debug = format!("{storage:?}");
+        assert!(debug.contains("tokens.json"));
+        assert!(!debug.contains("42"));
+    }
+}
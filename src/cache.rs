@@ -0,0 +1,333 @@
+//! Response caching with conditional requests.
+//!
+//! Designs, assets and folders are often fetched again and again even though
+//! they rarely change. With a cache configured, the client stores the body of
+//! each successful `GET` response keyed by access token and URL (see
+//! [`Client::cache_key`](crate::Client::cache_key)), and:
+//!
+//! - serves it without a request while it is younger than the
+//!   [TTL](CacheConfig::with_ttl);
+//! - afterwards revalidates it by sending `If-None-Match` with the stored
+//!   `ETag`; a `304 Not Modified` reply serves the stored body again;
+//! - drops it when a `POST`, `PUT`, `PATCH` or `DELETE` is sent to the same URL.
+//!
+//! Responses without an `ETag` are only cached when the TTL is non-zero.
+//! Job status endpoints such as `GET /v1/exports/{jobId}` are never cached,
+//! since polling them must see each change of status.
+//!
+//! The default store is [`MemoryCache`], a bounded least-recently-used map.
+//! Implement [`ResponseCache`] to keep responses somewhere else.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, cache::CacheConfig};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder(AccessToken::new("your-token"))
+//!     .cache(
+//!         CacheConfig::new()
+//!             .with_ttl(Duration::from_secs(30))
+//!             .with_max_entries(1_000),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, ETAG};
+use reqwest::StatusCode;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default number of responses kept by [`MemoryCache`]
+pub const DEFAULT_MAX_ENTRIES: usize = 256;
+
+/// A stored `GET` response
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// Response status
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Vec<u8>,
+    /// When the response was stored or last revalidated
+    pub stored_at: Instant,
+}
+
+impl CachedResponse {
+    /// The `ETag` the server sent with the response, if any
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get(ETAG).and_then(|v| v.to_str().ok())
+    }
+
+    /// Whether the response was stored or revalidated less than `ttl` ago
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at.elapsed() < ttl
+    }
+
+    pub(crate) fn to_response(&self) -> reqwest::Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers.clone();
+        response.into()
+    }
+}
+
+/// A place to keep cached responses
+///
+/// Implementations are shared between clones of a client and must be safe to
+/// call concurrently. A store that cannot reach its backend should behave as
+/// if the entry is missing rather than fail the request.
+#[async_trait::async_trait]
+pub trait ResponseCache: fmt::Debug + Send + Sync {
+    /// Look up the response stored for `key`
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Store a response, replacing any previous one for `key`
+    async fn put(&self, key: &str, response: CachedResponse);
+
+    /// Drop the response stored for `key`
+    async fn remove(&self, key: &str);
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, CachedResponse>,
+    // Least recently used key at the front
+    order: VecDeque<String>,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|k| k == key) {
+            if let Some(key) = self.order.remove(position) {
+                self.order.push_back(key);
+            }
+        }
+    }
+
+    fn forget(&mut self, key: &str) {
+        self.entries.remove(key);
+        self.order.retain(|k| k != key);
+    }
+}
+
+/// In-memory [`ResponseCache`] that evicts the least recently used entry
+/// once it holds `max_entries` responses
+#[derive(Debug)]
+pub struct MemoryCache {
+    max_entries: usize,
+    state: Mutex<LruState>,
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `max_entries` responses (at least one)
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Maximum number of responses kept
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Number of responses currently stored
+    pub fn len(&self) -> usize {
+        self.state.lock().map(|s| s.entries.len()).unwrap_or(0)
+    }
+
+    /// Whether no responses are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every stored response
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = LruState::default();
+        }
+    }
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES)
+    }
+}
+
+#[async_trait::async_trait]
+impl ResponseCache for MemoryCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut state = self.state.lock().ok()?;
+        let response = state.entries.get(key).cloned()?;
+        state.touch(key);
+        Some(response)
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.entries.insert(key.to_string(), response).is_some() {
+            state.touch(key);
+            return;
+        }
+        state.order.push_back(key.to_string());
+        while state.entries.len() > self.max_entries {
+            let Some(oldest) = state.order.pop_front() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+    }
+
+    async fn remove(&self, key: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.forget(key);
+        }
+    }
+}
+
+/// Shares one store between clients, for example clients acting for
+/// different users
+#[async_trait::async_trait]
+impl<T: ResponseCache + ?Sized> ResponseCache for Arc<T> {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        (**self).get(key).await
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        (**self).put(key, response).await
+    }
+
+    async fn remove(&self, key: &str) {
+        (**self).remove(key).await
+    }
+}
+
+/// Response cache settings for [`ClientBuilder::cache`](crate::ClientBuilder::cache)
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    ttl: Duration,
+    max_entries: usize,
+    store: Option<Arc<dyn ResponseCache>>,
+}
+
+impl CacheConfig {
+    /// Revalidate every cached response, keeping up to
+    /// [`DEFAULT_MAX_ENTRIES`] responses in a [`MemoryCache`]
+    pub fn new() -> Self {
+        Self {
+            ttl: Duration::ZERO,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            store: None,
+        }
+    }
+
+    /// Serve cached responses without contacting the API for `ttl`
+    ///
+    /// A response served this way may be up to `ttl` out of date.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Keep at most `max_entries` responses in the default [`MemoryCache`]
+    ///
+    /// Ignored when a custom store is set with [`with_store`](Self::with_store).
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Keep responses in `store` instead of a [`MemoryCache`]
+    pub fn with_store(mut self, store: impl ResponseCache + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// How long a response is served without revalidation
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Maximum number of responses in the default [`MemoryCache`]
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    pub(crate) fn into_store(self) -> (Arc<dyn ResponseCache>, Duration) {
+        let store = self
+            .store
+            .unwrap_or_else(|| Arc::new(MemoryCache::new(self.max_entries)));
+        (store, self.ttl)
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn response(body: &str) -> CachedResponse {
+        CachedResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: body.as_bytes().to_vec(),
+            stored_at: Instant::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        cache.put("a", response("a")).await;
+        cache.put("b", response("b")).await;
+        // Reading "a" makes "b" the oldest entry
+        assert!(cache.get("a").await.is_some());
+        cache.put("c", response("c")).await;
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("b").await.is_none());
+        assert_eq!(cache.get("a").await.expect("a").body, b"a");
+        assert_eq!(cache.get("c").await.expect("c").body, b"c");
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_replaces_and_removes() {
+        let cache = MemoryCache::new(2);
+        cache.put("a", response("old")).await;
+        cache.put("a", response("new")).await;
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("a").await.expect("a").body, b"new");
+
+        cache.remove("a").await;
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_cached_response_freshness_and_etag() {
+        let mut cached = response("{}");
+        assert_eq!(cached.etag(), None);
+        cached
+            .headers
+            .insert(ETAG, "\"v1\"".parse().expect("header"));
+        assert_eq!(cached.etag(), Some("\"v1\""));
+        assert!(cached.is_fresh(Duration::from_secs(60)));
+        assert!(!cached.is_fresh(Duration::ZERO));
+    }
+}
@@ -3,6 +3,12 @@
 //! This module provides the main [`Client`] struct that handles all API communication,
 //! including authentication, rate limiting, and endpoint access.
 //!
+//! Every request passes through the same pipeline: header providers, rate
+//! limiting, request interceptors, the [`HttpTransport`], response
+//! interceptors, then retries and error handling. Hook into it with
+//! [`HeaderProvider`], [`RequestInterceptor`] and [`ResponseInterceptor`] to
+//! add headers, log or collect metrics without wrapping the client.
+//!
 //! ## Examples
 //!
 //! ```rust,no_run
@@ -18,59 +24,977 @@
 //! ```
 
 use crate::{
-    auth::AccessToken,
-    endpoints::*,
-    error::{ApiError, Error, Result},
-    rate_limit::{ApiRateLimiter, RateLimitInfo},
-    BASE_URL,
+    auth::{AccessToken, OAuthClient, ScopeSet, TokenProvider},
+    cache::{CacheConfig, CachedResponse, ResponseCache},
+    config::{validate_settings, CanvaConfig, ClientSettings, PollingProfile},
+    debug_log::{DebugLogConfig, DebugLogger},
+    downloads::Downloader,
+    endpoints::{user::UserCapabilities, *},
+    error::{ApiError, ApiErrorCode, Error, RequestContext, Result},
+    event_log::{ApiEvent, EventLog},
+    jobs::{CancellationToken, JobPollConfig, JobPoller},
+    operations::Operation,
+    query::QueryBuilder,
+    rate_limit::{
+        self, ApiRateLimiter, EndpointRateLimits, Priority, RateLimitInfo, ThrottleEvent,
+        ThrottleListener,
+    },
+    retry::RetryPolicy,
+    uploads::{self, UploadCandidate, UploadPrecheck, UploadSource},
+    API_VERSION, BASE_URL,
+};
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH, USER_AGENT,
 };
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Default `User-Agent` header sent with every request
+pub const DEFAULT_USER_AGENT: &str = "canva-connect-rust/0.1.0";
+
+/// Bytes of an unparseable response body kept in [`Error::Deserialization`]
+pub const DEFAULT_CAPTURED_BODY_LIMIT: usize = 4 * 1024;
+
+/// Sends HTTP requests on behalf of a [`Client`]
+///
+/// The client builds each request, adds authentication and applies rate
+/// limiting and retries, then hands the request to its transport. The default
+/// transport is the client's own [`reqwest::Client`]; supply another with
+/// [`ClientBuilder::transport`] to route requests elsewhere, for example to
+/// `MockTransport` from the `testing` module (requires the `test-util`
+/// feature) in unit tests. To avoid `reqwest` types altogether, implement
+/// [`CanvaTransport`] instead.
+#[async_trait::async_trait]
+pub trait HttpTransport: std::fmt::Debug + Send + Sync {
+    /// Send a request and return the response, whatever its status
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response>;
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for reqwest::Client {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        Ok(reqwest::Client::execute(self, request).await?)
+    }
+}
+
+/// Sends requests as plain [`http`] types, independent of `reqwest`
+///
+/// Implement this instead of [`HttpTransport`] to back a [`Client`] with
+/// another HTTP stack, such as `hyper` on its own, or with an in-process
+/// fake. Every `CanvaTransport` is an [`HttpTransport`], so it plugs in with
+/// [`ClientBuilder::transport`]. Request and response bodies are buffered in
+/// memory.
+///
+/// ```rust
+/// use canva_connect::{Client, auth::AccessToken, client::CanvaTransport};
+///
+/// #[derive(Debug)]
+/// struct Fake;
+///
+/// #[async_trait::async_trait]
+/// impl CanvaTransport for Fake {
+///     async fn send(
+///         &self,
+///         request: http::Request<Vec<u8>>,
+///     ) -> canva_connect::Result<http::Response<Vec<u8>>> {
+///         assert_eq!(request.uri().path(), "/rest/v1/users/me");
+///         let body = br#"{"team_user":{"user_id":"u1","team_id":"t1"}}"#.to_vec();
+///         Ok(http::Response::new(body))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder(AccessToken::new("token"))
+///     .transport(Fake)
+///     .build()?;
+/// assert_eq!(client.user().get_me().await?.user_id, "u1");
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait CanvaTransport: std::fmt::Debug + Send + Sync {
+    /// Send a request and return the response, whatever its status
+    async fn send(&self, request: http::Request<Vec<u8>>) -> Result<http::Response<Vec<u8>>>;
+}
+
+#[async_trait::async_trait]
+impl<T: CanvaTransport> HttpTransport for T {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let body = match request.body() {
+            None => Vec::new(),
+            Some(body) => body.as_bytes().map(<[u8]>::to_vec).ok_or_else(|| {
+                Error::Generic(format!(
+                    "Streamed body of {} {} cannot be sent through a CanvaTransport",
+                    request.method(),
+                    request.url().path()
+                ))
+            })?,
+        };
+        let mut builder = http::Request::builder()
+            .method(request.method().clone())
+            .uri(request.url().as_str())
+            .version(request.version());
+        if let Some(headers) = builder.headers_mut() {
+            *headers = request.headers().clone();
+        }
+        let request = builder
+            .body(body)
+            .map_err(|e| Error::Generic(format!("Invalid request for CanvaTransport: {e}")))?;
+        Ok(self.send(request).await?.into())
+    }
+}
+
+/// Inspects or changes every request before it is sent
+///
+/// Interceptors run in the order they were added with
+/// [`ClientBuilder::request_interceptor`], once per attempt, after the
+/// `Authorization` header has been set. Returning an error aborts the request
+/// with that error.
+///
+/// ```rust
+/// use canva_connect::{client::RequestInterceptor, Client, auth::AccessToken};
+///
+/// #[derive(Debug)]
+/// struct TenantHeader(&'static str);
+///
+/// #[async_trait::async_trait]
+/// impl RequestInterceptor for TenantHeader {
+///     async fn on_request(&self, request: &mut reqwest::Request) -> canva_connect::Result<()> {
+///         request
+///             .headers_mut()
+///             .insert("x-tenant", reqwest::header::HeaderValue::from_static(self.0));
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder(AccessToken::new("your-token"))
+///     .request_interceptor(TenantHeader("acme"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait RequestInterceptor: std::fmt::Debug + Send + Sync {
+    /// Called with each request just before it is handed to the transport
+    async fn on_request(&self, request: &mut reqwest::Request) -> Result<()>;
+}
+
+/// The request a response belongs to, as seen by a [`ResponseInterceptor`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseContext<'a> {
+    /// Request method
+    pub method: &'a reqwest::Method,
+    /// Full request URL
+    pub url: &'a reqwest::Url,
+    /// Attempt number, starting at 1; higher for retries
+    pub attempt: u32,
+    /// Time from sending the request to receiving the response headers
+    pub elapsed: Duration,
+}
+
+/// Inspects or changes every response before the client handles it
+///
+/// Interceptors run in the order they were added with
+/// [`ClientBuilder::response_interceptor`], once per attempt, before retries
+/// and error handling. Requests that fail without a response, such as
+/// connection errors, do not reach them. Returning an error fails the request
+/// with that error.
+#[async_trait::async_trait]
+pub trait ResponseInterceptor: std::fmt::Debug + Send + Sync {
+    /// Called with each response as soon as the transport returns it
+    async fn on_response(
+        &self,
+        context: &ResponseContext<'_>,
+        response: &mut reqwest::Response,
+    ) -> Result<()>;
+}
+
+/// Supplies extra headers for every API request
+///
+/// For deployments behind a gateway that expects tenant or correlation IDs.
+/// Providers run in the order they were added with
+/// [`ClientBuilder::header_provider`], once per request rather than per
+/// attempt, so retries carry the same values. A returned `Authorization`
+/// header is ignored: it always comes from the client's token. Returning an
+/// error aborts the request with that error.
+///
+/// ```rust
+/// use canva_connect::{client::HeaderProvider, Client, auth::AccessToken};
+/// use reqwest::header::{HeaderMap, HeaderValue};
+///
+/// #[derive(Debug)]
+/// struct CorrelationId;
+///
+/// #[async_trait::async_trait]
+/// impl HeaderProvider for CorrelationId {
+///     async fn headers(&self, _request: &reqwest::Request) -> canva_connect::Result<HeaderMap> {
+///         let mut headers = HeaderMap::new();
+///         let id = uuid::Uuid::new_v4().to_string();
+///         headers.insert("x-correlation-id", HeaderValue::from_str(&id)?);
+///         Ok(headers)
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder(AccessToken::new("your-token"))
+///     .header_provider(CorrelationId)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait HeaderProvider: std::fmt::Debug + Send + Sync {
+    /// Headers to add to `request`, replacing any it already has with the same name
+    async fn headers(&self, request: &reqwest::Request) -> Result<HeaderMap>;
+}
+
+/// Kind of request, used to pick its timeout
+///
+/// See [`ClientBuilder::short_timeout`], [`ClientBuilder::upload_timeout`]
+/// and [`ClientBuilder::download_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationClass {
+    /// API calls with small JSON bodies, such as metadata reads and updates
+    Short,
+    /// API calls that send a file, such as asset uploads and design imports
+    Upload,
+    /// Fetching exported files and thumbnails from pre-signed URLs
+    Download,
+}
+
+/// Per-class timeouts; `None` falls back to the client-wide timeout
+#[derive(Debug, Clone, Copy, Default)]
+struct ClassTimeouts {
+    fallback: Option<Duration>,
+    short: Option<Duration>,
+    upload: Option<Duration>,
+    download: Option<Duration>,
+}
+
+impl ClassTimeouts {
+    fn get(&self, class: OperationClass) -> Option<Duration> {
+        match class {
+            OperationClass::Short => self.short,
+            OperationClass::Upload => self.upload,
+            OperationClass::Download => self.download,
+        }
+    }
+}
 
 /// Main client for the Canva Connect API
+///
+/// `Client` is cheap to clone and safe to use from many tasks at once.
+/// Clones, and clients derived with [`with_priority`](Client::with_priority),
+/// share one connection pool, rate limiter, cache and token, so create one
+/// client per application and clone it into each task rather than building
+/// a new one per request. Tune the pool with
+/// [`ClientBuilder::pool_max_idle_per_host`],
+/// [`ClientBuilder::pool_idle_timeout`] and
+/// [`ClientBuilder::http2_keep_alive`].
 #[derive(Debug, Clone)]
 pub struct Client {
     http_client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
     base_url: String,
+    api_version: String,
     access_token: AccessToken,
+    token_provider: Arc<dyn TokenProvider>,
     rate_limiter: Arc<ApiRateLimiter>,
+    endpoint_rate_limits: Arc<EndpointRateLimits>,
+    retry_policy: RetryPolicy,
+    event_log: Option<Arc<EventLog>>,
+    upload_precheck: Option<Arc<dyn UploadPrecheck>>,
+    throttle_listener: Option<Arc<dyn ThrottleListener>>,
+    validate_uploads: bool,
+    rate_limit_downloads: bool,
+    job_poll: JobPollConfig,
+    cache: Option<Arc<dyn ResponseCache>>,
+    cache_ttl: Duration,
+    class_timeouts: ClassTimeouts,
+    header_providers: Vec<Arc<dyn HeaderProvider>>,
+    request_interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    response_interceptors: Vec<Arc<dyn ResponseInterceptor>>,
+    debug_log: Option<DebugLogger>,
+    scope_preflight: bool,
+    captured_body_limit: usize,
+    scope_cache: Arc<tokio::sync::Mutex<Option<CachedScopes>>>,
+    capability_cache: Arc<tokio::sync::Mutex<Option<CachedCapabilities>>>,
+    last_rate_limit: Arc<std::sync::Mutex<Option<RateLimitInfo>>>,
+    lifecycle: Arc<Lifecycle>,
+    priority: Priority,
 }
 
-impl Client {
-    /// Create a new client with the given access token
-    pub fn new(access_token: AccessToken) -> crate::Result<Self> {
+/// Requests a client and its clones are sending, and whether they still
+/// accept new ones
+#[derive(Debug, Default)]
+struct Lifecycle {
+    closed: CancellationToken,
+    in_flight: AtomicUsize,
+    idle: tokio::sync::Notify,
+}
+
+impl Lifecycle {
+    /// Count a request as in flight until the guard is dropped, or fail with
+    /// [`Error::ShutDown`] once shutdown has begun
+    fn enter(&self) -> Result<InFlight<'_>> {
+        // Counted before checking, so `drained` cannot miss a request that
+        // got in just as shutdown began
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlight(self);
+        if self.closed.is_cancelled() {
+            return Err(Error::ShutDown);
+        }
+        Ok(guard)
+    }
+
+    async fn drained(&self) {
+        loop {
+            let idle = self.idle.notified();
+            tokio::pin!(idle);
+            idle.as_mut().enable();
+            if self.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// A request counted by [`Lifecycle::enter`]
+struct InFlight<'a>(&'a Lifecycle);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+/// Scopes granted to one access token, identified by a hash of the token
+#[derive(Debug)]
+struct CachedScopes {
+    token_hash: u64,
+    scopes: Option<ScopeSet>,
+}
+
+/// Capabilities of the user behind one access token
+#[derive(Debug)]
+struct CachedCapabilities {
+    token_hash: u64,
+    capabilities: UserCapabilities,
+}
+
+fn token_hash(token: &AccessToken) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    token.as_str().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Paths whose `GET` reports the progress of an asynchronous job
+const JOB_STATUS_PATHS: &[&str] = &[
+    "/v1/exports/",
+    "/v1/autofills/",
+    "/v1/imports/",
+    "/v1/url-imports/",
+    "/v1/resizes/",
+    "/v1/asset-uploads/",
+    "/v1/url-asset-uploads/",
+];
+
+/// Describe a failed request for the event log
+///
+/// Transport errors name the URL they failed on, whose query string can hold
+/// credentials such as pre-signed signatures; it is replaced with `path`,
+/// which has no query.
+fn event_error(error: &Error, path: &str) -> String {
+    let message = error.to_string();
+    match error {
+        Error::Http(e) => match e.url() {
+            Some(url) => message.replace(url.as_str(), path),
+            None => message,
+        },
+        _ => message,
+    }
+}
+
+/// The job poll config of a client built without overriding it
+fn default_job_poll() -> JobPollConfig {
+    JobPollConfig::new().with_interval(PollingProfile::default().interval())
+}
+
+/// Whether `path` polls a job, whose status must never be served from cache
+fn is_job_status(path: &str) -> bool {
+    JOB_STATUS_PATHS
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+}
+
+/// Host of a URL outside the API, for download spans
+#[cfg(feature = "observability")]
+fn url_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Builder for configuring a [`Client`]
+///
+/// ## Examples
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken, retry::RetryPolicy};
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder(AccessToken::new("your-token"))
+///     .timeout(Duration::from_secs(30))
+///     .connect_timeout(Duration::from_secs(5))
+///     .proxy(reqwest::Proxy::https("http://proxy.internal:8080")?)
+///     .user_agent("my-app/1.0")
+///     .retry_policy(RetryPolicy::new(3))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    access_token: AccessToken,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    base_url: String,
+    api_version: String,
+    rate_limiter: Option<ApiRateLimiter>,
+    endpoint_rate_limits: Option<EndpointRateLimits>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    class_timeouts: ClassTimeouts,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    http2_keep_alive: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: String,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    event_log_capacity: Option<usize>,
+    upload_precheck: Option<Arc<dyn UploadPrecheck>>,
+    throttle_listener: Option<Arc<dyn ThrottleListener>>,
+    validate_uploads: bool,
+    rate_limit_downloads: bool,
+    job_poll: JobPollConfig,
+    config: Option<CanvaConfig>,
+    cache: Option<CacheConfig>,
+    header_providers: Vec<Arc<dyn HeaderProvider>>,
+    request_interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    response_interceptors: Vec<Arc<dyn ResponseInterceptor>>,
+    debug_log: Option<DebugLogConfig>,
+    scope_preflight: bool,
+    captured_body_limit: usize,
+}
+
+impl ClientBuilder {
+    /// Create a new builder with the given access token and default settings
+    pub fn new(access_token: AccessToken) -> Self {
+        Self {
+            access_token,
+            token_provider: None,
+            transport: None,
+            base_url: BASE_URL.to_string(),
+            api_version: API_VERSION.to_string(),
+            rate_limiter: None,
+            endpoint_rate_limits: None,
+            timeout: None,
+            connect_timeout: None,
+            class_timeouts: ClassTimeouts::default(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http2_keep_alive: None,
+            proxies: Vec::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(),
+            event_log_capacity: None,
+            upload_precheck: None,
+            throttle_listener: None,
+            validate_uploads: true,
+            rate_limit_downloads: true,
+            job_poll: default_job_poll(),
+            config: None,
+            cache: None,
+            header_providers: Vec::new(),
+            request_interceptors: Vec::new(),
+            response_interceptors: Vec::new(),
+            debug_log: None,
+            scope_preflight: false,
+            captured_body_limit: DEFAULT_CAPTURED_BODY_LIMIT,
+        }
+    }
+
+    /// Fetch tokens from `provider` instead of using the static access token
+    ///
+    /// See [`TokenProvider`] for how tokens are requested and refreshed.
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Send requests through `transport` instead of the built-in HTTP client
+    ///
+    /// Timeouts, proxies, pool settings and default headers configure the
+    /// built-in client and only take effect if the transport uses it.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Set the base URL requests are sent to
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the API version requests are sent to (defaults to [`API_VERSION`])
+    ///
+    /// Endpoint paths follow the `v1` API reference; the client swaps their
+    /// leading `/v1` for `/{api_version}` when building request URLs.
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Set the rate limiter used to throttle requests
+    pub fn rate_limiter(mut self, rate_limiter: ApiRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Set the per-endpoint rate limits applied on top of the client-wide limiter
+    ///
+    /// Defaults to [`EndpointRateLimits::canva_defaults`]. Pass
+    /// [`EndpointRateLimits::new`] to only use the client-wide limiter.
+    pub fn endpoint_rate_limits(mut self, limits: EndpointRateLimits) -> Self {
+        self.endpoint_rate_limits = Some(limits);
+        self
+    }
+
+    /// Set the total timeout for each request, from connecting until the body is read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the total timeout for [`OperationClass::Short`] requests, overriding
+    /// [`timeout`](Self::timeout)
+    pub fn short_timeout(mut self, timeout: Duration) -> Self {
+        self.class_timeouts.short = Some(timeout);
+        self
+    }
+
+    /// Set the total timeout for [`OperationClass::Upload`] requests, overriding
+    /// [`timeout`](Self::timeout)
+    ///
+    /// Uploads of large videos can take minutes; give them more time than
+    /// metadata calls without loosening the timeout for everything else.
+    pub fn upload_timeout(mut self, timeout: Duration) -> Self {
+        self.class_timeouts.upload = Some(timeout);
+        self
+    }
+
+    /// Set the total timeout for [`OperationClass::Download`] requests,
+    /// overriding [`timeout`](Self::timeout)
+    pub fn download_timeout(mut self, timeout: Duration) -> Self {
+        self.class_timeouts.download = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep at most `max` idle connections open to each host
+    ///
+    /// Idle connections are reused by later requests, saving a TLS
+    /// handshake each. Raise this above the number of requests usually in
+    /// flight at once; `0` disables reuse. No limit by default.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close connections that have been idle for `timeout`; `None` keeps them open
+    ///
+    /// Defaults to 90 seconds.
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Ping HTTP/2 connections every `interval`, even while idle
+    ///
+    /// Keeps pooled connections from being dropped by proxies and load
+    /// balancers between bursts of requests. Off by default.
+    pub fn http2_keep_alive(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive = Some(interval);
+        self
+    }
+
+    /// Route requests through a proxy
+    ///
+    /// May be called multiple times to add several proxies.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Add headers sent with every request
+    ///
+    /// The `Authorization` header is always derived from the access token and
+    /// cannot be overridden here.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Set the policy used to retry transient failures
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Record metadata about the most recent `capacity` requests
+    ///
+    /// See [`Client::support_bundle`] for exporting the log.
+    pub fn event_log(mut self, capacity: usize) -> Self {
+        self.event_log_capacity = Some(capacity);
+        self
+    }
+
+    /// Set how often job waiters poll for completion
+    ///
+    /// Applies to methods such as
+    /// [`ExportsApi::wait_for_export_job`](crate::endpoints::exports::ExportsApi::wait_for_export_job).
+    /// Defaults to two seconds.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.job_poll = self.job_poll.with_interval(interval);
+        self
+    }
+
+    /// Set the interval, backoff and timeout every job waiter starts from
+    ///
+    /// Replaces any [`poll_interval`](Self::poll_interval) set before. Single
+    /// calls can still override these through
+    /// [`WaitOptions`](crate::jobs::WaitOptions).
+    pub fn job_poll_config(mut self, config: JobPollConfig) -> Self {
+        self.job_poll = config;
+        self
+    }
+
+    /// Apply the settings from a [`CanvaConfig`]
+    ///
+    /// Options set on the builder after this call take precedence. The
+    /// configuration is validated by [`build`](Self::build).
+    pub fn config(mut self, config: CanvaConfig) -> Self {
+        self.base_url = config.base_url().to_string();
+        self.api_version = config.api_version().to_string();
+        self.timeout = config.timeout();
+        self.connect_timeout = config.connect_timeout();
+        self.retry_policy = config.retry_policy().clone();
+        self.rate_limiter = Some(config.rate_limiter());
+        self.endpoint_rate_limits = Some(config.endpoint_rate_limits());
+        self.job_poll = self.job_poll.with_interval(config.polling().interval());
+        self.config = Some(config);
+        self
+    }
+
+    /// Run `precheck` before every asset upload
+    ///
+    /// See the [`uploads`] module for details.
+    pub fn upload_precheck(mut self, precheck: impl UploadPrecheck + 'static) -> Self {
+        self.upload_precheck = Some(Arc::new(precheck));
+        self
+    }
+
+    /// Check file uploads against Canva's supported formats and size limits
+    ///
+    /// Enabled by default; see [`uploads::validate_asset`].
+    /// Disable it to let Canva decide, for example when it accepts a format
+    /// this crate does not recognize yet.
+    pub fn validate_uploads(mut self, validate: bool) -> Self {
+        self.validate_uploads = validate;
+        self
+    }
+
+    /// Whether downloads from pre-signed URLs wait on the API rate limiter
+    ///
+    /// Enabled by default. Export files and thumbnails are served from
+    /// Canva's CDN, which does not count against the API quota, so turning
+    /// this off lets large downloads run without holding up API calls.
+    /// Retries and timeouts still apply.
+    pub fn rate_limit_downloads(mut self, enabled: bool) -> Self {
+        self.rate_limit_downloads = enabled;
+        self
+    }
+
+    /// Notify `listener` whenever server rate limit headers pause requests
+    ///
+    /// The client always honors `Retry-After` on HTTP 429 and slows down as
+    /// `X-RateLimit-Remaining` approaches zero; the listener only observes
+    /// those pauses.
+    pub fn throttle_listener(mut self, listener: impl ThrottleListener + 'static) -> Self {
+        self.throttle_listener = Some(Arc::new(listener));
+        self
+    }
+
+    /// Cache `GET` responses and revalidate them with `If-None-Match`
+    ///
+    /// See the [`cache`](crate::cache) module for what is cached and when
+    /// cached responses are served.
+    pub fn cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Add the headers from `provider` to every API request
+    ///
+    /// May be called several times; later providers win when two supply the
+    /// same header. See [`HeaderProvider`].
+    pub fn header_provider(mut self, provider: impl HeaderProvider + 'static) -> Self {
+        self.header_providers.push(Arc::new(provider));
+        self
+    }
+
+    /// Run every request through `interceptor` before it is sent
+    ///
+    /// May be called several times; interceptors run in the order added.
+    pub fn request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.request_interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Run every response through `interceptor` before it is handled
+    ///
+    /// May be called several times; interceptors run in the order added.
+    pub fn response_interceptor(mut self, interceptor: impl ResponseInterceptor + 'static) -> Self {
+        self.response_interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Log every request and response, with credentials redacted
+    ///
+    /// See the [`debug_log`](crate::debug_log) module for what is logged.
+    pub fn debug_log(mut self, config: DebugLogConfig) -> Self {
+        self.debug_log = Some(config);
+        self
+    }
+
+    /// Check the token's granted scopes before each request
+    ///
+    /// Requests to an [`Operation`] the token lacks scopes for fail with
+    /// [`Error::MissingScope`] without being sent. Granted scopes come from
+    /// [`Client::granted_scopes`]; the check is skipped when they are
+    /// unknown, as with a static [`AccessToken`], or the request is not a
+    /// known operation. Disabled by default.
+    pub fn scope_preflight(mut self, enabled: bool) -> Self {
+        self.scope_preflight = enabled;
+        self
+    }
+
+    /// Keep up to `max_bytes` of a response body that fails to deserialize
+    ///
+    /// The body is attached to [`Error::Deserialization`] with credentials
+    /// redacted, so a response Canva changed can be inspected after the
+    /// fact. Defaults to [`DEFAULT_CAPTURED_BODY_LIMIT`]; `0` disables it.
+    pub fn captured_body_limit(mut self, max_bytes: usize) -> Self {
+        self.captured_body_limit = max_bytes;
+        self
+    }
+
+    /// Build the client
+    ///
+    /// Fails with [`Error::Config`] if the settings the client would run
+    /// with are invalid, as described in [`CanvaConfig::validate`]; this
+    /// covers values set on the builder after [`config`](Self::config).
+    pub fn build(self) -> Result<Client> {
+        // Check what the client will run with, including anything changed
+        // on the builder after a config was applied
+        validate_settings(&ClientSettings {
+            base_url: &self.base_url,
+            api_version: &self.api_version,
+            timeout: self.timeout,
+            connect_timeout: self.connect_timeout,
+            retry_policy: &self.retry_policy,
+            poll_interval: self.job_poll.interval(),
+        })?;
+        if let Some(config) = &self.config {
+            config.validate_rate_limit()?;
+            #[cfg(feature = "observability")]
+            tracing::info!(?config, "Effective Canva client configuration");
+        }
+
+        // Validate the token up front so a malformed token fails at construction
+        HeaderValue::from_str(&self.access_token.authorization_header())?;
+
         let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&access_token.authorization_header())?,
-        );
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert(
-            USER_AGENT,
-            HeaderValue::from_static("canva-connect-rust/0.1.0"),
-        );
+        headers.insert(USER_AGENT, HeaderValue::from_str(&self.user_agent)?);
+        for (name, value) in self.default_headers.iter() {
+            if name != AUTHORIZATION {
+                headers.insert(name.clone(), value.clone());
+            }
+        }
 
-        let http_client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .map_err(crate::Error::ClientBuild)?;
+        let mut builder = reqwest::Client::builder().default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.http2_keep_alive {
+            builder = builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+        for proxy in self.proxies {
+            builder = builder.proxy(proxy);
+        }
+        let http_client = builder.build().map_err(Error::ClientBuild)?;
+
+        let token_provider = self
+            .token_provider
+            .unwrap_or_else(|| Arc::new(self.access_token.clone()));
+
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(http_client.clone()));
 
-        Ok(Self {
+        let (cache, cache_ttl) = match self.cache {
+            Some(config) => {
+                let (store, ttl) = config.into_store();
+                (Some(store), ttl)
+            }
+            None => (None, Duration::ZERO),
+        };
+
+        Ok(Client {
             http_client,
-            base_url: BASE_URL.to_string(),
-            access_token,
-            rate_limiter: Arc::new(ApiRateLimiter::default()),
+            transport,
+            base_url: self.base_url,
+            api_version: self.api_version,
+            access_token: self.access_token,
+            token_provider,
+            rate_limiter: Arc::new(self.rate_limiter.unwrap_or_default()),
+            endpoint_rate_limits: Arc::new(
+                self.endpoint_rate_limits
+                    .unwrap_or_else(EndpointRateLimits::canva_defaults),
+            ),
+            retry_policy: self.retry_policy,
+            event_log: self
+                .event_log_capacity
+                .map(|capacity| Arc::new(EventLog::new(capacity))),
+            upload_precheck: self.upload_precheck,
+            throttle_listener: self.throttle_listener,
+            validate_uploads: self.validate_uploads,
+            rate_limit_downloads: self.rate_limit_downloads,
+            job_poll: self.job_poll,
+            cache,
+            cache_ttl,
+            class_timeouts: ClassTimeouts {
+                fallback: self.timeout,
+                ..self.class_timeouts
+            },
+            header_providers: self.header_providers,
+            request_interceptors: self.request_interceptors,
+            response_interceptors: self.response_interceptors,
+            debug_log: self.debug_log.map(DebugLogConfig::into_logger),
+            scope_preflight: self.scope_preflight,
+            captured_body_limit: self.captured_body_limit,
+            scope_cache: Arc::default(),
+            capability_cache: Arc::default(),
+            last_rate_limit: Arc::default(),
+            lifecycle: Arc::default(),
+            priority: Priority::Normal,
         })
     }
+}
+
+impl Client {
+    /// Create a new client with the given access token
+    pub fn new(access_token: AccessToken) -> crate::Result<Self> {
+        Self::builder(access_token).build()
+    }
+
+    /// Create a builder for configuring timeouts, proxies, headers and retries
+    pub fn builder(access_token: AccessToken) -> ClientBuilder {
+        ClientBuilder::new(access_token)
+    }
 
     /// Create a new client with a custom base URL and access token
     pub fn with_base_url(
         base_url: impl Into<String>,
         access_token: AccessToken,
     ) -> crate::Result<Self> {
-        let mut client = Self::new(access_token)?;
-        client.base_url = base_url.into();
-        Ok(client)
+        Self::builder(access_token).base_url(base_url).build()
+    }
+
+    /// Create a new client that authenticates with tokens from an [`OAuthClient`]
+    ///
+    /// Each request uses a valid token from the OAuth client's token store,
+    /// refreshing it when expired or when the API rejects it with `401`.
+    /// Use [`ClientBuilder::token_provider`] to combine this with other options.
+    ///
+    /// A token is fetched up front, refreshing it if needed, so this fails
+    /// with [`Error::Auth`] if the OAuth client has not been authorized yet.
+    /// The fetched token is what [`Client::access_token`] returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::{OAuthClient, OAuthConfig, Scope}};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = OAuthConfig::new("id", "secret", "https://example.com/callback", vec![Scope::DesignMetaRead]);
+    /// let oauth = OAuthClient::new(config);
+    /// oauth.authorize_manual(canva_connect::auth::manual::stdin_prompt).await?;
+    ///
+    /// let client = Client::with_oauth(oauth).await?;
+    /// let designs = client.designs().list_with(&Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_oauth(oauth_client: OAuthClient) -> crate::Result<Self> {
+        let access_token = oauth_client.get_access_token().await?;
+        Self::builder(access_token)
+            .token_provider(oauth_client)
+            .build()
+    }
+
+    /// Create a new client from a [`CanvaConfig`]
+    ///
+    /// Fails with [`Error::Config`] if the configuration is invalid.
+    pub fn from_config(access_token: AccessToken, config: CanvaConfig) -> crate::Result<Self> {
+        Self::builder(access_token).config(config).build()
     }
 
     /// Create a new client with a custom rate limiter
@@ -78,9 +1002,44 @@ impl Client {
         access_token: AccessToken,
         rate_limiter: ApiRateLimiter,
     ) -> crate::Result<Self> {
-        let mut client = Self::new(access_token)?;
-        client.rate_limiter = Arc::new(rate_limiter);
-        Ok(client)
+        Self::builder(access_token)
+            .rate_limiter(rate_limiter)
+            .build()
+    }
+
+    /// A client whose requests wait on the rate limiters with `priority`
+    ///
+    /// The returned client shares everything, rate limiters included, with
+    /// this one, so its requests overtake lower-priority requests queued by
+    /// other clones. Use [`Priority::High`] for calls a user is waiting on
+    /// and [`Priority::Low`] for bulk background work.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, rate_limit::Priority};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("your-token"))?;
+    /// let background = client.with_priority(Priority::Low);
+    /// tokio::spawn(async move { background.designs().get("DAF123").await });
+    ///
+    /// // Released before the background request if both are waiting
+    /// let me = client.with_priority(Priority::High).user().get_me().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_priority(&self, priority: Priority) -> Client {
+        Client {
+            priority,
+            ..self.clone()
+        }
+    }
+
+    /// The [`Priority`] this client's requests wait with
+    pub fn priority(&self) -> Priority {
+        self.priority
     }
 
     /// Get the assets API
@@ -114,6 +1073,8 @@ impl Client {
     }
 
     /// Get the comments API
+    #[cfg(feature = "experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
     pub fn comments(&self) -> CommentsApi {
         CommentsApi::new(self.clone())
     }
@@ -123,6 +1084,13 @@ impl Client {
         ExportsApi::new(self.clone())
     }
 
+    /// Get the design imports API
+    #[cfg(feature = "experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+    pub fn imports(&self) -> DesignImportApi {
+        DesignImportApi::new(self.clone())
+    }
+
     /// Make a GET request
     pub async fn get(&self, path: &str) -> Result<reqwest::Response> {
         self.request(reqwest::Method::GET, path, None::<&()>).await
@@ -166,7 +1134,7 @@ impl Client {
         skip(self, body),
         fields(
             http.method = %method,
-            http.url = %format!("{}{}", self.base_url, path),
+            http.url = %self.url_for(path),
             http.status_code = tracing::field::Empty,
             canva.api.path = path,
             canva.request_id = tracing::field::Empty,
@@ -178,11 +1146,13 @@ impl Client {
         path: &str,
         body: Option<&T>,
     ) -> Result<reqwest::Response> {
-        // Wait for rate limiting
-        self.rate_limiter.wait_for_request().await;
-
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.http_client.request(method, &url);
+        let url = self.url_for(path);
+        let is_get = method == reqwest::Method::GET;
+        let started = std::time::Instant::now();
+        let mut request = self.classify(
+            self.http_client.request(method.clone(), &url),
+            OperationClass::Short,
+        );
 
         if let Some(body) = body {
             request = request.json(body);
@@ -191,29 +1161,43 @@ impl Client {
         #[cfg(feature = "observability")]
         tracing::debug!("Sending HTTP request");
 
-        let response = request.send().await?;
+        let response = match &self.cache {
+            Some(_) if is_get && is_job_status(path) => self.send(request).await?,
+            Some(cache) if is_get => {
+                let key = self.cache_key(path).await?;
+                self.send_cached(cache.as_ref(), request, &key).await?
+            }
+            Some(cache) => {
+                let response = self.send(request).await?;
+                if response.status().is_success() {
+                    cache.remove(&self.cache_key(path).await?).await;
+                }
+                response
+            }
+            None => self.send(request).await?,
+        };
 
         // Record response status and request ID in span
         #[cfg(feature = "observability")]
         {
-            tracing::Span::current().record("http.status_code", response.status().as_u16());
+            let span = tracing::Span::current();
+            if !span.is_disabled() {
+                span.record("http.status_code", response.status().as_u16());
 
-            // Capture x-request-id header for tracing correlation
-            if let Some(request_id) = response.headers().get("x-request-id") {
-                if let Ok(request_id_str) = request_id.to_str() {
-                    tracing::Span::current().record("canva.request_id", request_id_str);
-                    tracing::debug!("Canva API request ID: {}", request_id_str);
+                // Capture x-request-id header for tracing correlation
+                if let Some(request_id) = response.headers().get("x-request-id") {
+                    if let Ok(request_id_str) = request_id.to_str() {
+                        span.record("canva.request_id", request_id_str);
+                        tracing::debug!("Canva API request ID: {}", request_id_str);
+                    }
                 }
             }
         }
 
-        // Update rate limit info from headers
-        let _rate_limit_info = RateLimitInfo::from_headers(response.headers());
-
         // Handle API errors
         if !response.status().is_success() {
             #[cfg(feature = "observability")]
-            {
+            if tracing::enabled!(tracing::Level::WARN) {
                 let request_id = response
                     .headers()
                     .get("x-request-id")
@@ -225,7 +1209,9 @@ impl Client {
                     request_id
                 );
             }
-            return self.handle_error_response(response).await;
+            return self
+                .handle_error_response(response, method, path, started)
+                .await;
         }
 
         #[cfg(feature = "observability")]
@@ -234,96 +1220,880 @@ impl Client {
         Ok(response)
     }
 
-    /// Handle error responses from the API
-    async fn handle_error_response(
-        &self,
-        response: reqwest::Response,
-    ) -> Result<reqwest::Response> {
-        let status = response.status();
-
-        // Try to parse API error
-        if let Ok(api_error) = response.json::<ApiError>().await {
-            return Err(Error::from(api_error));
+    /// Send a request, waiting on the rate limiter and retrying transient failures
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let _in_flight = self.lifecycle.enter()?;
+        let mut request = request.build()?;
+        if self.scope_preflight {
+            self.check_scopes(&request).await?;
+        }
+        for provider in &self.header_providers {
+            let headers = provider.headers(&request).await?;
+            for name in headers.keys().filter(|name| *name != AUTHORIZATION) {
+                request.headers_mut().remove(name);
+                for value in headers.get_all(name) {
+                    request.headers_mut().append(name.clone(), value.clone());
+                }
+            }
         }
+        let token = self.token_provider.access_token().await?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&token.authorization_header())?,
+        );
 
-        // Fallback to generic HTTP error
-        Err(Error::Generic(format!("HTTP {status} error")))
-    }
+        let timestamp = chrono::Utc::now();
+        let started = std::time::Instant::now();
+        let method = request.method().to_string();
+        let path = request.url().path().to_string();
+        let api_path = self.api_path(request.url());
+        let mut attempts = 0;
+        let mut retries = 0;
+        let mut refreshed = false;
 
-    /// Get a JSON response from a path
-    pub async fn get_json<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let response = self.get(path).await?;
-        let json = response.json().await?;
-        Ok(json)
-    }
+        let result = loop {
+            let waited = self
+                .unless_shut_down(async {
+                    self.rate_limiter
+                        .wait_for_request_with_priority(self.priority)
+                        .await;
+                    self.endpoint_rate_limits
+                        .wait_for_request_with_priority(request.method(), &api_path, self.priority)
+                        .await;
+                })
+                .await;
+            if let Err(error) = waited {
+                break Err(error);
+            }
+            attempts += 1;
 
-    /// Post JSON and get JSON response
-    pub async fn post_json<T: serde::Serialize, R: serde::de::DeserializeOwned>(
-        &self,
-        path: &str,
-        body: &T,
-    ) -> Result<R> {
-        let response = self.post(path, body).await?;
-        let json = response.json().await?;
-        Ok(json)
-    }
+            // Bodies are always buffered, so cloning only fails for streaming bodies,
+            // which are sent once without retries.
+            let Some(attempt) = request.try_clone() else {
+                let outcome = self.execute(request, attempts).await;
+                if let Ok(response) = &outcome {
+                    self.record_rate_limit(response.headers());
+                }
+                break outcome;
+            };
+            let can_retry = retries < self.retry_policy.max_retries();
 
-    /// Patch JSON and get JSON response
-    pub async fn patch_json<T: serde::Serialize, R: serde::de::DeserializeOwned>(
-        &self,
-        path: &str,
-        body: &T,
-    ) -> Result<R> {
-        let response = self.patch(path, body).await?;
-        let json = response.json().await?;
-        Ok(json)
-    }
+            let outcome = self.execute(attempt, attempts).await;
+            let server_delay = match &outcome {
+                Ok(response) => {
+                    self.record_rate_limit(response.headers());
+                    self.apply_server_throttle(response, request.method(), &path, &api_path)
+                }
+                Err(_) => None,
+            };
 
-    /// Upload a file as multipart form data
-    pub async fn upload_file(
-        &self,
-        path: &str,
-        file_data: Vec<u8>,
-        metadata: Option<&str>,
-    ) -> Result<reqwest::Response> {
-        // Wait for rate limiting
-        self.rate_limiter.wait_for_request().await;
+            match outcome {
+                // Retry a rejected token once with a refreshed one
+                Ok(response) if response.status() == StatusCode::UNAUTHORIZED && !refreshed => {
+                    refreshed = true;
+                    match self.token_provider.refresh().await {
+                        Ok(token) => {
+                            request.headers_mut().insert(
+                                AUTHORIZATION,
+                                HeaderValue::from_str(&token.authorization_header())?,
+                            );
+                            continue;
+                        }
+                        Err(_) => break Ok(response),
+                    }
+                }
+                Ok(response)
+                    if can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_status_for(request.method(), response.status()) =>
+                {
+                    #[cfg(feature = "observability")]
+                    tracing::debug!("Retrying request after HTTP {}", response.status());
+                }
+                Err(Error::Http(error))
+                    if can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_error_for(request.method(), &error) =>
+                {
+                    #[cfg(feature = "observability")]
+                    tracing::debug!("Retrying request after transport error: {}", error);
+                }
+                result => break result,
+            }
 
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.http_client.post(&url);
+            retries += 1;
+            // A server-requested delay is enforced by the paused rate limiter
+            if server_delay.is_none() {
+                let backoff = tokio::time::sleep(self.retry_policy.backoff_for(retries));
+                if let Err(error) = self.unless_shut_down(backoff).await {
+                    break Err(error);
+                }
+            }
+        };
 
-        if let Some(metadata) = metadata {
-            request = request.header("Asset-Upload-Metadata", metadata);
+        if let Some(event_log) = &self.event_log {
+            let response = result.as_ref().ok();
+            let error = result.as_ref().err().map(|e| event_error(e, &path));
+            event_log.record(ApiEvent {
+                timestamp,
+                method,
+                path,
+                status: response.map(|r| r.status().as_u16()),
+                request_id: response
+                    .and_then(|r| r.headers().get("x-request-id"))
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string),
+                duration_ms: started.elapsed().as_millis().try_into().unwrap_or(u64::MAX),
+                attempts,
+                error,
+                rate_limit: response.map(|r| RateLimitInfo::from_headers(r.headers())),
+            });
         }
 
-        let response = request
-            .header(CONTENT_TYPE, "application/octet-stream")
-            .body(file_data)
-            .send()
-            .await?;
+        result
+    }
 
-        // Handle API errors
-        if !response.status().is_success() {
-            return self.handle_error_response(response).await;
+    /// Fail with [`Error::MissingScope`] if the token cannot make `request`
+    async fn check_scopes(&self, request: &reqwest::Request) -> Result<()> {
+        let api_path = self.api_path(request.url());
+        let Some(operation) = Operation::matching(request.method(), &api_path) else {
+            return Ok(());
+        };
+        let Some(granted) = self.granted_scopes().await? else {
+            return Ok(());
+        };
+        let required = operation.required_scopes();
+        if granted.contains_all(&required) {
+            return Ok(());
+        }
+        Err(Error::MissingScope { required, granted })
+    }
+
+    /// Hand one attempt of a request to the transport, running the interceptors
+    /// and the debug log
+    async fn execute(
+        &self,
+        mut request: reqwest::Request,
+        attempt: u32,
+    ) -> Result<reqwest::Response> {
+        for interceptor in &self.request_interceptors {
+            interceptor.on_request(&mut request).await?;
+        }
+        let log_entry = self
+            .debug_log
+            .as_ref()
+            .map(|logger| logger.capture(&request, attempt));
+        if self.response_interceptors.is_empty() && log_entry.is_none() {
+            return self.transport.execute(request).await;
         }
 
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let started = std::time::Instant::now();
+        let result = self.transport.execute(request).await;
+        let elapsed = started.elapsed();
+        let mut response = match (&self.debug_log, log_entry) {
+            (Some(logger), Some(entry)) => logger.finish(entry, elapsed, result).await?,
+            _ => result?,
+        };
+        let context = ResponseContext {
+            method: &method,
+            url: &url,
+            attempt,
+            elapsed,
+        };
+        for interceptor in &self.response_interceptors {
+            interceptor.on_response(&context, &mut response).await?;
+        }
         Ok(response)
     }
 
-    /// Get the base URL
-    pub fn base_url(&self) -> &str {
-        &self.base_url
+    /// The key a `GET` of `path` is stored under in the response cache
+    ///
+    /// Keys combine a hash of the current access token with the request
+    /// URL, so users sharing a store never see each other's responses.
+    pub async fn cache_key(&self, path: &str) -> Result<String> {
+        let token = self.token_provider.access_token().await?;
+        Ok(format!(
+            "{:016x} {}",
+            token_hash(&token),
+            self.url_for(path)
+        ))
     }
 
-    /// Get the access token
-    pub fn access_token(&self) -> &AccessToken {
+    /// Send a `GET` request through the response cache
+    ///
+    /// Serves a fresh cached response without a request, revalidates a stale
+    /// one with `If-None-Match`, and stores successful responses.
+    async fn send_cached(
+        &self,
+        cache: &dyn ResponseCache,
+        mut request: reqwest::RequestBuilder,
+        key: &str,
+    ) -> Result<reqwest::Response> {
+        let cached = cache.get(key).await;
+        if let Some(cached) = &cached {
+            if cached.is_fresh(self.cache_ttl) {
+                #[cfg(feature = "observability")]
+                tracing::debug!("Serving cached response");
+                return Ok(cached.to_response());
+            }
+            if let Some(etag) = cached.etag() {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+        }
+
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(mut cached) = cached {
+                #[cfg(feature = "observability")]
+                tracing::debug!("Cached response revalidated");
+                cached.stored_at = std::time::Instant::now();
+                let served = cached.to_response();
+                cache.put(key, cached).await;
+                return Ok(served);
+            }
+        }
+        let cacheable = response.headers().contains_key(ETAG) || !self.cache_ttl.is_zero();
+        if !response.status().is_success() || !cacheable {
+            return Ok(response);
+        }
+
+        let cached = CachedResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?.to_vec(),
+            stored_at: std::time::Instant::now(),
+        };
+        let served = cached.to_response();
+        cache.put(key, cached).await;
+        Ok(served)
+    }
+
+    /// Remember the response's rate limit headers for [`Client::last_rate_limit_info`]
+    fn record_rate_limit(&self, headers: &reqwest::header::HeaderMap) {
+        let info = RateLimitInfo::from_headers(headers);
+        if info.remaining.is_some() || info.limit.is_some() || info.reset_at.is_some() {
+            *self
+                .last_rate_limit
+                .lock()
+                .unwrap_or_else(|p| p.into_inner()) = Some(info);
+        }
+    }
+
+    /// Pause the rate limiter as the response's rate limit headers demand
+    ///
+    /// Canva's quotas are per endpoint, so the limiter for the request's
+    /// endpoint is paused; the client-wide limiter is only paused for routes
+    /// without an endpoint limit. Returns the delay if the server told the
+    /// client when to retry.
+    fn apply_server_throttle(
+        &self,
+        response: &reqwest::Response,
+        method: &reqwest::Method,
+        path: &str,
+        api_path: &str,
+    ) -> Option<Duration> {
+        let (reason, delay, rate_limit) =
+            rate_limit::server_throttle(response.status(), response.headers())?;
+        let limiter = self
+            .endpoint_rate_limits
+            .limiter_for(method, api_path)
+            .unwrap_or(&self.rate_limiter);
+        limiter.pause_for(delay);
+
+        #[cfg(feature = "observability")]
+        tracing::debug!(
+            ?reason,
+            ?delay,
+            "Throttling requests from rate limit headers"
+        );
+
+        if let Some(listener) = &self.throttle_listener {
+            listener.on_throttle(&ThrottleEvent {
+                method: method.to_string(),
+                path: path.to_string(),
+                reason,
+                delay,
+                rate_limit,
+            });
+        }
+        (reason == rate_limit::ThrottleReason::RetryAfter).then_some(delay)
+    }
+
+    /// Handle error responses from the API
+    async fn handle_error_response(
+        &self,
+        response: reqwest::Response,
+        method: reqwest::Method,
+        path: &str,
+        started: std::time::Instant,
+    ) -> Result<reqwest::Response> {
+        let status = response.status();
+        let context = RequestContext {
+            method,
+            path: path.to_string(),
+            status,
+            request_id: response
+                .headers()
+                .get("x-request-id")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string),
+            elapsed: started.elapsed(),
+        };
+
+        // Gateways and empty error responses have no JSON body, so fall back
+        // to a code for the status
+        let error = match response.json::<ApiError>().await {
+            Ok(api_error) => Error::from(api_error),
+            Err(_) => Error::Api {
+                code: ApiErrorCode::for_status(status),
+                message: format!("HTTP {status} error"),
+                context: None,
+            },
+        };
+        Err(error.with_request_context(context))
+    }
+
+    /// Get a JSON response from a path
+    pub async fn get_json<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self.get(path).await?;
+        self.read_json(response).await
+    }
+
+    /// Post JSON and get JSON response
+    pub async fn post_json<T: serde::Serialize, R: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<R> {
+        let response = self.post(path, body).await?;
+        self.read_json(response).await
+    }
+
+    /// Patch JSON and get JSON response
+    pub async fn patch_json<T: serde::Serialize, R: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<R> {
+        let response = self.patch(path, body).await?;
+        self.read_json(response).await
+    }
+
+    /// Deserialize a response body, keeping the body in the error if that fails
+    pub(crate) async fn read_json<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<T> {
+        let bytes = response.bytes().await?;
+        self.parse_json(&bytes)
+    }
+
+    fn parse_json<T: serde::de::DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        serde_json::from_slice(bytes).map_err(|source| Error::Deserialization {
+            source,
+            body: (self.captured_body_limit > 0)
+                .then(|| crate::debug_log::redact_body(bytes, self.captured_body_limit)),
+        })
+    }
+
+    /// Call any REST path and deserialize the JSON response into your own type
+    ///
+    /// An escape hatch for endpoints this crate does not wrap yet, such as new
+    /// preview APIs. The request gets the same authentication, rate limiting,
+    /// retries, caching and error handling as the built-in endpoints.
+    /// `path` is relative to the base URL, e.g. `/v1/designs`; `query` pairs
+    /// are percent-encoded and appended to it; build them with
+    /// [`QueryBuilder`] if some are optional. An empty response body
+    /// deserializes as JSON `null`, so use `()` or `Option<_>` for endpoints
+    /// that return `204 No Content`.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use reqwest::Method;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Brand {
+    ///     id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Brands {
+    ///     items: Vec<Brand>,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let brands: Brands = client
+    ///     .request_json(Method::GET, "/v1/brands", &[("limit", "10")], None::<&()>)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_json<T: serde::Serialize, R: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&T>,
+    ) -> Result<R> {
+        let path = query
+            .iter()
+            .fold(QueryBuilder::new(), |builder, (key, value)| {
+                builder.param(key, value)
+            })
+            .append_to(path);
+        let response = self.request(method, &path, body).await?;
+        let bytes = response.bytes().await?;
+        if bytes.is_empty() {
+            self.parse_json(b"null")
+        } else {
+            self.parse_json(&bytes)
+        }
+    }
+
+    /// Upload a file as multipart form data
+    pub async fn upload_file(
+        &self,
+        path: &str,
+        file_data: Vec<u8>,
+        metadata: Option<&str>,
+    ) -> Result<reqwest::Response> {
+        let metadata_header = metadata.map(|metadata| ("Asset-Upload-Metadata", metadata));
+        self.post_binary(path, file_data, metadata_header).await
+    }
+
+    /// Post a binary body, with JSON metadata passed in the given header
+    ///
+    /// Upload-style endpoints take the raw file as an `application/octet-stream`
+    /// body and describe it in a header such as `Asset-Upload-Metadata` or
+    /// `Import-Metadata`.
+    pub async fn post_binary(
+        &self,
+        path: &str,
+        file_data: Vec<u8>,
+        metadata_header: Option<(&str, &str)>,
+    ) -> Result<reqwest::Response> {
+        let url = self.url_for(path);
+        let started = std::time::Instant::now();
+        let mut request = self.classify(self.http_client.post(&url), OperationClass::Upload);
+
+        if let Some((name, metadata)) = metadata_header {
+            request = request.header(name, metadata);
+        }
+
+        let request = request
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(file_data);
+        let response = self.send(request).await?;
+
+        // Handle API errors
+        if !response.status().is_success() {
+            return self
+                .handle_error_response(response, reqwest::Method::POST, path, started)
+                .await;
+        }
+
+        Ok(response)
+    }
+
+    /// Validate an upload about to be sent, then run the configured precheck, if any
+    pub(crate) async fn precheck_upload(&self, upload: UploadCandidate<'_>) -> Result<()> {
+        if let (true, UploadSource::Bytes(bytes)) = (self.validate_uploads, upload.source()) {
+            uploads::validate_asset(upload.name(), bytes)?;
+        }
+        let Some(precheck) = &self.upload_precheck else {
+            return Ok(());
+        };
+        precheck
+            .check(&upload)
+            .await
+            .map_err(|rejection| Error::UploadRejected {
+                name: upload.name().to_string(),
+                rejection,
+            })
+    }
+
+    /// Scopes granted to the current access token, or `None` if they are unknown
+    ///
+    /// Asks the [`TokenProvider`] once per token and caches the answer, so a
+    /// refreshed token is looked up again. With [`Client::with_oauth`] the
+    /// scopes come from the token response or, if it did not list them, the
+    /// OAuth introspection endpoint; static tokens report `None`.
+    pub async fn granted_scopes(&self) -> Result<Option<ScopeSet>> {
+        let token_hash = token_hash(&self.token_provider.access_token().await?);
+        let mut cache = self.scope_cache.lock().await;
+        if let Some(cached) = cache.as_ref().filter(|c| c.token_hash == token_hash) {
+            return Ok(cached.scopes.clone());
+        }
+        let scopes = self.token_provider.granted_scopes().await?;
+        *cache = Some(CachedScopes {
+            token_hash,
+            scopes: scopes.clone(),
+        });
+        Ok(scopes)
+    }
+
+    /// Capabilities of the current user, fetched once per access token
+    ///
+    /// The first call asks the API; later calls answer from a cache shared
+    /// by clones of this client, until the access token changes or
+    /// [`invalidate_capabilities`](Self::invalidate_capabilities) is called.
+    ///
+    /// **Required OAuth scope:** `profile:read`
+    pub async fn capabilities(&self) -> Result<UserCapabilities> {
+        let token_hash = token_hash(&self.token_provider.access_token().await?);
+        let mut cache = self.capability_cache.lock().await;
+        if let Some(cached) = cache.as_ref().filter(|c| c.token_hash == token_hash) {
+            return Ok(cached.capabilities.clone());
+        }
+        let capabilities = UserCapabilities::new(self.user().get_capabilities().await?);
+        *cache = Some(CachedCapabilities {
+            token_hash,
+            capabilities: capabilities.clone(),
+        });
+        Ok(capabilities)
+    }
+
+    /// Forget the cached capabilities, e.g. after the user upgraded their plan
+    pub async fn invalidate_capabilities(&self) {
+        *self.capability_cache.lock().await = None;
+    }
+
+    /// Download a pre-signed URL, such as an export file or thumbnail
+    ///
+    /// Uses the client's connection pool, retry policy and download timeout,
+    /// and waits on the rate limiter unless
+    /// [`ClientBuilder::rate_limit_downloads`] turned that off. The access
+    /// token is not sent. The body is not read, so it can be streamed with
+    /// [`reqwest::Response::bytes_stream`]; use [`Client::downloader`] to
+    /// save files with resuming and checksums instead.
+    ///
+    /// Fails with [`Error::Download`], carrying the status, if the final
+    /// response is not a success.
+    #[cfg_attr(feature = "observability", tracing::instrument(
+        skip(self, url),
+        fields(
+            http.method = "GET",
+            server.address = url_host(url),
+            http.status_code = tracing::field::Empty,
+        )
+    ))]
+    pub async fn download(&self, url: &str) -> Result<reqwest::Response> {
+        let response = self.fetch_url(url).await?;
+        let status = response.status();
+        #[cfg(feature = "observability")]
+        tracing::Span::current().record("http.status_code", status.as_u16());
+        if !status.is_success() {
+            return Err(Error::download(status, response.url()));
+        }
+        Ok(response)
+    }
+
+    /// Fetch a pre-signed URL, retrying transient failures like API requests
+    ///
+    /// Waits on the client-wide rate limiter unless downloads bypass it and
+    /// follows the retry policy, then returns the last response whatever its status, so callers can
+    /// tell an expired URL from other failures. The access token is not sent.
+    pub(crate) async fn fetch_url(&self, url: &str) -> Result<reqwest::Response> {
+        self.fetch(reqwest::Method::GET, url, OperationClass::Download, 0)
+            .await
+    }
+
+    /// Fetch a pre-signed URL like [`fetch_url`](Self::fetch_url), asking
+    /// for the content from byte `offset` onwards when `offset` is not zero
+    pub(crate) async fn fetch_url_from(&self, url: &str, offset: u64) -> Result<reqwest::Response> {
+        self.fetch(reqwest::Method::GET, url, OperationClass::Download, offset)
+            .await
+    }
+
+    /// Send a `HEAD` request for a URL outside the API, like [`fetch_url`](Self::fetch_url)
+    pub(crate) async fn head_url(&self, url: &str) -> Result<reqwest::Response> {
+        self.fetch(reqwest::Method::HEAD, url, OperationClass::Short, 0)
+            .await
+    }
+
+    async fn fetch(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        class: OperationClass,
+        offset: u64,
+    ) -> Result<reqwest::Response> {
+        let _in_flight = self.lifecycle.enter()?;
+        let mut retries = 0;
+        loop {
+            if self.rate_limit_downloads {
+                self.unless_shut_down(
+                    self.rate_limiter
+                        .wait_for_request_with_priority(self.priority),
+                )
+                .await?;
+            }
+            let mut request = self.classify(self.http_client.request(method.clone(), url), class);
+            if offset > 0 {
+                request = request.header(reqwest::header::RANGE, format!("bytes={offset}-"));
+            }
+            let outcome = self.transport.execute(request.build()?).await;
+            let can_retry = retries < self.retry_policy.max_retries();
+            match outcome {
+                Ok(response)
+                    if can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_status_for(&method, response.status()) => {}
+                Err(Error::Http(error))
+                    if can_retry && self.retry_policy.should_retry_error_for(&method, &error) => {}
+                result => return result,
+            }
+            retries += 1;
+            self.unless_shut_down(tokio::time::sleep(self.retry_policy.backoff_for(retries)))
+                .await?;
+        }
+    }
+
+    /// Run `wait` unless the client shuts down first
+    ///
+    /// Used for waits before a request is sent, so shutting down does not
+    /// have to wait for queued requests that never started.
+    async fn unless_shut_down<F: std::future::Future>(&self, wait: F) -> Result<F::Output> {
+        tokio::select! {
+            biased;
+            _ = self.lifecycle.closed.cancelled() => Err(Error::ShutDown),
+            output = wait => Ok(output),
+        }
+    }
+
+    /// Stop sending requests and wait up to `timeout` for those in flight
+    ///
+    /// Applies to this client and all its clones. New requests, and ones
+    /// still queued on the rate limiter or waiting to retry, fail with
+    /// [`Error::ShutDown`]. Job waits started through
+    /// [`job_poller`](Self::job_poller) or the `wait_for_*` methods stop
+    /// with [`Error::Cancelled`]; the jobs themselves keep running on
+    /// Canva's side and can be resumed with another client. Requests that
+    /// were already sent are left to finish.
+    ///
+    /// Returns `true` if every in-flight request finished within `timeout`.
+    /// A request counts as finished once its response headers arrive, so
+    /// bodies being read afterwards are not waited for. Calling this again
+    /// only waits again.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let worker = client.clone();
+    /// tokio::spawn(async move { worker.designs().list_with(&Default::default()).await });
+    ///
+    /// tokio::signal::ctrl_c().await?;
+    /// if !client.shutdown(Duration::from_secs(10)).await {
+    ///     eprintln!("{} requests did not finish", client.in_flight_requests());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.lifecycle.closed.cancel();
+        tokio::time::timeout(timeout, self.lifecycle.drained())
+            .await
+            .is_ok()
+    }
+
+    /// Whether [`shutdown`](Self::shutdown) has been called on this client or a clone
+    pub fn is_shut_down(&self) -> bool {
+        self.lifecycle.closed.is_cancelled()
+    }
+
+    /// Number of requests currently being sent by this client and its clones
+    pub fn in_flight_requests(&self) -> usize {
+        self.lifecycle.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Get the base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get the API version requests are sent to, e.g. `v1`
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// Full URL for an endpoint path from the API reference, e.g. `/v1/designs`
+    ///
+    /// The path's leading `/v1` is replaced with the configured
+    /// [`api_version`](Self::api_version); other paths are appended unchanged.
+    pub fn url_for(&self, path: &str) -> String {
+        match path.strip_prefix("/v1") {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', '?']) => {
+                format!("{}/{}{rest}", self.base_url, self.api_version)
+            }
+            _ => format!("{}{}", self.base_url, path),
+        }
+    }
+
+    /// The API reference path a request URL was built from, for looking up
+    /// its operation and rate limit
+    fn api_path(&self, url: &reqwest::Url) -> String {
+        let Some(path) = url.as_str().strip_prefix(self.base_url.as_str()) else {
+            return url.path().to_string();
+        };
+        match path
+            .strip_prefix('/')
+            .and_then(|path| path.strip_prefix(self.api_version.as_str()))
+        {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', '?']) => format!("/v1{rest}"),
+            _ => path.to_string(),
+        }
+    }
+
+    /// Get the static access token the client was created with
+    ///
+    /// Clients using a [`TokenProvider`] return the token given to
+    /// [`Client::builder`], or for [`Client::with_oauth`] the token fetched
+    /// when the client was created; it may since have been refreshed. Use
+    /// [`Client::token_provider`] to get the token sent with requests.
+    pub fn access_token(&self) -> &AccessToken {
         &self.access_token
     }
 
-    /// Get the HTTP client
+    /// Get the provider supplying access tokens for requests
+    pub fn token_provider(&self) -> &dyn TokenProvider {
+        self.token_provider.as_ref()
+    }
+
+    /// Get the HTTP client used to build requests
     pub fn http_client(&self) -> &reqwest::Client {
         &self.http_client
     }
+
+    /// Get the transport requests are sent through
+    pub fn transport(&self) -> &dyn HttpTransport {
+        self.transport.as_ref()
+    }
+
+    /// Get the retry policy
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The total timeout applied to requests of `class`, if any
+    ///
+    /// This is the class's own timeout if one was set, otherwise the
+    /// client-wide [`ClientBuilder::timeout`].
+    pub fn timeout_for(&self, class: OperationClass) -> Option<Duration> {
+        self.class_timeouts
+            .get(class)
+            .or(self.class_timeouts.fallback)
+    }
+
+    /// Give `request` the timeout for its class, if one was set
+    ///
+    /// Requests without a class timeout keep the client-wide one, which the
+    /// HTTP client already applies.
+    fn classify(
+        &self,
+        request: reqwest::RequestBuilder,
+        class: OperationClass,
+    ) -> reqwest::RequestBuilder {
+        match self.class_timeouts.get(class) {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+
+    /// Get the interval job waiters poll at
+    pub fn poll_interval(&self) -> Duration {
+        self.job_poll.interval()
+    }
+
+    /// The defaults every job waiter starts from
+    pub fn job_poll_config(&self) -> &JobPollConfig {
+        &self.job_poll
+    }
+
+    /// A [`JobPoller`] set up from the client's [`JobPollConfig`]
+    ///
+    /// This is the poller the `wait_for_*` methods use; adjust it to wait for
+    /// a particular job differently. It stops when the client is
+    /// [shut down](Self::shutdown).
+    pub fn job_poller(&self) -> JobPoller {
+        self.job_poll
+            .poller()
+            .with_shutdown(self.lifecycle.closed.clone())
+    }
+
+    /// The poller for runs with many jobs at once
+    ///
+    /// Uses [`JobPollConfig::batch`] while the client keeps the default
+    /// config; otherwise the client's [`job_poller`](Self::job_poller) with
+    /// [`BATCH_POLL_JITTER`](crate::jobs::BATCH_POLL_JITTER) added unless it
+    /// already sets jitter.
+    pub(crate) fn batch_job_poller(&self) -> JobPoller {
+        if self.job_poll == default_job_poll() {
+            JobPollConfig::batch()
+                .poller()
+                .with_shutdown(self.lifecycle.closed.clone())
+        } else {
+            self.job_poller().for_batch()
+        }
+    }
+
+    /// A [`Downloader`] for pre-signed URLs such as export files and thumbnails
+    pub fn downloader(&self) -> Downloader {
+        Downloader::new(self.clone())
+    }
+
+    /// Rate limit headers of the most recent response that carried them
+    ///
+    /// Shows how much of Canva's quota is left, for a dashboard or for
+    /// spacing out background work. Canva limits each endpoint separately,
+    /// so the numbers describe whichever endpoint answered last. Clones of
+    /// the client share this value. `None` until a response has included
+    /// `X-RateLimit-*` headers.
+    pub fn last_rate_limit_info(&self) -> Option<RateLimitInfo> {
+        self.last_rate_limit
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+    }
+
+    /// Get the per-endpoint rate limits
+    pub fn endpoint_rate_limits(&self) -> &EndpointRateLimits {
+        &self.endpoint_rate_limits
+    }
+
+    /// Get the response cache, if enabled with [`ClientBuilder::cache`]
+    pub fn cache(&self) -> Option<&dyn ResponseCache> {
+        self.cache.as_deref()
+    }
+
+    /// Get the event log, if enabled with [`ClientBuilder::event_log`]
+    pub fn event_log(&self) -> Option<&EventLog> {
+        self.event_log.as_deref()
+    }
+
+    /// Export recent API interactions as JSON lines for support tickets
+    ///
+    /// Each line describes one request: method, path, status, timing, retry
+    /// count, Canva request ID and rate-limit headers. Bodies, query strings
+    /// and credentials are never included. Returns an empty string when the
+    /// event log is not enabled.
+    pub fn support_bundle(&self) -> Result<String> {
+        match &self.event_log {
+            Some(event_log) => event_log.to_jsonl(),
+            None => Ok(String::new()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -347,4 +2117,1509 @@ mod tests {
         let client = Client::with_base_url(base_url, token).expect("Failed to create client");
         assert_eq!(client.base_url(), base_url);
     }
+
+    #[test]
+    fn test_builder_defaults() {
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .build()
+            .expect("Failed to create client");
+        assert_eq!(client.base_url(), BASE_URL);
+        assert_eq!(client.retry_policy(), &RetryPolicy::none());
+    }
+
+    #[test]
+    fn test_builder_configuration() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-custom", HeaderValue::from_static("value"));
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url("https://test.api.canva.com")
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(2))
+            .user_agent("my-app/1.0")
+            .default_headers(headers)
+            .retry_policy(RetryPolicy::new(3))
+            .rate_limiter(ApiRateLimiter::permissive())
+            .build()
+            .expect("Failed to create client");
+        assert_eq!(client.base_url(), "https://test.api.canva.com");
+        assert_eq!(client.retry_policy().max_retries(), 3);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_user_agent() {
+        let result = Client::builder(AccessToken::new("test-token"))
+            .user_agent("bad\nagent")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidHeader(_))));
+    }
+
+    #[tokio::test]
+    async fn test_builder_sends_custom_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/users/me")
+            .match_header("user-agent", "my-app/1.0")
+            .match_header("x-custom", "value")
+            .match_header("authorization", "Bearer test-token")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-custom", HeaderValue::from_static("value"));
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer other"));
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .user_agent("my-app/1.0")
+            .default_headers(headers)
+            .build()
+            .expect("Failed to create client");
+
+        let response = client.get("/v1/users/me").await;
+        assert!(response.is_ok());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_transient_status() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/users/me")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .rate_limiter(ApiRateLimiter::new(1000))
+            .retry_policy(RetryPolicy::new(2).with_initial_backoff(Duration::from_millis(1)))
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.get("/v1/users/me").await;
+        assert!(result.is_err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_last_rate_limit_info_tracks_latest_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let _limited = server
+            .mock("GET", "/v1/designs")
+            .with_header("x-ratelimit-remaining", "95")
+            .with_header("x-ratelimit-limit", "100")
+            .with_header("x-ratelimit-reset", "4102444800")
+            .with_body(r#"{"items":[]}"#)
+            .create_async()
+            .await;
+        let _plain = server
+            .mock("GET", "/v1/users/me")
+            .with_body(r#"{"team_user":{}}"#)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        assert_eq!(client.last_rate_limit_info(), None);
+
+        let _ = client.get("/v1/designs").await;
+        // A response without rate limit headers keeps the last known values
+        let _ = client.clone().get("/v1/users/me").await;
+
+        assert_eq!(
+            client.last_rate_limit_info(),
+            Some(RateLimitInfo {
+                remaining: Some(95),
+                reset_at: chrono::DateTime::from_timestamp(4_102_444_800, 0),
+                limit: Some(100),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_can_bypass_rate_limiter() {
+        let mut server = mockito::Server::new_async().await;
+        let _file = server
+            .mock("GET", "/export/design.pdf")
+            .match_query(mockito::Matcher::Any)
+            .with_body("%PDF")
+            .expect(3)
+            .create_async()
+            .await;
+        let _expired = server
+            .mock("GET", "/export/expired.pdf")
+            .match_query(mockito::Matcher::Any)
+            .with_status(403)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .rate_limiter(ApiRateLimiter::with_burst(1, 1))
+            .rate_limit_downloads(false)
+            .build()
+            .expect("Failed to create client");
+        let url = format!("{}/export/design.pdf?signature=secret", server.url());
+
+        // With a one-request budget the second download would wait a minute
+        let downloads = async {
+            for _ in 0..3 {
+                let response = client.download(&url).await?;
+                assert_eq!(response.bytes().await?.as_ref(), b"%PDF");
+            }
+            Ok::<_, Error>(())
+        };
+        #[allow(clippy::expect_used)]
+        tokio::time::timeout(Duration::from_secs(10), downloads)
+            .await
+            .expect("downloads were not rate limited")
+            .expect("downloads succeed");
+
+        let expired = format!("{}/export/expired.pdf?signature=secret", server.url());
+        let error = client.download(&expired).await.err();
+        assert!(matches!(
+            &error,
+            Some(Error::Download { status, url })
+                if *status == StatusCode::FORBIDDEN && url.ends_with("/export/expired.pdf")
+        ));
+        let message = error.map(|e| e.to_string()).unwrap_or_default();
+        assert!(message.contains("403") && !message.contains("secret"));
+    }
+
+    /// Answers each request once `release` is notified
+    #[derive(Debug, Clone, Default)]
+    struct GatedTransport {
+        started: Arc<tokio::sync::Notify>,
+        release: Arc<tokio::sync::Notify>,
+    }
+
+    #[async_trait::async_trait]
+    impl CanvaTransport for GatedTransport {
+        async fn send(&self, _: http::Request<Vec<u8>>) -> Result<http::Response<Vec<u8>>> {
+            self.started.notify_one();
+            self.release.notified().await;
+            Ok(http::Response::new(br#"{"team_user":{}}"#.to_vec()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_requests_and_refuses_new_ones() {
+        let transport = GatedTransport::default();
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test_token"))
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client");
+        let worker = client.clone();
+        let in_flight = tokio::spawn(async move { worker.get("/v1/users/me").await });
+        transport.started.notified().await;
+        assert_eq!(client.in_flight_requests(), 1);
+
+        assert!(!client.shutdown(Duration::from_millis(20)).await);
+        assert!(client.clone().is_shut_down());
+        assert!(matches!(
+            client.get("/v1/users/me").await,
+            Err(Error::ShutDown)
+        ));
+        assert!(matches!(
+            client.exports().wait_for_export_job("E1").await,
+            Err(Error::Cancelled { job_id }) if job_id == "E1"
+        ));
+
+        transport.release.notify_one();
+        assert!(client.shutdown(Duration::from_secs(5)).await);
+        assert_eq!(client.in_flight_requests(), 0);
+        #[allow(clippy::expect_used)]
+        let finished = in_flight.await.expect("request task");
+        assert!(finished.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_abandons_requests_queued_on_the_rate_limiter() {
+        let transport = GatedTransport::default();
+        transport.release.notify_one();
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test_token"))
+            .transport(transport)
+            .rate_limiter(ApiRateLimiter::with_burst(1, 1))
+            .build()
+            .expect("Failed to create client");
+        assert!(client.get("/v1/users/me").await.is_ok());
+
+        let worker = client.clone();
+        let queued = tokio::spawn(async move { worker.get("/v1/users/me").await });
+        while client.in_flight_requests() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(client.shutdown(Duration::from_secs(5)).await);
+        #[allow(clippy::expect_used)]
+        let result = queued.await.expect("request task");
+        assert!(matches!(result, Err(Error::ShutDown)));
+    }
+
+    #[tokio::test]
+    async fn test_support_bundle_records_sanitized_events() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("x-request-id", "req-123")
+            .with_header("x-ratelimit-remaining", "9")
+            .with_header("x-ratelimit-limit", "10")
+            .with_body(r#"{"items":[]}"#)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("secret-token"))
+            .base_url(server.url())
+            .event_log(10)
+            .build()
+            .expect("Failed to create client");
+
+        let _ = client.get("/v1/designs?query=private%20search").await;
+
+        #[allow(clippy::expect_used)]
+        let bundle = client.support_bundle().expect("Failed to export bundle");
+        assert_eq!(bundle.lines().count(), 1);
+        assert!(!bundle.contains("secret-token"));
+        assert!(!bundle.contains("private"));
+
+        #[allow(clippy::expect_used)]
+        let event: ApiEvent = serde_json::from_str(bundle.trim()).expect("Invalid JSON line");
+        assert_eq!(event.method, "GET");
+        assert_eq!(event.path, "/v1/designs");
+        assert_eq!(event.status, Some(200));
+        assert_eq!(event.request_id.as_deref(), Some("req-123"));
+        assert_eq!(event.attempts, 1);
+        assert_eq!(event.rate_limit.and_then(|r| r.remaining), Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_event_log_keeps_query_out_of_transport_errors() {
+        // Nothing listens on port 1, so the request fails without a response
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("secret-token"))
+            .base_url("http://127.0.0.1:1")
+            .event_log(10)
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.get("/v1/designs?query=private%20search").await;
+        assert!(matches!(result, Err(Error::Http(_))));
+
+        #[allow(clippy::expect_used)]
+        let bundle = client.support_bundle().expect("Failed to export bundle");
+        assert!(!bundle.contains("private"));
+        #[allow(clippy::expect_used)]
+        let event: ApiEvent = serde_json::from_str(bundle.trim()).expect("Invalid JSON line");
+        let error = event.error.unwrap_or_default();
+        assert!(error.contains("/v1/designs"), "{error}");
+    }
+
+    #[test]
+    fn test_support_bundle_empty_when_disabled() {
+        #[allow(clippy::expect_used)]
+        let client = Client::new(AccessToken::new("test-token")).expect("Failed to create client");
+        assert!(client.event_log().is_none());
+        assert_eq!(client.support_bundle().ok().as_deref(), Some(""));
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_ignores_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/folders")
+            .with_status(400)
+            .with_body(r#"{"code":"invalid_request","message":"bad"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .retry_policy(RetryPolicy::new(2).with_initial_backoff(Duration::from_millis(1)))
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.post("/v1/folders", &serde_json::json!({})).await;
+        assert!(matches!(result, Err(Error::Api { .. })));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_does_not_resend_post_after_bad_gateway() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/exports")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .retry_policy(RetryPolicy::new(2).with_initial_backoff(Duration::from_millis(1)))
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.post("/v1/exports", &serde_json::json!({})).await;
+        assert!(matches!(result, Err(Error::Api { .. })));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_api_version_rewrites_endpoint_paths() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v2/users/me")
+            .with_status(200)
+            .with_body(r#"{"team_user":{"user_id":"u","team_id":"t"}}"#)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .api_version("v2")
+            .build()
+            .expect("Failed to create client");
+
+        assert_eq!(client.api_version(), "v2");
+        assert_eq!(
+            client.url_for("/v1/designs?limit=5"),
+            format!("{}/v2/designs?limit=5", server.url())
+        );
+        assert_eq!(
+            client.url_for("/v10/designs"),
+            format!("{}/v10/designs", server.url())
+        );
+
+        #[allow(clippy::expect_used)]
+        client.user().get_me().await.expect("request to v2");
+        mock.assert_async().await;
+        // Rate limits and operations are still looked up by the v1 reference path
+        #[allow(clippy::expect_used)]
+        let url = reqwest::Url::parse(&client.url_for("/v1/users/me")).expect("valid URL");
+        assert_eq!(client.api_path(&url), "/v1/users/me");
+
+        let invalid = Client::builder(AccessToken::new("test-token"))
+            .api_version("v2/")
+            .build();
+        assert!(matches!(invalid, Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_api_error_carries_request_context() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v1/designs/DAF123")
+            .with_status(404)
+            .with_header("x-request-id", "req-404")
+            .with_body(r#"{"code":"not_found","message":"No such design"}"#)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::with_base_url(server.url(), AccessToken::new("test-token"))
+            .expect("Failed to create client");
+
+        let Err(error) = client.get("/v1/designs/DAF123").await else {
+            panic!("expected the request to fail");
+        };
+        assert_eq!(error.method(), Some(&reqwest::Method::GET));
+        assert_eq!(error.path(), Some("/v1/designs/DAF123"));
+        assert_eq!(error.request_id(), Some("req-404"));
+        assert!(error.elapsed().is_some());
+        assert_eq!(
+            error.request_context().map(|context| context.status),
+            Some(reqwest::StatusCode::NOT_FOUND)
+        );
+
+        let message = error.to_string();
+        assert!(message.contains("No such design"));
+        assert!(message.contains("GET /v1/designs/DAF123 returned 404 Not Found"));
+        assert!(message.contains("request ID req-404"));
+
+        let workflow = Error::Workflow {
+            step: "get design DAF123".to_string(),
+            source: Box::new(error),
+        };
+        assert_eq!(workflow.request_id(), Some("req-404"));
+        assert!(std::error::Error::source(&workflow).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_non_json_error_carries_request_context() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v1/designs/DAF123")
+            .with_status(502)
+            .with_header("x-request-id", "req-502")
+            .with_body("<html>Bad Gateway</html>")
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::with_base_url(server.url(), AccessToken::new("test-token"))
+            .expect("Failed to create client");
+
+        let Err(error) = client.get("/v1/designs/DAF123").await else {
+            panic!("expected the request to fail");
+        };
+        assert_eq!(error.method(), Some(&reqwest::Method::GET));
+        assert_eq!(error.path(), Some("/v1/designs/DAF123"));
+        assert_eq!(error.request_id(), Some("req-502"));
+        assert_eq!(
+            error.request_context().map(|context| context.status),
+            Some(reqwest::StatusCode::BAD_GATEWAY)
+        );
+        assert!(error
+            .to_string()
+            .contains("GET /v1/designs/DAF123 returned 502 Bad Gateway"));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_deserialization_error_keeps_capped_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v1/designs/DAF123")
+            .with_body(
+                r#"{"design":{"id":"DAF123","access_token":"secret","title":"a long title"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .captured_body_limit(40)
+            .build()
+            .expect("Failed to create client");
+        let Err(Error::Deserialization { body, .. }) = client
+            .get_json::<crate::models::GetDesignResponse>("/v1/designs/DAF123")
+            .await
+        else {
+            panic!("expected a deserialization error");
+        };
+        let body = body.expect("body captured");
+        assert!(body.starts_with(r#"{"design":{"#));
+        assert!(!body.contains("secret"));
+        assert!(!body.contains("a long title"));
+
+        let client = Client::builder(AccessToken::new("test-token"))
+            .base_url(server.url())
+            .captured_body_limit(0)
+            .build()
+            .expect("Failed to create client");
+        let result = client
+            .get_json::<crate::models::GetDesignResponse>("/v1/designs/DAF123")
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Deserialization { body: None, .. })
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_clones_share_connection_pool() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<Client>();
+
+        let client = Client::builder(AccessToken::new("test-token"))
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(Some(Duration::from_secs(30)))
+            .http2_keep_alive(Duration::from_secs(20))
+            .build()
+            .expect("Failed to create client");
+        let clone = client.clone();
+        let background = client.with_priority(Priority::Low);
+
+        assert!(Arc::ptr_eq(&client.transport, &clone.transport));
+        assert!(Arc::ptr_eq(&client.transport, &background.transport));
+        assert!(Arc::ptr_eq(&client.rate_limiter, &background.rate_limiter));
+    }
+
+    #[tokio::test]
+    async fn test_with_oauth_refreshes_rejected_token() {
+        use crate::auth::{OAuthConfig, TokenExchangeResponse, TokenSet};
+
+        let mut server = mockito::Server::new_async().await;
+        let rejected = server
+            .mock("GET", "/v1/users/me")
+            .match_header("authorization", "Bearer stale-token")
+            .with_status(401)
+            .with_body(r#"{"code":"invalid_access_token","message":"revoked"}"#)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("POST", "/v1/oauth/token")
+            .with_body(
+                r#"{"access_token":"fresh-token","token_type":"Bearer","expires_in":3600,"refresh_token":"refresh-2"}"#,
+            )
+            .create_async()
+            .await;
+        let accepted = server
+            .mock("GET", "/v1/users/me")
+            .match_header("authorization", "Bearer fresh-token")
+            .with_body(r#"{"team_user":{"user_id":"u1","team_id":"t1"}}"#)
+            .create_async()
+            .await;
+
+        let config = OAuthConfig::new("id", "secret", "http://127.0.0.1/callback", vec![]);
+        let oauth = OAuthClient::new(config).with_api_base_url(server.url());
+        oauth
+            .token_store()
+            .store(TokenSet::from_exchange_response(TokenExchangeResponse {
+                access_token: "stale-token".to_string(),
+                token_type: "Bearer".to_string(),
+                expires_in: Some(3600),
+                refresh_token: Some("refresh-1".to_string()),
+                scope: None,
+            }))
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new(""))
+            .token_provider(oauth.clone())
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.get("/v1/users/me").await;
+        assert!(result.is_ok());
+        assert_eq!(
+            oauth.token_store().get_valid_access_token().await,
+            Some(AccessToken::new("fresh-token"))
+        );
+        rejected.assert_async().await;
+        refresh.assert_async().await;
+        accepted.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_static_token_unauthorized_is_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/users/me")
+            .with_status(401)
+            .with_body(r#"{"code":"invalid_access_token","message":"expired"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        #[allow(clippy::expect_used)]
+        let client = Client::with_base_url(server.url(), AccessToken::new("test-token"))
+            .expect("Failed to create client");
+
+        let result = client.get("/v1/users/me").await;
+        assert!(matches!(result, Err(Error::Api { .. })));
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_from_config_validates() {
+        let config = CanvaConfig::new().with_rate_limit(0, None);
+        let result = Client::from_config(AccessToken::new("test-token"), config);
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_build_validates_settings_changed_after_config() {
+        let builder = || {
+            Client::builder(AccessToken::new("test-token"))
+                .config(CanvaConfig::new().with_timeout(Duration::from_secs(30)))
+        };
+
+        let zero_timeout = builder().timeout(Duration::ZERO).build();
+        assert!(matches!(zero_timeout, Err(Error::Config(_))));
+        let zero_poll = builder().poll_interval(Duration::ZERO).build();
+        assert!(matches!(zero_poll, Err(Error::Config(_))));
+        let bad_url = builder().base_url("not a url").build();
+        assert!(matches!(bad_url, Err(Error::Config(_))));
+        let no_config = Client::builder(AccessToken::new("test-token"))
+            .job_poll_config(crate::jobs::JobPollConfig::new().with_interval(Duration::ZERO))
+            .build();
+        assert!(matches!(no_config, Err(Error::Config(_))));
+
+        #[allow(clippy::expect_used)]
+        builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .expect("valid settings");
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordThrottles(std::sync::Mutex<Vec<ThrottleEvent>>);
+
+    impl ThrottleListener for std::sync::Arc<RecordThrottles> {
+        fn on_throttle(&self, event: &ThrottleEvent) {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(event.clone());
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_after_is_honored_exactly() {
+        use crate::rate_limit::ThrottleReason;
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::error(429, "too_many_requests", "Slow down")
+                    .with_header("retry-after", "7"),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::json(200, &serde_json::json!({ "team_user": {} })),
+            );
+        let throttles = std::sync::Arc::new(RecordThrottles::default());
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .endpoint_rate_limits(EndpointRateLimits::new())
+            .retry_policy(crate::retry::RetryPolicy::new(1))
+            .throttle_listener(throttles.clone())
+            .build()
+            .expect("Failed to create client");
+
+        let started = tokio::time::Instant::now();
+        let _ = client.user().get_me().await;
+
+        assert_eq!(started.elapsed(), Duration::from_secs(7));
+        assert_eq!(transport.requests().len(), 2);
+        let events = throttles
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].reason, ThrottleReason::RetryAfter);
+        assert_eq!(events[0].delay, Duration::from_secs(7));
+        assert_eq!(events[0].path, "/rest/v1/users/me");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_exhausted_quota_pauses_until_reset() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let reset = (chrono::Utc::now() + chrono::Duration::seconds(30)).timestamp();
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/users/me",
+            MockResponse::json(200, &serde_json::json!({ "team_user": {} }))
+                .with_header("x-ratelimit-remaining", "0")
+                .with_header("x-ratelimit-limit", "10")
+                .with_header("x-ratelimit-reset", reset.to_string()),
+        );
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport)
+            .build()
+            .expect("Failed to create client");
+        let _ = client.user().get_me().await;
+
+        let paused = client
+            .endpoint_rate_limits
+            .limiter_for(&reqwest::Method::GET, "/v1/users/me")
+            .and_then(ApiRateLimiter::pause_remaining);
+        assert!(paused.is_some_and(|paused| paused > Duration::from_secs(25)));
+        assert!(client.rate_limiter.pause_remaining().is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttling_one_route_does_not_delay_another() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let reset = (chrono::Utc::now() + chrono::Duration::seconds(30)).timestamp();
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::json(200, &serde_json::json!({ "team_user": {} }))
+                    .with_header("x-ratelimit-remaining", "0")
+                    .with_header("x-ratelimit-limit", "10")
+                    .with_header("x-ratelimit-reset", reset.to_string()),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/exports/export_1",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "job": { "id": "export_1", "status": "in_progress" } }),
+                ),
+            );
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport)
+            .build()
+            .expect("Failed to create client");
+        let _ = client.user().get_me().await;
+
+        let started = tokio::time::Instant::now();
+        #[allow(clippy::expect_used)]
+        client
+            .exports()
+            .get_design_export_job("export_1")
+            .await
+            .expect("export job");
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttling_unlimited_route_pauses_client() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/users/me",
+            MockResponse::error(429, "too_many_requests", "Slow down")
+                .with_header("retry-after", "7"),
+        );
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport)
+            .endpoint_rate_limits(EndpointRateLimits::new())
+            .build()
+            .expect("Failed to create client");
+        let _ = client.user().get_me().await;
+
+        assert!(client.rate_limiter.pause_remaining().is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_config_sets_poll_interval() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/exports/export_1",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "job": { "id": "export_1", "status": "in_progress" } }),
+                ),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/exports/export_1",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({
+                        "job": { "id": "export_1", "status": "success", "urls": ["https://x/1.pdf"] }
+                    }),
+                ),
+            );
+
+        let config = CanvaConfig::new().with_polling(PollingProfile::Relaxed);
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .config(config)
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client");
+        assert_eq!(client.poll_interval(), Duration::from_secs(5));
+
+        let started = tokio::time::Instant::now();
+        #[allow(clippy::expect_used)]
+        let job = client
+            .exports()
+            .wait_for_export_job("export_1")
+            .await
+            .expect("export should finish");
+
+        assert_eq!(job.urls.len(), 1);
+        assert_eq!(started.elapsed(), Duration::from_secs(5));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_batch_job_poller_uses_batch_settings_for_default_config() {
+        use crate::jobs::{JobPollConfig, BATCH_POLL_JITTER};
+
+        #[allow(clippy::expect_used)]
+        let build = |config: Option<JobPollConfig>| {
+            let builder = Client::builder(AccessToken::new("test-token"));
+            match config {
+                Some(config) => builder.job_poll_config(config),
+                None => builder,
+            }
+            .build()
+            .expect("Failed to create client")
+        };
+
+        let poller = build(None).batch_job_poller();
+        assert_eq!(poller.interval(), Duration::from_secs(1));
+        assert_eq!(poller.backoff(), 2.0);
+        assert_eq!(poller.jitter(), BATCH_POLL_JITTER);
+
+        let custom = JobPollConfig::new().with_interval(Duration::from_secs(10));
+        let poller = build(Some(custom.clone())).batch_job_poller();
+        assert_eq!(poller.interval(), Duration::from_secs(10));
+        assert_eq!(poller.backoff(), 1.0);
+        assert_eq!(poller.jitter(), BATCH_POLL_JITTER);
+
+        let poller = build(Some(custom.with_jitter(0.1))).batch_job_poller();
+        assert_eq!(poller.jitter(), 0.1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_job_poll_config_applies_to_every_waiter() {
+        use crate::jobs::{JobPollConfig, WaitOptions};
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/exports/export_1",
+            MockResponse::json(
+                200,
+                &serde_json::json!({ "job": { "id": "export_1", "status": "in_progress" } }),
+            ),
+        );
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .job_poll_config(
+                JobPollConfig::new()
+                    .with_interval(Duration::from_secs(10))
+                    .with_timeout(Duration::from_secs(30)),
+            )
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client");
+        let exports = client.clone().exports();
+
+        let started = tokio::time::Instant::now();
+        let result = exports.wait_for_export_job("export_1").await;
+        assert!(
+            matches!(result, Err(Error::JobTimeout { timeout, .. }) if timeout == Duration::from_secs(30))
+        );
+        assert_eq!(started.elapsed(), Duration::from_secs(30));
+
+        let options = WaitOptions::new().with_timeout(Duration::from_secs(5));
+        let result = exports.wait_for_export_job_with("export_1", &options).await;
+        assert!(
+            matches!(result, Err(Error::JobTimeout { timeout, .. }) if timeout == Duration::from_secs(5))
+        );
+    }
+
+    #[derive(Debug)]
+    struct AddHeader(&'static str);
+
+    #[async_trait::async_trait]
+    impl RequestInterceptor for AddHeader {
+        async fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+            request
+                .headers_mut()
+                .append("x-trace", HeaderValue::from_static(self.0));
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordResponses(std::sync::Mutex<Vec<(String, u32, u16)>>);
+
+    #[async_trait::async_trait]
+    impl ResponseInterceptor for std::sync::Arc<RecordResponses> {
+        async fn on_response(
+            &self,
+            context: &ResponseContext<'_>,
+            response: &mut reqwest::Response,
+        ) -> Result<()> {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push((
+                    context.url.path().to_string(),
+                    context.attempt,
+                    response.status().as_u16(),
+                ));
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Deny;
+
+    #[async_trait::async_trait]
+    impl RequestInterceptor for Deny {
+        async fn on_request(&self, _request: &mut reqwest::Request) -> Result<()> {
+            Err(Error::Generic("denied".to_string()))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_interceptors_see_every_attempt() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::error(503, "unavailable", "Try again"),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "team_user": { "user_id": "u1", "team_id": "t1" } }),
+                ),
+            );
+        let responses = std::sync::Arc::new(RecordResponses::default());
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(1))
+            .request_interceptor(AddHeader("first"))
+            .request_interceptor(AddHeader("second"))
+            .response_interceptor(responses.clone())
+            .build()
+            .expect("Failed to create client");
+        #[allow(clippy::expect_used)]
+        client
+            .user()
+            .get_me()
+            .await
+            .expect("second attempt succeeds");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        let traces: Vec<_> = requests[1]
+            .headers
+            .get_all("x-trace")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect();
+        assert_eq!(traces, vec!["first", "second"]);
+        let seen = responses
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        assert_eq!(
+            seen,
+            vec![
+                ("/rest/v1/users/me".to_string(), 1, 503),
+                ("/rest/v1/users/me".to_string(), 2, 200),
+            ]
+        );
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct EchoTransport(Arc<std::sync::Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl CanvaTransport for EchoTransport {
+        async fn send(&self, request: http::Request<Vec<u8>>) -> Result<http::Response<Vec<u8>>> {
+            let (parts, body) = request.into_parts();
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(format!(
+                    "{} {} {}",
+                    parts.method,
+                    parts.uri.path(),
+                    String::from_utf8_lossy(&body)
+                ));
+            let response = match parts.uri.path() {
+                "/rest/v1/folders" => http::Response::builder().status(200).body(
+                    br#"{"folder":{"id":"FAF1","name":"Plans","created_at":1,"updated_at":1}}"#
+                        .to_vec(),
+                ),
+                _ => http::Response::builder()
+                    .status(404)
+                    .body(br#"{"code":"NOT_FOUND","message":"nothing here"}"#.to_vec()),
+            };
+            response.map_err(|e| Error::Generic(e.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_canva_transport_backs_client() {
+        let transport = EchoTransport::default();
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .expect("Failed to create client");
+
+        #[allow(clippy::expect_used)]
+        let created = client
+            .folders()
+            .create_folder(&crate::endpoints::folders::CreateFolderRequest {
+                name: "Plans".to_string(),
+                parent_folder_id: "root".to_string(),
+            })
+            .await
+            .expect("folder created");
+        assert_eq!(created.folder.id, "FAF1");
+        let missing = client.folders().get_folder("nope").await;
+        assert!(matches!(
+            missing,
+            Err(Error::Api {
+                code: ApiErrorCode::NotFound,
+                ..
+            })
+        ));
+
+        let seen = transport
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        assert_eq!(
+            seen,
+            vec![
+                r#"POST /rest/v1/folders {"name":"Plans","parent_folder_id":"root"}"#,
+                "GET /rest/v1/folders/nope ",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_interceptor_error_aborts_request() {
+        use crate::testing::MockTransport;
+
+        let transport = MockTransport::new();
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .request_interceptor(Deny)
+            .build()
+            .expect("Failed to create client");
+
+        let result = client.user().get_me().await;
+
+        assert!(matches!(result, Err(Error::Generic(message)) if message == "denied"));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[derive(Debug, Default)]
+    struct GatewayHeaders(std::sync::atomic::AtomicUsize);
+
+    #[async_trait::async_trait]
+    impl HeaderProvider for std::sync::Arc<GatewayHeaders> {
+        async fn headers(&self, _request: &reqwest::Request) -> Result<HeaderMap> {
+            let call = self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut headers = HeaderMap::new();
+            headers.insert("x-tenant", HeaderValue::from_static("acme"));
+            headers.insert("x-correlation-id", HeaderValue::from(call));
+            headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer gateway"));
+            Ok(headers)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_header_provider_adds_headers_but_not_authorization() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::error(503, "unavailable", "Try again"),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "team_user": { "user_id": "u1", "team_id": "t1" } }),
+                ),
+            );
+        let provider = std::sync::Arc::new(GatewayHeaders::default());
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(1))
+            .header_provider(std::sync::Arc::clone(&provider))
+            .build()
+            .expect("Failed to create client");
+        #[allow(clippy::expect_used)]
+        client
+            .user()
+            .get_me()
+            .await
+            .expect("second attempt succeeds");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.header("x-tenant"), Some("acme"));
+            // Retries reuse the headers from the first call
+            assert_eq!(request.header("x-correlation-id"), Some("0"));
+            assert_eq!(request.header("authorization"), Some("Bearer test-token"));
+        }
+        assert_eq!(provider.0.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordTimeouts(std::sync::Mutex<Vec<(String, Option<Duration>)>>);
+
+    #[async_trait::async_trait]
+    impl RequestInterceptor for std::sync::Arc<RecordTimeouts> {
+        async fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push((request.url().path().to_string(), request.timeout().copied()));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_get_their_class_timeout() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "team_user": { "user_id": "u1", "team_id": "t1" } }),
+                ),
+            )
+            .on(
+                reqwest::Method::POST,
+                "/rest/v1/asset-uploads",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "job": { "id": "job_1", "status": "in_progress" } }),
+                ),
+            );
+        let recorded = std::sync::Arc::new(RecordTimeouts::default());
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport)
+            .timeout(Duration::from_secs(30))
+            .upload_timeout(Duration::from_secs(600))
+            .request_interceptor(recorded.clone())
+            .build()
+            .expect("Failed to create client");
+        assert_eq!(
+            client.timeout_for(OperationClass::Short),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            client.timeout_for(OperationClass::Upload),
+            Some(Duration::from_secs(600))
+        );
+
+        #[allow(clippy::expect_used)]
+        client.user().get_me().await.expect("get_me");
+        #[allow(clippy::expect_used)]
+        client
+            .post_binary("/v1/asset-uploads", b"data".to_vec(), None)
+            .await
+            .expect("upload");
+
+        let seen = recorded
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        assert_eq!(
+            seen,
+            vec![
+                // The client-wide timeout is applied by the HTTP client itself
+                ("/rest/v1/users/me".to_string(), None),
+                (
+                    "/rest/v1/asset-uploads".to_string(),
+                    Some(Duration::from_secs(600))
+                ),
+            ]
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordLog(std::sync::Mutex<Vec<crate::debug_log::HttpLogEntry>>);
+
+    impl crate::debug_log::DebugLogSink for std::sync::Arc<RecordLog> {
+        fn log(&self, entry: &crate::debug_log::HttpLogEntry) {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(entry.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_debug_log_redacts_credentials_and_keeps_body() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::POST,
+            "/rest/v1/folders",
+            MockResponse::json(
+                200,
+                &serde_json::json!({ "folder": { "id": "f1", "name": "Launch",
+                    "created_at": 1, "updated_at": 1 } }),
+            )
+            .with_header("x-request-id", "req-1"),
+        );
+        let log = std::sync::Arc::new(RecordLog::default());
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new("secret-token"))
+            .transport(transport.clone())
+            .debug_log(
+                DebugLogConfig::new()
+                    .with_bodies(1024)
+                    .with_sink(log.clone()),
+            )
+            .build()
+            .expect("Failed to create client");
+        #[allow(clippy::expect_used)]
+        let body: serde_json::Value = client
+            .post_json(
+                "/v1/folders",
+                &serde_json::json!({ "name": "Launch", "access_token": "leaked" }),
+            )
+            .await
+            .expect("response survives logging");
+
+        assert_eq!(body["folder"]["id"], "f1");
+        let entries = log.0.lock().unwrap_or_else(|p| p.into_inner()).clone();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.method, "POST");
+        assert_eq!(entry.status, Some(200));
+        assert_eq!(entry.request_id.as_deref(), Some("req-1"));
+        assert!(entry
+            .request_headers
+            .contains(&("authorization".to_string(), "[REDACTED]".to_string())));
+        let logged = format!("{entry:?}");
+        assert!(!logged.contains("secret-token"));
+        assert!(!logged.contains("leaked"));
+        assert!(entry
+            .response_body
+            .as_deref()
+            .is_some_and(|body| body.contains("Launch")));
+    }
+
+    #[derive(Debug)]
+    struct ScopedToken(&'static str);
+
+    #[async_trait::async_trait]
+    impl TokenProvider for ScopedToken {
+        async fn access_token(&self) -> Result<AccessToken> {
+            Ok(AccessToken::new("scoped-token"))
+        }
+
+        async fn refresh(&self) -> Result<AccessToken> {
+            Err(Error::Auth("cannot refresh".to_string()))
+        }
+
+        async fn granted_scopes(&self) -> Result<Option<crate::auth::ScopeSet>> {
+            Ok(Some(crate::auth::ScopeSet::parse_lossy(self.0)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scope_preflight_rejects_request_without_sending() {
+        use crate::auth::Scope;
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/designs",
+            MockResponse::json(200, &serde_json::json!({ "items": [] })),
+        );
+
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new(""))
+            .token_provider(ScopedToken("design:meta:read"))
+            .transport(transport.clone())
+            .scope_preflight(true)
+            .build()
+            .expect("Failed to create client");
+
+        let error = client
+            .exports()
+            .get_design_export_job("export_1")
+            .await
+            .err();
+        match error {
+            Some(Error::MissingScope { required, granted }) => {
+                assert!(required.contains(Scope::DesignContentRead));
+                assert_eq!(granted.to_string(), "design:meta:read");
+            }
+            other => panic!("expected MissingScope, got {other:?}"),
+        }
+        assert!(transport.requests().is_empty());
+
+        assert!(client
+            .designs()
+            .list_with(&Default::default())
+            .await
+            .is_ok());
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct SwappableToken(Arc<std::sync::Mutex<String>>);
+
+    #[async_trait::async_trait]
+    impl TokenProvider for SwappableToken {
+        async fn access_token(&self) -> Result<AccessToken> {
+            let token = self.0.lock().unwrap_or_else(|p| p.into_inner()).clone();
+            Ok(AccessToken::new(token))
+        }
+
+        async fn refresh(&self) -> Result<AccessToken> {
+            Err(Error::Auth("cannot refresh".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_cached_per_token() {
+        use crate::testing::{MockResponse, MockTransport};
+
+        let transport = MockTransport::new();
+        transport
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me/capabilities",
+                MockResponse::json(200, &serde_json::json!({ "capabilities": ["resize"] })),
+            )
+            .on(
+                reqwest::Method::GET,
+                "/rest/v1/users/me/capabilities",
+                MockResponse::json(
+                    200,
+                    &serde_json::json!({ "capabilities": ["autofill", "brand_template", "new"] }),
+                ),
+            );
+        let token = SwappableToken::default();
+        *token.0.lock().unwrap_or_else(|p| p.into_inner()) = "first".to_string();
+        #[allow(clippy::expect_used)]
+        let client = Client::builder(AccessToken::new(""))
+            .token_provider(token.clone())
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client");
+
+        #[allow(clippy::expect_used)]
+        let first = client.capabilities().await.expect("capabilities");
+        assert!(first.can_resize());
+        assert!(!first.can_autofill());
+        #[allow(clippy::expect_used)]
+        let again = client
+            .with_priority(Priority::Low)
+            .capabilities()
+            .await
+            .expect("cached capabilities");
+        assert_eq!(again, first);
+        assert_eq!(transport.requests().len(), 1);
+
+        *token.0.lock().unwrap_or_else(|p| p.into_inner()) = "second".to_string();
+        #[allow(clippy::expect_used)]
+        let upgraded = client.capabilities().await.expect("refetched capabilities");
+        assert!(upgraded.can_autofill());
+        assert!(upgraded.can_use_brand_templates());
+        assert!(!upgraded.contains(crate::endpoints::user::Capability::Unknown));
+        assert_eq!(upgraded.as_slice().len(), 3);
+        assert_eq!(transport.requests().len(), 2);
+
+        client.invalidate_capabilities().await;
+        #[allow(clippy::expect_used)]
+        client.capabilities().await.expect("refetched capabilities");
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_granted_scopes_introspects_once_per_token() {
+        use crate::auth::{OAuthConfig, Scope, TokenExchangeResponse, TokenSet};
+
+        let mut server = mockito::Server::new_async().await;
+        let introspect = server
+            .mock("POST", "/v1/oauth/introspect")
+            .with_body(r#"{"active":true,"scope":"design:meta:read asset:read"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let config = OAuthConfig::new("id", "secret", "http://127.0.0.1/callback", vec![]);
+        let oauth = OAuthClient::new(config).with_api_base_url(server.url());
+        let token_set = |token: &str| {
+            TokenSet::from_exchange_response(TokenExchangeResponse {
+                access_token: token.to_string(),
+                token_type: "Bearer".to_string(),
+                expires_in: Some(3600),
+                refresh_token: None,
+                scope: None,
+            })
+        };
+        oauth.token_store().store(token_set("token-1")).await;
+
+        let unauthorized = OAuthClient::new(OAuthConfig::new(
+            "id",
+            "secret",
+            "http://127.0.0.1/callback",
+            vec![],
+        ));
+        assert!(matches!(
+            Client::with_oauth(unauthorized).await,
+            Err(Error::Auth(_))
+        ));
+
+        #[allow(clippy::expect_used)]
+        let client = Client::with_oauth(oauth.clone())
+            .await
+            .expect("Failed to create client");
+        assert_eq!(client.access_token().as_str(), "token-1");
+        for _ in 0..2 {
+            #[allow(clippy::expect_used)]
+            let scopes = client
+                .granted_scopes()
+                .await
+                .expect("introspection succeeds")
+                .unwrap_or_default();
+            assert!(scopes.contains(Scope::AssetRead));
+        }
+
+        oauth.token_store().store(token_set("token-2")).await;
+        assert!(client.granted_scopes().await.is_ok());
+        introspect.assert_async().await;
+    }
 }
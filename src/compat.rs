@@ -0,0 +1,77 @@
+//! Deprecated signatures kept for source compatibility.
+//!
+//! When a public signature is reshaped, the old form moves here as a thin
+//! `#[deprecated]` wrapper around its replacement and stays for one release
+//! cycle before removal. Keeping the shims together makes it easy to see what
+//! is scheduled to go, and keeps them out of the main endpoint code.
+//!
+//! Each shim's deprecation note names the replacement and the release the
+//! shim was introduced in.
+//!
+//! | Deprecated | Replacement | Since |
+//! |------------|-------------|-------|
+//! | [`OAuthClient::exchange_code`] | [`OAuthClient::exchange_code_with_pkce`] | 0.1.0 |
+//! | [`ExportResult`](crate::models::ExportResult) | [`ExportJob::urls`](crate::models::ExportJob::urls) | 0.1.0 |
+//! | [`OAuthConfig::scopes_string`] | [`OAuthConfig::scope_set`] | 0.1.0 |
+//! | [`DesignsApi::list`] | [`DesignsApi::list_with`] and a [`ListDesignsRequest`] | 0.1.0 |
+
+use crate::{
+    auth::{OAuthClient, OAuthConfig, TokenExchangeResponse},
+    endpoints::designs::{DesignsApi, ListDesignsRequest},
+    error::{Error, Result},
+    models::{GetListDesignResponse, OwnershipType, SortByType},
+};
+
+impl OAuthClient {
+    /// Exchange authorization code for access token (PKCE required for Canva Connect API)
+    ///
+    /// Note: This method is deprecated. Use `exchange_code_with_pkce` instead as PKCE is required.
+    #[deprecated(
+        since = "0.1.0",
+        note = "PKCE is required for Canva Connect API. Use exchange_code_with_pkce instead."
+    )]
+    pub async fn exchange_code(&self, _code: &str) -> Result<TokenExchangeResponse> {
+        Err(Error::Auth(
+            "PKCE is required for Canva Connect API. Use exchange_code_with_pkce instead."
+                .to_string(),
+        ))
+    }
+}
+
+impl OAuthConfig {
+    /// Convert scopes to a space-separated string
+    #[deprecated(
+        since = "0.1.0",
+        note = "Use scope_set(), whose Display impl produces the same string."
+    )]
+    pub fn scopes_string(&self) -> String {
+        self.scope_set().to_string()
+    }
+}
+
+impl DesignsApi {
+    /// List designs, passing each filter positionally
+    ///
+    /// Superseded by [`DesignsApi::list_with`], which takes a
+    /// [`ListDesignsRequest`] and can also set a page size.
+    #[deprecated(
+        since = "0.1.0",
+        note = "Use list_with() with a ListDesignsRequest, e.g. from ListDesignsRequest::builder()."
+    )]
+    pub async fn list(
+        &self,
+        query: Option<String>,
+        continuation: Option<String>,
+        ownership: Option<OwnershipType>,
+        sort_by: Option<SortByType>,
+    ) -> Result<GetListDesignResponse> {
+        self.list_with(&ListDesignsRequest {
+            query,
+            ownership,
+            sort_by,
+            continuation,
+            limit: None,
+        })
+        .await
+    }
+}
@@ -0,0 +1,517 @@
+//! Client configuration with environment variable overrides.
+//!
+//! [`CanvaConfig`] gathers the settings operators usually need to tune in
+//! production (retries, rate limits, timeouts and job polling) in one place.
+//! Start from the defaults or from [`CanvaConfig::from_env`], then build a
+//! client with [`Client::from_config`](crate::Client::from_config) or
+//! [`ClientBuilder::config`](crate::ClientBuilder::config). The configuration
+//! is validated when the client is built, and logged when the
+//! `observability` feature is enabled.
+//!
+//! ## Environment variables
+//!
+//! | Variable | Setting | Example |
+//! |----------|---------|---------|
+//! | `CANVA_BASE_URL` | API base URL | `https://api.canva.com/rest` |
+//! | `CANVA_API_VERSION` | API version | `v1` |
+//! | `CANVA_TIMEOUT_SECS` | Total request timeout | `30` |
+//! | `CANVA_CONNECT_TIMEOUT_SECS` | Connection timeout | `5` |
+//! | `CANVA_MAX_RETRIES` | Retries after a transient failure | `3` |
+//! | `CANVA_RETRY_INITIAL_BACKOFF_MS` | Delay before the first retry | `500` |
+//! | `CANVA_RETRY_MAX_BACKOFF_MS` | Upper bound for retry delays | `30000` |
+//! | `CANVA_RPM` | Client-wide requests per minute | `30` |
+//! | `CANVA_RATE_BURST` | Client-wide burst size | `10` |
+//! | `CANVA_ENDPOINT_LIMITS` | Apply Canva's per-endpoint quotas | `true` |
+//! | `CANVA_POLL_PROFILE` | Job polling profile: `fast`, `standard` or `relaxed` | `relaxed` |
+//! | `CANVA_POLL_INTERVAL_MS` | Job polling interval, overriding the profile | `1500` |
+//!
+//! Unset variables keep the configured value; malformed ones are reported as
+//! [`Error::Config`].
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, config::CanvaConfig};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = CanvaConfig::from_env()?;
+//! let client = Client::from_config(AccessToken::new("your-token"), config)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    rate_limit::{ApiRateLimiter, EndpointRateLimits},
+    retry::RetryPolicy,
+    API_VERSION, BASE_URL,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How often job waiters such as
+/// [`ExportsApi::wait_for_export_job`](crate::endpoints::exports::ExportsApi::wait_for_export_job)
+/// poll for completion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PollingProfile {
+    /// Poll every second, for interactive use
+    Fast,
+    /// Poll every two seconds
+    #[default]
+    Standard,
+    /// Poll every five seconds, for background jobs and tight quotas
+    Relaxed,
+    /// Poll at a custom interval
+    Custom(Duration),
+}
+
+impl PollingProfile {
+    /// Time between two status checks
+    pub fn interval(&self) -> Duration {
+        match self {
+            Self::Fast => Duration::from_secs(1),
+            Self::Standard => Duration::from_secs(2),
+            Self::Relaxed => Duration::from_secs(5),
+            Self::Custom(interval) => *interval,
+        }
+    }
+}
+
+impl FromStr for PollingProfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "fast" => Ok(Self::Fast),
+            "standard" => Ok(Self::Standard),
+            "relaxed" => Ok(Self::Relaxed),
+            other => Err(Error::Config(format!(
+                "Unknown polling profile {other:?}; expected fast, standard or relaxed"
+            ))),
+        }
+    }
+}
+
+/// Tunable client settings
+///
+/// The defaults match [`Client::new`](crate::Client::new).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvaConfig {
+    base_url: String,
+    api_version: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    requests_per_minute: u32,
+    burst: Option<u32>,
+    endpoint_limits: bool,
+    polling: PollingProfile,
+}
+
+impl Default for CanvaConfig {
+    fn default() -> Self {
+        let rate_limiter = ApiRateLimiter::default();
+        Self {
+            base_url: BASE_URL.to_string(),
+            api_version: API_VERSION.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            requests_per_minute: rate_limiter.requests_per_minute(),
+            burst: None,
+            endpoint_limits: true,
+            polling: PollingProfile::default(),
+        }
+    }
+}
+
+impl CanvaConfig {
+    /// The default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default configuration with overrides from the process environment
+    pub fn from_env() -> Result<Self> {
+        Self::new().with_env_overrides()
+    }
+
+    /// Apply overrides from the process environment
+    pub fn with_env_overrides(self) -> Result<Self> {
+        self.with_overrides(|name| std::env::var(name).ok())
+    }
+
+    /// Apply overrides from any source of `CANVA_*` variables
+    ///
+    /// `lookup` receives a variable name such as `CANVA_MAX_RETRIES` and
+    /// returns its value, if set. Useful for configuration files or secrets
+    /// managers that expose the same names as the environment.
+    pub fn with_overrides(mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let lookup = |name: &str| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        if let Some(base_url) = lookup("CANVA_BASE_URL") {
+            self.base_url = base_url;
+        }
+        if let Some(api_version) = lookup("CANVA_API_VERSION") {
+            self.api_version = api_version;
+        }
+        if let Some(secs) = parse_var::<u64>(&lookup, "CANVA_TIMEOUT_SECS")? {
+            self.timeout = Some(Duration::from_secs(secs));
+        }
+        if let Some(secs) = parse_var::<u64>(&lookup, "CANVA_CONNECT_TIMEOUT_SECS")? {
+            self.connect_timeout = Some(Duration::from_secs(secs));
+        }
+        if let Some(max_retries) = parse_var::<u32>(&lookup, "CANVA_MAX_RETRIES")? {
+            self.retry_policy = self.retry_policy.with_max_retries(max_retries);
+        }
+        if let Some(ms) = parse_var::<u64>(&lookup, "CANVA_RETRY_INITIAL_BACKOFF_MS")? {
+            self.retry_policy = self
+                .retry_policy
+                .with_initial_backoff(Duration::from_millis(ms));
+        }
+        if let Some(ms) = parse_var::<u64>(&lookup, "CANVA_RETRY_MAX_BACKOFF_MS")? {
+            self.retry_policy = self
+                .retry_policy
+                .with_max_backoff(Duration::from_millis(ms));
+        }
+        if let Some(rpm) = parse_var::<u32>(&lookup, "CANVA_RPM")? {
+            self.requests_per_minute = rpm;
+        }
+        if let Some(burst) = parse_var::<u32>(&lookup, "CANVA_RATE_BURST")? {
+            self.burst = Some(burst);
+        }
+        if let Some(enabled) = parse_var::<bool>(&lookup, "CANVA_ENDPOINT_LIMITS")? {
+            self.endpoint_limits = enabled;
+        }
+        if let Some(profile) = parse_var::<PollingProfile>(&lookup, "CANVA_POLL_PROFILE")? {
+            self.polling = profile;
+        }
+        if let Some(ms) = parse_var::<u64>(&lookup, "CANVA_POLL_INTERVAL_MS")? {
+            self.polling = PollingProfile::Custom(Duration::from_millis(ms));
+        }
+
+        Ok(self)
+    }
+
+    /// Set the API base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the API version, e.g. `v1`
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Set the total timeout for each request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the retry policy
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the client-wide rate limit
+    ///
+    /// `burst` defaults to `requests_per_minute` when `None`.
+    pub fn with_rate_limit(mut self, requests_per_minute: u32, burst: Option<u32>) -> Self {
+        self.requests_per_minute = requests_per_minute;
+        self.burst = burst;
+        self
+    }
+
+    /// Enable or disable Canva's per-endpoint quotas
+    pub fn with_endpoint_limits(mut self, enabled: bool) -> Self {
+        self.endpoint_limits = enabled;
+        self
+    }
+
+    /// Set how often job waiters poll
+    pub fn with_polling(mut self, polling: PollingProfile) -> Self {
+        self.polling = polling;
+        self
+    }
+
+    /// API base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// API version
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// Total timeout for each request, if any
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Timeout for establishing a connection, if any
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Retry policy
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Client-wide requests per minute
+    pub fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute
+    }
+
+    /// Client-wide burst size
+    pub fn burst(&self) -> u32 {
+        self.burst.unwrap_or(self.requests_per_minute)
+    }
+
+    /// Whether Canva's per-endpoint quotas are applied
+    pub fn endpoint_limits(&self) -> bool {
+        self.endpoint_limits
+    }
+
+    /// Job polling profile
+    pub fn polling(&self) -> PollingProfile {
+        self.polling
+    }
+
+    /// Check that the settings are usable
+    ///
+    /// Rejects unparseable base URLs, malformed API versions, zero timeouts and rates, a connection
+    /// timeout longer than the request timeout, retry backoff bounds in the
+    /// wrong order and a zero polling interval.
+    pub fn validate(&self) -> Result<()> {
+        validate_settings(&ClientSettings {
+            base_url: &self.base_url,
+            api_version: &self.api_version,
+            timeout: self.timeout,
+            connect_timeout: self.connect_timeout,
+            retry_policy: &self.retry_policy,
+            poll_interval: self.polling.interval(),
+        })?;
+        self.validate_rate_limit()
+    }
+
+    /// Check the rate limit settings, which only a config can set
+    pub(crate) fn validate_rate_limit(&self) -> Result<()> {
+        if self.requests_per_minute == 0 {
+            return Err(Error::Config(
+                "requests per minute must be greater than zero".to_string(),
+            ));
+        }
+        if self.burst == Some(0) {
+            return Err(Error::Config(
+                "rate limit burst must be greater than zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Client-wide rate limiter for these settings
+    pub(crate) fn rate_limiter(&self) -> ApiRateLimiter {
+        ApiRateLimiter::with_burst(self.requests_per_minute, self.burst())
+    }
+
+    /// Per-endpoint limits for these settings
+    pub(crate) fn endpoint_rate_limits(&self) -> EndpointRateLimits {
+        if self.endpoint_limits {
+            EndpointRateLimits::canva_defaults()
+        } else {
+            EndpointRateLimits::new()
+        }
+    }
+}
+
+fn parse_var<T: FromStr>(lookup: &impl Fn(&str) -> Option<String>, name: &str) -> Result<Option<T>>
+where
+    T::Err: std::fmt::Display,
+{
+    lookup(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|e| Error::Config(format!("{name}={value:?} is invalid: {e}")))
+        })
+        .transpose()
+}
+
+/// Settings a [`ClientBuilder`](crate::ClientBuilder) can change after a
+/// [`CanvaConfig`] is applied
+pub(crate) struct ClientSettings<'a> {
+    pub(crate) base_url: &'a str,
+    pub(crate) api_version: &'a str,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) retry_policy: &'a RetryPolicy,
+    pub(crate) poll_interval: Duration,
+}
+
+/// Check the settings shared by [`CanvaConfig`] and the client builder
+pub(crate) fn validate_settings(settings: &ClientSettings<'_>) -> Result<()> {
+    let invalid = |message: String| Err(Error::Config(message));
+
+    if let Err(e) = reqwest::Url::parse(settings.base_url) {
+        return invalid(format!(
+            "base URL {:?} is not a valid URL: {e}",
+            settings.base_url
+        ));
+    }
+    validate_api_version(settings.api_version)?;
+    if settings.timeout == Some(Duration::ZERO) {
+        return invalid("request timeout must be greater than zero".to_string());
+    }
+    if settings.connect_timeout == Some(Duration::ZERO) {
+        return invalid("connect timeout must be greater than zero".to_string());
+    }
+    if let (Some(timeout), Some(connect_timeout)) = (settings.timeout, settings.connect_timeout) {
+        if connect_timeout > timeout {
+            return invalid(format!(
+                "connect timeout ({connect_timeout:?}) exceeds request timeout ({timeout:?})"
+            ));
+        }
+    }
+    let retry_policy = settings.retry_policy;
+    if retry_policy.initial_backoff() > retry_policy.max_backoff() {
+        return invalid(format!(
+            "initial retry backoff ({:?}) exceeds maximum backoff ({:?})",
+            retry_policy.initial_backoff(),
+            retry_policy.max_backoff()
+        ));
+    }
+    if settings.poll_interval.is_zero() {
+        return invalid("polling interval must be greater than zero".to_string());
+    }
+    Ok(())
+}
+
+/// Check that `api_version` can stand in for `v1` as a single path segment
+pub(crate) fn validate_api_version(api_version: &str) -> Result<()> {
+    if api_version.is_empty() || !api_version.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::Config(format!(
+            "API version {api_version:?} must be a single path segment such as \"v1\""
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn overrides(vars: &[(&str, &str)]) -> Result<CanvaConfig> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        CanvaConfig::new().with_overrides(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let config = CanvaConfig::default();
+        config.validate().expect("defaults should be valid");
+        assert_eq!(config.base_url(), BASE_URL);
+        assert_eq!(config.api_version(), API_VERSION);
+        assert_eq!(config.requests_per_minute(), 30);
+        assert_eq!(config.retry_policy().max_retries(), 0);
+        assert!(config.endpoint_limits());
+        assert_eq!(config.polling().interval(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_overrides_apply() {
+        let config = overrides(&[
+            ("CANVA_MAX_RETRIES", "3"),
+            ("CANVA_RETRY_INITIAL_BACKOFF_MS", "250"),
+            ("CANVA_RPM", "90"),
+            ("CANVA_RATE_BURST", " 5 "),
+            ("CANVA_TIMEOUT_SECS", "30"),
+            ("CANVA_CONNECT_TIMEOUT_SECS", "5"),
+            ("CANVA_ENDPOINT_LIMITS", "false"),
+            ("CANVA_POLL_PROFILE", "Relaxed"),
+            ("CANVA_BASE_URL", ""),
+            ("CANVA_API_VERSION", "v2"),
+        ])
+        .expect("valid overrides");
+
+        assert_eq!(config.retry_policy().max_retries(), 3);
+        assert_eq!(
+            config.retry_policy().initial_backoff(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(config.requests_per_minute(), 90);
+        assert_eq!(config.burst(), 5);
+        assert_eq!(config.timeout(), Some(Duration::from_secs(30)));
+        assert_eq!(config.connect_timeout(), Some(Duration::from_secs(5)));
+        assert!(!config.endpoint_limits());
+        assert_eq!(config.polling(), PollingProfile::Relaxed);
+        assert_eq!(config.api_version(), "v2");
+        // Empty values are treated as unset
+        assert_eq!(config.base_url(), BASE_URL);
+    }
+
+    #[test]
+    fn test_poll_interval_overrides_profile() {
+        let config = overrides(&[
+            ("CANVA_POLL_PROFILE", "fast"),
+            ("CANVA_POLL_INTERVAL_MS", "1500"),
+        ])
+        .expect("valid overrides");
+        assert_eq!(config.polling().interval(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_malformed_values_name_the_variable() {
+        let error = overrides(&[("CANVA_MAX_RETRIES", "lots")]).expect_err("should fail");
+        assert!(matches!(&error, Error::Config(message) if message.contains("CANVA_MAX_RETRIES")));
+
+        assert!(overrides(&[("CANVA_POLL_PROFILE", "sometimes")]).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_settings() {
+        let invalid = [
+            CanvaConfig::new().with_base_url("not a url"),
+            CanvaConfig::new().with_api_version(""),
+            CanvaConfig::new().with_api_version("/v2"),
+            CanvaConfig::new().with_timeout(Duration::ZERO),
+            CanvaConfig::new()
+                .with_timeout(Duration::from_secs(5))
+                .with_connect_timeout(Duration::from_secs(10)),
+            CanvaConfig::new().with_rate_limit(0, None),
+            CanvaConfig::new().with_rate_limit(30, Some(0)),
+            CanvaConfig::new().with_retry_policy(
+                RetryPolicy::new(3)
+                    .with_initial_backoff(Duration::from_secs(10))
+                    .with_max_backoff(Duration::from_secs(1)),
+            ),
+            CanvaConfig::new().with_polling(PollingProfile::Custom(Duration::ZERO)),
+        ];
+        for config in invalid {
+            assert!(
+                matches!(config.validate(), Err(Error::Config(_))),
+                "{config:?} should be invalid"
+            );
+        }
+    }
+}
@@ -0,0 +1,433 @@
+//! Opt-in logging of every HTTP exchange, with secrets redacted.
+//!
+//! Enabled with [`ClientBuilder::debug_log`](crate::ClientBuilder::debug_log),
+//! the client reports one [`HttpLogEntry`] per attempt to a [`DebugLogSink`]:
+//! method, URL, status, latency, attempt number, the Canva request ID and the
+//! request headers. With [`with_bodies`](DebugLogConfig::with_bodies) it also
+//! includes the start of the request and response bodies.
+//!
+//! Credentials never reach the sink. The `Authorization`, `Cookie` and
+//! similar headers are replaced with `[REDACTED]`, as are token-like fields
+//! (`access_token`, `refresh_token`, `client_secret`, ...) in query strings,
+//! JSON bodies and form bodies. The OAuth `code` parameter is redacted in
+//! query strings and form bodies only, so Canva error codes in JSON bodies
+//! stay readable.
+//!
+//! Unlike the [event log](crate::event_log), which keeps metadata for
+//! support tickets, this is meant for watching traffic while debugging. The
+//! default sink, [`StderrLogSink`], prints one line per exchange.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, debug_log::DebugLogConfig};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder(AccessToken::new("your-token"))
+//!     .debug_log(DebugLogConfig::new().with_bodies(1024))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::HeaderMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Replacement for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are credentials
+pub(crate) const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// Fields redacted in JSON bodies, form bodies and query strings
+const SECRET_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "token",
+    "code_verifier",
+    "client_secret",
+    "password",
+];
+
+/// Parameters redacted in form bodies and query strings only
+///
+/// There `code` is an OAuth authorization code. In JSON bodies it is a Canva
+/// error code, such as `design_not_found`, which is worth seeing.
+const SECRET_PARAMS: &[&str] = &["code"];
+
+/// One attempt of an HTTP request, as reported to a [`DebugLogSink`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpLogEntry {
+    /// HTTP method
+    pub method: String,
+    /// Full request URL, with secret query parameters redacted
+    pub url: String,
+    /// 1-based attempt number; retries of the same request count up
+    pub attempt: u32,
+    /// Response status, if a response was received
+    pub status: Option<u16>,
+    /// Time from sending the request to receiving the response headers
+    pub elapsed: Duration,
+    /// Canva request ID from the `x-request-id` response header
+    pub request_id: Option<String>,
+    /// Request headers, with secret values redacted
+    pub request_headers: Vec<(String, String)>,
+    /// Start of the request body, if bodies are logged and there is one
+    pub request_body: Option<String>,
+    /// Start of the response body, if bodies are logged
+    pub response_body: Option<String>,
+    /// Transport error, if no response was received
+    pub error: Option<String>,
+}
+
+impl fmt::Display for HttpLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        match (self.status, &self.error) {
+            (Some(status), _) => write!(f, " -> {status}")?,
+            (None, Some(error)) => write!(f, " -> error: {error}")?,
+            (None, None) => {}
+        }
+        write!(f, " in {}ms", self.elapsed.as_millis())?;
+        if self.attempt > 1 {
+            write!(f, " (attempt {})", self.attempt)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " request_id={request_id}")?;
+        }
+        if let Some(body) = &self.request_body {
+            write!(f, "\n  request: {body}")?;
+        }
+        if let Some(body) = &self.response_body {
+            write!(f, "\n  response: {body}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Receives an entry for every HTTP exchange
+pub trait DebugLogSink: fmt::Debug + Send + Sync {
+    /// Called once per attempt, after the response headers arrive or the
+    /// transport fails
+    fn log(&self, entry: &HttpLogEntry);
+}
+
+/// [`DebugLogSink`] that prints each entry to standard error
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrLogSink;
+
+impl DebugLogSink for StderrLogSink {
+    fn log(&self, entry: &HttpLogEntry) {
+        eprintln!("[canva] {entry}");
+    }
+}
+
+/// Settings for [`ClientBuilder::debug_log`](crate::ClientBuilder::debug_log)
+#[derive(Debug, Clone, Default)]
+pub struct DebugLogConfig {
+    max_body_bytes: Option<usize>,
+    sink: Option<Arc<dyn DebugLogSink>>,
+}
+
+impl DebugLogConfig {
+    /// Log metadata only, to standard error
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also log request and response bodies, cut to `max_bytes` each
+    ///
+    /// Logging a response body means reading all of it before it is handed
+    /// on, so keep this off for large downloads.
+    pub fn with_bodies(mut self, max_bytes: usize) -> Self {
+        self.max_body_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Send entries to `sink` instead of standard error
+    pub fn with_sink(mut self, sink: impl DebugLogSink + 'static) -> Self {
+        self.sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Longest body logged, if bodies are logged
+    pub fn max_body_bytes(&self) -> Option<usize> {
+        self.max_body_bytes
+    }
+
+    pub(crate) fn into_logger(self) -> DebugLogger {
+        DebugLogger {
+            max_body_bytes: self.max_body_bytes,
+            sink: self.sink.unwrap_or_else(|| Arc::new(StderrLogSink)),
+        }
+    }
+}
+
+/// Builds and reports log entries for a client
+#[derive(Debug, Clone)]
+pub(crate) struct DebugLogger {
+    max_body_bytes: Option<usize>,
+    sink: Arc<dyn DebugLogSink>,
+}
+
+impl DebugLogger {
+    /// Capture the parts of a request that are logged, before it is sent
+    pub(crate) fn capture(&self, request: &reqwest::Request, attempt: u32) -> HttpLogEntry {
+        let request_body = self.max_body_bytes.and_then(|max| {
+            request.body().map(|body| match body.as_bytes() {
+                Some(bytes) => redact_body(bytes, max),
+                None => "<streamed body>".to_string(),
+            })
+        });
+        HttpLogEntry {
+            method: request.method().to_string(),
+            url: redact_url(request.url()),
+            attempt,
+            status: None,
+            elapsed: Duration::ZERO,
+            request_id: None,
+            request_headers: redact_headers(request.headers()),
+            request_body,
+            response_body: None,
+            error: None,
+        }
+    }
+
+    /// Complete `entry` with the outcome and report it
+    ///
+    /// When bodies are logged the response body is read here, and an
+    /// equivalent response is returned in its place.
+    pub(crate) async fn finish(
+        &self,
+        mut entry: HttpLogEntry,
+        elapsed: Duration,
+        result: crate::Result<reqwest::Response>,
+    ) -> crate::Result<reqwest::Response> {
+        entry.elapsed = elapsed;
+        let response = match result {
+            Ok(response) => response,
+            Err(error) => {
+                entry.error = Some(error.to_string());
+                self.sink.log(&entry);
+                return Err(error);
+            }
+        };
+        entry.status = Some(response.status().as_u16());
+        entry.request_id = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        let Some(max) = self.max_body_bytes else {
+            self.sink.log(&entry);
+            return Ok(response);
+        };
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        entry.response_body = Some(redact_body(&body, max));
+        self.sink.log(&entry);
+
+        let mut rebuilt = http::Response::new(body);
+        *rebuilt.status_mut() = status;
+        *rebuilt.headers_mut() = headers;
+        Ok(rebuilt.into())
+    }
+}
+
+fn is_secret_param(name: &str) -> bool {
+    is_listed(name, SECRET_FIELDS) || is_listed(name, SECRET_PARAMS)
+}
+
+pub(crate) fn is_listed(name: &str, fields: &[&str]) -> bool {
+    fields.iter().any(|field| field.eq_ignore_ascii_case(name))
+}
+
+/// Header names and values, with credentials replaced by [`REDACTED`]
+pub fn redact_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// The URL as a string, with token-like query parameters replaced by [`REDACTED`]
+pub fn redact_url(url: &url::Url) -> String {
+    if !url.query_pairs().any(|(key, _)| is_secret_param(&key)) {
+        return url.to_string();
+    }
+    let mut redacted = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if is_secret_param(&key) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+/// A body as loggable text, with token-like fields redacted and cut to `max_bytes`
+///
+/// JSON and form-encoded bodies have secret fields replaced by [`REDACTED`];
+/// binary bodies are described by their size only.
+pub fn redact_body(body: &[u8], max_bytes: usize) -> String {
+    if body.is_empty() {
+        return String::new();
+    }
+    let text = if let Ok(mut json) = serde_json::from_slice::<serde_json::Value>(body) {
+        redact_json(&mut json, SECRET_FIELDS);
+        json.to_string()
+    } else if let Ok(text) = std::str::from_utf8(body) {
+        redact_form(text, is_secret_param)
+    } else {
+        return format!("<{} bytes of binary data>", body.len());
+    };
+    truncate(text, max_bytes)
+}
+
+/// Replace the values of `fields`, at any depth, with [`REDACTED`]
+pub(crate) fn redact_json(value: &mut serde_json::Value, fields: &[&str]) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_listed(key, fields) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value, fields);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact_json(item, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace the values of parameters `is_secret` accepts in a form-encoded body with [`REDACTED`]
+pub(crate) fn redact_form(text: &str, is_secret: impl Fn(&str) -> bool) -> String {
+    let looks_like_form = !text.contains(char::is_whitespace) && text.contains('=');
+    if !looks_like_form {
+        return text.to_string();
+    }
+    text.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_secret(key) => format!("{key}={REDACTED}"),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn truncate(mut text: String, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let total = text.len();
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.push_str(&format!("... ({total} bytes)"));
+    text
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+
+    #[test]
+    fn test_headers_are_redacted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let redacted = redact_headers(&headers);
+
+        assert!(redacted.contains(&("authorization".to_string(), REDACTED.to_string())));
+        assert!(redacted.contains(&("content-type".to_string(), "application/json".to_string())));
+    }
+
+    #[test]
+    fn test_url_secrets_are_redacted() {
+        let url = url::Url::parse("https://x.test/cb?code=abc&state=s1").expect("url");
+        assert_eq!(
+            redact_url(&url),
+            "https://x.test/cb?code=%5BREDACTED%5D&state=s1"
+        );
+
+        let url = url::Url::parse("https://x.test/v1/designs?query=a%20b").expect("url");
+        assert_eq!(redact_url(&url), "https://x.test/v1/designs?query=a%20b");
+    }
+
+    #[test]
+    fn test_json_and_form_bodies_are_redacted() {
+        let json = br#"{"access_token":"at","nested":[{"refresh_token":"rt","keep":1}]}"#;
+        assert_eq!(
+            redact_body(json, 1000),
+            r#"{"access_token":"[REDACTED]","nested":[{"keep":1,"refresh_token":"[REDACTED]"}]}"#
+        );
+
+        let form = b"grant_type=refresh_token&refresh_token=rt&client_secret=cs";
+        assert_eq!(
+            redact_body(form, 1000),
+            "grant_type=refresh_token&refresh_token=[REDACTED]&client_secret=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_code_is_redacted_only_outside_json() {
+        let error = br#"{"code":"design_not_found","message":"No such design"}"#;
+        assert_eq!(
+            redact_body(error, 1000),
+            r#"{"code":"design_not_found","message":"No such design"}"#
+        );
+
+        let job = br#"{"job":{"status":"failed","error":{"code":"autofill_error"}}}"#;
+        assert!(redact_body(job, 1000).contains("autofill_error"));
+
+        let form = b"grant_type=authorization_code&code=abc&code_verifier=v";
+        assert_eq!(
+            redact_body(form, 1000),
+            "grant_type=authorization_code&code=[REDACTED]&code_verifier=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_bodies_are_truncated_and_binary_is_summarized() {
+        assert_eq!(redact_body(b"hello world", 5), "hello... (11 bytes)");
+        assert_eq!(redact_body("héllo".as_bytes(), 2), "h... (6 bytes)");
+        assert_eq!(
+            redact_body(&[0xff, 0xfe, 0x00], 100),
+            "<3 bytes of binary data>"
+        );
+    }
+}
@@ -0,0 +1,344 @@
+//! Concurrency limits and reports for bulk file downloads.
+//!
+//! Export jobs can return dozens of pre-signed URLs, usually all on the same
+//! CDN host. Fetching them all at once overwhelms some corporate proxies, so
+//! bulk download helpers such as
+//! [`ExportsApi::download_export_to_path`](crate::endpoints::exports::ExportsApi::download_export_to_path)
+//! take [`DownloadLimits`]: a cap on the total number of files in flight and a
+//! separate cap per host. Each helper returns a [`DownloadReport`] with
+//! per-file and aggregate timing and throughput.
+//!
+//! For URLs from anywhere else, such as thumbnails, or to resume
+//! interrupted downloads and verify checksums, use a [`Downloader`].
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, downloads::DownloadLimits};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-token"))?;
+//! let job = client.exports().wait_for_export_job("export-id").await?;
+//!
+//! let limits = DownloadLimits::new(8).with_max_per_host(2);
+//! let report = client
+//!     .exports()
+//!     .download_export_to_path(&job, "deck.png", &limits)
+//!     .await?;
+//! println!(
+//!     "{} files, {} bytes at {:.0} B/s",
+//!     report.files.len(),
+//!     report.total_bytes,
+//!     report.throughput()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+pub mod downloader;
+pub use downloader::{DownloadRequest, Downloader, PARTIAL_SUFFIX};
+
+/// Default number of files downloaded at once
+pub const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+/// Default number of files downloaded at once from a single host
+pub const DEFAULT_MAX_PER_HOST: usize = 4;
+
+/// Parallelism and size caps for bulk downloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadLimits {
+    max_concurrency: usize,
+    max_per_host: usize,
+    max_file_bytes: Option<u64>,
+}
+
+impl DownloadLimits {
+    /// Download at most `max_concurrency` files at once, with the default per-host cap
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn new(max_concurrency: usize) -> Self {
+        Self {
+            max_concurrency: max_concurrency.max(1),
+            max_per_host: DEFAULT_MAX_PER_HOST,
+            max_file_bytes: None,
+        }
+    }
+
+    /// Download files one at a time
+    pub fn sequential() -> Self {
+        Self::new(1).with_max_per_host(1)
+    }
+
+    /// Download at most `max_per_host` files at once from any single host
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn with_max_per_host(mut self, max_per_host: usize) -> Self {
+        self.max_per_host = max_per_host.max(1);
+        self
+    }
+
+    /// Fail downloads when a file is larger than `max_bytes`
+    ///
+    /// Applies to [`Downloader`] and the export helpers built on these
+    /// limits, such as [`ExportsApi::download_all`](crate::endpoints::exports::ExportsApi::download_all).
+    /// A file whose `Content-Length` is over the cap is not read at all.
+    /// Unlimited by default.
+    pub fn with_max_file_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_file_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Maximum number of files downloaded at once
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Maximum number of files downloaded at once from a single host
+    pub fn max_per_host(&self) -> usize {
+        self.max_per_host
+    }
+
+    /// Largest file accepted by downloads, if capped
+    pub fn max_file_bytes(&self) -> Option<u64> {
+        self.max_file_bytes
+    }
+
+    /// Fail if `bytes` from `url` is over the file size cap
+    pub(crate) fn check_file_size(&self, url: &str, bytes: u64) -> Result<()> {
+        match self.max_file_bytes {
+            Some(max) if bytes > max => Err(crate::error::Error::Generic(format!(
+                "Download from {} is larger than the {max} byte limit",
+                url_host(url)
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for DownloadLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENCY)
+    }
+}
+
+/// A file written by a bulk download
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadedFile {
+    /// Where the file was written
+    pub path: PathBuf,
+    /// Host the file was downloaded from
+    pub host: String,
+    /// Number of bytes written
+    pub bytes: u64,
+    /// Time spent downloading this file, excluding time waiting for a slot
+    pub elapsed: Duration,
+}
+
+impl DownloadedFile {
+    /// Download speed of this file in bytes per second
+    pub fn throughput(&self) -> f64 {
+        bytes_per_second(self.bytes, self.elapsed)
+    }
+}
+
+/// Summary of a bulk download
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadReport {
+    /// The downloaded files, in the order they were requested
+    pub files: Vec<DownloadedFile>,
+    /// Bytes written across all files
+    pub total_bytes: u64,
+    /// Wall-clock time for the whole download
+    pub elapsed: Duration,
+    /// Highest number of files that were downloading at the same time
+    pub peak_concurrency: usize,
+}
+
+impl DownloadReport {
+    /// Aggregate download speed in bytes per second of wall-clock time
+    pub fn throughput(&self) -> f64 {
+        bytes_per_second(self.total_bytes, self.elapsed)
+    }
+
+    /// Paths of the downloaded files, in the order they were requested
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files.iter().map(|file| file.path.clone()).collect()
+    }
+}
+
+fn bytes_per_second(bytes: u64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        bytes as f64 / seconds
+    } else {
+        0.0
+    }
+}
+
+/// Hands out download slots according to [`DownloadLimits`]
+#[derive(Debug)]
+pub(crate) struct DownloadGate {
+    limits: DownloadLimits,
+    total: Arc<Semaphore>,
+    hosts: Mutex<HashMap<String, Arc<Semaphore>>>,
+    active: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// A held download slot, released on drop
+#[derive(Debug)]
+pub(crate) struct DownloadSlot<'a> {
+    gate: &'a DownloadGate,
+    _host: OwnedSemaphorePermit,
+    _total: OwnedSemaphorePermit,
+}
+
+impl DownloadGate {
+    pub(crate) fn new(limits: DownloadLimits) -> Self {
+        Self {
+            limits,
+            total: Arc::new(Semaphore::new(limits.max_concurrency)),
+            hosts: Mutex::new(HashMap::new()),
+            active: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Wait for a free slot for `host`
+    ///
+    /// The host slot is taken first, so files queued behind a busy host do not
+    /// hold global slots that other hosts could use.
+    pub(crate) async fn acquire(&self, host: &str) -> Result<DownloadSlot<'_>> {
+        let host_semaphore = {
+            let mut hosts = self.hosts.lock().unwrap_or_else(|p| p.into_inner());
+            hosts
+                .entry(host.to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(self.limits.max_per_host)))
+                .clone()
+        };
+        let host_permit = host_semaphore.acquire_owned().await.map_err(closed)?;
+        let total_permit = self.total.clone().acquire_owned().await.map_err(closed)?;
+
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(active, Ordering::SeqCst);
+
+        Ok(DownloadSlot {
+            gate: self,
+            _host: host_permit,
+            _total: total_permit,
+        })
+    }
+
+    /// Highest number of slots held at the same time
+    pub(crate) fn peak_concurrency(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for DownloadSlot<'_> {
+    fn drop(&mut self) {
+        self.gate.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn closed(_: tokio::sync::AcquireError) -> crate::error::Error {
+    crate::error::Error::Generic("Download limiter closed".to_string())
+}
+
+/// Host part of a download URL, used to apply per-host limits
+pub(crate) fn url_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Lowercase hex encoding of a digest, the form SHA-256 checksums are reported in
+pub(crate) fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[test]
+    fn test_limits_clamp_to_one() {
+        let limits = DownloadLimits::new(0).with_max_per_host(0);
+        assert_eq!(limits.max_concurrency(), 1);
+        assert_eq!(limits.max_per_host(), 1);
+        assert_eq!(DownloadLimits::default().max_concurrency(), 8);
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(
+            url_host("https://export-download.canva.com/abc/1.png?sig=x"),
+            "export-download.canva.com"
+        );
+        assert_eq!(url_host("not a url"), "");
+    }
+
+    #[tokio::test]
+    async fn test_gate_enforces_per_host_cap() {
+        let gate = DownloadGate::new(DownloadLimits::new(4).with_max_per_host(1));
+
+        let first = gate.acquire("a.example").await.expect("slot");
+        assert!(gate.acquire("a.example").now_or_never().is_none());
+
+        let other_host = gate.acquire("b.example").await.expect("slot");
+        assert_eq!(gate.peak_concurrency(), 2);
+
+        drop(first);
+        let _again = gate.acquire("a.example").await.expect("slot");
+        drop(other_host);
+        assert_eq!(gate.peak_concurrency(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gate_enforces_total_cap() {
+        let gate = DownloadGate::new(DownloadLimits::new(1).with_max_per_host(4));
+
+        let first = gate.acquire("a.example").await.expect("slot");
+        assert!(gate.acquire("b.example").now_or_never().is_none());
+        drop(first);
+        assert!(gate.acquire("b.example").now_or_never().is_some());
+    }
+
+    #[test]
+    fn test_report_throughput() {
+        let report = DownloadReport {
+            files: vec![DownloadedFile {
+                path: PathBuf::from("a.png"),
+                host: "cdn.example".to_string(),
+                bytes: 2048,
+                elapsed: Duration::from_secs(2),
+            }],
+            total_bytes: 2048,
+            elapsed: Duration::from_secs(1),
+            peak_concurrency: 1,
+        };
+        assert_eq!(report.throughput(), 2048.0);
+        assert_eq!(report.files[0].throughput(), 1024.0);
+        assert_eq!(report.paths(), vec![PathBuf::from("a.png")]);
+
+        let empty = DownloadReport {
+            files: Vec::new(),
+            total_bytes: 0,
+            elapsed: Duration::ZERO,
+            peak_concurrency: 0,
+        };
+        assert_eq!(empty.throughput(), 0.0);
+    }
+}
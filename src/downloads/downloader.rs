@@ -0,0 +1,543 @@
+//! A reusable downloader for pre-signed URLs.
+//!
+//! [`Downloader`] fetches export files, thumbnails and other pre-signed URLs
+//! to disk within [`DownloadLimits`], optionally waiting between requests to
+//! the same host. Each file is written to a `.part` file next to its
+//! destination and renamed into place once complete and, if a SHA-256 was
+//! given, verified. A `.part` file left by an interrupted run is resumed
+//! with a `Range` request; if the server ignores the range, the file is
+//! downloaded again from the start.
+//!
+//! Failed responses are retried under the client's
+//! [`RetryPolicy`](crate::retry::RetryPolicy), and so are connections that
+//! drop mid-file, continuing from the last byte written.
+
+use super::{hex, url_host, DownloadGate, DownloadLimits, DownloadReport, DownloadedFile};
+use crate::client::Client;
+use crate::endpoints::exports::{ensure_downloadable, page_paths};
+use crate::error::{Error, Result};
+use crate::models::ExportJob;
+use futures::future::BoxFuture;
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::time::Instant;
+
+/// Suffix of the file a download is written to until it completes
+pub const PARTIAL_SUFFIX: &str = ".part";
+
+/// Looks up a fresh URL for the request at an index, once its URL is rejected
+pub(crate) type RefreshUrl<'a> = dyn Fn(usize) -> BoxFuture<'a, Result<String>> + Send + Sync + 'a;
+
+/// A file to download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadRequest {
+    /// Pre-signed URL of the file
+    pub url: String,
+    /// Where to write the file
+    pub path: PathBuf,
+    /// Expected SHA-256 of the file in hex, checked before the file is moved
+    /// into place
+    pub sha256: Option<String>,
+}
+
+impl DownloadRequest {
+    /// Download `url` to `path`
+    pub fn new(url: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            url: url.into(),
+            path: path.into(),
+            sha256: None,
+        }
+    }
+
+    /// Reject the file unless its SHA-256 matches `sha256`, given in hex
+    pub fn with_sha256(mut self, sha256: impl Into<String>) -> Self {
+        self.sha256 = Some(sha256.into());
+        self
+    }
+
+    /// Requests for every file of a completed export job
+    ///
+    /// Files are named like
+    /// [`ExportsApi::export_to_path`](crate::endpoints::exports::ExportsApi::export_to_path)
+    /// names them: `path` itself for a single file, or `path` with `-1`,
+    /// `-2`, ... added to the file stem for several.
+    pub fn for_export(job: &ExportJob, path: impl AsRef<Path>) -> Result<Vec<Self>> {
+        ensure_downloadable(job)?;
+        let paths = page_paths(path.as_ref(), job.urls.len());
+        Ok(job
+            .urls
+            .iter()
+            .zip(paths)
+            .map(|(url, path)| Self::new(url.clone(), path))
+            .collect())
+    }
+}
+
+/// Downloads pre-signed URLs to disk with concurrency caps and resumption
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken};
+/// use canva_connect::downloads::{DownloadLimits, DownloadRequest};
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("your-token"))?;
+/// let job = client.exports().wait_for_export_job("export-id").await?;
+///
+/// let downloader = client
+///     .downloader()
+///     .with_limits(DownloadLimits::new(8).with_max_per_host(2))
+///     .with_host_delay(Duration::from_millis(100));
+/// let report = downloader
+///     .download_all(&DownloadRequest::for_export(&job, "deck.png")?)
+///     .await?;
+/// println!("{} files in {:?}", report.files.len(), report.elapsed);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Downloader {
+    client: Client,
+    limits: DownloadLimits,
+    host_delay: Duration,
+    resume: bool,
+}
+
+impl Downloader {
+    /// Download with `client`'s retry policy, default limits and no host delay
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            limits: DownloadLimits::default(),
+            host_delay: Duration::ZERO,
+            resume: true,
+        }
+    }
+
+    /// Cap how many files are fetched at once
+    pub fn with_limits(mut self, limits: DownloadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Leave at least `delay` between the starts of requests to the same host
+    pub fn with_host_delay(mut self, delay: Duration) -> Self {
+        self.host_delay = delay;
+        self
+    }
+
+    /// Whether to continue from `.part` files left by earlier runs (default `true`)
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Parallelism caps for downloads
+    pub fn limits(&self) -> &DownloadLimits {
+        &self.limits
+    }
+
+    /// Minimum time between requests to the same host
+    pub fn host_delay(&self) -> Duration {
+        self.host_delay
+    }
+
+    /// Download a single file
+    pub async fn download(&self, request: &DownloadRequest) -> Result<DownloadedFile> {
+        let report = self.download_all(std::slice::from_ref(request)).await?;
+        report
+            .files
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Generic("Download produced no file".to_string()))
+    }
+
+    /// Download every file, stopping at the first that fails
+    ///
+    /// Files that were still downloading when another failed keep their
+    /// `.part` files, so running the same requests again resumes them.
+    pub async fn download_all(&self, requests: &[DownloadRequest]) -> Result<DownloadReport> {
+        self.download_all_refreshing(requests, None).await
+    }
+
+    /// Like [`download_all`](Self::download_all), asking `refresh_url` for a
+    /// new URL once when a request's URL is rejected with `403 Forbidden`
+    pub(crate) async fn download_all_refreshing(
+        &self,
+        requests: &[DownloadRequest],
+        refresh_url: Option<&RefreshUrl<'_>>,
+    ) -> Result<DownloadReport> {
+        let gate = DownloadGate::new(self.limits);
+        let pacer = HostPacer::new(self.host_delay);
+        let started = std::time::Instant::now();
+
+        let downloads = requests.iter().enumerate().map(|(index, request)| {
+            let (gate, pacer) = (&gate, &pacer);
+            let refresh_url = refresh_url.map(|refresh_url| (refresh_url, index));
+            async move {
+                let host = url_host(&request.url);
+                let _slot = gate.acquire(&host).await?;
+                let file_started = std::time::Instant::now();
+                let bytes = self
+                    .fetch_to_disk(request, &host, pacer, refresh_url)
+                    .await?;
+                Ok::<_, Error>(DownloadedFile {
+                    path: request.path.clone(),
+                    host,
+                    bytes,
+                    elapsed: file_started.elapsed(),
+                })
+            }
+        });
+        let files = futures::future::try_join_all(downloads).await?;
+
+        Ok(DownloadReport {
+            total_bytes: files.iter().map(|file| file.bytes).sum(),
+            files,
+            elapsed: started.elapsed(),
+            peak_concurrency: gate.peak_concurrency(),
+        })
+    }
+
+    /// Fetch one file into its `.part` file, verify it and move it into place
+    async fn fetch_to_disk(
+        &self,
+        request: &DownloadRequest,
+        host: &str,
+        pacer: &HostPacer,
+        mut refresh_url: Option<(&RefreshUrl<'_>, usize)>,
+    ) -> Result<u64> {
+        let partial = partial_path(&request.path);
+        let mut offset = if self.resume {
+            tokio::fs::metadata(&partial)
+                .await
+                .map(|metadata| metadata.len())
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let policy = self.client.retry_policy();
+        let mut retries = 0;
+        let mut url = request.url.clone();
+
+        'attempt: loop {
+            pacer.wait(host).await;
+            let mut response = self.client.fetch_url_from(&url, offset).await?;
+            let status = response.status();
+            if status == StatusCode::RANGE_NOT_SATISFIABLE && offset > 0 {
+                // The partial file is stale or already complete; start over
+                offset = 0;
+                continue;
+            }
+            if status == StatusCode::FORBIDDEN {
+                // Pre-signed URLs expire; ask for a fresh one, but only once
+                if let Some((refresh_url, index)) = refresh_url.take() {
+                    url = refresh_url(index).await?;
+                    continue;
+                }
+            }
+            if !status.is_success() {
+                return Err(Error::download(status, response.url()));
+            }
+            if status != StatusCode::PARTIAL_CONTENT {
+                offset = 0;
+            }
+            if let Some(length) = response.content_length() {
+                self.limits.check_file_size(&request.url, offset + length)?;
+            }
+
+            let mut file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(offset > 0)
+                .truncate(offset == 0)
+                .open(&partial)
+                .await?;
+            loop {
+                match response.chunk().await {
+                    Ok(Some(chunk)) => {
+                        file.write_all(&chunk).await?;
+                        offset += chunk.len() as u64;
+                        self.limits.check_file_size(&request.url, offset)?;
+                    }
+                    Ok(None) => break,
+                    Err(error)
+                        if retries < policy.max_retries() && policy.should_retry_error(&error) =>
+                    {
+                        file.flush().await?;
+                        retries += 1;
+                        tokio::time::sleep(policy.backoff_for(retries)).await;
+                        continue 'attempt;
+                    }
+                    Err(error) => return Err(error.into()),
+                }
+            }
+            file.flush().await?;
+            break;
+        }
+
+        if let Some(expected) = &request.sha256 {
+            let actual = file_sha256(&partial).await?;
+            if !actual.eq_ignore_ascii_case(expected) {
+                tokio::fs::remove_file(&partial).await?;
+                return Err(Error::Generic(format!(
+                    "Checksum mismatch for {}: expected SHA-256 {expected}, got {actual}",
+                    request.path.display()
+                )));
+            }
+        }
+        tokio::fs::rename(&partial, &request.path).await?;
+        Ok(offset)
+    }
+}
+
+/// Spaces out the starts of requests to each host
+#[derive(Debug)]
+struct HostPacer {
+    delay: Duration,
+    next_start: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostPacer {
+    fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            next_start: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for the next turn to send a request to `host`
+    async fn wait(&self, host: &str) {
+        if self.delay.is_zero() {
+            return;
+        }
+        let start = {
+            let mut next_start = self.next_start.lock().unwrap_or_else(|p| p.into_inner());
+            let now = Instant::now();
+            let start = next_start
+                .get(host)
+                .copied()
+                .filter(|next| *next > now)
+                .unwrap_or(now);
+            next_start.insert(host.to_string(), start + self.delay);
+            start
+        };
+        tokio::time::sleep_until(start).await;
+    }
+}
+
+fn partial_path(path: &Path) -> PathBuf {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(PARTIAL_SUFFIX);
+    PathBuf::from(partial)
+}
+
+async fn file_sha256(path: &Path) -> Result<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex(&hasher.finalize()))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::auth::AccessToken;
+
+    fn client(server: &mockito::Server) -> Client {
+        Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client")
+    }
+
+    fn scratch_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("canva-downloader-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create scratch dir");
+        dir
+    }
+
+    const HELLO_SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    #[tokio::test]
+    async fn test_download_all_verifies_and_renames() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/a.txt")
+            .with_body("hello world")
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/files/b.txt")
+            .with_body("second")
+            .create_async()
+            .await;
+        let dir = scratch_dir();
+        let requests = [
+            DownloadRequest::new(format!("{}/files/a.txt", server.url()), dir.join("a.txt"))
+                .with_sha256(HELLO_SHA256.to_uppercase()),
+            DownloadRequest::new(format!("{}/files/b.txt", server.url()), dir.join("b.txt")),
+        ];
+
+        let report = client(&server)
+            .downloader()
+            .with_host_delay(Duration::from_millis(5))
+            .download_all(&requests)
+            .await
+            .expect("downloads succeed");
+
+        assert_eq!(report.paths(), vec![dir.join("a.txt"), dir.join("b.txt")]);
+        assert_eq!(report.total_bytes, 17);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("a.txt")).expect("read a"),
+            "hello world"
+        );
+        assert!(!partial_path(&dir.join("a.txt")).exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_download_resumes_partial_file() {
+        let mut server = mockito::Server::new_async().await;
+        let ranged = server
+            .mock("GET", "/files/a.txt")
+            .match_header("range", "bytes=6-")
+            .with_status(206)
+            .with_body("world")
+            .create_async()
+            .await;
+        let dir = scratch_dir();
+        let path = dir.join("a.txt");
+        std::fs::write(partial_path(&path), "hello ").expect("write partial");
+
+        let file = client(&server)
+            .downloader()
+            .download(
+                &DownloadRequest::new(format!("{}/files/a.txt", server.url()), &path)
+                    .with_sha256(HELLO_SHA256),
+            )
+            .await
+            .expect("download resumes");
+
+        assert_eq!(file.bytes, 11);
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), "hello world");
+        ranged.assert_async().await;
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_download_restarts_when_range_ignored() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/a.txt")
+            .with_body("hello world")
+            .create_async()
+            .await;
+        let dir = scratch_dir();
+        let path = dir.join("a.txt");
+        std::fs::write(partial_path(&path), "stale bytes").expect("write partial");
+
+        client(&server)
+            .downloader()
+            .download(&DownloadRequest::new(
+                format!("{}/files/a.txt", server.url()),
+                &path,
+            ))
+            .await
+            .expect("download restarts");
+
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), "hello world");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_download_rejects_checksum_mismatch() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/a.txt")
+            .with_body("tampered")
+            .create_async()
+            .await;
+        let dir = scratch_dir();
+        let path = dir.join("a.txt");
+
+        let result = client(&server)
+            .downloader()
+            .download(
+                &DownloadRequest::new(format!("{}/files/a.txt", server.url()), &path)
+                    .with_sha256(HELLO_SHA256),
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::Generic(message)) if message.contains("Checksum")));
+        assert!(!path.exists());
+        assert!(!partial_path(&path).exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_download_reports_status_and_size_limit() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/expired.txt")
+            .match_query(mockito::Matcher::Any)
+            .with_status(403)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/files/big.txt")
+            .with_body("hello world")
+            .create_async()
+            .await;
+        let dir = scratch_dir();
+        let downloader = client(&server)
+            .downloader()
+            .with_limits(DownloadLimits::default().with_max_file_bytes(5));
+
+        let expired = downloader
+            .download(&DownloadRequest::new(
+                format!("{}/files/expired.txt?X-Amz-Signature=secret", server.url()),
+                dir.join("expired.txt"),
+            ))
+            .await;
+        assert!(matches!(
+            expired,
+            Err(Error::Download { status, url })
+                if status == StatusCode::FORBIDDEN && !url.contains("secret")
+        ));
+
+        let big = downloader
+            .download(&DownloadRequest::new(
+                format!("{}/files/big.txt", server.url()),
+                dir.join("big.txt"),
+            ))
+            .await;
+        assert!(matches!(big, Err(Error::Generic(message)) if message.contains("5 byte limit")));
+        assert!(!dir.join("big.txt").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_host_pacer_spaces_requests() {
+        let pacer = HostPacer::new(Duration::from_secs(1));
+        let started = Instant::now();
+        pacer.wait("a.example").await;
+        pacer.wait("b.example").await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+        pacer.wait("a.example").await;
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+    }
+}
@@ -13,13 +13,24 @@
 //! |-----------|---------|----------|----------------|-------------|
 //! | [`get`](AssetsApi::get) | `GET` | `/v1/assets/{assetId}` | `asset:read` | Get metadata for a specific asset |
 //! | [`update`](AssetsApi::update) | `PATCH` | `/v1/assets/{assetId}` | `asset:write` | Update asset name and tags |
+//! | [`add_tags`](AssetsApi::add_tags) | N/A | Multiple calls | `asset:read`, `asset:write` | Add tags, keeping existing ones |
+//! | [`remove_tags`](AssetsApi::remove_tags) | N/A | Multiple calls | `asset:read`, `asset:write` | Remove some tags, keeping the rest |
+//! | [`change_tags`](AssetsApi::change_tags) | N/A | Multiple calls | `asset:read`, `asset:write` | Add and remove tags in one edit |
+//! | [`fetch_matching`](AssetsApi::fetch_matching) | N/A | Multiple calls | `asset:read` | Fetch assets by ID, keeping those with given tags, type or name |
+//! | [`bulk_update`](AssetsApi::bulk_update) | N/A | Multiple calls | `asset:read`, `asset:write` | Rename and retag many assets, or preview the changes |
 //! | [`delete`](AssetsApi::delete) | `DELETE` | `/v1/assets/{assetId}` | `asset:write` | Delete an asset (moves to trash) |
 //! | [`create_upload_job`](AssetsApi::create_upload_job) | `POST` | `/v1/asset-uploads` | `asset:write` | Upload asset from binary data |
 //! | [`create_url_upload_job`](AssetsApi::create_url_upload_job) | `POST` | `/v1/url-asset-uploads` | `asset:write` | Upload asset from URL |
 //! | [`get_upload_job`](AssetsApi::get_upload_job) | `GET` | `/v1/asset-uploads/{jobId}` | `asset:read` | Check upload job status |
+//! | [`upload_from_url`](AssetsApi::upload_from_url) | `POST` | `/v1/url-asset-uploads` | `asset:write` | Check a URL, name the asset after it and upload from it |
 //! | [`get_url_upload_job`](AssetsApi::get_url_upload_job) | `GET` | `/v1/url-asset-uploads/{jobId}` | `asset:read` | Check URL upload job status |
 //! | [`wait_for_upload_job`](AssetsApi::wait_for_upload_job) | N/A | Multiple calls | `asset:read` | Wait for upload completion |
 //! | [`wait_for_url_upload_job`](AssetsApi::wait_for_url_upload_job) | N/A | Multiple calls | `asset:read` | Wait for URL upload completion |
+//! | [`download_thumbnail`](AssetsApi::download_thumbnail) | `GET` | Thumbnail URL | `asset:read` | Download a thumbnail, refreshing an expired URL |
+//! | [`upload_directory`](AssetsApi::upload_directory) | N/A | Multiple calls | `asset:read`, `asset:write` | Upload every image and video in a local directory |
+//! | `upload_image_with_processing` | N/A | Multiple calls | `asset:read`, `asset:write` | Resize and re-encode a local image, then upload it (`image` feature) |
+//! | [`upload_resumable`](AssetsApi::upload_resumable) | N/A | Multiple calls | `asset:read`, `asset:write` | Upload a local file, recording progress so it survives a crash |
+//! | [`resume_upload_job`](AssetsApi::resume_upload_job) | N/A | Multiple calls | `asset:read`, `asset:write` | Finish an interrupted resumable upload |
 //!
 //! ## OAuth Scopes
 //!
@@ -31,9 +42,61 @@
 //! The Canva Connect API does not provide a general asset listing endpoint.
 //! Assets are typically accessed through other endpoints like designs or
 //! by their specific asset IDs.
+//!
+//! Without a listing there are no server-side filters either. To narrow a
+//! known set of asset IDs down by tag, type or name, build an
+//! [`AssetFilter`] and stream the matches with
+//! [`fetch_matching`](AssetsApi::fetch_matching).
+//!
+//! ## Note on Asset Usage
+//!
+//! There is also no way to find the designs that use an asset. Designs are
+//! only exposed through their metadata, pages and exports, none of which
+//! name the assets they contain, so a search over designs could not tell a
+//! used asset from an unused one. Check usage in the Canva UI before
+//! deleting brand assets; deleted assets stay in the trash for a while and
+//! can be restored from there.
 
-use crate::{client::Client, error::Result, models::*};
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    jobs::{PollableJob, WaitOptions},
+    models::*,
+    routes,
+    uploads::{UploadCandidate, UploadSource},
+};
+use bulk_update::UpdateOutcome;
+use directory::FileOutcome;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+pub mod bulk;
+pub mod bulk_update;
+pub mod directory;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod processing;
+pub mod resumable;
+pub mod tags;
+pub use bulk::{BulkDeleteSummary, FailedDeletion, DEFAULT_DELETE_CONCURRENCY};
+pub use bulk_update::{
+    AssetChange, AssetFilter, BulkUpdateReport, FailedUpdate, UpdatePlan,
+    DEFAULT_UPDATE_CONCURRENCY,
+};
+pub use directory::{
+    DirectoryUploadOptions, DirectoryUploadProgress, DirectoryUploadSummary, FailedUpload,
+    FileUploadStatus, UploadedFile, DEFAULT_UPLOAD_CONCURRENCY,
+};
+#[cfg(feature = "image")]
+pub use processing::{
+    process_image, ImageOutputFormat, ProcessedImage, ProcessingOptions, DEFAULT_JPEG_QUALITY,
+};
+pub use resumable::{
+    FileUploadStateStore, MemoryUploadStateStore, ResumableUpload, UploadStateStore,
+};
+pub use tags::{Tag, TagChanges, DEFAULT_TAG_RETRIES};
 
 /// Assets API client
 #[derive(Debug, Clone)]
@@ -51,7 +114,7 @@ impl AssetsApi {
     ///
     /// **Required OAuth scope:** `asset:read`
     pub async fn get(&self, asset_id: &str) -> Result<Asset> {
-        let path = format!("/v1/assets/{asset_id}");
+        let path = routes::asset(asset_id);
         let response: GetAssetResponse = self.client.get_json(&path).await?;
         Ok(response.asset)
     }
@@ -60,22 +123,296 @@ impl AssetsApi {
     ///
     /// **Required OAuth scope:** `asset:write`
     pub async fn update(&self, asset_id: &str, request: UpdateAssetRequest) -> Result<Asset> {
-        let path = format!("/v1/assets/{asset_id}");
+        let path = routes::asset(asset_id);
         let response: UpdateAssetResponse = self.client.patch_json(&path, &request).await?;
         Ok(response.asset)
     }
 
+    /// Add tags to an asset, keeping the tags it already has
+    ///
+    /// Tags the asset already has are skipped. Retries up to
+    /// [`DEFAULT_TAG_RETRIES`] times if a concurrent write undoes the
+    /// change; see [`tags`] for how that is detected.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    pub async fn add_tags<I>(&self, asset_id: &str, tags: I) -> Result<Asset>
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        let changes = TagChanges {
+            add: tags.into_iter().collect(),
+            ..TagChanges::default()
+        };
+        self.change_tags(asset_id, &changes, DEFAULT_TAG_RETRIES)
+            .await
+    }
+
+    /// Remove tags from an asset, keeping its other tags
+    ///
+    /// Tags the asset does not have are ignored. Retries like
+    /// [`add_tags`](Self::add_tags).
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    pub async fn remove_tags<I>(&self, asset_id: &str, tags: I) -> Result<Asset>
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        let changes = TagChanges {
+            remove: tags.into_iter().collect(),
+            ..TagChanges::default()
+        };
+        self.change_tags(asset_id, &changes, DEFAULT_TAG_RETRIES)
+            .await
+    }
+
+    /// Apply `changes` to an asset's current tags and write them back
+    ///
+    /// After writing, the asset is read again; if another write has undone
+    /// any of the changes they are applied to the new tags and written
+    /// again, at most `retries` times. Pass `0` to write once without
+    /// checking. Fails with [`Error::Generic`] if the changes still do not
+    /// stick after the last retry, and with [`Error::InvalidAsset`] if the
+    /// asset would end up with more than [`MAX_ASSET_TAGS`] tags.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    pub async fn change_tags(
+        &self,
+        asset_id: &str,
+        changes: &TagChanges,
+        retries: u32,
+    ) -> Result<Asset> {
+        let mut asset = self.get(asset_id).await?;
+        let mut attempt = 0;
+        loop {
+            let tags = changes.apply(&asset.tags);
+            if tags == asset.tags {
+                return Ok(asset);
+            }
+            if attempt > retries {
+                return Err(Error::Generic(format!(
+                    "Tags of asset {asset_id} kept being overwritten by other writes"
+                )));
+            }
+            if tags.len() > MAX_ASSET_TAGS {
+                return Err(Error::InvalidAsset {
+                    name: asset.name,
+                    reason: format!(
+                        "{} tags after the change, over the limit of {MAX_ASSET_TAGS}",
+                        tags.len()
+                    ),
+                });
+            }
+            let request = UpdateAssetRequest {
+                name: None,
+                tags: Some(tags),
+            };
+            asset = self.update(asset_id, request).await?;
+            if retries == 0 {
+                return Ok(asset);
+            }
+            asset = self.get(asset_id).await?;
+            attempt += 1;
+        }
+    }
+
+    /// Stream the assets in `filter` that pass its name, tag and type checks
+    ///
+    /// The API has no asset listing or search, so this is a client-side
+    /// filter: every ID in the filter is fetched, [`DEFAULT_UPDATE_CONCURRENCY`]
+    /// at a time, and assets that fail the checks are dropped. Assets come
+    /// out in the order their IDs were given. A failed fetch is yielded as an
+    /// error and the stream carries on with the next ID.
+    ///
+    /// **Required OAuth scope:** `asset:read`
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, models::AssetType};
+    /// use canva_connect::endpoints::assets::AssetFilter;
+    /// use futures::TryStreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let filter = AssetFilter::ids(["Msd59349ff", "Msd59349fg"])
+    ///     .tagged("campaign-2024".parse()?)
+    ///     .of_type(AssetType::Video);
+    /// let videos: Vec<_> = client.assets().fetch_matching(&filter).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_matching<'a>(
+        &'a self,
+        filter: &'a AssetFilter,
+    ) -> impl futures::Stream<Item = Result<Asset>> + 'a {
+        futures::stream::iter(filter.asset_ids())
+            .map(move |asset_id| self.get(asset_id))
+            .buffered(DEFAULT_UPDATE_CONCURRENCY)
+            .filter(move |result| {
+                std::future::ready(result.as_ref().map_or(true, |asset| filter.matches(asset)))
+            })
+    }
+
+    /// Rename and retag the assets in `filter` as `plan` says
+    ///
+    /// Each asset is fetched, checked against the filter and, unless the
+    /// plan is a dry run, updated with a single `PATCH`. Requests go out at
+    /// [`Priority::Low`](crate::rate_limit::Priority::Low), at most
+    /// [`UpdatePlan::max_concurrency`] assets at a time, and transient
+    /// failures are retried under the client's retry policy. Every asset is
+    /// attempted even if some fail; see [`bulk_update`] for the report.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write` (`asset:read` only for a dry run)
+    pub async fn bulk_update(&self, filter: &AssetFilter, plan: &UpdatePlan) -> BulkUpdateReport {
+        let assets = self
+            .client
+            .with_priority(crate::rate_limit::Priority::Low)
+            .assets();
+        let assets = &assets;
+        let mut outcomes: Vec<(usize, String, Result<UpdateOutcome>)> =
+            futures::stream::iter(filter.asset_ids().iter().enumerate())
+                .map(|(index, asset_id)| async move {
+                    let outcome = async {
+                        let asset = assets.get(asset_id).await?;
+                        if !filter.matches(&asset) {
+                            return Ok(UpdateOutcome::Skipped);
+                        }
+                        let Some(change) = plan.change_for(&asset)? else {
+                            return Ok(UpdateOutcome::Unchanged);
+                        };
+                        if !plan.is_dry_run() {
+                            assets.update(asset_id, change.request()).await?;
+                        }
+                        Ok(UpdateOutcome::Changed(change))
+                    }
+                    .await;
+                    (index, asset_id.clone(), outcome)
+                })
+                .buffer_unordered(plan.max_concurrency())
+                .collect()
+                .await;
+        outcomes.sort_by_key(|(index, _, _)| *index);
+
+        let mut report = BulkUpdateReport {
+            dry_run: plan.is_dry_run(),
+            ..BulkUpdateReport::default()
+        };
+        for (_, asset_id, outcome) in outcomes {
+            match outcome {
+                Ok(UpdateOutcome::Changed(change)) => report.changed.push(change),
+                Ok(UpdateOutcome::Unchanged) => report.unchanged.push(asset_id),
+                Ok(UpdateOutcome::Skipped) => report.skipped.push(asset_id),
+                Err(error) => report.failed.push(FailedUpdate { asset_id, error }),
+            }
+        }
+        report
+    }
+
     /// Delete an asset
     ///
     /// **Required OAuth scope:** `asset:write`
     pub async fn delete(&self, asset_id: &str) -> Result<()> {
-        let path = format!("/v1/assets/{asset_id}");
+        let path = routes::asset(asset_id);
         self.client.delete(&path).await?;
         Ok(())
     }
 
+    /// Delete several assets, [`DEFAULT_DELETE_CONCURRENCY`] at a time
+    ///
+    /// Every ID is attempted even if some fail; check
+    /// [`BulkDeleteSummary::failed`] for the ones that were not deleted.
+    ///
+    /// **Required OAuth scope:** `asset:write`
+    pub async fn delete_many<I>(&self, asset_ids: I) -> BulkDeleteSummary
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut outcomes: Vec<(usize, String, Result<()>)> =
+            futures::stream::iter(asset_ids.into_iter().map(Into::into).enumerate())
+                .map(|(index, asset_id): (usize, String)| async move {
+                    let outcome = self.delete(&asset_id).await;
+                    (index, asset_id, outcome)
+                })
+                .buffer_unordered(DEFAULT_DELETE_CONCURRENCY)
+                .collect()
+                .await;
+        outcomes.sort_by_key(|(index, _, _)| *index);
+
+        let mut summary = BulkDeleteSummary::default();
+        for (_, asset_id, outcome) in outcomes {
+            match outcome {
+                Ok(()) => summary.deleted.push(asset_id),
+                Err(error) => summary.failed.push(FailedDeletion { asset_id, error }),
+            }
+        }
+        summary
+    }
+
+    /// Download an asset's thumbnail into `writer`
+    ///
+    /// Thumbnail URLs expire after a short time. If the URL in `asset` is
+    /// rejected with `403 Forbidden`, the asset is fetched again for a fresh
+    /// URL and the download retried once. Transient failures are retried
+    /// under the client's [`RetryPolicy`](crate::retry::RetryPolicy); any
+    /// other failed response is an [`Error::Download`].
+    ///
+    /// **Required OAuth scope:** `asset:read` (only when the URL has expired)
+    pub async fn download_thumbnail<W>(
+        &self,
+        asset: &Asset,
+        writer: &mut W,
+    ) -> Result<DownloadedThumbnail>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let no_thumbnail = || Error::Generic(format!("Asset {} has no thumbnail", asset.id));
+        let mut thumbnail = asset.thumbnail.clone().ok_or_else(no_thumbnail)?;
+        let mut refreshed = false;
+        let mut response = self.client.fetch_url(&thumbnail.url).await?;
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            thumbnail = self
+                .get(&asset.id)
+                .await?
+                .thumbnail
+                .ok_or_else(no_thumbnail)?;
+            refreshed = true;
+            response = self.client.fetch_url(&thumbnail.url).await?;
+        }
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::download(status, response.url()));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let mut size = 0;
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk).await?;
+            size += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(DownloadedThumbnail {
+            width: thumbnail.width,
+            height: thumbnail.height,
+            content_type,
+            size,
+            refreshed,
+        })
+    }
+
     /// Create an asset upload job
     ///
+    /// Fails with [`Error::InvalidAsset`] if the metadata is over Canva's
+    /// limits (see [`AssetUploadMetadata::to_header_value`]), or if the file
+    /// is not in a format or size Canva accepts, unless disabled with
+    /// [`ClientBuilder::validate_uploads`](crate::ClientBuilder::validate_uploads).
+    /// Then runs the client's [upload precheck](crate::uploads), if any, before
+    /// sending the file.
+    ///
     /// **Required OAuth scope:** `asset:write`
     #[cfg_attr(feature = "observability", tracing::instrument(
         skip(self, file_data),
@@ -88,12 +425,24 @@ impl AssetsApi {
         file_data: Vec<u8>,
         metadata: AssetUploadMetadata,
     ) -> Result<crate::models::AssetUploadJob> {
-        let metadata_json = serde_json::to_string(&metadata)?;
+        let metadata_header = metadata.to_header_value()?;
+        let name = metadata
+            .name()
+            .unwrap_or_else(|_| metadata.name_base64.clone());
+        self.client
+            .precheck_upload(UploadCandidate::new(
+                &name,
+                &metadata.tags,
+                UploadSource::Bytes(&file_data),
+            ))
+            .await?;
+
         let response = self
             .client
-            .upload_file("/v1/asset-uploads", file_data, Some(&metadata_json))
+            .upload_file(routes::ASSET_UPLOADS, file_data, Some(&metadata_header))
             .await?;
-        let job_response: crate::models::AssetUploadJobResponse = response.json().await?;
+        let job_response: crate::models::AssetUploadJobResponse =
+            self.client.read_json(response).await?;
         Ok(job_response.job)
     }
 
@@ -101,93 +450,518 @@ impl AssetsApi {
     ///
     /// **Required OAuth scope:** `asset:read`
     pub async fn get_upload_job(&self, job_id: &str) -> Result<crate::models::AssetUploadJob> {
-        let path = format!("/v1/asset-uploads/{job_id}");
+        let path = routes::asset_upload_job(job_id);
         let response: crate::models::AssetUploadJobResponse = self.client.get_json(&path).await?;
         Ok(response.job)
     }
 
     /// Create an asset upload job from URL
     ///
+    /// Runs the client's [upload precheck](crate::uploads), if any, on the URL
+    /// before creating the job.
+    ///
     /// **Required OAuth scope:** `asset:write`
     pub async fn create_url_upload_job(
         &self,
         request: CreateUrlAssetUploadJobRequest,
     ) -> Result<crate::models::AssetUploadJob> {
+        self.client
+            .precheck_upload(UploadCandidate::new(
+                &request.name,
+                &[],
+                UploadSource::Url(&request.url),
+            ))
+            .await?;
+
         let response: crate::models::AssetUploadJobResponse = self
             .client
-            .post_json("/v1/url-asset-uploads", &request)
+            .post_json(routes::URL_ASSET_UPLOADS, &request)
             .await?;
         Ok(response.job)
     }
 
+    /// Upload an asset from a URL, checking the URL before creating the job
+    ///
+    /// Only `http` and `https` URLs are accepted. Without a name in
+    /// `options`, the asset is named after the URL's last path segment, or
+    /// its host if the path is empty. With
+    /// [`check_remote`](UrlUploadOptions::check_remote) set, the file is
+    /// requested with `HEAD` first and refused with [`Error::InvalidAsset`]
+    /// if it is missing, of a type Canva does not accept, or too large.
+    ///
+    /// **Required OAuth scope:** `asset:write`
+    pub async fn upload_from_url(
+        &self,
+        url: url::Url,
+        options: &UrlUploadOptions,
+    ) -> Result<crate::models::AssetUploadJob> {
+        let name = match &options.name {
+            Some(name) => name.clone(),
+            None => asset_name_from_url(&url),
+        };
+        let invalid = |reason: String| Error::InvalidAsset {
+            name: name.clone(),
+            reason,
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(invalid(format!(
+                "{} URLs cannot be uploaded; Canva only fetches http and https URLs",
+                url.scheme()
+            )));
+        }
+        if url.as_str().len() > MAX_UPLOAD_URL_CHARS {
+            return Err(invalid(format!(
+                "URL is over the {MAX_UPLOAD_URL_CHARS} character limit"
+            )));
+        }
+        if options.check_remote {
+            self.check_remote_asset(&url).await.map_err(invalid)?;
+        }
+
+        self.create_url_upload_job(CreateUrlAssetUploadJobRequest {
+            url: url.into(),
+            name,
+        })
+        .await
+    }
+
+    /// Why Canva would fail to upload the file at `url`, judged from a `HEAD` request
+    async fn check_remote_asset(&self, url: &url::Url) -> std::result::Result<(), String> {
+        let response = self
+            .client
+            .head_url(url.as_str())
+            .await
+            .map_err(|e| format!("could not reach URL: {e}"))?;
+        if !response.status().is_success() {
+            return Err(format!("URL returned HTTP {}", response.status()));
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        let Some(content_type) = header(reqwest::header::CONTENT_TYPE) else {
+            return Ok(());
+        };
+        let Some(format) = crate::uploads::AssetFormat::from_mime_type(content_type) else {
+            // Servers that do not know the type can still serve a valid file
+            if content_type.starts_with("application/octet-stream") {
+                return Ok(());
+            }
+            return Err(format!("{content_type} is not a file type Canva accepts"));
+        };
+        let size = header(reqwest::header::CONTENT_LENGTH).and_then(|len| len.parse::<u64>().ok());
+        let max_size = format.kind().max_size();
+        match size {
+            Some(size) if size > max_size => Err(format!(
+                "{} is {} MB, over the {} MB limit",
+                format.mime_type(),
+                size.div_ceil(1024 * 1024),
+                max_size / (1024 * 1024)
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Get the status of a URL asset upload job
     ///
     /// **Required OAuth scope:** `asset:read`
     pub async fn get_url_upload_job(&self, job_id: &str) -> Result<crate::models::AssetUploadJob> {
-        let path = format!("/v1/url-asset-uploads/{job_id}");
+        let path = routes::url_asset_upload_job(job_id);
         let response: crate::models::AssetUploadJobResponse = self.client.get_json(&path).await?;
         Ok(response.job)
     }
 
     /// Wait for an upload job to complete
     ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
     /// **Required OAuth scope:** `asset:read`
     pub async fn wait_for_upload_job(&self, job_id: &str) -> Result<crate::models::Asset> {
-        loop {
-            let job = self.get_upload_job(job_id).await?;
+        self.client
+            .job_poller()
+            .wait(&self.pending_upload_job(job_id))
+            .await
+    }
 
-            match job.status {
-                JobStatus::Success => {
-                    return job.asset.ok_or_else(|| {
-                        crate::error::Error::Generic("Job succeeded but no asset data".to_string())
-                    });
-                }
-                JobStatus::Failed => {
-                    let error_msg = job
-                        .error
-                        .map(|e| format!("{}: {}", e.code, e.message))
-                        .unwrap_or_else(|| "Job failed with unknown error".to_string());
-                    return Err(crate::error::Error::Generic(error_msg));
-                }
-                JobStatus::InProgress => {
-                    // Wait a bit before polling again
-                    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    /// Like [`wait_for_upload_job`](Self::wait_for_upload_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `asset:read`
+    pub async fn wait_for_upload_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<crate::models::Asset> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_upload_job(job_id))
+            .await
+    }
+
+    /// A upload job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_upload_job(&self, job_id: &str) -> PendingUploadJob {
+        PendingUploadJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
+        }
+    }
+
+    /// Upload every image and video in a local directory
+    ///
+    /// Files are named after their file name and tagged with
+    /// [`DirectoryUploadOptions::with_tags`]. Each upload job is awaited, with
+    /// at most [`concurrency`](DirectoryUploadOptions::with_concurrency) files
+    /// in flight, polled with jitter like a [`JobManager`](crate::jobs::JobManager)'s.
+    /// Only listing the directory can fail the whole call; errors for
+    /// individual files are collected in the summary.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use canva_connect::endpoints::assets::DirectoryUploadOptions;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let options = DirectoryUploadOptions::new()
+    ///     .with_recursive(true)
+    ///     .with_tags(vec!["product-shots".to_string()]);
+    ///
+    /// let summary = client
+    ///     .assets()
+    ///     .upload_directory_with_progress("./images", &options, |progress| {
+    ///         println!("{}/{} {}", progress.completed, progress.total, progress.path.display());
+    ///     })
+    ///     .await?;
+    /// for failure in &summary.failed {
+    ///     eprintln!("{}: {}", failure.path.display(), failure.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_directory(
+        &self,
+        dir: impl AsRef<Path>,
+        options: &DirectoryUploadOptions,
+    ) -> Result<DirectoryUploadSummary> {
+        self.upload_directory_with_progress(dir, options, |_| {})
+            .await
+    }
+
+    /// Like [`upload_directory`](Self::upload_directory), reporting progress after each file
+    pub async fn upload_directory_with_progress<F>(
+        &self,
+        dir: impl AsRef<Path>,
+        options: &DirectoryUploadOptions,
+        mut on_progress: F,
+    ) -> Result<DirectoryUploadSummary>
+    where
+        F: FnMut(&DirectoryUploadProgress<'_>),
+    {
+        let files = directory::collect_files(dir.as_ref(), options.recursive()).await?;
+        let total = files.len();
+        let mut summary = DirectoryUploadSummary::default();
+        let mut uploads = futures::stream::iter(files)
+            .map(|path| async move {
+                let outcome = self.upload_file_at(&path, options).await;
+                (path, outcome)
+            })
+            .buffer_unordered(options.concurrency());
+
+        let mut completed = 0;
+        while let Some((path, outcome)) = uploads.next().await {
+            completed += 1;
+            let status = summary.record(path.clone(), outcome);
+            on_progress(&DirectoryUploadProgress {
+                path: &path,
+                status,
+                completed,
+                total,
+            });
+        }
+        summary.sort();
+        Ok(summary)
+    }
+
+    /// Upload one file of a directory upload
+    async fn upload_file_at(&self, path: &Path, options: &DirectoryUploadOptions) -> FileOutcome {
+        let bytes = match directory::read_supported(path, options.skip_unsupported()).await {
+            Ok(Some(bytes)) => bytes,
+            Ok(None) => return FileOutcome::Skipped,
+            Err(e) => return FileOutcome::Failed(e.into()),
+        };
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let metadata = AssetUploadMetadata::new(&name, options.tags().to_vec());
+
+        let result = async {
+            let job = self.create_upload_job(bytes, metadata).await?;
+            self.client
+                .batch_job_poller()
+                .wait(&self.pending_upload_job(&job.id))
+                .await
+        }
+        .await;
+        match result {
+            Ok(asset) => FileOutcome::Uploaded(Box::new(asset)),
+            Err(e) => FileOutcome::Failed(e),
+        }
+    }
+
+    /// Upload a local image after resizing and re-encoding it as set in `options`
+    ///
+    /// The image is processed on a blocking thread with
+    /// [`processing::process_image`]. A re-encoded image is named after the
+    /// file with the new format's extension. Waits for the upload to finish
+    /// and returns the asset.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub async fn upload_image_with_processing(
+        &self,
+        path: impl AsRef<Path>,
+        options: &processing::ProcessingOptions,
+    ) -> Result<crate::models::Asset> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let options = options.clone();
+        let processed =
+            tokio::task::spawn_blocking(move || processing::process_image(&bytes, &options))
+                .await
+                .map_err(|e| Error::Generic(format!("Image processing stopped: {e}")))??;
+
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = match processed.format {
+            Some(format) => processing::renamed(&name, format),
+            None => name,
+        };
+        let job = self
+            .create_upload_job(processed.bytes, AssetUploadMetadata::new(&name, Vec::new()))
+            .await?;
+        self.wait_for_upload_job(&job.id).await
+    }
+
+    /// Upload a local file, recording progress in `store` so a crash does not lose it
+    ///
+    /// The upload is saved in `store` before the file is sent and again once
+    /// Canva has accepted it, and removed when the asset is ready. If the same
+    /// file was already accepted by an earlier, interrupted call, this waits
+    /// for that job instead of sending the file again. See the
+    /// [`resumable`] module for details.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    pub async fn upload_resumable(
+        &self,
+        path: impl AsRef<Path>,
+        tags: Vec<String>,
+        store: &dyn UploadStateStore,
+    ) -> Result<crate::models::Asset> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let upload = ResumableUpload::new(path, &name, tags, &bytes);
+        if let Some(saved) = store.load(&upload.id).await? {
+            if saved.job_id.is_some() {
+                return self.resume_upload_job(saved, store).await;
+            }
+        }
+        store.save(&upload).await?;
+        self.send_resumable(upload, bytes, store).await
+    }
+
+    /// Finish an upload started by [`upload_resumable`](Self::upload_resumable)
+    ///
+    /// Waits for the upload job if Canva accepted the file before the
+    /// interruption. Otherwise, or if that job failed or has expired, sends
+    /// the file again, failing with [`Error::InvalidAsset`] if it has changed.
+    ///
+    /// **Required OAuth scopes:** `asset:read`, `asset:write`
+    pub async fn resume_upload_job(
+        &self,
+        mut upload: ResumableUpload,
+        store: &dyn UploadStateStore,
+    ) -> Result<crate::models::Asset> {
+        if let Some(job_id) = &upload.job_id {
+            match self.get_upload_job(job_id).await {
+                Ok(job) if job.status != JobStatus::Failed => {
+                    let asset = self.wait_for_upload_job(job_id).await?;
+                    store.remove(&upload.id).await?;
+                    return Ok(asset);
                 }
+                Ok(_) => {}
+                Err(e) if e.is_not_found() => {}
+                Err(e) => return Err(e),
             }
         }
+
+        let bytes = upload.read_file().await?;
+        upload.job_id = None;
+        store.save(&upload).await?;
+        self.send_resumable(upload, bytes, store).await
+    }
+
+    /// Send the file of a resumable upload and wait for the asset
+    async fn send_resumable(
+        &self,
+        mut upload: ResumableUpload,
+        bytes: Vec<u8>,
+        store: &dyn UploadStateStore,
+    ) -> Result<crate::models::Asset> {
+        let metadata = AssetUploadMetadata::new(&upload.name, upload.tags.clone());
+        let job = self.create_upload_job(bytes, metadata).await?;
+        upload.job_id = Some(job.id.clone());
+        store.save(&upload).await?;
+
+        let asset = self.wait_for_upload_job(&job.id).await?;
+        store.remove(&upload.id).await?;
+        Ok(asset)
     }
 
     /// Wait for a URL upload job to complete
     ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
     /// **Required OAuth scope:** `asset:read`
     pub async fn wait_for_url_upload_job(&self, job_id: &str) -> Result<crate::models::Asset> {
-        loop {
-            let job = self.get_url_upload_job(job_id).await?;
+        self.client
+            .job_poller()
+            .wait(&self.pending_url_upload_job(job_id))
+            .await
+    }
 
-            match job.status {
-                JobStatus::Success => {
-                    return job.asset.ok_or_else(|| {
-                        crate::error::Error::Generic("Job succeeded but no asset data".to_string())
-                    });
-                }
-                JobStatus::Failed => {
-                    let error_msg = job
-                        .error
-                        .map(|e| format!("{}: {}", e.code, e.message))
-                        .unwrap_or_else(|| "Job failed with unknown error".to_string());
-                    return Err(crate::error::Error::Generic(error_msg));
-                }
-                JobStatus::InProgress => {
-                    // Wait a bit before polling again
-                    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-                }
-            }
+    /// Like [`wait_for_url_upload_job`](Self::wait_for_url_upload_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `asset:read`
+    pub async fn wait_for_url_upload_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<crate::models::Asset> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_url_upload_job(job_id))
+            .await
+    }
+
+    /// A URL upload job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_url_upload_job(&self, job_id: &str) -> PendingUrlUploadJob {
+        PendingUrlUploadJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
+        }
+    }
+}
+
+/// A thumbnail written by [`AssetsApi::download_thumbnail`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedThumbnail {
+    /// Thumbnail width in pixels
+    pub width: u32,
+    /// Thumbnail height in pixels
+    pub height: u32,
+    /// `Content-Type` of the image, if the server sent one
+    pub content_type: Option<String>,
+    /// Number of bytes written
+    pub size: u64,
+    /// Whether the asset was fetched again because its thumbnail URL had expired
+    pub refreshed: bool,
+}
+
+/// Resolve an upload job's status to its asset, or `None` while it is running
+pub(crate) fn upload_job_outcome(job: AssetUploadJob) -> Result<Option<Asset>> {
+    match job.status {
+        JobStatus::Success => job
+            .asset
+            .map(Some)
+            .ok_or_else(|| Error::Generic("Job succeeded but no asset data".to_string())),
+        JobStatus::Failed => {
+            let error_msg = job
+                .error
+                .map(|e| format!("{}: {}", e.code, e.message))
+                .unwrap_or_else(|| "Job failed with unknown error".to_string());
+            Err(Error::Generic(error_msg))
         }
+        JobStatus::InProgress | JobStatus::Unknown => Ok(None),
     }
 }
 
+/// An asset upload job that can be polled until it finishes
+///
+/// Returned by [`AssetsApi::pending_upload_job`]; its output is the new asset.
+#[derive(Debug, Clone)]
+pub struct PendingUploadJob {
+    api: AssetsApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingUploadJob {
+    type Output = Asset;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<Asset>> {
+        upload_job_outcome(self.api.get_upload_job(&self.job_id).await?)
+    }
+}
+
+/// A URL asset upload job that can be polled until it finishes
+///
+/// Returned by [`AssetsApi::pending_url_upload_job`]; its output is the new asset.
+#[derive(Debug, Clone)]
+pub struct PendingUrlUploadJob {
+    api: AssetsApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingUrlUploadJob {
+    type Output = Asset;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<Asset>> {
+        upload_job_outcome(self.api.get_url_upload_job(&self.job_id).await?)
+    }
+}
+
+/// Longest asset name Canva accepts, in characters
+pub const MAX_ASSET_NAME_CHARS: usize = 50;
+/// Most tags an asset can have
+pub const MAX_ASSET_TAGS: usize = 50;
+/// Longest asset tag Canva accepts, in characters
+pub const MAX_ASSET_TAG_CHARS: usize = 50;
+
 /// Asset upload metadata
+///
+/// Sent in the `Asset-Upload-Metadata` header; see
+/// [`to_header_value`](Self::to_header_value).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssetUploadMetadata {
     /// Asset name, encoded in Base64
     pub name_base64: String,
@@ -205,10 +979,100 @@ impl AssetUploadMetadata {
             tags,
         }
     }
+
+    /// The `Asset-Upload-Metadata` header value for this metadata
+    ///
+    /// Checks the metadata against Canva's limits first: the decoded name
+    /// must be 1 to [`MAX_ASSET_NAME_CHARS`] characters, and there may be at
+    /// most [`MAX_ASSET_TAGS`] tags of at most [`MAX_ASSET_TAG_CHARS`]
+    /// characters each. Fails with [`Error::InvalidAsset`] otherwise.
+    ///
+    /// ```rust
+    /// use canva_connect::endpoints::assets::AssetUploadMetadata;
+    ///
+    /// let metadata = AssetUploadMetadata::new("My Awesome Upload 🚀", vec![]);
+    /// assert_eq!(
+    ///     metadata.to_header_value().unwrap(),
+    ///     r#"{"name_base64":"TXkgQXdlc29tZSBVcGxvYWQg8J+agA=="}"#
+    /// );
+    /// ```
+    pub fn to_header_value(&self) -> Result<String> {
+        let name = self.name()?;
+        let invalid = |reason: String| crate::error::Error::InvalidAsset {
+            name: name.clone(),
+            reason,
+        };
+        let name_chars = name.chars().count();
+        if name.trim().is_empty() {
+            return Err(invalid("asset name is empty".to_string()));
+        }
+        if name_chars > MAX_ASSET_NAME_CHARS {
+            return Err(invalid(format!(
+                "asset name is {name_chars} characters, over the {MAX_ASSET_NAME_CHARS} character limit"
+            )));
+        }
+        if self.tags.len() > MAX_ASSET_TAGS {
+            return Err(invalid(format!(
+                "{} tags given, over the limit of {MAX_ASSET_TAGS}",
+                self.tags.len()
+            )));
+        }
+        if let Some(tag) = self
+            .tags
+            .iter()
+            .find(|tag| tag.chars().count() > MAX_ASSET_TAG_CHARS)
+        {
+            return Err(invalid(format!(
+                "tag \"{tag}\" is over the {MAX_ASSET_TAG_CHARS} character limit"
+            )));
+        }
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Decode the Base64-encoded asset name
+    pub fn name(&self) -> Result<String> {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        let bytes = STANDARD
+            .decode(&self.name_base64)
+            .map_err(|e| crate::error::Error::Generic(format!("Invalid Base64 asset name: {e}")))?;
+        String::from_utf8(bytes)
+            .map_err(|e| crate::error::Error::Generic(format!("Asset name is not UTF-8: {e}")))
+    }
+}
+
+/// Longest URL Canva will upload an asset from
+pub const MAX_UPLOAD_URL_CHARS: usize = 2048;
+/// Longest name a URL upload can give an asset, in characters
+const MAX_URL_ASSET_NAME_CHARS: usize = 255;
+
+/// Options for [`AssetsApi::upload_from_url`]
+#[derive(Debug, Clone, Default)]
+pub struct UrlUploadOptions {
+    /// Name for the asset; taken from the URL when `None`
+    pub name: Option<String>,
+    /// Check the file with a `HEAD` request before creating the upload job
+    pub check_remote: bool,
+}
+
+/// Asset name for a file at `url`: its decoded last path segment, or the host
+fn asset_name_from_url(url: &url::Url) -> String {
+    let segment = url
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+        .map(|segment| {
+            urlencoding::decode(segment)
+                .map(|decoded| decoded.into_owned())
+                .unwrap_or_else(|_| segment.to_string())
+        });
+    let name = segment
+        .or_else(|| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "asset".to_string());
+    name.chars().take(MAX_URL_ASSET_NAME_CHARS).collect()
 }
 
 /// Request to create URL asset upload job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateUrlAssetUploadJobRequest {
     /// URL to upload from
     pub url: String,
@@ -218,6 +1082,7 @@ pub struct CreateUrlAssetUploadJobRequest {
 
 /// Request to update an asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateAssetRequest {
     /// New asset name
     pub name: Option<String>,
@@ -227,6 +1092,7 @@ pub struct UpdateAssetRequest {
 
 /// Response from getting an asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetAssetResponse {
     /// The asset
     pub asset: Asset,
@@ -234,7 +1100,928 @@ pub struct GetAssetResponse {
 
 /// Response from updating an asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateAssetResponse {
     /// The updated asset
     pub asset: Asset,
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        auth::AccessToken,
+        error::Error,
+        uploads::{UploadPrecheck, UploadRejection},
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct RejectLargeFiles {
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl UploadPrecheck for RejectLargeFiles {
+        async fn check(
+            &self,
+            upload: &UploadCandidate<'_>,
+        ) -> std::result::Result<(), UploadRejection> {
+            self.seen
+                .lock()
+                .expect("lock")
+                .push(upload.name().to_string());
+            match upload.size() {
+                Some(size) if size > 4 => {
+                    Err(UploadRejection::new("File too large").with_policy("size-limit"))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_upload_job_with_cancellation() {
+        use crate::jobs::CancellationToken;
+        use crate::testing::{MockResponse, MockTransport};
+        use std::time::Duration;
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/asset-uploads/job_1",
+            MockResponse::json(
+                200,
+                &serde_json::json!({ "job": { "id": "job_1", "status": "in_progress" } }),
+            ),
+        );
+        let client = Client::builder(AccessToken::new("test_token"))
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client");
+        let token = CancellationToken::new();
+        let options = WaitOptions::new()
+            .with_cancellation(token.clone())
+            .with_poll_interval(Duration::from_secs(1));
+
+        let assets = client.assets();
+        let canceller = async {
+            tokio::time::sleep(Duration::from_millis(2500)).await;
+            token.cancel();
+        };
+        let (result, ()) = tokio::join!(
+            assets.wait_for_upload_job_with("job_1", &options),
+            canceller
+        );
+
+        assert!(matches!(result, Err(Error::Cancelled { ref job_id }) if job_id == "job_1"));
+        assert_eq!(transport.requests().len(), 3);
+    }
+    const JPEG: &[u8] = b"\xFF\xD8\xFF";
+
+    fn client(server: &mockito::Server, precheck: RejectLargeFiles) -> Client {
+        Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .upload_precheck(precheck)
+            .build()
+            .expect("Failed to create client")
+    }
+
+    #[tokio::test]
+    async fn test_rejected_upload_is_not_sent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/asset-uploads")
+            .expect(0)
+            .create_async()
+            .await;
+        let precheck = RejectLargeFiles::default();
+        let seen = precheck.seen.clone();
+
+        let result = client(&server, precheck)
+            .assets()
+            .create_upload_job(PNG.to_vec(), AssetUploadMetadata::new("report.png", vec![]))
+            .await;
+
+        match result {
+            Err(Error::UploadRejected { name, rejection }) => {
+                assert_eq!(name, "report.png");
+                assert_eq!(rejection.policy.as_deref(), Some("size-limit"));
+            }
+            other => panic!("expected UploadRejected, got {other:?}"),
+        }
+        assert_eq!(*seen.lock().expect("lock"), vec!["report.png"]);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_allowed_uploads_are_sent() {
+        let mut server = mockito::Server::new_async().await;
+        let upload = server
+            .mock("POST", "/v1/asset-uploads")
+            .match_body(JPEG.to_vec())
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        let url_upload = server
+            .mock("POST", "/v1/url-asset-uploads")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_2","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        let precheck = RejectLargeFiles::default();
+        let seen = precheck.seen.clone();
+        let assets = client(&server, precheck).assets();
+
+        let job = assets
+            .create_upload_job(JPEG.to_vec(), AssetUploadMetadata::new("a.jpg", vec![]))
+            .await
+            .expect("upload");
+        assert_eq!(job.id, "job_1");
+        let job = assets
+            .create_url_upload_job(CreateUrlAssetUploadJobRequest {
+                url: "https://example.com/b.png".to_string(),
+                name: "b.png".to_string(),
+            })
+            .await
+            .expect("URL upload");
+        assert_eq!(job.id, "job_2");
+
+        assert_eq!(*seen.lock().expect("lock"), vec!["a.jpg", "b.png"]);
+        upload.assert_async().await;
+        url_upload.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_file_is_not_sent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/asset-uploads")
+            .expect(0)
+            .create_async()
+            .await;
+        let precheck = RejectLargeFiles::default();
+        let seen = precheck.seen.clone();
+
+        let result = client(&server, precheck)
+            .assets()
+            .create_upload_job(
+                b"%PDF-1.7".to_vec(),
+                AssetUploadMetadata::new("doc.pdf", vec![]),
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidAsset { name, .. }) if name == "doc.pdf"));
+        assert!(seen.lock().expect("lock").is_empty());
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_metadata_header_value_checks_limits() {
+        let header = AssetUploadMetadata::new("Café ☕", vec!["menu".to_string()])
+            .to_header_value()
+            .expect("valid metadata");
+        let json: serde_json::Value = serde_json::from_str(&header).expect("JSON header");
+        assert_eq!(json["name_base64"], "Q2Fmw6kg4piV");
+        assert_eq!(json["tags"], serde_json::json!(["menu"]));
+
+        // Fifty emoji are within the limit, which counts characters, not bytes
+        let emoji = "🚀".repeat(MAX_ASSET_NAME_CHARS);
+        assert!(AssetUploadMetadata::new(&emoji, vec![])
+            .to_header_value()
+            .is_ok());
+
+        for metadata in [
+            AssetUploadMetadata::new(&"a".repeat(MAX_ASSET_NAME_CHARS + 1), vec![]),
+            AssetUploadMetadata::new(" ", vec![]),
+            AssetUploadMetadata::new("a.png", vec!["t".to_string(); MAX_ASSET_TAGS + 1]),
+            AssetUploadMetadata::new("a.png", vec!["t".repeat(MAX_ASSET_TAG_CHARS + 1)]),
+        ] {
+            assert!(matches!(
+                metadata.to_header_value(),
+                Err(Error::InvalidAsset { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_sends_metadata_header_and_rejects_long_names() {
+        let mut server = mockito::Server::new_async().await;
+        let upload = server
+            .mock("POST", "/v1/asset-uploads")
+            .match_header("asset-upload-metadata", r#"{"name_base64":"YS5qcGc="}"#)
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let assets = client(&server, RejectLargeFiles::default()).assets();
+
+        assets
+            .create_upload_job(JPEG.to_vec(), AssetUploadMetadata::new("a.jpg", vec![]))
+            .await
+            .expect("upload");
+        let long_name = format!("{}.jpg", "a".repeat(MAX_ASSET_NAME_CHARS));
+        let result = assets
+            .create_upload_job(JPEG.to_vec(), AssetUploadMetadata::new(&long_name, vec![]))
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidAsset { name, .. }) if name == long_name));
+        upload.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_validation_can_be_disabled() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/asset-uploads")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .validate_uploads(false)
+            .build()
+            .expect("Failed to create client");
+
+        let job = client
+            .assets()
+            .create_upload_job(
+                b"%PDF".to_vec(),
+                AssetUploadMetadata::new("doc.pdf", vec![]),
+            )
+            .await
+            .expect("upload");
+
+        assert_eq!(job.id, "job_1");
+        mock.assert_async().await;
+    }
+
+    fn write_upload_dir() -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("canva-upload-dir-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(dir.join("nested")).expect("create dir");
+        std::fs::write(dir.join("a.png"), PNG).expect("write");
+        std::fs::write(dir.join("b.jpg"), JPEG).expect("write");
+        std::fs::write(dir.join("notes.txt"), b"hello").expect("write");
+        std::fs::write(dir.join(".hidden.png"), PNG).expect("write");
+        std::fs::write(dir.join("nested").join("c.png"), PNG).expect("write");
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_reports_each_file() {
+        let mut server = mockito::Server::new_async().await;
+        let uploaded = server
+            .mock("POST", "/v1/asset-uploads")
+            .match_body(PNG.to_vec())
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_png","status":"in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let rejected = server
+            .mock("POST", "/v1/asset-uploads")
+            .match_body(JPEG.to_vec())
+            .with_status(400)
+            .with_body(r#"{"code":"invalid_file","message":"Corrupt image"}"#)
+            .create_async()
+            .await;
+        let finished = server
+            .mock("GET", "/v1/asset-uploads/job_png")
+            .with_status(200)
+            .with_body(
+                r#"{"job":{"id":"job_png","status":"success","asset":{"id":"asset_1","name":"a.png","tags":["shots"],"type":"image","created_at":1700000000,"updated_at":1700000000}}}"#,
+            )
+            .create_async()
+            .await;
+        let dir = write_upload_dir();
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+        let options = DirectoryUploadOptions::new()
+            .with_concurrency(2)
+            .with_tags(vec!["shots".to_string()]);
+
+        let mut progress = Vec::new();
+        let summary = client
+            .assets()
+            .upload_directory_with_progress(&dir, &options, |p| {
+                progress.push((p.status, p.completed, p.total));
+            })
+            .await
+            .expect("directory upload");
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(summary.total(), 3);
+        assert!(!summary.is_success());
+        assert_eq!(summary.uploaded.len(), 1);
+        assert_eq!(summary.uploaded[0].path, dir.join("a.png"));
+        assert_eq!(summary.uploaded[0].asset.id, "asset_1");
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].path, dir.join("b.jpg"));
+        assert_eq!(summary.skipped, vec![dir.join("notes.txt")]);
+
+        assert_eq!(progress.len(), 3);
+        assert_eq!(
+            progress.iter().map(|p| p.1).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(progress.iter().all(|p| p.2 == 3));
+        uploaded.assert_async().await;
+        rejected.assert_async().await;
+        finished.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_recursive_without_skipping() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/asset-uploads")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"failed","error":{"code":"upload_failed","message":"Try again"}}}"#)
+            .expect(3)
+            .create_async()
+            .await;
+        let failed = server
+            .mock("GET", "/v1/asset-uploads/job_1")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"failed","error":{"code":"upload_failed","message":"Try again"}}}"#)
+            .expect(3)
+            .create_async()
+            .await;
+        let dir = write_upload_dir();
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+        let options = DirectoryUploadOptions::new()
+            .with_recursive(true)
+            .with_skip_unsupported(false);
+
+        let summary = client
+            .assets()
+            .upload_directory(&dir, &options)
+            .await
+            .expect("directory upload");
+        std::fs::remove_dir_all(&dir).ok();
+
+        // notes.txt is rejected locally, the three images by the API
+        assert_eq!(summary.total(), 4);
+        assert!(summary.skipped.is_empty());
+        let failed_paths: Vec<_> = summary.failed.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(
+            failed_paths,
+            vec![
+                dir.join("a.png"),
+                dir.join("b.jpg"),
+                dir.join("nested").join("c.png"),
+                dir.join("notes.txt"),
+            ]
+        );
+        assert!(matches!(
+            summary.failed[3].error,
+            Error::InvalidAsset { .. }
+        ));
+        mock.assert_async().await;
+        failed.assert_async().await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_upload_directory_records_dangling_symlink_as_failed() {
+        let server = mockito::Server::new_async().await;
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+        let dir = std::env::temp_dir().join(format!("canva-upload-dir-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create dir");
+        std::fs::write(dir.join("notes.txt"), b"hello").expect("write");
+        std::os::unix::fs::symlink(dir.join("gone.png"), dir.join("link.png")).expect("symlink");
+
+        let summary = client
+            .assets()
+            .upload_directory(&dir, &DirectoryUploadOptions::default())
+            .await
+            .expect("directory upload");
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(summary.skipped, vec![dir.join("notes.txt")]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].path, dir.join("link.png"));
+        assert!(matches!(summary.failed[0].error, Error::Io(_)));
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_missing_dir_is_an_error() {
+        let server = mockito::Server::new_async().await;
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+        let dir = std::env::temp_dir().join(format!("canva-missing-{}", uuid::Uuid::new_v4()));
+
+        let result = client
+            .assets()
+            .upload_directory(&dir, &DirectoryUploadOptions::default())
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    const ASSET_JOB_SUCCESS: &str = r#"{"job":{"id":"JOB","status":"success","asset":{"id":"asset_1","name":"clip.mp4","tags":[],"type":"video","created_at":1700000000,"updated_at":1700000000}}}"#;
+
+    fn write_video() -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "canva-resume-{}.mp4",
+            uuid::Uuid::new_v4().simple()
+        ));
+        // Minimal MP4 header: a 16-byte `ftyp` box
+        std::fs::write(&path, b"\x00\x00\x00\x10ftypisom\x00\x00\x02\x00").expect("write");
+        path
+    }
+
+    fn plain_client(server: &mockito::Server) -> Client {
+        Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client")
+    }
+
+    #[tokio::test]
+    async fn test_resumable_upload_is_not_sent_twice() {
+        let mut server = mockito::Server::new_async().await;
+        let upload = server
+            .mock("POST", "/v1/asset-uploads")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let outage = server
+            .mock("GET", "/v1/asset-uploads/job_1")
+            .with_status(503)
+            .with_body(r#"{"code":"SERVICE_UNAVAILABLE","message":"Down"}"#)
+            .create_async()
+            .await;
+        let path = write_video();
+        let store = MemoryUploadStateStore::new();
+        let assets = plain_client(&server).assets();
+
+        // The file was accepted, but waiting for the job failed
+        assert!(assets
+            .upload_resumable(&path, vec![], &store)
+            .await
+            .is_err());
+        let pending = store.list().await.expect("list");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].job_id.as_deref(), Some("job_1"));
+
+        outage.remove_async().await;
+        let finished = server
+            .mock("GET", "/v1/asset-uploads/job_1")
+            .with_status(200)
+            .with_body(ASSET_JOB_SUCCESS.replace("JOB", "job_1"))
+            // Status check, then the wait
+            .expect(2)
+            .create_async()
+            .await;
+        let asset = assets
+            .upload_resumable(&path, vec![], &store)
+            .await
+            .expect("resumed upload");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(asset.id, "asset_1");
+        assert!(store.list().await.expect("list").is_empty());
+        upload.assert_async().await;
+        finished.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_resume_resends_expired_job() {
+        let mut server = mockito::Server::new_async().await;
+        let expired = server
+            .mock("GET", "/v1/asset-uploads/job_old")
+            .with_status(404)
+            .with_body(r#"{"code":"not_found","message":"No such job"}"#)
+            .create_async()
+            .await;
+        let upload = server
+            .mock("POST", "/v1/asset-uploads")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_new","status":"in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let _finished = server
+            .mock("GET", "/v1/asset-uploads/job_new")
+            .with_status(200)
+            .with_body(ASSET_JOB_SUCCESS.replace("JOB", "job_new"))
+            .create_async()
+            .await;
+        let path = write_video();
+        let bytes = std::fs::read(&path).expect("read");
+        let mut saved = ResumableUpload::new(&path, "clip.mp4", vec![], &bytes);
+        saved.job_id = Some("job_old".to_string());
+        let store = MemoryUploadStateStore::new();
+        store.save(&saved).await.expect("save");
+
+        let asset = plain_client(&server)
+            .assets()
+            .resume_upload_job(saved, &store)
+            .await
+            .expect("resumed upload");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(asset.id, "asset_1");
+        assert!(store.list().await.expect("list").is_empty());
+        expired.assert_async().await;
+        upload.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_download_thumbnail_refreshes_expired_url() {
+        let mut server = mockito::Server::new_async().await;
+        let expired = server
+            .mock("GET", "/thumbs/old.png")
+            .with_status(403)
+            .expect(1)
+            .create_async()
+            .await;
+        let base_url = server.url();
+        let asset_json = |path: &str| {
+            serde_json::json!({ "asset": {
+                "id": "asset_1", "name": "logo.png", "tags": [], "type": "image",
+                "thumbnail": { "url": format!("{base_url}{path}"), "width": 320, "height": 180 },
+                "created_at": 1_700_000_000, "updated_at": 1_700_000_000
+            }})
+        };
+        let asset: Asset =
+            serde_json::from_value(asset_json("/thumbs/old.png")["asset"].clone()).expect("asset");
+        server
+            .mock("GET", "/v1/assets/asset_1")
+            .with_body(asset_json("/thumbs/new.png").to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/thumbs/new.png")
+            .with_header("content-type", "image/png")
+            .with_body("png-bytes")
+            .create_async()
+            .await;
+
+        let mut out = Vec::new();
+        let thumbnail = plain_client(&server)
+            .assets()
+            .download_thumbnail(&asset, &mut out)
+            .await
+            .expect("thumbnail downloads");
+
+        assert_eq!(out, b"png-bytes");
+        assert_eq!(
+            thumbnail,
+            DownloadedThumbnail {
+                width: 320,
+                height: 180,
+                content_type: Some("image/png".to_string()),
+                size: 9,
+                refreshed: true,
+            }
+        );
+        expired.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_reports_each_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let deleted = server
+            .mock(
+                "DELETE",
+                mockito::Matcher::Regex("^/v1/assets/keep_".to_string()),
+            )
+            .with_status(204)
+            .expect(2)
+            .create_async()
+            .await;
+        server
+            .mock("DELETE", "/v1/assets/gone")
+            .with_status(404)
+            .with_body(r#"{"code":"not_found","message":"No such asset"}"#)
+            .create_async()
+            .await;
+        server
+            .mock("DELETE", "/v1/assets/locked")
+            .with_status(403)
+            .with_body(r#"{"code":"FORBIDDEN","message":"Not yours"}"#)
+            .create_async()
+            .await;
+
+        let summary = plain_client(&server)
+            .assets()
+            .delete_many(["keep_1", "gone", "keep_2", "locked"])
+            .await;
+
+        assert_eq!(summary.deleted, vec!["keep_1", "keep_2"]);
+        let failed: Vec<_> = summary.failed.iter().map(|f| f.asset_id.as_str()).collect();
+        assert_eq!(failed, vec!["gone", "locked"]);
+        assert!(summary.failed[0].is_not_found());
+        assert!(!summary.is_success());
+        assert!(!summary.all_gone());
+        deleted.assert_async().await;
+    }
+
+    fn named_asset(server: &mockito::Server, asset_id: &str, name: &str) -> String {
+        let mut asset = crate::testing::fixtures::asset(&server.url(), asset_id);
+        asset["name"] = serde_json::json!(name);
+        serde_json::json!({ "asset": asset }).to_string()
+    }
+
+    async fn mock_named_assets(server: &mut mockito::Server) {
+        for (asset_id, name) in [("A1", "IMG_0001"), ("A2", "logo"), ("A3", "IMG_0003")] {
+            server
+                .mock("GET", format!("/v1/assets/{asset_id}").as_str())
+                .with_body(named_asset(server, asset_id, name))
+                .create_async()
+                .await;
+        }
+        server
+            .mock("GET", "/v1/assets/gone")
+            .with_status(404)
+            .with_body(r#"{"code":"not_found","message":"No such asset"}"#)
+            .create_async()
+            .await;
+    }
+
+    fn launch_plan() -> UpdatePlan {
+        UpdatePlan::new().rename(
+            regex::Regex::new(r"^IMG_(\d+)").expect("valid regex"),
+            "Launch $1",
+        )
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_dry_run_writes_nothing() {
+        let mut server = mockito::Server::new_async().await;
+        mock_named_assets(&mut server).await;
+        let patch = server
+            .mock("PATCH", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let filter = AssetFilter::ids(["A1", "A2", "gone", "A3"])
+            .name_matches(regex::Regex::new("^IMG_").expect("valid regex"));
+
+        let report = plain_client(&server)
+            .assets()
+            .bulk_update(&filter, &launch_plan().dry_run(true))
+            .await;
+
+        assert!(report.dry_run);
+        let renamed: Vec<_> = report
+            .changed
+            .iter()
+            .map(|change| change.new_name.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(renamed, vec!["Launch 0001", "Launch 0003"]);
+        assert_eq!(report.skipped, vec!["A2"]);
+        assert_eq!(report.failed_ids(), vec!["gone"]);
+        patch.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_patches_changed_assets() {
+        let mut server = mockito::Server::new_async().await;
+        mock_named_assets(&mut server).await;
+        let patch = server
+            .mock("PATCH", "/v1/assets/A1")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "name": "Launch 0001",
+                "tags": null,
+            })))
+            .with_body(named_asset(&server, "A1", "Launch 0001"))
+            .create_async()
+            .await;
+
+        let report = plain_client(&server)
+            .assets()
+            .bulk_update(&AssetFilter::ids(["A1", "A2"]), &launch_plan())
+            .await;
+
+        assert!(!report.dry_run);
+        assert!(report.is_success());
+        assert_eq!(report.changed.len(), 1);
+        assert_eq!(report.unchanged, vec!["A2"]);
+        patch.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_matching_keeps_order_and_reports_failures() {
+        let mut server = mockito::Server::new_async().await;
+        mock_named_assets(&mut server).await;
+        let filter = AssetFilter::ids(["A3", "A2", "gone", "A1"])
+            .name_matches(regex::Regex::new("^IMG_").expect("valid regex"))
+            .of_type(AssetType::Image);
+        let client = plain_client(&server);
+        let assets = client.assets();
+
+        let results: Vec<Result<Asset>> = assets.fetch_matching(&filter).collect().await;
+
+        let ids: Vec<_> = results
+            .iter()
+            .map(|result| result.as_ref().map(|asset| asset.id.as_str()).ok())
+            .collect();
+        assert_eq!(ids, vec![Some("A3"), None, Some("A1")]);
+    }
+
+    fn asset_with_tags(server: &mockito::Server, tags: &[&str]) -> String {
+        let mut asset = crate::testing::fixtures::asset(&server.url(), "Msd59349ff");
+        asset["tags"] = serde_json::json!(tags);
+        serde_json::json!({ "asset": asset }).to_string()
+    }
+
+    fn tags(tags: &[&str]) -> Vec<Tag> {
+        tags.iter()
+            .map(|tag| Tag::new(*tag).expect("valid tag"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_add_tags_keeps_existing_tags() {
+        let mut server = mockito::Server::new_async().await;
+        let read = server
+            .mock("GET", "/v1/assets/Msd59349ff")
+            .with_body(asset_with_tags(&server, &["image", "holiday"]))
+            .expect(1)
+            .create_async()
+            .await;
+        let write = server
+            .mock("PATCH", "/v1/assets/Msd59349ff")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({ "name": null, "tags": ["image", "holiday", "beach"] }),
+            ))
+            .with_body(asset_with_tags(&server, &["image", "holiday", "beach"]))
+            .expect(1)
+            .create_async()
+            .await;
+        let verify = server
+            .mock("GET", "/v1/assets/Msd59349ff")
+            .with_body(asset_with_tags(&server, &["image", "holiday", "beach"]))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let asset = plain_client(&server)
+            .assets()
+            .add_tags("Msd59349ff", tags(&["holiday", "beach"]))
+            .await
+            .expect("tags added");
+
+        assert_eq!(asset.tags, vec!["image", "holiday", "beach"]);
+        read.assert_async().await;
+        write.assert_async().await;
+        verify.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_change_tags_reapplies_after_concurrent_write() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/assets/Msd59349ff")
+            .with_body(asset_with_tags(&server, &["image", "draft"]))
+            .expect(1)
+            .create_async()
+            .await;
+        let first_write = server
+            .mock("PATCH", "/v1/assets/Msd59349ff")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({ "name": null, "tags": ["image", "final"] }),
+            ))
+            .with_body(asset_with_tags(&server, &["image", "final"]))
+            .expect(1)
+            .create_async()
+            .await;
+        // Another tagger read before our write and wrote after it
+        server
+            .mock("GET", "/v1/assets/Msd59349ff")
+            .with_body(asset_with_tags(&server, &["image", "draft", "holiday"]))
+            .expect(1)
+            .create_async()
+            .await;
+        let second_write = server
+            .mock("PATCH", "/v1/assets/Msd59349ff")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({ "name": null, "tags": ["image", "holiday", "final"] }),
+            ))
+            .with_body(asset_with_tags(&server, &["image", "holiday", "final"]))
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/assets/Msd59349ff")
+            .with_body(asset_with_tags(&server, &["image", "holiday", "final"]))
+            .create_async()
+            .await;
+
+        let changes = TagChanges {
+            add: tags(&["final"]),
+            remove: tags(&["draft"]),
+        };
+        let asset = plain_client(&server)
+            .assets()
+            .change_tags("Msd59349ff", &changes, 1)
+            .await
+            .expect("tags changed");
+
+        assert_eq!(asset.tags, vec!["image", "holiday", "final"]);
+        first_write.assert_async().await;
+        second_write.assert_async().await;
+    }
+
+    #[test]
+    fn test_tag_validation() {
+        assert!(Tag::new("best day ever").is_ok());
+        assert!(Tag::new("🚀".repeat(MAX_ASSET_TAG_CHARS)).is_ok());
+        for tag in ["", "   ", "tab\there"] {
+            assert!(Tag::new(tag).is_err(), "{tag:?} should be rejected");
+        }
+        assert!(Tag::new("a".repeat(MAX_ASSET_TAG_CHARS + 1)).is_err());
+        assert!(serde_json::from_str::<Tag>("\"\"").is_err());
+    }
+
+    #[test]
+    fn test_asset_name_from_url() {
+        let name = |url: &str| asset_name_from_url(&url::Url::parse(url).expect("valid URL"));
+        assert_eq!(
+            name("https://example.com/img/My%20Photo.jpg?size=l"),
+            "My Photo.jpg"
+        );
+        assert_eq!(name("https://example.com/videos/clip.mp4/"), "clip.mp4");
+        assert_eq!(name("https://cdn.example.com/"), "cdn.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_upload_from_url_checks_remote_file() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("HEAD", "/files/page.html")
+            .with_header("content-type", "text/html; charset=utf-8")
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/files/huge.png")
+            .with_header("content-type", "image/png")
+            .with_header("content-length", &(60 * 1024 * 1024).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/files/holiday%20photo.png")
+            .with_header("content-type", "image/png")
+            .with_header("content-length", "2048")
+            .create_async()
+            .await;
+        let url_upload = server
+            .mock("POST", "/v1/url-asset-uploads")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "url": format!("{}/files/holiday%20photo.png", server.url()),
+                "name": "holiday photo.png",
+            })))
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let assets = plain_client(&server).assets();
+        let options = UrlUploadOptions {
+            check_remote: true,
+            ..UrlUploadOptions::default()
+        };
+        let url = |path: &str| url::Url::parse(&format!("{}{path}", server.url())).expect("URL");
+
+        for rejected in ["/files/page.html", "/files/huge.png", "/files/missing.png"] {
+            let result = assets.upload_from_url(url(rejected), &options).await;
+            assert!(
+                matches!(result, Err(Error::InvalidAsset { .. })),
+                "{rejected} should be refused"
+            );
+        }
+        let ftp = url::Url::parse("ftp://example.com/a.png").expect("URL");
+        assert!(matches!(
+            assets.upload_from_url(ftp, &options).await,
+            Err(Error::InvalidAsset { .. })
+        ));
+
+        let job = assets
+            .upload_from_url(url("/files/holiday%20photo.png"), &options)
+            .await
+            .expect("upload job");
+        assert_eq!(job.id, "job_1");
+        url_upload.assert_async().await;
+    }
+}
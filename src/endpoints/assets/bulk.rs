@@ -0,0 +1,51 @@
+//! Deleting many assets at once.
+//!
+//! [`AssetsApi::delete_many`](super::AssetsApi::delete_many) sends one delete
+//! per asset with a bounded number in flight. A failed delete does not stop
+//! the others; the returned [`BulkDeleteSummary`] says which assets went to
+//! the trash and which did not, and why.
+//!
+//! The API has no endpoints for listing or restoring trashed assets; that is
+//! only possible from the Canva UI.
+
+use crate::error::Error;
+
+/// Number of deletes sent at once by `delete_many`
+pub const DEFAULT_DELETE_CONCURRENCY: usize = 4;
+
+/// An asset that could not be deleted
+#[derive(Debug)]
+pub struct FailedDeletion {
+    /// ID of the asset
+    pub asset_id: String,
+    /// Why the delete failed
+    pub error: Error,
+}
+
+impl FailedDeletion {
+    /// Whether the asset did not exist, e.g. because it was already deleted
+    pub fn is_not_found(&self) -> bool {
+        self.error.is_not_found()
+    }
+}
+
+/// Outcome of a bulk delete; both lists keep the order the IDs were given in
+#[derive(Debug, Default)]
+pub struct BulkDeleteSummary {
+    /// IDs of the assets moved to the trash
+    pub deleted: Vec<String>,
+    /// Assets that could not be deleted
+    pub failed: Vec<FailedDeletion>,
+}
+
+impl BulkDeleteSummary {
+    /// Whether every asset was deleted
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Whether every asset is gone, counting ones that no longer existed
+    pub fn all_gone(&self) -> bool {
+        self.failed.iter().all(FailedDeletion::is_not_found)
+    }
+}
@@ -0,0 +1,375 @@
+//! Renaming and retagging many assets at once.
+//!
+//! The API cannot list assets, so an [`AssetFilter`] starts from a set of
+//! asset IDs and narrows it down by name pattern, tags and type once each
+//! asset has been fetched.
+//! [`AssetsApi::fetch_matching`](super::AssetsApi::fetch_matching) streams
+//! the assets that pass. An [`UpdatePlan`] says what to do with the assets that
+//! pass: rewrite their names with a regex replacement and add or remove
+//! tags.
+//!
+//! [`AssetsApi::bulk_update`](super::AssetsApi::bulk_update) works through
+//! the assets a few at a time at [`Priority::Low`], so the client's rate
+//! limiter lets interactive calls go first. In a
+//! [`dry run`](UpdatePlan::dry_run) nothing is written and the returned
+//! [`BulkUpdateReport`] lists the changes that would have been made. Failed
+//! assets are listed with their errors; feed
+//! [`BulkUpdateReport::failed_ids`] back into a new filter to retry them.
+//!
+//! [`Priority::Low`]: crate::rate_limit::Priority::Low
+
+use super::{TagChanges, UpdateAssetRequest, MAX_ASSET_NAME_CHARS, MAX_ASSET_TAGS};
+use crate::{
+    endpoints::assets::Tag,
+    error::{Error, Result},
+    models::{Asset, AssetType},
+};
+use regex::Regex;
+
+/// Number of assets `bulk_update` works on at once
+pub const DEFAULT_UPDATE_CONCURRENCY: usize = 4;
+
+/// The assets a [`UpdatePlan`] applies to
+///
+/// ```rust
+/// use canva_connect::{endpoints::assets::AssetFilter, models::AssetType};
+/// use regex::Regex;
+///
+/// let filter = AssetFilter::ids(["Msd59349ff", "Msd59349fg"])
+///     .name_matches(Regex::new(r"^IMG_\d+").unwrap())
+///     .tagged("campaign-2024".parse().unwrap())
+///     .of_type(AssetType::Image);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssetFilter {
+    asset_ids: Vec<String>,
+    name: Option<Regex>,
+    tags: Vec<Tag>,
+    asset_type: Option<AssetType>,
+}
+
+impl AssetFilter {
+    /// Consider the assets with these IDs
+    pub fn ids<I>(asset_ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            asset_ids: asset_ids.into_iter().map(Into::into).collect(),
+            name: None,
+            tags: Vec::new(),
+            asset_type: None,
+        }
+    }
+
+    /// Only keep assets whose name matches `pattern`
+    pub fn name_matches(mut self, pattern: Regex) -> Self {
+        self.name = Some(pattern);
+        self
+    }
+
+    /// Only keep assets that have `tag`
+    ///
+    /// Call it again to require more tags; an asset must have all of them.
+    pub fn tagged(mut self, tag: Tag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Only keep assets of type `asset_type`
+    pub fn of_type(mut self, asset_type: AssetType) -> Self {
+        self.asset_type = Some(asset_type);
+        self
+    }
+
+    /// The IDs of the assets considered
+    pub fn asset_ids(&self) -> &[String] {
+        &self.asset_ids
+    }
+
+    /// Whether `asset` passes the name, tag and type checks
+    pub fn matches(&self, asset: &Asset) -> bool {
+        self.name
+            .as_ref()
+            .is_none_or(|pattern| pattern.is_match(&asset.name))
+            && self
+                .tags
+                .iter()
+                .all(|tag| asset.tags.iter().any(|existing| existing == tag.as_str()))
+            && self
+                .asset_type
+                .as_ref()
+                .is_none_or(|asset_type| asset.asset_type == *asset_type)
+    }
+}
+
+/// What [`AssetsApi::bulk_update`](super::AssetsApi::bulk_update) changes
+///
+/// ```rust
+/// use canva_connect::endpoints::assets::UpdatePlan;
+/// use regex::Regex;
+///
+/// // Turn "IMG_0042" into "Launch 0042" and mark the asset as reviewed
+/// let plan = UpdatePlan::new()
+///     .rename(Regex::new(r"^IMG_(\d+)").unwrap(), "Launch $1")
+///     .add_tag("reviewed".parse().unwrap())
+///     .dry_run(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UpdatePlan {
+    rename: Option<(Regex, String)>,
+    tags: TagChanges,
+    dry_run: bool,
+    concurrency: usize,
+}
+
+impl Default for UpdatePlan {
+    fn default() -> Self {
+        Self {
+            rename: None,
+            tags: TagChanges::default(),
+            dry_run: false,
+            concurrency: DEFAULT_UPDATE_CONCURRENCY,
+        }
+    }
+}
+
+impl UpdatePlan {
+    /// A plan that changes nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the first match of `pattern` in each name with `replacement`
+    ///
+    /// `replacement` may refer to capture groups as in
+    /// [`Regex::replace`]. Names the pattern does not match are kept.
+    pub fn rename(mut self, pattern: Regex, replacement: impl Into<String>) -> Self {
+        self.rename = Some((pattern, replacement.into()));
+        self
+    }
+
+    /// Add `tag` to assets that do not have it
+    pub fn add_tag(mut self, tag: Tag) -> Self {
+        self.tags.add.push(tag);
+        self
+    }
+
+    /// Remove `tag` from assets that have it
+    pub fn remove_tag(mut self, tag: Tag) -> Self {
+        self.tags.remove.push(tag);
+        self
+    }
+
+    /// Report the changes without writing them
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Work on at most `concurrency` assets at once (at least 1)
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Whether this plan only reports changes
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Number of assets worked on at once
+    pub fn max_concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// The change this plan makes to `asset`, or `None` if it would stay as is
+    ///
+    /// Fails with [`Error::InvalidAsset`] if the new name or tag list would
+    /// break Canva's limits.
+    pub fn change_for(&self, asset: &Asset) -> Result<Option<AssetChange>> {
+        let new_name = self
+            .rename
+            .as_ref()
+            .map(|(pattern, replacement)| {
+                pattern
+                    .replace(&asset.name, replacement.as_str())
+                    .into_owned()
+            })
+            .filter(|name| *name != asset.name);
+        let new_tags = Some(self.tags.apply(&asset.tags)).filter(|tags| *tags != asset.tags);
+        if new_name.is_none() && new_tags.is_none() {
+            return Ok(None);
+        }
+
+        let invalid = |reason: String| {
+            Err(Error::InvalidAsset {
+                name: asset.name.clone(),
+                reason,
+            })
+        };
+        if let Some(name) = &new_name {
+            if name.trim().is_empty() {
+                return invalid("renamed to a blank name".to_string());
+            }
+            if name.chars().count() > MAX_ASSET_NAME_CHARS {
+                return invalid(format!(
+                    "new name {name:?} is over the limit of {MAX_ASSET_NAME_CHARS} characters"
+                ));
+            }
+        }
+        if let Some(tags) = &new_tags {
+            if tags.len() > MAX_ASSET_TAGS {
+                return invalid(format!(
+                    "{} tags after the change, over the limit of {MAX_ASSET_TAGS}",
+                    tags.len()
+                ));
+            }
+        }
+
+        Ok(Some(AssetChange {
+            asset_id: asset.id.clone(),
+            old_name: asset.name.clone(),
+            new_name,
+            old_tags: asset.tags.clone(),
+            new_tags,
+        }))
+    }
+}
+
+/// A change to one asset, made or (in a dry run) planned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetChange {
+    /// ID of the asset
+    pub asset_id: String,
+    /// Name before the change
+    pub old_name: String,
+    /// Name after the change, if it is renamed
+    pub new_name: Option<String>,
+    /// Tags before the change
+    pub old_tags: Vec<String>,
+    /// Tags after the change, if they change
+    pub new_tags: Option<Vec<String>>,
+}
+
+impl AssetChange {
+    /// The `PATCH` request that makes this change
+    pub fn request(&self) -> UpdateAssetRequest {
+        UpdateAssetRequest {
+            name: self.new_name.clone(),
+            tags: self.new_tags.clone(),
+        }
+    }
+}
+
+/// An asset that could not be checked or updated
+#[derive(Debug)]
+pub struct FailedUpdate {
+    /// ID of the asset
+    pub asset_id: String,
+    /// Why fetching, planning or writing failed
+    pub error: Error,
+}
+
+/// Outcome of a bulk update; every list keeps the order the IDs were given in
+#[derive(Debug, Default)]
+pub struct BulkUpdateReport {
+    /// Whether this was a dry run, in which case `changed` was not written
+    pub dry_run: bool,
+    /// Changes made, or that would be made in a dry run
+    pub changed: Vec<AssetChange>,
+    /// IDs of matching assets the plan left as they were
+    pub unchanged: Vec<String>,
+    /// IDs of assets the filter excluded
+    pub skipped: Vec<String>,
+    /// Assets that could not be updated
+    pub failed: Vec<FailedUpdate>,
+}
+
+impl BulkUpdateReport {
+    /// Whether no asset failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// IDs of the failed assets, for retrying with [`AssetFilter::ids`]
+    pub fn failed_ids(&self) -> Vec<String> {
+        self.failed
+            .iter()
+            .map(|failed| failed.asset_id.clone())
+            .collect()
+    }
+}
+
+/// What happened to one asset during a bulk update
+pub(crate) enum UpdateOutcome {
+    Changed(AssetChange),
+    Unchanged,
+    Skipped,
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn asset(name: &str, tags: &[&str]) -> Asset {
+        serde_json::from_value(serde_json::json!({
+            "id": "A1",
+            "type": "image",
+            "name": name,
+            "tags": tags,
+            "created_at": 1,
+            "updated_at": 1,
+        }))
+        .expect("valid asset")
+    }
+
+    fn tag(tag: &str) -> Tag {
+        tag.parse().expect("valid tag")
+    }
+
+    #[test]
+    fn test_plan_renames_with_capture_groups_and_retags() {
+        let plan = UpdatePlan::new()
+            .rename(Regex::new(r"^IMG_(\d+)").expect("valid regex"), "Launch $1")
+            .add_tag(tag("reviewed"))
+            .remove_tag(tag("raw"));
+
+        let change = plan
+            .change_for(&asset("IMG_0042", &["raw", "q3"]))
+            .expect("valid change")
+            .expect("something changes");
+
+        assert_eq!(change.new_name.as_deref(), Some("Launch 0042"));
+        assert_eq!(
+            change.new_tags,
+            Some(vec!["q3".to_string(), "reviewed".to_string()])
+        );
+        assert_eq!(change.old_tags, vec!["raw", "q3"]);
+    }
+
+    #[test]
+    fn test_filter_requires_every_tag_and_the_type() {
+        let filter = AssetFilter::ids(["A1"])
+            .tagged(tag("q3"))
+            .tagged(tag("raw"))
+            .of_type(AssetType::Image);
+
+        assert!(filter.matches(&asset("photo", &["raw", "q3", "extra"])));
+        assert!(!filter.matches(&asset("photo", &["q3"])));
+        assert!(!AssetFilter::ids(["A1"])
+            .of_type(AssetType::Video)
+            .matches(&asset("photo", &[])));
+    }
+
+    #[test]
+    fn test_plan_rejects_blank_names() {
+        let plan = UpdatePlan::new().rename(Regex::new(".*").expect("valid regex"), "");
+
+        let result = plan.change_for(&asset("photo", &[]));
+
+        assert!(matches!(result, Err(Error::InvalidAsset { .. })));
+    }
+}
@@ -0,0 +1,246 @@
+//! Bulk upload of a local directory of assets.
+//!
+//! [`AssetsApi::upload_directory`](super::AssetsApi::upload_directory) walks a
+//! directory, uploads every image and video in it with a bounded number of
+//! uploads in flight, and waits for each upload job. One failing file does not
+//! stop the others; the returned [`DirectoryUploadSummary`] lists what was
+//! uploaded, what failed and what was skipped.
+
+use crate::{error::Error, models::Asset, uploads::AssetFormat};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncReadExt;
+
+/// Default number of files uploaded at once
+pub const DEFAULT_UPLOAD_CONCURRENCY: usize = 4;
+
+/// Settings for a directory upload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryUploadOptions {
+    concurrency: usize,
+    tags: Vec<String>,
+    recursive: bool,
+    skip_unsupported: bool,
+}
+
+impl DirectoryUploadOptions {
+    /// Upload the top level of the directory, [`DEFAULT_UPLOAD_CONCURRENCY`]
+    /// files at a time, skipping files that are not images or videos
+    pub fn new() -> Self {
+        Self {
+            concurrency: DEFAULT_UPLOAD_CONCURRENCY,
+            tags: Vec::new(),
+            recursive: false,
+            skip_unsupported: true,
+        }
+    }
+
+    /// Upload at most `concurrency` files at once; values below 1 are treated as 1
+    ///
+    /// Asset uploads are rate limited to 30 requests per minute per user, so
+    /// higher values mostly help with large files.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Tag every uploaded asset with `tags`
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Also upload files in subdirectories
+    ///
+    /// Hidden entries (names starting with `.`) and symbolic links to
+    /// directories are never followed.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Whether files that are not in a format Canva accepts are skipped
+    /// (the default) or reported as failures
+    pub fn with_skip_unsupported(mut self, skip: bool) -> Self {
+        self.skip_unsupported = skip;
+        self
+    }
+
+    /// Maximum number of files uploaded at once
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Tags given to every uploaded asset
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Whether subdirectories are uploaded too
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Whether unsupported files are skipped rather than reported as failures
+    pub fn skip_unsupported(&self) -> bool {
+        self.skip_unsupported
+    }
+}
+
+impl Default for DirectoryUploadOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What happened to one file of a directory upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileUploadStatus {
+    /// The asset was created
+    Uploaded,
+    /// The upload failed
+    Failed,
+    /// The file is not an image or video and was not uploaded
+    Skipped,
+}
+
+/// Progress of a directory upload, reported after each file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectoryUploadProgress<'a> {
+    /// The file that just finished
+    pub path: &'a Path,
+    /// What happened to it
+    pub status: FileUploadStatus,
+    /// Number of files finished so far, including this one
+    pub completed: usize,
+    /// Total number of files found
+    pub total: usize,
+}
+
+/// A file that became an asset
+#[derive(Debug, Clone)]
+pub struct UploadedFile {
+    /// Local path of the file
+    pub path: PathBuf,
+    /// The created asset
+    pub asset: Asset,
+}
+
+/// A file that could not be uploaded
+#[derive(Debug)]
+pub struct FailedUpload {
+    /// Local path of the file
+    pub path: PathBuf,
+    /// Why the upload failed
+    pub error: Error,
+}
+
+/// Outcome of a directory upload; each list is sorted by path
+#[derive(Debug, Default)]
+pub struct DirectoryUploadSummary {
+    /// Files uploaded as assets
+    pub uploaded: Vec<UploadedFile>,
+    /// Files that failed to upload
+    pub failed: Vec<FailedUpload>,
+    /// Files skipped because they are not images or videos
+    pub skipped: Vec<PathBuf>,
+}
+
+impl DirectoryUploadSummary {
+    /// Whether no file failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Number of files found in the directory
+    pub fn total(&self) -> usize {
+        self.uploaded.len() + self.failed.len() + self.skipped.len()
+    }
+
+    pub(super) fn record(&mut self, path: PathBuf, outcome: FileOutcome) -> FileUploadStatus {
+        match outcome {
+            FileOutcome::Uploaded(asset) => {
+                self.uploaded.push(UploadedFile {
+                    path,
+                    asset: *asset,
+                });
+                FileUploadStatus::Uploaded
+            }
+            FileOutcome::Failed(error) => {
+                self.failed.push(FailedUpload { path, error });
+                FileUploadStatus::Failed
+            }
+            FileOutcome::Skipped => {
+                self.skipped.push(path);
+                FileUploadStatus::Skipped
+            }
+        }
+    }
+
+    pub(super) fn sort(&mut self) {
+        self.uploaded.sort_by(|a, b| a.path.cmp(&b.path));
+        self.failed.sort_by(|a, b| a.path.cmp(&b.path));
+        self.skipped.sort();
+    }
+}
+
+/// Result of handling a single file
+pub(super) enum FileOutcome {
+    Uploaded(Box<Asset>),
+    Failed(Error),
+    Skipped,
+}
+
+/// Bytes read to tell a file's format, enough for [`AssetFormat::detect`]
+const SNIFF_LEN: u64 = 64;
+
+/// Read the file at `path`, or return `None` if `skip_unsupported` is set
+/// and its first bytes show it is not an image or video
+///
+/// Skipped files are never read past their first [`SNIFF_LEN`] bytes.
+pub(super) async fn read_supported(
+    path: &Path,
+    skip_unsupported: bool,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut bytes = Vec::new();
+    (&mut file).take(SNIFF_LEN).read_to_end(&mut bytes).await?;
+    if skip_unsupported && AssetFormat::detect(&bytes).is_none() {
+        return Ok(None);
+    }
+    file.read_to_end(&mut bytes).await?;
+    Ok(Some(bytes))
+}
+
+/// List the files to upload under `dir`, sorted by path
+///
+/// A symbolic link whose target cannot be read, such as a dangling link, is
+/// listed so that opening it is reported as a failure for that file.
+pub(super) async fn collect_files(dir: &Path, recursive: bool) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push(entry.path());
+                }
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            } else if file_type.is_symlink() {
+                let is_dir = tokio::fs::metadata(entry.path())
+                    .await
+                    .is_ok_and(|metadata| metadata.is_dir());
+                if !is_dir {
+                    files.push(entry.path());
+                }
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
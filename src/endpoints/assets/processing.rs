@@ -0,0 +1,252 @@
+//! Shrinking images before they are uploaded.
+//!
+//! Canva scales large images down after the upload, so sending a 50
+//! megapixel camera file spends most of its bandwidth on pixels that are
+//! thrown away. [`process_image`] resizes and re-encodes an image locally, and
+//! [`AssetsApi::upload_image_with_processing`] uploads the result.
+//!
+//! Photos are turned upright from their EXIF orientation before resizing,
+//! since re-encoding drops the EXIF data that told viewers how to rotate
+//! them. Images already within the limits and in the requested format are
+//! uploaded as they are, without being re-encoded.
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::endpoints::assets::{ImageOutputFormat, ProcessingOptions};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token"))?;
+//! let options = ProcessingOptions {
+//!     max_dimension: Some(4000),
+//!     format: Some(ImageOutputFormat::Jpeg),
+//!     quality: 85,
+//! };
+//!
+//! let asset = client
+//!     .assets()
+//!     .upload_image_with_processing("photos/DSC_0042.jpg", &options)
+//!     .await?;
+//! println!("Uploaded {}", asset.id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`AssetsApi::upload_image_with_processing`]: super::AssetsApi::upload_image_with_processing
+
+use crate::error::{Error, Result};
+use image::{codecs::jpeg::JpegEncoder, codecs::png::PngEncoder, DynamicImage, ImageDecoder};
+use std::io::Cursor;
+
+/// JPEG quality used by [`ProcessingOptions::default`]
+pub const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+/// Format to re-encode an image in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageOutputFormat {
+    /// JPEG at [`ProcessingOptions::quality`]; transparency is flattened onto black
+    Jpeg,
+    /// Lossless PNG
+    Png,
+}
+
+impl ImageOutputFormat {
+    /// File extension for the format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageOutputFormat::Jpeg => "jpg",
+            ImageOutputFormat::Png => "png",
+        }
+    }
+}
+
+/// How [`process_image`] changes an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessingOptions {
+    /// Longest side, in pixels, to scale the image down to
+    ///
+    /// The aspect ratio is kept and smaller images are not enlarged. `None`
+    /// keeps the original size.
+    pub max_dimension: Option<u32>,
+    /// Format to re-encode in
+    ///
+    /// `None` keeps JPEG images as JPEG and encodes everything else as PNG.
+    pub format: Option<ImageOutputFormat>,
+    /// JPEG quality from 1 to 100
+    pub quality: u8,
+}
+
+impl Default for ProcessingOptions {
+    /// Keep the size and format, with [`DEFAULT_JPEG_QUALITY`] if re-encoded
+    fn default() -> Self {
+        Self {
+            max_dimension: None,
+            format: None,
+            quality: DEFAULT_JPEG_QUALITY,
+        }
+    }
+}
+
+/// An image after [`process_image`]
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    /// Encoded image
+    pub bytes: Vec<u8>,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Format of `bytes`, or `None` if the original file was kept
+    pub format: Option<ImageOutputFormat>,
+}
+
+impl ProcessedImage {
+    /// Whether the original bytes were kept unchanged
+    pub fn is_unchanged(&self) -> bool {
+        self.format.is_none()
+    }
+}
+
+/// Resize and re-encode an image as set in `options`
+///
+/// Reads JPEG, PNG, GIF, TIFF and WebP. Fails with [`Error::Generic`] for
+/// other formats, damaged files or a quality outside 1 to 100. This does
+/// CPU-heavy work; in async code call it through
+/// [`tokio::task::spawn_blocking`], as
+/// [`upload_image_with_processing`](super::AssetsApi::upload_image_with_processing) does.
+pub fn process_image(bytes: &[u8], options: &ProcessingOptions) -> Result<ProcessedImage> {
+    if !(1..=100).contains(&options.quality) {
+        return Err(invalid(format!(
+            "JPEG quality must be between 1 and 100, not {}",
+            options.quality
+        )));
+    }
+    let reader = image::ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| invalid(e.to_string()))?;
+    let source_format = reader.format();
+    let mut decoder = reader.into_decoder().map_err(|e| invalid(e.to_string()))?;
+    let orientation = decoder.orientation().map_err(|e| invalid(e.to_string()))?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| invalid(e.to_string()))?;
+    image.apply_orientation(orientation);
+
+    let too_large = options
+        .max_dimension
+        .is_some_and(|max| image.width().max(image.height()) > max);
+    let format = options.format.unwrap_or(match source_format {
+        Some(image::ImageFormat::Jpeg) => ImageOutputFormat::Jpeg,
+        _ => ImageOutputFormat::Png,
+    });
+    let same_format = matches!(
+        (format, source_format),
+        (ImageOutputFormat::Jpeg, Some(image::ImageFormat::Jpeg))
+            | (ImageOutputFormat::Png, Some(image::ImageFormat::Png))
+    );
+    let upright = matches!(orientation, image::metadata::Orientation::NoTransforms);
+    if !too_large && same_format && upright {
+        return Ok(ProcessedImage {
+            bytes: bytes.to_vec(),
+            width: image.width(),
+            height: image.height(),
+            format: None,
+        });
+    }
+
+    if let Some(max) = options.max_dimension.filter(|_| too_large) {
+        image = image.resize(max, max, image::imageops::FilterType::Lanczos3);
+    }
+    let mut encoded = Vec::new();
+    let written = match format {
+        ImageOutputFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut encoded, options.quality)),
+        ImageOutputFormat::Png => image.write_with_encoder(PngEncoder::new(&mut encoded)),
+    };
+    written.map_err(|e| invalid(e.to_string()))?;
+    Ok(ProcessedImage {
+        bytes: encoded,
+        width: image.width(),
+        height: image.height(),
+        format: Some(format),
+    })
+}
+
+/// `name` with its extension replaced by the one for `format`
+pub(super) fn renamed(name: &str, format: ImageOutputFormat) -> String {
+    let stem = std::path::Path::new(name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| name.to_string());
+    format!("{stem}.{}", format.extension())
+}
+
+fn invalid(reason: String) -> Error {
+    Error::Generic(format!("Cannot process image: {reason}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgba8(image::RgbaImage::from_pixel(
+            width,
+            height,
+            image::Rgba([200, 40, 40, 128]),
+        ));
+        let mut bytes = Vec::new();
+        image
+            .write_with_encoder(PngEncoder::new(&mut bytes))
+            .expect("encode PNG");
+        bytes
+    }
+
+    #[test]
+    fn test_large_images_are_scaled_down_keeping_aspect_ratio() {
+        let options = ProcessingOptions {
+            max_dimension: Some(50),
+            format: Some(ImageOutputFormat::Jpeg),
+            quality: 80,
+        };
+
+        let processed = process_image(&png(200, 100), &options).expect("processes");
+
+        assert_eq!((processed.width, processed.height), (50, 25));
+        assert_eq!(processed.format, Some(ImageOutputFormat::Jpeg));
+        assert!(processed.bytes.starts_with(&[0xFF, 0xD8, 0xFF]));
+    }
+
+    #[test]
+    fn test_images_within_limits_are_kept_as_is() {
+        let original = png(40, 30);
+        let options = ProcessingOptions {
+            max_dimension: Some(100),
+            ..ProcessingOptions::default()
+        };
+
+        let processed = process_image(&original, &options).expect("processes");
+
+        assert!(processed.is_unchanged());
+        assert_eq!(processed.bytes, original);
+        assert_eq!((processed.width, processed.height), (40, 30));
+    }
+
+    #[test]
+    fn test_bad_input_and_quality_are_rejected() {
+        let quality = ProcessingOptions {
+            quality: 0,
+            ..ProcessingOptions::default()
+        };
+        assert!(process_image(&png(1, 1), &quality).is_err());
+        assert!(process_image(b"not an image", &ProcessingOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_renamed_swaps_extension() {
+        assert_eq!(
+            renamed("holiday.heic.png", ImageOutputFormat::Jpeg),
+            "holiday.heic.jpg"
+        );
+        assert_eq!(renamed("scan", ImageOutputFormat::Png), "scan.png");
+    }
+}
@@ -0,0 +1,289 @@
+//! Crash-safe uploads of large local files.
+//!
+//! Canva's asset upload endpoint takes the whole file in a single request;
+//! there is no endpoint for sending it in parts, so an interrupted transfer
+//! always starts again from the first byte. What can be saved is everything
+//! around the transfer. [`AssetsApi::upload_resumable`](super::AssetsApi::upload_resumable)
+//! records each upload in an [`UploadStateStore`] before sending it and again
+//! once Canva has accepted it. After a crash,
+//! [`resume_upload_job`](super::AssetsApi::resume_upload_job) picks up from
+//! the saved [`ResumableUpload`]:
+//!
+//! - if Canva already accepted the file, it only waits for the existing job,
+//!   so a large video is not sent twice;
+//! - otherwise it sends the file again, after checking it has not changed.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::endpoints::assets::{FileUploadStateStore, UploadStateStore};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token"))?;
+//! let store = FileUploadStateStore::new(".canva-uploads");
+//!
+//! // Finish uploads interrupted by a previous run
+//! for upload in store.list().await? {
+//!     client.assets().resume_upload_job(upload, &store).await?;
+//! }
+//!
+//! let asset = client
+//!     .assets()
+//!     .upload_resumable("intro.mp4", vec!["video".to_string()], &store)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::downloads::hex;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Saved state of an upload that has not finished yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResumableUpload {
+    /// Key of this upload in the store, derived from the file name and contents
+    pub id: String,
+    /// Local file being uploaded
+    pub path: PathBuf,
+    /// Asset name
+    pub name: String,
+    /// Asset tags
+    pub tags: Vec<String>,
+    /// File size in bytes
+    pub size: u64,
+    /// Hex SHA-256 of the file contents
+    pub sha256: String,
+    /// Upload job ID, once Canva has accepted the file
+    pub job_id: Option<String>,
+}
+
+impl ResumableUpload {
+    /// Describe an upload of `bytes`, read from `path`
+    pub(super) fn new(path: &Path, name: &str, tags: Vec<String>, bytes: &[u8]) -> Self {
+        let sha256 = hex(&Sha256::digest(bytes));
+        let id = hex(&Sha256::new()
+            .chain_update(name.as_bytes())
+            .chain_update([0])
+            .chain_update(sha256.as_bytes())
+            .finalize()[..16]);
+        Self {
+            id,
+            path: path.to_path_buf(),
+            name: name.to_string(),
+            tags,
+            size: bytes.len() as u64,
+            sha256,
+            job_id: None,
+        }
+    }
+
+    /// Read the file again and check it is the one the upload started with
+    pub(super) async fn read_file(&self) -> Result<Vec<u8>> {
+        let bytes = tokio::fs::read(&self.path).await?;
+        if bytes.len() as u64 != self.size || hex(&Sha256::digest(&bytes)) != self.sha256 {
+            return Err(Error::InvalidAsset {
+                name: self.name.clone(),
+                reason: format!("{} changed since the upload started", self.path.display()),
+            });
+        }
+        Ok(bytes)
+    }
+}
+
+/// A place to keep the state of unfinished uploads
+///
+/// The state must outlive the process for uploads to survive a crash, as
+/// with [`FileUploadStateStore`].
+#[async_trait::async_trait]
+pub trait UploadStateStore: fmt::Debug + Send + Sync {
+    /// Save an upload, replacing any previous state with the same ID
+    async fn save(&self, upload: &ResumableUpload) -> Result<()>;
+
+    /// Load the upload with the given ID
+    async fn load(&self, id: &str) -> Result<Option<ResumableUpload>>;
+
+    /// Forget a finished upload
+    async fn remove(&self, id: &str) -> Result<()>;
+
+    /// All unfinished uploads
+    async fn list(&self) -> Result<Vec<ResumableUpload>>;
+}
+
+/// [`UploadStateStore`] that keeps state in memory, for tests and short-lived tools
+#[derive(Debug, Default)]
+pub struct MemoryUploadStateStore {
+    uploads: Mutex<HashMap<String, ResumableUpload>>,
+}
+
+impl MemoryUploadStateStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn uploads(&self) -> std::sync::MutexGuard<'_, HashMap<String, ResumableUpload>> {
+        self.uploads
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait::async_trait]
+impl UploadStateStore for MemoryUploadStateStore {
+    async fn save(&self, upload: &ResumableUpload) -> Result<()> {
+        self.uploads().insert(upload.id.clone(), upload.clone());
+        Ok(())
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<ResumableUpload>> {
+        Ok(self.uploads().get(id).cloned())
+    }
+
+    async fn remove(&self, id: &str) -> Result<()> {
+        self.uploads().remove(id);
+        Ok(())
+    }
+
+    async fn list(&self) -> Result<Vec<ResumableUpload>> {
+        let mut uploads: Vec<_> = self.uploads().values().cloned().collect();
+        uploads.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(uploads)
+    }
+}
+
+/// [`UploadStateStore`] that keeps one JSON file per upload in a directory
+#[derive(Debug, Clone)]
+pub struct FileUploadStateStore {
+    dir: PathBuf,
+}
+
+impl FileUploadStateStore {
+    /// Keep state in `dir`, which is created when the first upload is saved
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Directory holding the state files
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn file(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+}
+
+#[async_trait::async_trait]
+impl UploadStateStore for FileUploadStateStore {
+    async fn save(&self, upload: &ResumableUpload) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        // Write then rename, so a crash never leaves a half-written file
+        let file = self.file(&upload.id);
+        let partial = file.with_extension("json.tmp");
+        tokio::fs::write(&partial, serde_json::to_vec_pretty(upload)?).await?;
+        tokio::fs::rename(&partial, &file).await?;
+        Ok(())
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<ResumableUpload>> {
+        match tokio::fs::read(self.file(id)).await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn remove(&self, id: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.file(id)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<ResumableUpload>> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut uploads = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                uploads.push(serde_json::from_slice(&tokio::fs::read(&path).await?)?);
+            }
+        }
+        uploads.sort_by(|a: &ResumableUpload, b| a.path.cmp(&b.path));
+        Ok(uploads)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_depends_on_name_and_contents() {
+        let path = Path::new("a.mp4");
+        let upload = ResumableUpload::new(path, "a.mp4", vec![], b"data");
+        assert_eq!(upload.id.len(), 32);
+        assert_eq!(
+            upload.id,
+            ResumableUpload::new(Path::new("other/a.mp4"), "a.mp4", vec![], b"data").id
+        );
+        assert_ne!(
+            upload.id,
+            ResumableUpload::new(path, "b.mp4", vec![], b"data").id
+        );
+        assert_ne!(
+            upload.id,
+            ResumableUpload::new(path, "a.mp4", vec![], b"changed").id
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!("canva-upload-state-{}", uuid::Uuid::new_v4()));
+        let store = FileUploadStateStore::new(&dir);
+        assert!(store.list().await.expect("list").is_empty());
+
+        let mut upload = ResumableUpload::new(Path::new("a.mp4"), "a.mp4", vec![], b"data");
+        store.save(&upload).await.expect("save");
+        upload.job_id = Some("job_1".to_string());
+        store.save(&upload).await.expect("save again");
+
+        assert_eq!(
+            store.load(&upload.id).await.expect("load"),
+            Some(upload.clone())
+        );
+        assert_eq!(store.list().await.expect("list"), vec![upload.clone()]);
+        store.remove(&upload.id).await.expect("remove");
+        store.remove(&upload.id).await.expect("remove twice");
+        assert_eq!(store.load(&upload.id).await.expect("load"), None);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_changed_file_is_rejected() {
+        let path = std::env::temp_dir().join(format!("canva-resume-{}.bin", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"original").expect("write");
+        let upload = ResumableUpload::new(&path, "clip.mp4", vec![], b"original");
+        assert_eq!(upload.read_file().await.expect("read"), b"original");
+
+        std::fs::write(&path, b"replaced").expect("write");
+        let result = upload.read_file().await;
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(Error::InvalidAsset { .. })));
+    }
+}
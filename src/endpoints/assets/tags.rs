@@ -0,0 +1,155 @@
+//! Adding and removing asset tags without replacing the whole list.
+//!
+//! `PATCH /v1/assets/{assetId}` only accepts a complete replacement tag list,
+//! so two programs tagging the same asset can each overwrite the other's
+//! tags. [`AssetsApi::add_tags`](super::AssetsApi::add_tags) and
+//! [`remove_tags`](super::AssetsApi::remove_tags) read the current tags,
+//! change only the ones they were given and write the result back.
+//!
+//! The API has no conditional update, so the race cannot be closed
+//! entirely. Instead each write is checked by reading the asset again: if a
+//! concurrent write undid the edit, it is applied to the new tags and
+//! written again, up to the given number of retries.
+//!
+//! [`Tag`] checks a tag against Canva's limits before anything is sent.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::MAX_ASSET_TAG_CHARS;
+
+/// Times `add_tags` and `remove_tags` rewrite the tags after a concurrent write undid them
+pub const DEFAULT_TAG_RETRIES: u32 = 2;
+
+/// An asset tag Canva will accept
+///
+/// Tags are 1 to [`MAX_ASSET_TAG_CHARS`] characters, are not blank and
+/// contain no control characters such as newlines.
+///
+/// ```rust
+/// use canva_connect::endpoints::assets::Tag;
+///
+/// let tag: Tag = "best day ever".parse().unwrap();
+/// assert_eq!(tag.as_str(), "best day ever");
+/// assert!(Tag::new("line\nbreak").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct Tag(String);
+
+impl Tag {
+    /// Check `tag` against Canva's rules
+    pub fn new(tag: impl Into<String>) -> Result<Self> {
+        let tag = tag.into();
+        let invalid = |reason: &str| {
+            Err(Error::Generic(format!(
+                "Invalid asset tag {tag:?}: {reason}"
+            )))
+        };
+        if tag.trim().is_empty() {
+            return invalid("tag is blank");
+        }
+        if tag.chars().count() > MAX_ASSET_TAG_CHARS {
+            return invalid("tag is over the character limit");
+        }
+        if tag.chars().any(char::is_control) {
+            return invalid("tag contains control characters");
+        }
+        Ok(Self(tag))
+    }
+
+    /// The tag text
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The tag text, taking ownership
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Tag {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Tag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for Tag {
+    type Error = Error;
+
+    fn try_from(tag: String) -> Result<Self> {
+        Self::new(tag)
+    }
+}
+
+impl TryFrom<&str> for Tag {
+    type Error = Error;
+
+    fn try_from(tag: &str) -> Result<Self> {
+        Self::new(tag)
+    }
+}
+
+impl From<Tag> for String {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+/// Tags to add to and remove from an asset
+///
+/// Used with
+/// [`AssetsApi::change_tags`](super::AssetsApi::change_tags). Removals are
+/// applied first, so a tag in both lists ends up on the asset.
+#[derive(Debug, Clone, Default)]
+pub struct TagChanges {
+    /// Tags to add, unless the asset already has them
+    pub add: Vec<Tag>,
+    /// Tags to remove, if the asset has them
+    pub remove: Vec<Tag>,
+}
+
+impl TagChanges {
+    /// `tags` with the changes applied; remaining tags keep their order
+    pub fn apply(&self, tags: &[String]) -> Vec<String> {
+        let mut tags: Vec<String> = tags
+            .iter()
+            .filter(|existing| {
+                !self
+                    .remove
+                    .iter()
+                    .any(|tag| tag.as_str() == existing.as_str())
+            })
+            .cloned()
+            .collect();
+        for tag in &self.add {
+            if !tags.iter().any(|existing| existing == tag.as_str()) {
+                tags.push(tag.as_str().to_string());
+            }
+        }
+        tags
+    }
+
+    /// Whether there is nothing to change
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.remove.is_empty()
+    }
+}
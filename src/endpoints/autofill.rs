@@ -12,6 +12,7 @@
 //! | [`create_autofill_job`](AutofillApi::create_autofill_job) | `POST` | `/v1/autofills` | `design:content:write` | Create a design autofill job |
 //! | [`get_autofill_job`](AutofillApi::get_autofill_job) | `GET` | `/v1/autofills/{jobId}` | `design:meta:read` | Get autofill job status |
 //! | [`wait_for_autofill_job`](AutofillApi::wait_for_autofill_job) | N/A | Multiple calls | `design:meta:read` | Wait for autofill job completion |
+//! | [`create_autofill_jobs_batch`](AutofillApi::create_autofill_jobs_batch) | N/A | Multiple calls | `design:content:write`, `design:meta:read` | Autofill many rows of data and wait for all jobs |
 //!
 //! ## OAuth Scopes
 //!
@@ -28,9 +29,45 @@
 //! the status and retrieve results. Use the `wait_for_autofill_job` method to poll
 //! until completion.
 
-use crate::{client::Client, error::Result, models::*};
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    jobs::{PollableJob, WaitOptions},
+    models::*,
+    routes,
+};
+use futures::StreamExt;
+use std::collections::HashMap;
 use std::time::Duration;
 
+/// An autofill job that can be polled until it finishes
+///
+/// Returned by [`AutofillApi::pending_autofill_job`]. Its output is the
+/// finished job, including a job that failed: check its
+/// [`status`](DesignAutofillJob::status).
+#[derive(Debug, Clone)]
+pub struct PendingAutofillJob {
+    api: AutofillApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingAutofillJob {
+    type Output = DesignAutofillJob;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<DesignAutofillJob>> {
+        let job = self.api.get_autofill_job(&self.job_id).await?;
+        match job.status {
+            DesignAutofillStatus::Success | DesignAutofillStatus::Failed => Ok(Some(job)),
+            DesignAutofillStatus::InProgress | DesignAutofillStatus::Unknown => Ok(None),
+        }
+    }
+}
+
 /// Autofill API client
 #[derive(Debug, Clone)]
 pub struct AutofillApi {
@@ -59,6 +96,7 @@ impl AutofillApi {
     ///
     /// # Errors
     ///
+    /// * `Error::Generic` - A chart field's [`DataTable`] is empty or has rows of different lengths
     /// * `Error::BadRequest` - Invalid brand template ID or data
     /// * `Error::Forbidden` - User doesn't have access to the brand template or isn't in an Enterprise organization
     /// * `Error::NotFound` - Brand template not found
@@ -77,12 +115,17 @@ impl AutofillApi {
     /// let autofill = client.autofill();
     ///
     /// let mut data = HashMap::new();
-    /// data.insert("text_field".to_string(), DatasetValue::Text {
-    ///     text: "Hello, World!".to_string(),
-    /// });
-    /// data.insert("image_field".to_string(), DatasetValue::Image {
-    ///     asset_id: "asset_123".to_string(),
-    /// });
+    /// data.insert("text_field".to_string(), DatasetValue::text("Hello, World!"));
+    /// data.insert("image_field".to_string(), DatasetValue::image("asset_123"));
+    /// data.insert(
+    ///     "chart_field".to_string(),
+    ///     DatasetValue::chart(
+    ///         DataTable::builder()
+    ///             .header(["Month", "Sales"])
+    ///             .row(("Jan", 1500.75))
+    ///             .build()?,
+    ///     ),
+    /// );
     ///
     /// let job = autofill.create_autofill_job(
     ///     "template_123",
@@ -97,18 +140,23 @@ impl AutofillApi {
     pub async fn create_autofill_job(
         &self,
         brand_template_id: &str,
-        data: std::collections::HashMap<String, DatasetValue>,
+        data: HashMap<String, DatasetValue>,
         title: Option<String>,
     ) -> Result<DesignAutofillJob> {
+        for value in data.values() {
+            if let DatasetValue::Chart { chart_data } = value {
+                chart_data.validate()?;
+            }
+        }
         let request = CreateDesignAutofillJobRequest {
             brand_template_id: brand_template_id.to_string(),
             title,
             data,
         };
 
-        let response = self.client.post("/v1/autofills", &request).await?;
+        let response = self.client.post(routes::AUTOFILLS, &request).await?;
 
-        let response: CreateDesignAutofillJobResponse = response.json().await?;
+        let response: CreateDesignAutofillJobResponse = self.client.read_json(response).await?;
         Ok(response.job)
     }
 
@@ -155,7 +203,7 @@ impl AutofillApi {
     ///             println!("Autofill failed: {}", error.message);
     ///         }
     ///     }
-    ///     DesignAutofillStatus::InProgress => {
+    ///     DesignAutofillStatus::InProgress | DesignAutofillStatus::Unknown => {
     ///         println!("Autofill still in progress...");
     ///     }
     /// }
@@ -163,9 +211,9 @@ impl AutofillApi {
     /// # }
     /// ```
     pub async fn get_autofill_job(&self, job_id: &str) -> Result<DesignAutofillJob> {
-        let response = self.client.get(&format!("/v1/autofills/{job_id}")).await?;
+        let response = self.client.get(&routes::autofill_job(job_id)).await?;
 
-        let response: GetDesignAutofillJobResponse = response.json().await?;
+        let response: GetDesignAutofillJobResponse = self.client.read_json(response).await?;
 
         Ok(response.job)
     }
@@ -175,10 +223,13 @@ impl AutofillApi {
     /// Polls the autofill job status until it completes (success or failure).
     /// This is a convenience method that handles the polling logic.
     ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
     /// # Arguments
     ///
     /// * `job_id` - The design autofill job ID
-    /// * `poll_interval` - How often to check the job status (defaults to 2 seconds)
+    /// * `poll_interval` - How often to check the job status (defaults to the client's
+    ///   [poll interval](Client::poll_interval))
     ///
     /// # Returns
     ///
@@ -208,7 +259,7 @@ impl AutofillApi {
     /// match job.status {
     ///     DesignAutofillStatus::Success => println!("Autofill completed!"),
     ///     DesignAutofillStatus::Failed => println!("Autofill failed"),
-    ///     DesignAutofillStatus::InProgress => println!("Job still in progress"),
+    ///     DesignAutofillStatus::InProgress | DesignAutofillStatus::Unknown => println!("Job still in progress"),
     /// }
     /// # Ok(())
     /// # }
@@ -218,21 +269,145 @@ impl AutofillApi {
         job_id: &str,
         poll_interval: Option<Duration>,
     ) -> Result<DesignAutofillJob> {
-        let interval = poll_interval.unwrap_or(Duration::from_secs(2));
-
-        loop {
-            let job = self.get_autofill_job(job_id).await?;
-
-            match job.status {
-                DesignAutofillStatus::Success | DesignAutofillStatus::Failed => {
-                    return Ok(job);
-                }
-                DesignAutofillStatus::InProgress => {
-                    tokio::time::sleep(interval).await;
-                }
-            }
+        let mut options = WaitOptions::new();
+        if let Some(interval) = poll_interval {
+            options = options.with_poll_interval(interval);
+        }
+        self.client
+            .job_poller()
+            .with_options(&options)
+            .wait(&self.pending_autofill_job(job_id))
+            .await
+    }
+
+    /// Like [`wait_for_autofill_job`](Self::wait_for_autofill_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `design:meta:read`
+    pub async fn wait_for_autofill_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<DesignAutofillJob> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_autofill_job(job_id))
+            .await
+    }
+
+    /// An autofill job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_autofill_job(&self, job_id: &str) -> PendingAutofillJob {
+        PendingAutofillJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
         }
     }
+
+    /// Autofill a brand template once per row of data and wait for every job
+    ///
+    /// At most `concurrency` rows are in flight at once; values below 1 are
+    /// treated as 1. Each row's job is created and then polled until it
+    /// finishes, independently of the other rows, so one failing row does not
+    /// stop the rest.
+    ///
+    /// Returns one result per row, in the same order as `rows`. A row whose
+    /// job could not be created, or finished with a `failed` status, yields
+    /// an error.
+    ///
+    /// Keep `concurrency` modest: job creation is rate limited to 10 requests
+    /// per minute per client-user. Jobs are waited for with
+    /// [`JobPollConfig::batch`](crate::jobs::JobPollConfig::batch) settings
+    /// when the client keeps the default poll config; otherwise with the
+    /// client's job poller, with [`BATCH_POLL_JITTER`](crate::jobs::BATCH_POLL_JITTER)
+    /// added unless the client already sets jitter.
+    ///
+    /// **Required OAuth scopes:** `design:content:write`, `design:meta:read`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, models::*};
+    /// use std::collections::HashMap;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    ///
+    /// let rows: Vec<HashMap<String, DatasetValue>> = ["Ada", "Grace"]
+    ///     .into_iter()
+    ///     .map(|name| {
+    ///         HashMap::from([(
+    ///             "name".to_string(),
+    ///             DatasetValue::Text { text: name.to_string() },
+    ///         )])
+    ///     })
+    ///     .collect();
+    ///
+    /// let results = client
+    ///     .autofill()
+    ///     .create_autofill_jobs_batch("template_123", rows, 4)
+    ///     .await;
+    /// for (row, result) in results.iter().enumerate() {
+    ///     match result {
+    ///         Ok(job) => println!("row {row}: job {} succeeded", job.id),
+    ///         Err(e) => println!("row {row}: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_autofill_jobs_batch(
+        &self,
+        brand_template_id: &str,
+        rows: Vec<HashMap<String, DatasetValue>>,
+        concurrency: usize,
+    ) -> Vec<Result<DesignAutofillJob>> {
+        futures::stream::iter(rows)
+            .map(|data| self.autofill_row(brand_template_id, data, None))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Create an autofill job, wait for it, and turn a `failed` status into an error
+    pub(crate) async fn autofill_row(
+        &self,
+        brand_template_id: &str,
+        data: HashMap<String, DatasetValue>,
+        title: Option<String>,
+    ) -> Result<DesignAutofillJob> {
+        let job = self
+            .create_autofill_job(brand_template_id, data, title)
+            .await?;
+        let job = self
+            .client
+            .batch_job_poller()
+            .wait(&self.pending_autofill_job(&job.id))
+            .await?;
+        ensure_succeeded(job)
+    }
+}
+
+/// Turn a failed autofill job into an error carrying its error code and message
+pub(crate) fn ensure_succeeded(job: DesignAutofillJob) -> Result<DesignAutofillJob> {
+    match job.status {
+        DesignAutofillStatus::Failed => {
+            let error_msg = job
+                .error
+                .map(|e| {
+                    let code = serde_json::to_value(&e.code)
+                        .ok()
+                        .and_then(|code| code.as_str().map(str::to_string))
+                        .unwrap_or_default();
+                    format!("{code}: {}", e.message)
+                })
+                .unwrap_or_else(|| "Job failed with unknown error".to_string());
+            Err(Error::Generic(error_msg))
+        }
+        _ => Ok(job),
+    }
 }
 
 #[cfg(test)]
@@ -240,7 +415,6 @@ impl AutofillApi {
 mod tests {
     use super::*;
     use crate::auth::AccessToken;
-    use std::collections::HashMap;
 
     #[test]
     fn test_autofill_api_creation() {
@@ -737,4 +911,160 @@ mod tests {
         assert!(deserialized.data.contains_key("roundtrip_text"));
         assert!(deserialized.data.contains_key("roundtrip_image"));
     }
+
+    #[test]
+    fn test_data_table_builder_infers_cell_types() {
+        let date = chrono::DateTime::from_timestamp(1_700_000_000, 0).expect("valid timestamp");
+        let table = DataTable::builder()
+            .header(["Month", "Sales", "Target met", "Closed"])
+            .row(("Jan", 1500.75, true, date))
+            .row((
+                "Feb",
+                980,
+                None::<bool>,
+                None::<chrono::DateTime<chrono::Utc>>,
+            ))
+            .build()
+            .expect("rows have equal lengths");
+
+        assert_eq!(
+            serde_json::to_value(&table).expect("serializes"),
+            serde_json::json!({ "rows": [
+                { "cells": [
+                    { "type": "string", "value": "Month" },
+                    { "type": "string", "value": "Sales" },
+                    { "type": "string", "value": "Target met" },
+                    { "type": "string", "value": "Closed" },
+                ]},
+                { "cells": [
+                    { "type": "string", "value": "Jan" },
+                    { "type": "number", "value": 1500.75 },
+                    { "type": "boolean", "value": true },
+                    { "type": "date", "value": 1_700_000_000 },
+                ]},
+                { "cells": [
+                    { "type": "string", "value": "Feb" },
+                    { "type": "number", "value": 980.0 },
+                    { "type": "boolean", "value": null },
+                    { "type": "date", "value": null },
+                ]},
+            ]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ragged_chart_data_is_rejected_before_sending() {
+        let ragged = DataTable::builder()
+            .header(["Month", "Sales"])
+            .row(["Jan"])
+            .build();
+        assert!(
+            matches!(&ragged, Err(Error::Generic(message)) if message == "Invalid data table: row 2 has 1 cells, expected 2")
+        );
+        assert!(DataTable::builder().build().is_err());
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/autofills")
+            .expect(0)
+            .create_async()
+            .await;
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let chart_data = DataTable {
+            rows: vec![vec![1, 2].into(), vec![3].into()],
+        };
+        let data = HashMap::from([("chart".to_string(), DatasetValue::chart(chart_data))]);
+
+        let result = client
+            .autofill()
+            .create_autofill_job("template_1", data, None)
+            .await;
+
+        assert!(matches!(result, Err(Error::Generic(_))));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_autofill_jobs_batch_reports_each_row() {
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mut mocks = Vec::new();
+        for (name, create_status, create_body) in [
+            (
+                "ok",
+                200,
+                r#"{"job":{"id":"job_ok","status":"in_progress"}}"#,
+            ),
+            (
+                "bad",
+                200,
+                r#"{"job":{"id":"job_bad","status":"in_progress"}}"#,
+            ),
+            (
+                "invalid",
+                400,
+                r#"{"code":"invalid_field","message":"Unknown field"}"#,
+            ),
+        ] {
+            mocks.push(
+                server
+                    .mock("POST", "/v1/autofills")
+                    .match_body(Matcher::PartialJson(serde_json::json!({
+                        "brand_template_id": "template_1",
+                        "data": { "name": { "type": "text", "text": name } }
+                    })))
+                    .with_status(create_status)
+                    .with_body(create_body)
+                    .create_async()
+                    .await,
+            );
+        }
+        mocks.push(
+            server
+                .mock("GET", "/v1/autofills/job_ok")
+                .with_body(r#"{"job":{"id":"job_ok","status":"success"}}"#)
+                .create_async()
+                .await,
+        );
+        mocks.push(
+            server
+                .mock("GET", "/v1/autofills/job_bad")
+                .with_body(
+                    r#"{"job":{"id":"job_bad","status":"failed","error":{"code":"autofill_error","message":"Missing image"}}}"#,
+                )
+                .create_async()
+                .await,
+        );
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let rows = ["ok", "bad", "invalid"]
+            .into_iter()
+            .map(|name| {
+                HashMap::from([(
+                    "name".to_string(),
+                    DatasetValue::Text {
+                        text: name.to_string(),
+                    },
+                )])
+            })
+            .collect();
+
+        let results = client
+            .autofill()
+            .create_autofill_jobs_batch("template_1", rows, 2)
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().expect("row 0").id, "job_ok");
+        assert!(
+            matches!(&results[1], Err(Error::Generic(message)) if message == "autofill_error: Missing image")
+        );
+        assert!(matches!(&results[2], Err(Error::Api { .. })));
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
 }
@@ -5,9 +5,12 @@
 
 use crate::{
     client::Client,
-    error::Result,
-    models::{BrandTemplate, DataField},
+    error::{Error, Result},
+    models::{BrandTemplate, DataField, Page, PageRequest},
+    query::QueryBuilder,
+    routes,
 };
+use futures::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -19,7 +22,11 @@ pub struct BrandTemplatesApi {
 
 /// Request body for brand template queries
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ListBrandTemplatesRequest {
+    /// Search term to filter brand templates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
     /// Continuation token for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
     pub continuation: Option<String>,
@@ -28,28 +35,71 @@ pub struct ListBrandTemplatesRequest {
     pub limit: Option<u32>,
 }
 
-/// Response from listing brand templates
-#[derive(Debug, Clone, Deserialize)]
-pub struct ListBrandTemplatesResponse {
-    /// List of brand templates
-    pub items: Vec<BrandTemplate>,
-    /// Continuation token for pagination
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuation: Option<String>,
+impl PageRequest for ListBrandTemplatesRequest {
+    fn set_continuation(&mut self, continuation: Option<String>) {
+        self.continuation = continuation;
+    }
 }
 
+impl ListBrandTemplatesRequest {
+    /// Start building a request with no options set
+    pub fn builder() -> ListBrandTemplatesRequestBuilder {
+        ListBrandTemplatesRequestBuilder::default()
+    }
+}
+
+/// Builder for [`ListBrandTemplatesRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct ListBrandTemplatesRequestBuilder {
+    request: ListBrandTemplatesRequest,
+}
+
+impl ListBrandTemplatesRequestBuilder {
+    /// Only return brand templates matching a search term
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.request.query = Some(query.into());
+        self
+    }
+
+    /// Continue from a previous page
+    pub fn continuation(mut self, continuation: impl Into<String>) -> Self {
+        self.request.continuation = Some(continuation.into());
+        self
+    }
+
+    /// Return at most `limit` brand templates per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Finish the request
+    pub fn build(self) -> ListBrandTemplatesRequest {
+        self.request
+    }
+}
+
+/// Response from listing brand templates
+pub type ListBrandTemplatesResponse = Page<BrandTemplate>;
+
 /// Response from getting a brand template
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetBrandTemplateResponse {
     /// The brand template
     pub brand_template: BrandTemplate,
 }
 
+/// The data fields of a brand template, keyed by field name
+pub type BrandTemplateDataset = HashMap<String, DataField>;
+
 /// Response from getting a brand template dataset
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetBrandTemplateDatasetResponse {
     /// The brand template dataset fields (keyed by field name)
-    pub dataset: HashMap<String, DataField>,
+    #[serde(default)]
+    pub dataset: BrandTemplateDataset,
 }
 
 impl BrandTemplatesApi {
@@ -68,28 +118,17 @@ impl BrandTemplatesApi {
         &self,
         request: &ListBrandTemplatesRequest,
     ) -> Result<ListBrandTemplatesResponse> {
-        let mut query_params = Vec::new();
-
-        if let Some(continuation) = &request.continuation {
-            query_params.push(format!(
-                "continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-
-        if let Some(limit) = request.limit {
-            query_params.push(format!("limit={limit}"));
-        }
-
-        let url = if query_params.is_empty() {
-            "/v1/brand-templates".to_string()
-        } else {
-            format!("/v1/brand-templates?{}", query_params.join("&"))
-        };
+        let url = QueryBuilder::new()
+            .param_opt("query", request.query.as_deref())
+            .param_opt("continuation", request.continuation.as_deref())
+            .param_opt("limit", request.limit)
+            .append_to(routes::BRAND_TEMPLATES);
 
         let response = self.client.get(&url).await?;
 
-        Ok(response.json::<ListBrandTemplatesResponse>().await?)
+        self.client
+            .read_json::<ListBrandTemplatesResponse>(response)
+            .await
     }
 
     /// Get a specific brand template by ID
@@ -99,9 +138,11 @@ impl BrandTemplatesApi {
     /// **Required OAuth scope:** `brandtemplate:meta:read`
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn get(&self, brand_template_id: &str) -> Result<GetBrandTemplateResponse> {
-        let url = format!("/v1/brand-templates/{brand_template_id}");
+        let url = routes::brand_template(brand_template_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetBrandTemplateResponse>().await?)
+        self.client
+            .read_json::<GetBrandTemplateResponse>(response)
+            .await
     }
 
     /// Get a brand template's dataset
@@ -115,9 +156,66 @@ impl BrandTemplatesApi {
         &self,
         brand_template_id: &str,
     ) -> Result<GetBrandTemplateDatasetResponse> {
-        let url = format!("/v1/brand-templates/{brand_template_id}/dataset");
+        let url = routes::brand_template_dataset(brand_template_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetBrandTemplateDatasetResponse>().await?)
+        self.client
+            .read_json::<GetBrandTemplateDatasetResponse>(response)
+            .await
+    }
+
+    /// Stream every brand template together with its dataset
+    ///
+    /// Pages through [`list`](Self::list) and fetches up to `concurrency`
+    /// datasets at a time, yielding templates in listing order. Every
+    /// request waits on the client's rate limiters, so a high `concurrency`
+    /// queues rather than exceeding Canva's limits. Each template comes
+    /// with the result of fetching its dataset, so a template deleted after
+    /// it was listed reports its own error; a failed page ends the stream.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use futures::TryStreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let templates = client.brand_templates();
+    /// let mut all = std::pin::pin!(templates.list_with_datasets(4));
+    /// while let Some((template, dataset)) = all.try_next().await? {
+    ///     match dataset {
+    ///         Ok(dataset) => println!("{}: {} fields", template.title, dataset.len()),
+    ///         Err(e) => println!("{}: {e}", template.id),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required OAuth scopes:** `brandtemplate:meta:read` and `brandtemplate:content:read`
+    pub fn list_with_datasets(
+        &self,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<(BrandTemplate, Result<BrandTemplateDataset>)>> + '_ {
+        let first = ListBrandTemplatesRequest::default();
+        let pages = futures::stream::try_unfold(Some(first), move |request| async move {
+            let Some(current) = request else {
+                return Ok(None);
+            };
+            let page = self.list(&current).await?;
+            let next = page.next_request(&current);
+            Ok::<_, Error>(Some((page.items, next)))
+        });
+        pages
+            .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+            .map_ok(move |template| async move {
+                let dataset = self
+                    .get_dataset(&template.id)
+                    .await
+                    .map(|response| response.dataset);
+                Ok((template, dataset))
+            })
+            .try_buffered(concurrency.max(1))
     }
 }
 
@@ -126,6 +224,112 @@ impl BrandTemplatesApi {
 mod tests {
     use super::*;
     use crate::auth::AccessToken;
+    use serde_json::json;
+
+    fn listed_template(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "title": format!("Template {id}"),
+            "view_url": "https://www.canva.com/design/view",
+            "create_url": "https://www.canva.com/design/create",
+            "created_at": 1_700_000_000,
+            "updated_at": 1_700_000_000,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_sends_search_query() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/brand-templates")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("query".into(), "sale poster".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "10".into()),
+            ]))
+            .with_body(json!({ "items": [listed_template("T1")] }).to_string())
+            .create_async()
+            .await;
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+
+        let request = ListBrandTemplatesRequest::builder()
+            .query("sale poster")
+            .limit(10)
+            .build();
+        let page = client.brand_templates().list(&request).await.expect("list");
+
+        assert_eq!(page.items.len(), 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_with_datasets_pairs_templates_across_pages() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/brand-templates")
+            .match_query(mockito::Matcher::Missing)
+            .with_body(
+                json!({
+                    "items": [listed_template("T1"), listed_template("T2")],
+                    "continuation": "page2",
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "continuation".into(),
+                "page2".into(),
+            ))
+            .with_body(json!({ "items": [listed_template("T3")] }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates/T1/dataset")
+            .with_body(json!({ "dataset": { "field0": { "type": "text" } } }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates/T2/dataset")
+            .with_status(404)
+            .with_body(json!({ "code": "not_found", "message": "Deleted" }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates/T3/dataset")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let templates = client.brand_templates();
+        let pairs: Vec<(String, Option<usize>)> = templates
+            .list_with_datasets(2)
+            .map_ok(|(template, dataset)| (template.id, dataset.ok().map(|d| d.len())))
+            .try_collect()
+            .await
+            .expect("all templates listed");
+
+        assert_eq!(
+            pairs,
+            vec![
+                ("T1".into(), Some(1)),
+                ("T2".into(), None),
+                ("T3".into(), Some(0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dataset_response_without_dataset_is_empty() {
+        let response: GetBrandTemplateDatasetResponse =
+            serde_json::from_str("{}").expect("dataset is optional");
+
+        assert!(response.dataset.is_empty());
+    }
 
     #[test]
     fn test_brand_templates_api_creation() {
@@ -146,6 +350,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_with_limit() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: None,
             limit: Some(50),
         };
@@ -157,6 +362,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_with_continuation() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("next_page_token".to_string()),
             limit: Some(25),
         };
@@ -177,6 +383,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_serialization_with_values() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("test_token".to_string()),
             limit: Some(100),
         };
@@ -189,6 +396,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_serialization_limit_only() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: None,
             limit: Some(10),
         };
@@ -201,6 +409,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_serialization_continuation_only() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("abc123".to_string()),
             limit: None,
         };
@@ -214,6 +423,7 @@ mod tests {
     fn test_list_brand_templates_request_edge_cases() {
         // Test with minimum limit
         let min_request = ListBrandTemplatesRequest {
+            query: None,
             continuation: None,
             limit: Some(1),
         };
@@ -221,6 +431,7 @@ mod tests {
 
         // Test with maximum limit
         let max_request = ListBrandTemplatesRequest {
+            query: None,
             continuation: None,
             limit: Some(100),
         };
@@ -228,6 +439,7 @@ mod tests {
 
         // Test with empty continuation token
         let empty_continuation_request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("".to_string()),
             limit: None,
         };
@@ -240,6 +452,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_with_special_characters() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("token_with_special_chars_@#$%_🎨".to_string()),
             limit: Some(42),
         };
@@ -281,6 +494,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_debug_format() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("debug_continuation".to_string()),
             limit: Some(75),
         };
@@ -294,6 +508,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_clone() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("original_token".to_string()),
             limit: Some(30),
         };
@@ -314,6 +529,7 @@ mod tests {
     #[test]
     fn test_list_brand_templates_request_serialization_structure() {
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some("structure_test".to_string()),
             limit: Some(55),
         };
@@ -332,6 +548,7 @@ mod tests {
     fn test_list_brand_templates_request_with_long_continuation_token() {
         let long_token = "very_long_continuation_token_".repeat(20);
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some(long_token.clone()),
             limit: Some(15),
         };
@@ -362,6 +579,7 @@ mod tests {
     fn test_list_brand_templates_request_with_unicode() {
         let unicode_token = "令牌_🔑_τοκεν";
         let request = ListBrandTemplatesRequest {
+            query: None,
             continuation: Some(unicode_token.to_string()),
             limit: Some(33),
         };
@@ -420,14 +638,17 @@ mod tests {
         let original_requests = vec![
             ListBrandTemplatesRequest::default(),
             ListBrandTemplatesRequest {
+                query: None,
                 continuation: None,
                 limit: Some(50),
             },
             ListBrandTemplatesRequest {
+                query: None,
                 continuation: Some("roundtrip_test".to_string()),
                 limit: None,
             },
             ListBrandTemplatesRequest {
+                query: None,
                 continuation: Some("full_test".to_string()),
                 limit: Some(99),
             },
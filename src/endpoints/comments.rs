@@ -6,14 +6,32 @@
 //! **Note:** The Comments API is currently in preview and includes both
 //! deprecated and new endpoints. This implementation focuses on the newer
 //! thread-based API.
+//!
+//! ## Note on Thread Management
+//!
+//! The preview API has no endpoints to list a design's threads, resolve a
+//! thread, or delete threads and replies, so this client cannot offer those
+//! operations. Threads are reached by the IDs returned when they are created,
+//! or from comment webhook notifications. Resolving and deleting are only
+//! possible in the Canva editor.
+//!
+//! ## Mentions
+//!
+//! Use [`CommentMessageBuilder`] to mention users and assign threads
+//! without writing the `[user_id:team_id]` syntax by hand.
 
 use crate::{
     client::Client,
     error::Result,
-    models::{CommentReply, CommentThread, CreateThreadResponse},
+    models::{CommentReply, CommentThread, CreateThreadResponse, Page, PageRequest},
+    query::QueryBuilder,
+    routes,
 };
 use serde::{Deserialize, Serialize};
 
+pub mod message;
+pub use message::{CommentMessageBuilder, MAX_COMMENT_CHARS};
+
 /// Client for the Comments API
 #[derive(Debug, Clone)]
 pub struct CommentsApi {
@@ -22,6 +40,7 @@ pub struct CommentsApi {
 
 /// Request to create a new comment thread
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateThreadRequest {
     /// The comment message in plaintext
     pub message_plaintext: String,
@@ -32,6 +51,7 @@ pub struct CreateThreadRequest {
 
 /// Object to attach a comment to
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum CommentObjectInput {
     /// Design comment object
@@ -43,6 +63,7 @@ pub enum CommentObjectInput {
 
 /// Request to create a reply to a comment thread
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateReplyRequest {
     /// The reply comment message in plaintext
     pub message_plaintext: String,
@@ -50,6 +71,7 @@ pub struct CreateReplyRequest {
 
 /// Response from creating a reply
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateReplyResponse {
     /// The created reply
     pub reply: CommentReply,
@@ -57,6 +79,7 @@ pub struct CreateReplyResponse {
 
 /// Response from getting a thread
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetThreadResponse {
     /// The thread
     pub thread: CommentThread,
@@ -64,20 +87,14 @@ pub struct GetThreadResponse {
 
 /// Response from getting a reply
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetReplyResponse {
     /// The reply
     pub reply: CommentReply,
 }
 
 /// Response from listing replies
-#[derive(Debug, Clone, Deserialize)]
-pub struct ListRepliesResponse {
-    /// List of replies
-    pub items: Vec<CommentReply>,
-    /// Continuation token for pagination
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuation: Option<String>,
-}
+pub type ListRepliesResponse = Page<CommentReply>;
 
 /// Request parameters for listing replies
 #[derive(Debug, Clone, Default)]
@@ -88,6 +105,44 @@ pub struct ListRepliesRequest {
     pub continuation: Option<String>,
 }
 
+impl PageRequest for ListRepliesRequest {
+    fn set_continuation(&mut self, continuation: Option<String>) {
+        self.continuation = continuation;
+    }
+}
+
+impl ListRepliesRequest {
+    /// Start building a request with no options set
+    pub fn builder() -> ListRepliesRequestBuilder {
+        ListRepliesRequestBuilder::default()
+    }
+}
+
+/// Builder for [`ListRepliesRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct ListRepliesRequestBuilder {
+    request: ListRepliesRequest,
+}
+
+impl ListRepliesRequestBuilder {
+    /// Continue from a previous page
+    pub fn continuation(mut self, continuation: impl Into<String>) -> Self {
+        self.request.continuation = Some(continuation.into());
+        self
+    }
+
+    /// Return at most `limit` replies per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Finish the request
+    pub fn build(self) -> ListRepliesRequest {
+        self.request
+    }
+}
+
 impl CommentsApi {
     /// Create a new comments API client
     pub fn new(client: Client) -> Self {
@@ -105,9 +160,11 @@ impl CommentsApi {
         design_id: &str,
         request: &CreateThreadRequest,
     ) -> Result<CreateThreadResponse> {
-        let url = format!("/v1/designs/{design_id}/comments");
+        let url = routes::comment_threads(design_id);
         let response = self.client.post(&url, request).await?;
-        Ok(response.json::<CreateThreadResponse>().await?)
+        self.client
+            .read_json::<CreateThreadResponse>(response)
+            .await
     }
 
     /// Get a comment thread
@@ -117,9 +174,9 @@ impl CommentsApi {
     /// **Note:** This API is currently in preview and may have breaking changes.
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn get_thread(&self, design_id: &str, thread_id: &str) -> Result<GetThreadResponse> {
-        let url = format!("/v1/designs/{design_id}/comments/{thread_id}");
+        let url = routes::comment_thread(design_id, thread_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetThreadResponse>().await?)
+        self.client.read_json::<GetThreadResponse>(response).await
     }
 
     /// Create a reply to a comment thread
@@ -134,9 +191,9 @@ impl CommentsApi {
         thread_id: &str,
         request: &CreateReplyRequest,
     ) -> Result<CreateReplyResponse> {
-        let url = format!("/v1/designs/{design_id}/comments/{thread_id}/replies");
+        let url = routes::comment_replies(design_id, thread_id);
         let response = self.client.post(&url, request).await?;
-        Ok(response.json::<CreateReplyResponse>().await?)
+        self.client.read_json::<CreateReplyResponse>(response).await
     }
 
     /// Get a specific reply
@@ -151,9 +208,9 @@ impl CommentsApi {
         thread_id: &str,
         reply_id: &str,
     ) -> Result<GetReplyResponse> {
-        let url = format!("/v1/designs/{design_id}/comments/{thread_id}/replies/{reply_id}");
+        let url = routes::comment_reply(design_id, thread_id, reply_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetReplyResponse>().await?)
+        self.client.read_json::<GetReplyResponse>(response).await
     }
 
     /// List replies for a comment thread
@@ -168,32 +225,13 @@ impl CommentsApi {
         thread_id: &str,
         request: &ListRepliesRequest,
     ) -> Result<ListRepliesResponse> {
-        let mut query_params = Vec::new();
-
-        if let Some(limit) = request.limit {
-            query_params.push(format!("limit={limit}"));
-        }
-
-        if let Some(continuation) = &request.continuation {
-            query_params.push(format!(
-                "continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-
-        let url = if query_params.is_empty() {
-            format!("/v1/designs/{design_id}/comments/{thread_id}/replies")
-        } else {
-            format!(
-                "/v1/designs/{}/comments/{}/replies?{}",
-                design_id,
-                thread_id,
-                query_params.join("&")
-            )
-        };
+        let url = QueryBuilder::new()
+            .param_opt("limit", request.limit)
+            .param_opt("continuation", request.continuation.as_deref())
+            .append_to(&routes::comment_replies(design_id, thread_id));
 
         let response = self.client.get(&url).await?;
-        Ok(response.json::<ListRepliesResponse>().await?)
+        self.client.read_json::<ListRepliesResponse>(response).await
     }
 }
 
@@ -416,4 +454,60 @@ mod tests {
         assert!(debug_str.contains("CreateReplyRequest"));
         assert!(debug_str.contains("Reply debug test"));
     }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_message_builder_mentions_users() {
+        let user = crate::models::TeamUserSummary {
+            user_id: "oUnPjZ2k2yuhftbWF7873o".to_string(),
+            team_id: "oBpVhLW22VrqtwKgaayRbP".to_string(),
+        };
+        let reply = CommentMessageBuilder::new()
+            .text("Thanks ")
+            .mention(&user)
+            .build_reply()
+            .expect("valid reply");
+        assert_eq!(
+            reply.message_plaintext,
+            "Thanks [oUnPjZ2k2yuhftbWF7873o:oBpVhLW22VrqtwKgaayRbP]"
+        );
+
+        let thread = CommentMessageBuilder::new()
+            .mention(&user)
+            .text(" please review")
+            .assign_to_last_mention()
+            .build_thread()
+            .expect("valid thread");
+        assert_eq!(
+            thread.assignee_id.as_deref(),
+            Some("oUnPjZ2k2yuhftbWF7873o")
+        );
+    }
+
+    #[test]
+    fn test_message_builder_rejects_invalid_messages() {
+        let invalid = [
+            CommentMessageBuilder::new().text("  ").build_thread(),
+            CommentMessageBuilder::new()
+                .text("a".repeat(MAX_COMMENT_CHARS + 1))
+                .build_thread(),
+            CommentMessageBuilder::new()
+                .text("hi ")
+                .mention_ids("user:1", "team")
+                .build_thread(),
+            CommentMessageBuilder::new()
+                .text("hi")
+                .assign_to_last_mention()
+                .build_thread(),
+        ];
+        for result in invalid {
+            assert!(matches!(result, Err(crate::Error::Generic(_))));
+        }
+
+        let assigned_reply = CommentMessageBuilder::new()
+            .mention_ids("user", "team")
+            .assign_to_last_mention()
+            .build_reply();
+        assert!(assigned_reply.is_err());
+    }
 }
@@ -0,0 +1,140 @@
+//! Building comment messages with user mentions.
+//!
+//! Canva mentions a user when a message contains their IDs as
+//! `[user_id:team_id]`. [`CommentMessageBuilder`] writes that syntax for
+//! you, checks the IDs, and enforces the API's message length limits:
+//!
+//! ```rust
+//! use canva_connect::endpoints::comments::CommentMessageBuilder;
+//!
+//! let request = CommentMessageBuilder::new()
+//!     .text("Can you check the colours, ")
+//!     .mention_ids("oUnPjZ2k2yuhftbWF7873o", "oBpVhLW22VrqtwKgaayRbP")
+//!     .text("?")
+//!     .assign_to_last_mention()
+//!     .build_thread()
+//!     .unwrap();
+//! assert_eq!(
+//!     request.message_plaintext,
+//!     "Can you check the colours, [oUnPjZ2k2yuhftbWF7873o:oBpVhLW22VrqtwKgaayRbP]?"
+//! );
+//! assert_eq!(request.assignee_id.as_deref(), Some("oUnPjZ2k2yuhftbWF7873o"));
+//! ```
+//!
+//! The create endpoints only accept plain text. Canva renders the message
+//! as Markdown in the responses and the editor, so Markdown typed into
+//! [`text`](CommentMessageBuilder::text) is sent as is rather than
+//! converted.
+
+use super::{CreateReplyRequest, CreateThreadRequest};
+use crate::error::{Error, Result};
+use crate::models::TeamUserSummary;
+
+/// Longest comment or reply message Canva accepts, in characters
+pub const MAX_COMMENT_CHARS: usize = 2048;
+
+/// Builder for the message of a [`CreateThreadRequest`] or [`CreateReplyRequest`]
+///
+/// Mistakes such as a malformed ID are reported by
+/// [`build_thread`](Self::build_thread) and [`build_reply`](Self::build_reply).
+#[derive(Debug, Clone, Default)]
+pub struct CommentMessageBuilder {
+    message: String,
+    last_mention: Option<String>,
+    assignee_id: Option<String>,
+    error: Option<String>,
+}
+
+impl CommentMessageBuilder {
+    /// Start an empty message
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append plain text
+    pub fn text(mut self, text: impl AsRef<str>) -> Self {
+        self.message.push_str(text.as_ref());
+        self
+    }
+
+    /// Append a mention of `user`
+    pub fn mention(self, user: &TeamUserSummary) -> Self {
+        self.mention_ids(&user.user_id, &user.team_id)
+    }
+
+    /// Append a mention of the user with the given user and team IDs
+    pub fn mention_ids(mut self, user_id: &str, team_id: &str) -> Self {
+        for (kind, id) in [("user", user_id), ("team", team_id)] {
+            if !is_valid_id(id) {
+                self.error
+                    .get_or_insert_with(|| format!("{kind} ID {id:?} cannot be mentioned"));
+                return self;
+            }
+        }
+        self.message.push_str(&format!("[{user_id}:{team_id}]"));
+        self.last_mention = Some(user_id.to_string());
+        self
+    }
+
+    /// Assign the thread to the most recently mentioned user
+    ///
+    /// Canva only accepts an assignee who is mentioned in the message.
+    /// Replies cannot be assigned.
+    pub fn assign_to_last_mention(mut self) -> Self {
+        match self.last_mention.clone() {
+            Some(user_id) => self.assignee_id = Some(user_id),
+            None => {
+                self.error
+                    .get_or_insert_with(|| "cannot assign before mentioning a user".to_string());
+            }
+        }
+        self
+    }
+
+    /// Finish as a request to start a thread
+    pub fn build_thread(self) -> Result<CreateThreadRequest> {
+        let assignee_id = self.assignee_id.clone();
+        Ok(CreateThreadRequest {
+            message_plaintext: self.finish()?,
+            assignee_id,
+        })
+    }
+
+    /// Finish as a request to reply to a thread
+    pub fn build_reply(self) -> Result<CreateReplyRequest> {
+        if self.assignee_id.is_some() {
+            return Err(invalid("replies cannot be assigned"));
+        }
+        Ok(CreateReplyRequest {
+            message_plaintext: self.finish()?,
+        })
+    }
+
+    fn finish(self) -> Result<String> {
+        if let Some(error) = self.error {
+            return Err(invalid(&error));
+        }
+        if self.message.trim().is_empty() {
+            return Err(invalid("message is empty"));
+        }
+        let chars = self.message.chars().count();
+        if chars > MAX_COMMENT_CHARS {
+            return Err(invalid(&format!(
+                "message is {chars} characters, over the {MAX_COMMENT_CHARS} character limit"
+            )));
+        }
+        Ok(self.message)
+    }
+}
+
+/// Canva user and team IDs are non-empty runs of letters, digits, `-` and `_`
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::Generic(format!("Invalid comment message: {reason}"))
+}
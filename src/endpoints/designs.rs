@@ -4,9 +4,24 @@
 //!
 //! | Method | HTTP | Endpoint | OAuth Scope | Description |
 //! |--------|------|----------|-------------|-------------|
-//! | [`list`](DesignsApi::list) | `GET` | `/v1/designs` | `design:meta:read` | List user's designs |
+//! | [`list_with`](DesignsApi::list_with) | `GET` | `/v1/designs` | `design:meta:read` | List user's designs |
+//! | [`search`](DesignsApi::search) | `GET` | `/v1/designs` | `design:meta:read` | Stream designs matching API and client-side filters |
+//! | [`list_raw`](DesignsApi::list_raw) | `GET` | `/v1/designs` | `design:meta:read` | List designs as an undecoded body |
 //! | [`get`](DesignsApi::get) | `GET` | `/v1/designs/{designId}` | `design:meta:read` | Get design metadata |
+//! | [`get_pages`](DesignsApi::get_pages) | `GET` | `/v1/designs/{designId}/pages` | `design:content:read` | List page thumbnails (preview) |
 //! | [`create`](DesignsApi::create) | `POST` | `/v1/designs` | `design:content:write` | Create new design |
+//! | [`create_resize_job`](DesignsApi::create_resize_job) | `POST` | `/v1/resizes` | `design:content:read`, `design:content:write` | Resize a copy of a design |
+//! | [`get_resize_job`](DesignsApi::get_resize_job) | `GET` | `/v1/resizes/{jobId}` | `design:content:read`, `design:content:write` | Check resize job status |
+//! | [`wait_for_resize_job`](DesignsApi::wait_for_resize_job) | N/A | Multiple calls | `design:content:read`, `design:content:write` | Wait for resize completion |
+//!
+//! The API does not expose a design's elements or text; the pages endpoint
+//! is the only read of design content beyond its metadata.
+//!
+//! Designs cannot be renamed or otherwise edited through the API: there is
+//! no update endpoint. Set the title when the design is made instead, with
+//! [`CreateDesignRequest::title`](crate::models::CreateDesignRequest::title)
+//! or, for autofill output,
+//! [`CreateDesignAutofillJobRequest::title`](crate::models::CreateDesignAutofillJobRequest::title).
 //!
 //! ## Usage
 //!
@@ -21,7 +36,7 @@
 //! let designs_api = client.designs();
 //!
 //! // List designs
-//! let designs = designs_api.list(None, None, None, None).await?;
+//! let designs = designs_api.list_with(&Default::default()).await?;
 //! println!("Found {} designs", designs.items.len());
 //!
 //! // Create a new presentation
@@ -44,12 +59,149 @@
 
 use crate::{
     client::Client,
+    error::Error,
+    jobs::{PollableJob, WaitOptions},
     models::{
-        CreateDesignRequest, CreateDesignResponse, GetDesignResponse, GetListDesignResponse,
-        OwnershipType, SortByType,
+        CreateDesignRequest, CreateDesignResizeJobRequest, CreateDesignResizeJobResponse,
+        CreateDesignResponse, Design, DesignResizeJob, DesignSummary, GetDesignResizeJobResponse,
+        GetDesignResponse, GetListDesignResponse, JobStatus, OwnershipType, PageRequest,
+        SortByType,
     },
-    Result,
+    query::QueryBuilder,
+    routes, Result,
 };
+use futures::{Stream, TryStreamExt};
+
+pub mod search;
+pub use search::DesignSearch;
+
+/// Filters for [`DesignsApi::list_with`]
+///
+/// Build one with [`ListDesignsRequest::builder`], or set the fields
+/// directly; the default lists every design the user can access.
+#[derive(Debug, Clone, Default)]
+pub struct ListDesignsRequest {
+    /// Search term to filter designs
+    pub query: Option<String>,
+    /// Filter by ownership (owned, shared, or any)
+    pub ownership: Option<OwnershipType>,
+    /// Sort order for results
+    pub sort_by: Option<SortByType>,
+    /// Continuation token for pagination
+    pub continuation: Option<String>,
+    /// Maximum number of results to return (1-100)
+    pub limit: Option<u32>,
+}
+
+impl PageRequest for ListDesignsRequest {
+    fn set_continuation(&mut self, continuation: Option<String>) {
+        self.continuation = continuation;
+    }
+}
+
+impl ListDesignsRequest {
+    /// Start building a request with no filters set
+    pub fn builder() -> ListDesignsRequestBuilder {
+        ListDesignsRequestBuilder::default()
+    }
+}
+
+/// Builder for [`ListDesignsRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct ListDesignsRequestBuilder {
+    request: ListDesignsRequest,
+}
+
+impl ListDesignsRequestBuilder {
+    /// Only return designs matching a search term
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.request.query = Some(query.into());
+        self
+    }
+
+    /// Only return designs with the given ownership
+    pub fn ownership(mut self, ownership: OwnershipType) -> Self {
+        self.request.ownership = Some(ownership);
+        self
+    }
+
+    /// Sort the results
+    pub fn sort_by(mut self, sort_by: SortByType) -> Self {
+        self.request.sort_by = Some(sort_by);
+        self
+    }
+
+    /// Continue from a previous page
+    pub fn continuation(mut self, continuation: impl Into<String>) -> Self {
+        self.request.continuation = Some(continuation.into());
+        self
+    }
+
+    /// Return at most `limit` designs per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Finish the request
+    pub fn build(self) -> ListDesignsRequest {
+        self.request
+    }
+}
+
+/// Page range for [`DesignsApi::get_pages`]
+///
+/// The default returns the first 50 pages.
+#[cfg(feature = "experimental")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+#[derive(Debug, Clone, Default)]
+pub struct GetDesignPagesRequest {
+    /// Index of the first page to return, starting at 1
+    pub offset: Option<u32>,
+    /// Number of pages to return, up to 200
+    pub limit: Option<u32>,
+}
+
+/// A design resize job that can be polled until it finishes
+///
+/// Returned by [`DesignsApi::pending_resize_job`]; its output is the resized
+/// copy of the design.
+#[derive(Debug, Clone)]
+pub struct PendingResizeJob {
+    api: DesignsApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingResizeJob {
+    type Output = DesignSummary;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<DesignSummary>> {
+        resize_job_outcome(self.api.get_resize_job(&self.job_id).await?)
+    }
+}
+
+/// Resolve a resize job's status to the new design, or `None` while it is running
+pub(crate) fn resize_job_outcome(job: DesignResizeJob) -> Result<Option<DesignSummary>> {
+    match job.status {
+        JobStatus::Success => job
+            .result
+            .map(|result| Some(result.design))
+            .ok_or_else(|| Error::Generic("Job succeeded but no design data".to_string())),
+        JobStatus::Failed => {
+            let error_msg = job
+                .error
+                .map(|e| format!("{}: {}", e.code, e.message))
+                .unwrap_or_else(|| "Job failed with unknown error".to_string());
+            Err(Error::Generic(error_msg))
+        }
+        JobStatus::InProgress | JobStatus::Unknown => Ok(None),
+    }
+}
 
 /// Designs API client
 #[derive(Debug, Clone)]
@@ -67,17 +219,11 @@ impl DesignsApi {
     ///
     /// **Required OAuth scope:** `design:meta:read`
     ///
-    /// # Parameters
-    ///
-    /// - `query`: Search term to filter designs
-    /// - `continuation`: Continuation token for pagination
-    /// - `ownership`: Filter by ownership (owned, shared, or any)
-    /// - `sort_by`: Sort order for results
-    ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use canva_connect::{Client, auth::AccessToken};
+    /// use canva_connect::endpoints::designs::ListDesignsRequest;
     /// use canva_connect::models::{OwnershipType, SortByType};
     ///
     /// # #[tokio::main]
@@ -87,64 +233,91 @@ impl DesignsApi {
     /// let designs = client.designs();
     ///
     /// // List all designs
-    /// let all_designs = designs.list(None, None, None, None).await?;
+    /// let all_designs = designs.list_with(&ListDesignsRequest::default()).await?;
     ///
     /// // Search for designs
-    /// let search_results = designs.list(
-    ///     Some("presentation".to_string()),
-    ///     None,
-    ///     Some(OwnershipType::Owned),
-    ///     Some(SortByType::ModifiedDescending)
-    /// ).await?;
+    /// let request = ListDesignsRequest::builder()
+    ///     .query("presentation")
+    ///     .ownership(OwnershipType::Owned)
+    ///     .sort_by(SortByType::ModifiedDescending)
+    ///     .limit(20)
+    ///     .build();
+    /// let search_results = designs.list_with(&request).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(
-        &self,
-        query: Option<String>,
-        continuation: Option<String>,
-        ownership: Option<OwnershipType>,
-        sort_by: Option<SortByType>,
-    ) -> Result<GetListDesignResponse> {
-        let mut params = Vec::new();
-
-        if let Some(q) = query {
-            params.push(("query", q));
-        }
-        if let Some(cont) = continuation {
-            params.push(("continuation", cont));
-        }
-        if let Some(own) = ownership {
-            let ownership_str = match own {
-                OwnershipType::Any => "any",
-                OwnershipType::Owned => "owned",
-                OwnershipType::Shared => "shared",
-            };
-            params.push(("ownership", ownership_str.to_string()));
-        }
-        if let Some(sort) = sort_by {
-            let sort_str = match sort {
-                SortByType::Relevance => "relevance",
-                SortByType::ModifiedDescending => "modified_descending",
-                SortByType::ModifiedAscending => "modified_ascending",
-                SortByType::TitleDescending => "title_descending",
-                SortByType::TitleAscending => "title_ascending",
+    pub async fn list_with(&self, request: &ListDesignsRequest) -> Result<GetListDesignResponse> {
+        self.client.get_json(&Self::list_path(request)).await
+    }
+
+    /// Stream the designs matching `search`, fetching pages as needed
+    ///
+    /// The search term, ownership and sort order go to the API; the title
+    /// pattern, modification cutoff and page count range are checked here.
+    /// See [`DesignSearch`].
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use canva_connect::endpoints::designs::DesignSearch;
+    /// use futures::TryStreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// let search = DesignSearch::new().query("deck").page_count(10..);
+    /// let designs = client.designs();
+    /// let mut results = std::pin::pin!(designs.search(search));
+    /// while let Some(design) = results.try_next().await? {
+    ///     println!("{} ({:?} pages)", design.id, design.page_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required OAuth scope:** `design:meta:read`
+    pub fn search(&self, search: DesignSearch) -> impl Stream<Item = Result<Design>> + '_ {
+        let first = search.request().clone();
+        let pages = futures::stream::try_unfold(Some(first), move |request| async move {
+            let Some(current) = request else {
+                return Ok(None);
             };
-            params.push(("sort_by", sort_str.to_string()));
-        }
+            let page = self.list_with(&current).await?;
+            let next = page.next_request(&current);
+            Ok::<_, Error>(Some((page.items, next)))
+        });
+        let designs = pages
+            .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+            .try_flatten();
+        let cutoff = search.clone();
+        designs
+            .try_take_while(move |design| {
+                futures::future::ready(Ok(!cutoff.is_exhausted_by(design)))
+            })
+            .try_filter(move |design| futures::future::ready(search.matches(design)))
+    }
 
-        let path = if params.is_empty() {
-            "/v1/designs".to_string()
-        } else {
-            let query_string = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("/v1/designs?{query_string}")
-        };
+    /// List designs, returning the undecoded response body
+    ///
+    /// Parse the body with
+    /// [`ListDesignsResponseRef::from_slice`](crate::models::borrowed::ListDesignsResponseRef)
+    /// to borrow the designs' strings from it rather than copying each one;
+    /// see [`models::borrowed`](crate::models::borrowed). Worth it when
+    /// syncing large libraries page by page.
+    ///
+    /// **Required OAuth scope:** `design:meta:read`
+    pub async fn list_raw(&self, request: &ListDesignsRequest) -> Result<Vec<u8>> {
+        let response = self.client.get(&Self::list_path(request)).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
 
-        self.client.get_json(&path).await
+    fn list_path(request: &ListDesignsRequest) -> String {
+        QueryBuilder::new()
+            .param_opt("query", request.query.as_deref())
+            .param_opt("continuation", request.continuation.as_deref())
+            .param_opt("ownership", request.ownership.as_ref())
+            .param_opt("sort_by", request.sort_by.as_ref())
+            .param_opt("limit", request.limit)
+            .append_to(routes::DESIGNS)
     }
 
     /// Get design metadata by ID
@@ -169,7 +342,48 @@ impl DesignsApi {
     /// # }
     /// ```
     pub async fn get(&self, design_id: &str) -> Result<GetDesignResponse> {
-        let path = format!("/v1/designs/{}", urlencoding::encode(design_id));
+        let path = routes::design(design_id);
+        self.client.get_json(&path).await
+    }
+
+    /// List the pages of a design, with a thumbnail of each
+    ///
+    /// This wraps a Canva preview API, so it needs the `experimental`
+    /// feature and may change without notice. Some design types, such as
+    /// Canva Docs, have no pages.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use canva_connect::endpoints::designs::GetDesignPagesRequest;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))
+    ///     .expect("Failed to create client");
+    ///
+    /// let request = GetDesignPagesRequest { offset: Some(1), limit: Some(10) };
+    /// let pages = client.designs().get_pages("DAFVztcvd9z", &request).await?;
+    /// for page in pages.items {
+    ///     println!("Page {}: {:?}", page.index, page.thumbnail.map(|t| t.url));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+    pub async fn get_pages(
+        &self,
+        design_id: &str,
+        request: &GetDesignPagesRequest,
+    ) -> Result<crate::models::GetDesignPagesResponse> {
+        let path = QueryBuilder::new()
+            .param_opt("offset", request.offset)
+            .param_opt("limit", request.limit)
+            .append_to(&routes::design_pages(design_id));
         self.client.get_json(&path).await
     }
 
@@ -178,10 +392,15 @@ impl DesignsApi {
     /// **Required OAuth scope:** `design:content:write`
     ///
     /// You can create a design using either:
-    /// - A preset design type (e.g., presentation, Instagram post)
+    /// - A preset design type (doc, whiteboard or presentation)
     /// - Custom dimensions (width and height in pixels)
     /// - An existing asset ID to insert into the design
     ///
+    /// The request is checked with [`CreateDesignRequest::validate`] first;
+    /// custom sizes outside [`MIN_DESIGN_DIMENSION`](crate::models::MIN_DESIGN_DIMENSION)
+    /// to [`MAX_DESIGN_DIMENSION`](crate::models::MAX_DESIGN_DIMENSION) pixels
+    /// fail with [`Error::Generic`] without calling the API.
+    ///
     /// **Note:** Blank designs created with this API are automatically deleted
     /// if they're not edited within 7 days.
     ///
@@ -223,6 +442,488 @@ impl DesignsApi {
     /// # }
     /// ```
     pub async fn create(&self, request: CreateDesignRequest) -> Result<CreateDesignResponse> {
-        self.client.post_json("/v1/designs", &request).await
+        request.validate()?;
+        self.client.post_json(routes::DESIGNS, &request).await
+    }
+
+    /// Create a design resize job
+    ///
+    /// Starts an asynchronous job that creates a resized copy of a design.
+    /// The original design is left unchanged and the copy is saved to the
+    /// user's root folder. Resizing requires a Canva plan with the
+    /// [`Resize`](crate::endpoints::user::Capability::Resize) capability.
+    /// Custom sizes are checked locally with
+    /// [`CreateDesignResizeJobRequest::validate`] before the job is started.
+    ///
+    /// **Required OAuth scopes:** `design:content:read`, `design:content:write`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken};
+    /// use canva_connect::models::{CreateDesignResizeJobRequest, PresetDesignTypeName};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))
+    ///     .expect("Failed to create client");
+    /// let designs = client.designs();
+    ///
+    /// // Resize to a preset design type
+    /// let request = CreateDesignResizeJobRequest::preset("DAFVztcvd9z", PresetDesignTypeName::Presentation);
+    /// let job = designs.create_resize_job(request).await?;
+    ///
+    /// // Or to custom dimensions
+    /// let request = CreateDesignResizeJobRequest::custom("DAFVztcvd9z", 1080, 1920);
+    /// let job = designs.create_resize_job(request).await?;
+    /// let resized = designs.wait_for_resize_job(&job.id).await?;
+    /// println!("Resized design: {}", resized.urls.edit_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn create_resize_job(
+        &self,
+        request: CreateDesignResizeJobRequest,
+    ) -> Result<DesignResizeJob> {
+        request.validate()?;
+        let response: CreateDesignResizeJobResponse =
+            self.client.post_json(routes::RESIZES, &request).await?;
+        Ok(response.job)
+    }
+
+    /// Get the status of a design resize job
+    ///
+    /// **Required OAuth scopes:** `design:content:read`, `design:content:write`
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn get_resize_job(&self, job_id: &str) -> Result<DesignResizeJob> {
+        let path = routes::resize_job(job_id);
+        let response: GetDesignResizeJobResponse = self.client.get_json(&path).await?;
+        Ok(response.job)
+    }
+
+    /// Wait for a resize job to complete, returning the resized design
+    ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
+    /// **Required OAuth scopes:** `design:content:read`, `design:content:write`
+    pub async fn wait_for_resize_job(&self, job_id: &str) -> Result<DesignSummary> {
+        self.client
+            .job_poller()
+            .wait(&self.pending_resize_job(job_id))
+            .await
+    }
+
+    /// Like [`wait_for_resize_job`](Self::wait_for_resize_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scopes:** `design:content:read`, `design:content:write`
+    pub async fn wait_for_resize_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<DesignSummary> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_resize_job(job_id))
+            .await
+    }
+
+    /// A resize job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_resize_job(&self, job_id: &str) -> PendingResizeJob {
+        PendingResizeJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        auth::AccessToken,
+        models::{DesignTypeInput, PresetDesignTypeName},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_resize_request_preset_serialization() {
+        let request =
+            CreateDesignResizeJobRequest::preset("DAFVztcvd9z", PresetDesignTypeName::Presentation);
+        let json = serde_json::to_value(&request).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            json!({
+                "design_id": "DAFVztcvd9z",
+                "design_type": { "type": "preset", "name": "presentation" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_resize_request_custom_serialization() {
+        let request = CreateDesignResizeJobRequest::custom("DAFVztcvd9z", 1080, 1920);
+        let json = serde_json::to_value(&request).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            json!({
+                "design_id": "DAFVztcvd9z",
+                "design_type": { "type": "custom", "width": 1080, "height": 1920 }
+            })
+        );
+    }
+
+    #[test]
+    fn test_design_size_validation() {
+        let custom = |width, height| DesignTypeInput::Custom { width, height };
+        assert!(custom(40, 8000).validate().is_ok());
+        assert!(custom(39, 600).validate().is_err());
+        assert!(custom(800, 8001).validate().is_err());
+        assert!(CreateDesignResizeJobRequest::custom("D1", 5000, 5000)
+            .validate()
+            .is_ok());
+        assert!(CreateDesignResizeJobRequest::custom("D1", 8000, 8000)
+            .validate()
+            .is_err());
+
+        let unknown: PresetDesignTypeName =
+            serde_json::from_value(json!("instagram_post")).expect("Failed to deserialize");
+        assert_eq!(unknown, PresetDesignTypeName::Unknown);
+        assert!(DesignTypeInput::Preset { name: unknown }
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_out_of_range_size_without_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/designs")
+            .expect(0)
+            .create_async()
+            .await;
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+
+        let result = client
+            .designs()
+            .create(CreateDesignRequest {
+                design_type: Some(DesignTypeInput::Custom {
+                    width: 20_000,
+                    height: 600,
+                }),
+                asset_id: None,
+                title: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::Generic(message)) if message.contains("20000x600")));
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_resize_job_deserialization_failed() {
+        let job: DesignResizeJob = serde_json::from_value(json!({
+            "id": "bbd8dfcd-ead1-4871-81d5-962bfec82274",
+            "status": "failed",
+            "error": {
+                "code": "design_resize_error",
+                "message": "Failed to resize the design"
+            }
+        }))
+        .expect("Failed to deserialize");
+
+        assert_eq!(job.status, JobStatus::Failed);
+        let error = job.error.expect("error should be present");
+        assert_eq!(error.code.to_string(), "design_resize_error");
+    }
+
+    #[tokio::test]
+    async fn test_list_sends_builder_filters() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("query".into(), "summer sale".into()),
+                mockito::Matcher::UrlEncoded("ownership".into(), "shared".into()),
+                mockito::Matcher::UrlEncoded("sort_by".into(), "title_ascending".into()),
+                mockito::Matcher::UrlEncoded("continuation".into(), "page2".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "5".into()),
+            ]))
+            .with_status(200)
+            .with_body(json!({ "items": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let request = ListDesignsRequest::builder()
+            .query("summer sale")
+            .ownership(OwnershipType::Shared)
+            .sort_by(SortByType::TitleAscending)
+            .continuation("page2")
+            .limit(5)
+            .build();
+        let designs = client.designs().list_with(&request).await.expect("list");
+
+        assert!(designs.items.is_empty());
+        mock.assert_async().await;
+    }
+
+    fn listed_design(
+        server: &mockito::Server,
+        id: &str,
+        title: &str,
+        updated_at: i64,
+        pages: u32,
+    ) -> serde_json::Value {
+        let mut design = crate::testing::fixtures::design(&server.url(), id);
+        design["title"] = json!(title);
+        design["updated_at"] = json!(updated_at);
+        design["page_count"] = json!(pages);
+        design
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_across_pages() {
+        use futures::TryStreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = json!({
+            "items": [
+                listed_design(&server, "D1", "Q1 deck", 2_000, 12),
+                listed_design(&server, "D2", "Q2 deck", 2_000, 3),
+            ],
+            "continuation": "page2",
+        });
+        let second = json!({
+            "items": [
+                listed_design(&server, "D3", "notes", 2_000, 20),
+                listed_design(&server, "D4", "Q3 deck", 2_000, 15),
+            ],
+        });
+        server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::UrlEncoded("query".into(), "deck".into()))
+            .with_body(first.to_string())
+            .create_async()
+            .await;
+        let page_two = server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("query".into(), "deck".into()),
+                mockito::Matcher::UrlEncoded("continuation".into(), "page2".into()),
+            ]))
+            .with_body(second.to_string())
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let pattern = regex::Regex::new("^Q[0-9] ").expect("valid regex");
+        let search = DesignSearch::new()
+            .query("deck")
+            .title_matches(move |title| pattern.is_match(title))
+            .page_count(10..);
+        let ids: Vec<String> = client
+            .designs()
+            .search(search)
+            .map_ok(|design| design.id)
+            .try_collect()
+            .await
+            .expect("search");
+
+        assert_eq!(ids, vec!["D1", "D4"]);
+        page_two.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_stops_at_updated_since_when_sorted_by_modified() {
+        use futures::TryStreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = json!({
+            "items": [
+                listed_design(&server, "new", "a", 2_000, 1),
+                listed_design(&server, "old", "b", 500, 1),
+            ],
+            "continuation": "page2",
+        });
+        server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "sort_by".into(),
+                "modified_descending".into(),
+            ))
+            .with_body(first.to_string())
+            .create_async()
+            .await;
+        let page_two = server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "continuation".into(),
+                "page2".into(),
+            ))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let since = chrono::DateTime::from_timestamp(1_000, 0).expect("valid timestamp");
+        let search = DesignSearch::new()
+            .sort_by(SortByType::ModifiedDescending)
+            .updated_since(since);
+        let designs: Vec<Design> = client
+            .designs()
+            .search(search)
+            .try_collect()
+            .await
+            .expect("search");
+
+        assert_eq!(designs.len(), 1);
+        assert_eq!(designs[0].id, "new");
+        page_two.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_without_filters_sends_no_query() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/designs")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(json!({ "items": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        client
+            .designs()
+            .list_with(&ListDesignsRequest::default())
+            .await
+            .expect("list");
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_raw_parses_borrowed_designs() {
+        use crate::models::borrowed::ListDesignsResponseRef;
+        use std::borrow::Cow;
+
+        let mut server = mockito::Server::new_async().await;
+        let mut design = crate::testing::fixtures::design(&server.url(), "DAFVztcvd9z");
+        design["title"] = json!("Summer \"sale\"");
+        let _mock = server
+            .mock("GET", "/v1/designs")
+            .with_status(200)
+            .with_body(json!({ "items": [design], "continuation": "page2" }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let body = client
+            .designs()
+            .list_raw(&ListDesignsRequest::default())
+            .await
+            .expect("list_raw");
+        let page = ListDesignsResponseRef::from_slice(&body).expect("parse");
+
+        let design = &page.items[0];
+        assert!(matches!(design.id, Cow::Borrowed("DAFVztcvd9z")));
+        // Escaped strings cannot be borrowed and fall back to an owned copy
+        assert!(matches!(design.title, Some(Cow::Owned(_))));
+        assert_eq!(page.continuation.as_deref(), Some("page2"));
+
+        let owned = page.into_owned();
+        assert_eq!(owned.items[0].title.as_deref(), Some("Summer \"sale\""));
+        assert_eq!(
+            owned.items[0].owner.user_id,
+            crate::testing::fixtures::USER_ID
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_resize_job_returns_design() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/resizes/job_123")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "job": {
+                        "id": "job_123",
+                        "status": "success",
+                        "result": {
+                            "design": {
+                                "id": "DAGirp_1ZUA",
+                                "title": "Resized",
+                                "urls": {
+                                    "edit_url": "https://www.canva.com/api/design/edit",
+                                    "view_url": "https://www.canva.com/api/design/view"
+                                },
+                                "created_at": 1726198998,
+                                "updated_at": 1726199000
+                            }
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let design = client
+            .designs()
+            .wait_for_resize_job("job_123")
+            .await
+            .expect("Resize should succeed");
+
+        assert_eq!(design.id, "DAGirp_1ZUA");
+        mock.assert_async().await;
+    }
+
+    #[cfg(feature = "experimental")]
+    #[tokio::test]
+    async fn test_get_pages_sends_range() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/designs/DAFVztcvd9z/pages")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("offset".into(), "3".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"{"items":[{"index":3,"thumbnail":{"width":595,"height":335,"url":"https://x/3.png"}},{"index":4}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let request = GetDesignPagesRequest {
+            offset: Some(3),
+            limit: Some(2),
+        };
+        let pages = client
+            .designs()
+            .get_pages("DAFVztcvd9z", &request)
+            .await
+            .expect("Pages should load");
+
+        let indexes: Vec<u32> = pages.items.iter().map(|page| page.index).collect();
+        assert_eq!(indexes, vec![3, 4]);
+        assert!(pages.items[1].thumbnail.is_none());
+        mock.assert_async().await;
     }
 }
@@ -0,0 +1,145 @@
+//! Searching designs with filters the API does not offer.
+//!
+//! `GET /v1/designs` filters by search term and ownership and sorts the
+//! results, nothing more. [`DesignSearch`] sends those options to the API
+//! and applies the rest (a title filter, a last-modified cutoff and a page
+//! count range) to each page as it arrives.
+//! [`DesignsApi::search`](super::DesignsApi::search) yields only the
+//! designs that pass, fetching further pages as the stream is read.
+//!
+//! When the results are sorted by [`SortByType::ModifiedDescending`], an
+//! [`updated_since`](DesignSearch::updated_since) cutoff also ends the
+//! search at the first older design, instead of paging through the rest.
+
+use super::ListDesignsRequest;
+use crate::models::{Design, OwnershipType, SortByType};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+/// Options for [`DesignsApi::search`](super::DesignsApi::search)
+///
+/// ```rust
+/// use canva_connect::endpoints::designs::DesignSearch;
+/// use canva_connect::models::{OwnershipType, SortByType};
+///
+/// let search = DesignSearch::new()
+///     .query("launch")
+///     .ownership(OwnershipType::Owned)
+///     .sort_by(SortByType::ModifiedDescending)
+///     .title_matches(|title| title.to_lowercase().starts_with("q1 "))
+///     .updated_since(chrono::Utc::now() - chrono::Duration::days(30))
+///     .page_count(1..=10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DesignSearch {
+    request: ListDesignsRequest,
+    title: Option<TitleFilter>,
+    updated_since: Option<DateTime<Utc>>,
+    page_count: Option<(Bound<u32>, Bound<u32>)>,
+}
+
+/// Title predicate, see [`DesignSearch::title_matches`]
+#[derive(Clone)]
+struct TitleFilter(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl fmt::Debug for TitleFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TitleFilter")
+    }
+}
+
+impl DesignSearch {
+    /// Match every design the user can access
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Have the API search for `query`
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.request.query = Some(query.into());
+        self
+    }
+
+    /// Have the API return only designs with the given ownership
+    pub fn ownership(mut self, ownership: OwnershipType) -> Self {
+        self.request.ownership = Some(ownership);
+        self
+    }
+
+    /// Have the API sort the results
+    pub fn sort_by(mut self, sort_by: SortByType) -> Self {
+        self.request.sort_by = Some(sort_by);
+        self
+    }
+
+    /// Fetch at most `limit` designs per page (1-100)
+    pub fn page_size(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Only keep designs whose title passes `filter`; untitled designs never match
+    ///
+    /// To match a regular expression, capture it in the closure:
+    /// `move |title| pattern.is_match(title)`.
+    pub fn title_matches<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.title = Some(TitleFilter(Arc::new(filter)));
+        self
+    }
+
+    /// Only keep designs modified at or after `since`
+    pub fn updated_since(mut self, since: DateTime<Utc>) -> Self {
+        self.updated_since = Some(since);
+        self
+    }
+
+    /// Only keep designs whose page count is in `range`
+    ///
+    /// Designs without a page count never match.
+    pub fn page_count(mut self, range: impl RangeBounds<u32>) -> Self {
+        self.page_count = Some((range.start_bound().cloned(), range.end_bound().cloned()));
+        self
+    }
+
+    /// The request sent to the API for the first page
+    pub fn request(&self) -> &ListDesignsRequest {
+        &self.request
+    }
+
+    /// Whether `design` passes the filters applied on the client
+    pub fn matches(&self, design: &Design) -> bool {
+        if let Some(TitleFilter(filter)) = &self.title {
+            if !design.title.as_deref().is_some_and(|title| filter(title)) {
+                return false;
+            }
+        }
+        if self
+            .updated_since
+            .is_some_and(|since| design.updated_at < since)
+        {
+            return false;
+        }
+        if let Some(range) = self.page_count {
+            if !design
+                .page_count
+                .is_some_and(|count| range.contains(&count))
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether no design after `design` in the results can match
+    pub(crate) fn is_exhausted_by(&self, design: &Design) -> bool {
+        matches!(self.request.sort_by, Some(SortByType::ModifiedDescending))
+            && self
+                .updated_since
+                .is_some_and(|since| design.updated_at < since)
+    }
+}
@@ -2,13 +2,64 @@
 //!
 //! This module provides access to the Canva Exports API, allowing you to
 //! export designs to various file formats.
+//!
+//! Besides the raw job endpoints, [`ExportsApi::export_to_path`] and
+//! [`ExportsApi::download_export`] run the whole export flow and stream the
+//! exported files to disk or any [`AsyncWrite`].
+//! [`ExportsApi::download_export_to_path`] fetches the files of a multi-page
+//! export in parallel, within [`DownloadLimits`].
+//! [`ExportsApi::download_document`] loads an export into an
+//! [`ExportedDocument`], which treats single-file and per-page exports alike.
+//! [`ExportsApi::download_all`] fetches every file into memory in parallel
+//! as a [`DownloadedExport`] with its content type, size and checksum.
+//! [`ExportsApi::export_designs`] exports many designs concurrently, for
+//! batch jobs such as nightly archiving.
+//! [`ExportsApi::create_export_checked`] checks the design's export formats
+//! before starting a job, so an unsupported format fails with the list of
+//! formats that would work.
+//!
+//! Download URLs expire a day after they are issued. Jobs remember when they
+//! were fetched, and the download methods fetch a job again through
+//! [`ExportsApi::refresh_export`] when its URLs are likely to have expired.
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, models::ExportFormat};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-token"))?;
+//! let format = ExportFormat::pdf_print_a4().pages([1, 2]);
+//!
+//! let files = client
+//!     .exports()
+//!     .export_to_path_with_progress("DAFVztcvd9z", format, "design.pdf", |progress| {
+//!         println!("page {}/{}: {} bytes", progress.page, progress.total_pages, progress.page_bytes);
+//!     })
+//!     .await?;
+//! println!("Wrote {files:?}");
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::{
     client::Client,
-    error::Result,
-    models::{ExportFormat, ExportJob},
+    downloads::{hex, url_host, DownloadGate, DownloadLimits, DownloadReport, DownloadRequest},
+    error::{Error, Result},
+    jobs::{PollableJob, WaitOptions},
+    models::{ExportFormat, ExportJob, JobStatus},
+    routes,
 };
+use futures::{FutureExt, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::OnceCell;
+
+pub mod document;
+
+pub use document::{DownloadedExport, ExportedDocument, ExportedPage};
 
 /// Client for the Exports API
 #[derive(Debug, Clone)]
@@ -16,8 +67,47 @@ pub struct ExportsApi {
     client: Client,
 }
 
+/// An export job that can be polled until it finishes
+///
+/// Returned by [`ExportsApi::pending_export_job`]; its output is the finished
+/// job, whose [`urls`](ExportJob::urls) are ready to download.
+#[derive(Debug, Clone)]
+pub struct PendingExportJob {
+    api: ExportsApi,
+    export_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingExportJob {
+    type Output = ExportJob;
+
+    fn id(&self) -> &str {
+        &self.export_id
+    }
+
+    async fn poll(&self) -> Result<Option<ExportJob>> {
+        export_job_outcome(self.api.get_design_export_job(&self.export_id).await?.job)
+    }
+}
+
+/// Resolve an export job's status to the finished job, or `None` while it is running
+pub(crate) fn export_job_outcome(job: ExportJob) -> Result<Option<ExportJob>> {
+    match job.status {
+        JobStatus::Success => Ok(Some(job)),
+        JobStatus::Failed => {
+            let error_msg = job
+                .error
+                .map(|e| format!("{}: {}", e.code, e.message))
+                .unwrap_or_else(|| "Job failed with unknown error".to_string());
+            Err(Error::Generic(error_msg))
+        }
+        JobStatus::InProgress | JobStatus::Unknown => Ok(None),
+    }
+}
+
 /// Request to create a design export job
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignExportJobRequest {
     /// The design ID to export
     pub design_id: String,
@@ -27,6 +117,7 @@ pub struct CreateDesignExportJobRequest {
 
 /// Response from creating a design export job
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignExportJobResponse {
     /// The export job
     pub job: ExportJob,
@@ -34,6 +125,7 @@ pub struct CreateDesignExportJobResponse {
 
 /// Response from getting a design export job
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetDesignExportJobResponse {
     /// The export job
     pub job: ExportJob,
@@ -41,6 +133,7 @@ pub struct GetDesignExportJobResponse {
 
 /// Response from getting available export formats
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetDesignExportFormatsResponse {
     /// Available formats for the design
     pub formats: ExportFormatOptions,
@@ -48,6 +141,7 @@ pub struct GetDesignExportFormatsResponse {
 
 /// Available export format options
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ExportFormatOptions {
     /// PDF export option
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,8 +166,35 @@ pub struct ExportFormatOptions {
     pub mp4: Option<Mp4ExportFormatOption>,
 }
 
+impl ExportFormatOptions {
+    /// Names of the formats the design can be exported as, e.g. `["pdf", "png"]`
+    ///
+    /// Canva lists only the formats a design supports, so a format is
+    /// supported when it is present in the response.
+    pub fn supported(&self) -> Vec<&'static str> {
+        [
+            ("pdf", self.pdf.is_some()),
+            ("jpg", self.jpg.is_some()),
+            ("png", self.png.is_some()),
+            ("svg", self.svg.is_some()),
+            ("pptx", self.pptx.is_some()),
+            ("gif", self.gif.is_some()),
+            ("mp4", self.mp4.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, present)| present.then_some(name))
+        .collect()
+    }
+
+    /// Whether the design can be exported as `format`
+    pub fn supports(&self, format: &ExportFormat) -> bool {
+        self.supported().contains(&format.name())
+    }
+}
+
 /// PDF export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PdfExportFormatOption {
     /// Whether PDF export is available
     #[serde(default)]
@@ -82,6 +203,7 @@ pub struct PdfExportFormatOption {
 
 /// JPG export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct JpgExportFormatOption {
     /// Whether JPG export is available
     #[serde(default)]
@@ -90,6 +212,7 @@ pub struct JpgExportFormatOption {
 
 /// PNG export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PngExportFormatOption {
     /// Whether PNG export is available
     #[serde(default)]
@@ -98,6 +221,7 @@ pub struct PngExportFormatOption {
 
 /// SVG export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SvgExportFormatOption {
     /// Whether SVG export is available
     #[serde(default)]
@@ -106,6 +230,7 @@ pub struct SvgExportFormatOption {
 
 /// PPTX export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PptxExportFormatOption {
     /// Whether PPTX export is available
     #[serde(default)]
@@ -114,6 +239,7 @@ pub struct PptxExportFormatOption {
 
 /// GIF export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GifExportFormatOption {
     /// Whether GIF export is available
     #[serde(default)]
@@ -122,12 +248,64 @@ pub struct GifExportFormatOption {
 
 /// MP4 export format option
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Mp4ExportFormatOption {
     /// Whether MP4 export is available
     #[serde(default)]
     pub available: bool,
 }
 
+/// Progress of an export download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportProgress {
+    /// 1-based number of the file currently downloading
+    pub page: usize,
+    /// Total number of files in the export
+    pub total_pages: usize,
+    /// Bytes downloaded so far for the current file
+    pub page_bytes: u64,
+    /// Size of the current file, if the server reported it
+    pub page_total_bytes: Option<u64>,
+    /// Bytes downloaded so far across all files
+    pub total_bytes: u64,
+}
+
+/// Settings for [`ExportsApi::download_all`]
+#[derive(Debug, Clone, Default)]
+pub struct ExportDownloadOptions {
+    limits: DownloadLimits,
+    checksums: bool,
+}
+
+impl ExportDownloadOptions {
+    /// Default [`DownloadLimits`], without checksums
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cap how many files are fetched at once and how large each may be
+    pub fn with_limits(mut self, limits: DownloadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Compute a SHA-256 checksum of each file
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.checksums = checksums;
+        self
+    }
+
+    /// Parallelism and size caps for the download
+    pub fn limits(&self) -> &DownloadLimits {
+        &self.limits
+    }
+
+    /// Whether checksums are computed
+    pub fn checksums(&self) -> bool {
+        self.checksums
+    }
+}
+
 impl ExportsApi {
     /// Create a new exports API client
     pub fn new(client: Client) -> Self {
@@ -144,8 +322,13 @@ impl ExportsApi {
         &self,
         request: &CreateDesignExportJobRequest,
     ) -> Result<CreateDesignExportJobResponse> {
-        let response = self.client.post("/v1/exports", request).await?;
-        Ok(response.json::<CreateDesignExportJobResponse>().await?)
+        let response = self.client.post(routes::EXPORTS, request).await?;
+        let mut response = self
+            .client
+            .read_json::<CreateDesignExportJobResponse>(response)
+            .await?;
+        response.job.fetched_at = Some(chrono::Utc::now());
+        Ok(response)
     }
 
     /// Get a design export job
@@ -158,9 +341,14 @@ impl ExportsApi {
         &self,
         export_id: &str,
     ) -> Result<GetDesignExportJobResponse> {
-        let url = format!("/v1/exports/{export_id}");
+        let url = routes::export_job(export_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetDesignExportJobResponse>().await?)
+        let mut response = self
+            .client
+            .read_json::<GetDesignExportJobResponse>(response)
+            .await?;
+        response.job.fetched_at = Some(chrono::Utc::now());
+        Ok(response)
     }
 
     /// Get available export formats for a design
@@ -173,17 +361,606 @@ impl ExportsApi {
         &self,
         design_id: &str,
     ) -> Result<GetDesignExportFormatsResponse> {
-        let url = format!("/v1/designs/{design_id}/export-formats");
+        let url = routes::design_export_formats(design_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetDesignExportFormatsResponse>().await?)
+        self.client
+            .read_json::<GetDesignExportFormatsResponse>(response)
+            .await
+    }
+
+    /// Create an export job after checking that the design supports `format`
+    ///
+    /// Fetches the design's export formats first and fails with
+    /// [`Error::UnsupportedExportFormat`], listing the formats it does
+    /// support, instead of letting the job fail on Canva's side. A JPG
+    /// `quality` outside 1-100 fails with [`Error::Generic`] before any
+    /// request is made. Returns the new job, which is usually still running.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, Error, auth::AccessToken, models::ExportFormat};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("token"))?;
+    /// match client.exports().create_export_checked("DAFVztcvd9z", ExportFormat::mp4()).await {
+    ///     Ok(job) => println!("Exporting: {}", job.id),
+    ///     Err(Error::UnsupportedExportFormat { supported, .. }) => {
+    ///         println!("Try one of: {}", supported.join(", "));
+    ///     }
+    ///     Err(error) => return Err(error.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn create_export_checked(
+        &self,
+        design_id: &str,
+        format: ExportFormat,
+    ) -> Result<ExportJob> {
+        if let ExportFormat::Jpg { quality, .. } = format {
+            if !(1..=100).contains(&quality) {
+                return Err(Error::Generic(format!(
+                    "Invalid JPG quality {quality}: must be 1 to 100"
+                )));
+            }
+        }
+        let options = self.get_design_export_formats(design_id).await?.formats;
+        if !options.supports(&format) {
+            return Err(Error::UnsupportedExportFormat {
+                design_id: design_id.to_string(),
+                format: format.name().to_string(),
+                supported: options.supported().into_iter().map(String::from).collect(),
+            });
+        }
+        let request = CreateDesignExportJobRequest {
+            design_id: design_id.to_string(),
+            format,
+        };
+        Ok(self.create_design_export_job(&request).await?.job)
+    }
+
+    /// Wait for an export job to complete
+    ///
+    /// Returns the finished job, whose [`urls`](ExportJob::urls) are ready to download.
+    ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_export_job(&self, export_id: &str) -> Result<ExportJob> {
+        self.client
+            .job_poller()
+            .wait(&self.pending_export_job(export_id))
+            .await
+    }
+
+    /// Like [`wait_for_export_job`](Self::wait_for_export_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_export_job_with(
+        &self,
+        export_id: &str,
+        options: &WaitOptions,
+    ) -> Result<ExportJob> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_export_job(export_id))
+            .await
+    }
+
+    /// An export job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_export_job(&self, export_id: &str) -> PendingExportJob {
+        PendingExportJob {
+            api: self.clone(),
+            export_id: export_id.to_string(),
+        }
+    }
+
+    /// Fetch a completed export job again for fresh download URLs
+    ///
+    /// Download URLs expire [`EXPORT_URL_LIFETIME`](crate::models::EXPORT_URL_LIFETIME)
+    /// after they are issued. The download methods here call this
+    /// themselves when [`ExportJob::is_likely_expired`] says so, and once
+    /// more if a URL is still rejected with `403 Forbidden`; call it
+    /// directly before handing URLs to other code.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn refresh_export(&self, job_id: &str) -> Result<ExportJob> {
+        let job = self.get_design_export_job(job_id).await?.job;
+        ensure_downloadable(&job)?;
+        Ok(job)
+    }
+
+    /// `job` if its URLs can be downloaded, or a refreshed copy if they have likely expired
+    async fn downloadable<'a>(&self, job: &'a ExportJob) -> Result<Cow<'a, ExportJob>> {
+        ensure_downloadable(job)?;
+        if job.is_likely_expired() {
+            Ok(Cow::Owned(self.refresh_export(&job.id).await?))
+        } else {
+            Ok(Cow::Borrowed(job))
+        }
+    }
+
+    /// Stream every file of a completed export job into a writer, in page order
+    ///
+    /// Returns the total number of bytes written. Exports with several files
+    /// (for example one PNG per page) are written back to back, so this is
+    /// most useful for single-file formats such as PDF or PPTX; use
+    /// [`export_to_path`](Self::export_to_path) to get one file per page.
+    pub async fn download_export<W>(&self, job: &ExportJob, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.download_export_with_progress(job, writer, |_| {})
+            .await
+    }
+
+    /// Like [`download_export`](Self::download_export), reporting progress after each chunk
+    pub async fn download_export_with_progress<W, F>(
+        &self,
+        job: &ExportJob,
+        writer: &mut W,
+        mut on_progress: F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(&ExportProgress),
+    {
+        let job = self.downloadable(job).await?;
+        let refreshed = OnceCell::new();
+        let total_pages = job.urls.len();
+        let mut total_bytes = 0;
+
+        for index in 0..total_pages {
+            total_bytes += self
+                .download_page(
+                    &job,
+                    index,
+                    &refreshed,
+                    writer,
+                    total_bytes,
+                    &mut on_progress,
+                )
+                .await?;
+        }
+        writer.flush().await?;
+
+        Ok(total_bytes)
+    }
+
+    /// Export a design and save the result to `path`
+    ///
+    /// Creates the export job, waits for it to complete and streams the files
+    /// to disk. Single-file exports are written to `path` itself; exports with
+    /// several files are written next to it with a page suffix, e.g.
+    /// `design-1.png`, `design-2.png`. Returns the paths written.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn export_to_path(
+        &self,
+        design_id: &str,
+        format: ExportFormat,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>> {
+        self.export_to_path_with_progress(design_id, format, path, |_| {})
+            .await
+    }
+
+    /// Like [`export_to_path`](Self::export_to_path), reporting download progress after each chunk
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn export_to_path_with_progress<F>(
+        &self,
+        design_id: &str,
+        format: ExportFormat,
+        path: impl AsRef<Path>,
+        mut on_progress: F,
+    ) -> Result<Vec<PathBuf>>
+    where
+        F: FnMut(&ExportProgress),
+    {
+        let request = CreateDesignExportJobRequest {
+            design_id: design_id.to_string(),
+            format,
+        };
+        let created = self.create_design_export_job(&request).await?;
+        let job = self.wait_for_export_job(&created.job.id).await?;
+        self.save_export(&job, path.as_ref(), &mut on_progress)
+            .await
+    }
+
+    /// Export many designs, running up to `concurrency` exports at once
+    ///
+    /// Each export is created and then polled to completion; the stream
+    /// yields every design ID with its finished job, or the error that stopped
+    /// it, in the order they finish. One failed export does not affect the
+    /// others. Requests still pass through the client's rate limiters, so a
+    /// high `concurrency` mostly means more jobs waiting on Canva at once. A
+    /// `concurrency` of 0 is treated as 1. Jobs are polled with jitter, like
+    /// a [`JobManager`](crate::jobs::JobManager)'s, so they do not poll in step.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, models::ExportFormat};
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("your-token"))?;
+    /// let exports = client.exports();
+    /// let mut results = exports.export_designs(["DAFVztcvd9z", "DAGQm2AkzOk"], ExportFormat::pdf(), 4);
+    /// while let Some((design_id, result)) = results.next().await {
+    ///     match result {
+    ///         Ok(job) => println!("{design_id}: {} file(s)", job.urls.len()),
+    ///         Err(e) => eprintln!("{design_id}: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub fn export_designs<I>(
+        &self,
+        design_ids: I,
+        format: ExportFormat,
+        concurrency: usize,
+    ) -> impl Stream<Item = (String, Result<ExportJob>)> + '_
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let design_ids: Vec<String> = design_ids.into_iter().map(Into::into).collect();
+        futures::stream::iter(design_ids)
+            .map(move |design_id| {
+                let request = CreateDesignExportJobRequest {
+                    design_id,
+                    format: format.clone(),
+                };
+                async move {
+                    let result = match self.create_design_export_job(&request).await {
+                        Ok(created) => {
+                            self.client
+                                .batch_job_poller()
+                                .wait(&self.pending_export_job(&created.job.id))
+                                .await
+                        }
+                        Err(e) => Err(e),
+                    };
+                    (request.design_id, result)
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Stream the files of a completed export job to `path`, one per page,
+    /// named as [`export_to_path`](Self::export_to_path) describes
+    pub(crate) async fn save_export<F>(
+        &self,
+        job: &ExportJob,
+        path: &Path,
+        on_progress: &mut F,
+    ) -> Result<Vec<PathBuf>>
+    where
+        F: FnMut(&ExportProgress),
+    {
+        let job = self.downloadable(job).await?;
+        let refreshed = OnceCell::new();
+
+        let paths = page_paths(path, job.urls.len());
+        let mut total_bytes = 0;
+
+        for (index, path) in paths.iter().enumerate() {
+            let mut file = tokio::fs::File::create(path).await?;
+            total_bytes += self
+                .download_page(&job, index, &refreshed, &mut file, total_bytes, on_progress)
+                .await?;
+            file.flush().await?;
+        }
+
+        Ok(paths)
+    }
+
+    /// Download every file of a completed export job to disk in parallel
+    ///
+    /// Files are named like [`export_to_path`](Self::export_to_path) names
+    /// them. At most [`DownloadLimits::max_concurrency`] files are fetched at
+    /// once, and at most [`DownloadLimits::max_per_host`] from any one host.
+    /// Files are written by a [`Downloader`](crate::downloads::Downloader),
+    /// so an interrupted run resumes from its `.part` files. URLs rejected
+    /// with `403 Forbidden` are refreshed once with
+    /// [`refresh_export`](Self::refresh_export). Returns per-file and
+    /// aggregate timing in a [`DownloadReport`].
+    pub async fn download_export_to_path(
+        &self,
+        job: &ExportJob,
+        path: impl AsRef<Path>,
+        limits: &DownloadLimits,
+    ) -> Result<DownloadReport> {
+        let job = self.downloadable(job).await?;
+        let refreshed = OnceCell::new();
+        let refresh_url = |index| self.fresh_url(&job, index, &refreshed).boxed();
+        self.client
+            .downloader()
+            .with_limits(*limits)
+            .download_all_refreshing(
+                &DownloadRequest::for_export(&job, path)?,
+                Some(&refresh_url),
+            )
+            .await
+    }
+
+    /// Download every file of a completed export job into memory
+    ///
+    /// The result looks the same whether Canva returned one file for the
+    /// whole design or one file per page; see [`ExportedDocument`]. Files are
+    /// held in memory, so prefer [`download_export_to_path`](Self::download_export_to_path)
+    /// for large video exports, or cap their size with
+    /// [`download_document_with`](Self::download_document_with).
+    pub async fn download_document(&self, job: &ExportJob) -> Result<ExportedDocument> {
+        self.download_document_with(job, &DownloadLimits::default())
+            .await
+    }
+
+    /// Like [`download_document`](Self::download_document), within `limits`
+    ///
+    /// Files are fetched in parallel within the concurrency caps, and a file
+    /// over [`DownloadLimits::max_file_bytes`] fails the download.
+    pub async fn download_document_with(
+        &self,
+        job: &ExportJob,
+        limits: &DownloadLimits,
+    ) -> Result<ExportedDocument> {
+        let job = self.downloadable(job).await?;
+        let gate = DownloadGate::new(*limits);
+        let refreshed = OnceCell::new();
+
+        let downloads = job.urls.iter().enumerate().map(|(index, url)| {
+            let (gate, job, refreshed) = (&gate, &job, &refreshed);
+            async move {
+                let _slot = gate.acquire(&url_host(url)).await?;
+                let (_, bytes) = self.fetch_limited(job, index, refreshed, limits).await?;
+                Ok::<_, Error>(bytes)
+            }
+        });
+        let files = futures::future::try_join_all(downloads).await?;
+
+        Ok(ExportedDocument::new(
+            job.id.clone(),
+            url_extension(&job.urls[0]),
+            files,
+        ))
+    }
+
+    /// Download every file of a completed export job into memory, with metadata
+    ///
+    /// Files are fetched in parallel within the options'
+    /// [limits](ExportDownloadOptions::with_limits) and returned in page
+    /// order. Each [`DownloadedExport`] carries the content type the server
+    /// reported and, with [`with_checksums`](ExportDownloadOptions::with_checksums),
+    /// a SHA-256 of the content for integrity checks or deduplication.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, downloads::DownloadLimits};
+    /// use canva_connect::endpoints::exports::ExportDownloadOptions;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(AccessToken::new("your-token"))?;
+    /// let job = client.exports().wait_for_export_job("export-id").await?;
+    ///
+    /// let options = ExportDownloadOptions::new()
+    ///     .with_limits(DownloadLimits::new(4))
+    ///     .with_checksums(true);
+    /// for file in client.exports().download_all(&job, &options).await? {
+    ///     println!("page {}: {} bytes, sha256 {:?}", file.page, file.size, file.sha256);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_all(
+        &self,
+        job: &ExportJob,
+        options: &ExportDownloadOptions,
+    ) -> Result<Vec<DownloadedExport>> {
+        let job = self.downloadable(job).await?;
+        let gate = DownloadGate::new(options.limits);
+        let refreshed = OnceCell::new();
+
+        let downloads = job.urls.iter().enumerate().map(|(index, url)| {
+            let (gate, job, refreshed) = (&gate, &job, &refreshed);
+            async move {
+                let _slot = gate.acquire(&url_host(url)).await?;
+                let (content_type, bytes) = self
+                    .fetch_limited(job, index, refreshed, &options.limits)
+                    .await?;
+
+                Ok::<_, Error>(DownloadedExport {
+                    page: index as u32 + 1,
+                    url: url.clone(),
+                    content_type,
+                    extension: url_extension(url),
+                    size: bytes.len() as u64,
+                    sha256: options.checksums.then(|| hex(&Sha256::digest(&bytes))),
+                    bytes,
+                })
+            }
+        });
+        futures::future::try_join_all(downloads).await
+    }
+
+    /// Request file `index` of `job`, fetching the job again for fresh URLs
+    /// if its URL is rejected with `403 Forbidden`
+    async fn fetch_page(
+        &self,
+        job: &ExportJob,
+        index: usize,
+        refreshed: &OnceCell<ExportJob>,
+    ) -> Result<reqwest::Response> {
+        let already_refreshed = refreshed.initialized();
+        let url = if already_refreshed {
+            self.fresh_url(job, index, refreshed).await?
+        } else {
+            job.urls[index].clone()
+        };
+        let mut response = self.client.fetch_url(&url).await?;
+        if response.status() == reqwest::StatusCode::FORBIDDEN && !already_refreshed {
+            let url = self.fresh_url(job, index, refreshed).await?;
+            response = self.client.fetch_url(&url).await?;
+        }
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::download(status, response.url()));
+        }
+        Ok(response)
+    }
+
+    /// URL of file `index` of `job` once the job has been fetched again
+    ///
+    /// Files downloaded together share `refreshed`, so the job is fetched
+    /// again at most once however many of its URLs have expired.
+    async fn fresh_url(
+        &self,
+        job: &ExportJob,
+        index: usize,
+        refreshed: &OnceCell<ExportJob>,
+    ) -> Result<String> {
+        let fresh = refreshed
+            .get_or_try_init(|| self.refresh_export(&job.id))
+            .await?;
+        fresh.urls.get(index).cloned().ok_or_else(|| {
+            Error::Generic(format!(
+                "Export job {} has no file {} after refreshing its URLs",
+                job.id,
+                index + 1
+            ))
+        })
+    }
+
+    /// Read file `index` of `job` into memory within the file size cap in
+    /// `limits`, returning the content type the server sent and the content
+    async fn fetch_limited(
+        &self,
+        job: &ExportJob,
+        index: usize,
+        refreshed: &OnceCell<ExportJob>,
+        limits: &DownloadLimits,
+    ) -> Result<(Option<String>, Vec<u8>)> {
+        let mut response = self.fetch_page(job, index, refreshed).await?;
+        let url = response.url().to_string();
+        if let Some(length) = response.content_length() {
+            limits.check_file_size(&url, length)?;
+        }
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            bytes.extend_from_slice(&chunk);
+            limits.check_file_size(&url, bytes.len() as u64)?;
+        }
+        Ok((content_type, bytes))
+    }
+
+    /// Stream file `index` of `job` into a writer, returning the bytes written
+    async fn download_page<W, F>(
+        &self,
+        job: &ExportJob,
+        index: usize,
+        refreshed: &OnceCell<ExportJob>,
+        writer: &mut W,
+        bytes_before: u64,
+        on_progress: &mut F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(&ExportProgress),
+    {
+        let mut response = self.fetch_page(job, index, refreshed).await?;
+        let page_total_bytes = response.content_length();
+        let mut page_bytes = 0;
+
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk).await?;
+            page_bytes += chunk.len() as u64;
+            on_progress(&ExportProgress {
+                page: index + 1,
+                total_pages: job.urls.len(),
+                page_bytes,
+                page_total_bytes,
+                total_bytes: bytes_before + page_bytes,
+            });
+        }
+
+        Ok(page_bytes)
+    }
+}
+
+/// Check that a job has finished successfully and has files to download
+pub(crate) fn ensure_downloadable(job: &ExportJob) -> Result<()> {
+    if job.status != JobStatus::Success {
+        return Err(Error::Generic(format!(
+            "Export job {} has not completed successfully",
+            job.id
+        )));
+    }
+    if job.urls.is_empty() {
+        return Err(Error::Generic(format!(
+            "Export job {} has no download URLs",
+            job.id
+        )));
     }
+    Ok(())
+}
+
+/// Lowercase file extension of the path in a download URL, if any
+fn url_extension(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let file_name = url.path_segments()?.next_back()?;
+    let (_, extension) = file_name.rsplit_once('.')?;
+    (!extension.is_empty()).then(|| extension.to_ascii_lowercase())
+}
+
+/// Destination paths for an export with `count` files
+pub(crate) fn page_paths(path: &Path, count: usize) -> Vec<PathBuf> {
+    if count <= 1 {
+        return vec![path.to_path_buf()];
+    }
+
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().into_owned());
+
+    (1..=count)
+        .map(|page| {
+            let file_name = match &extension {
+                Some(ext) => format!("{stem}-{page}.{ext}"),
+                None => format!("{stem}-{page}"),
+            };
+            path.with_file_name(file_name)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use super::*;
-    use crate::{auth::AccessToken, models::ExportQuality};
+    use crate::{
+        auth::AccessToken,
+        models::{ExportPageSize, ExportQuality},
+    };
 
     #[test]
     fn test_exports_api_creation() {
@@ -193,6 +970,39 @@ mod tests {
         let _exports_api = client.exports();
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_export_job_with_times_out() {
+        use crate::testing::{MockResponse, MockTransport};
+        use std::time::Duration;
+
+        let transport = MockTransport::new();
+        transport.on(
+            reqwest::Method::GET,
+            "/rest/v1/exports/export_1",
+            MockResponse::json(
+                200,
+                &serde_json::json!({ "job": { "id": "export_1", "status": "in_progress" } }),
+            ),
+        );
+        let client = Client::builder(AccessToken::new("test_token"))
+            .transport(transport.clone())
+            .poll_interval(Duration::from_secs(2))
+            .build()
+            .expect("Failed to create client");
+
+        let options = WaitOptions::new().with_timeout(Duration::from_secs(5));
+        let result = client
+            .exports()
+            .wait_for_export_job_with("export_1", &options)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::JobTimeout { ref job_id, .. }) if job_id == "export_1"
+        ));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
     #[test]
     fn test_create_design_export_job_request_pdf() {
         let request = CreateDesignExportJobRequest {
@@ -550,4 +1360,732 @@ mod tests {
             _ => panic!("Expected JPG format"),
         }
     }
+
+    #[test]
+    fn test_export_format_constructors_serialize_like_literals() {
+        let format = ExportFormat::png().width(1920).pages([1, 2]);
+        assert_eq!(
+            serde_json::to_value(&format).expect("format serializes"),
+            serde_json::json!({ "type": "png", "width": 1920, "pages": [1, 2] })
+        );
+
+        let preset = ExportFormat::pdf_print_a4();
+        assert_eq!(
+            serde_json::to_value(&preset).expect("format serializes"),
+            serde_json::json!({ "type": "pdf", "export_quality": "pro", "size": "a4" })
+        );
+
+        // Options a format does not support are ignored
+        let pptx = ExportFormat::pptx().width(800).size(ExportPageSize::A3);
+        assert_eq!(
+            serde_json::to_value(&pptx).expect("format serializes"),
+            serde_json::json!({ "type": "pptx" })
+        );
+    }
+
+    fn success_job(urls: Vec<String>) -> ExportJob {
+        ExportJob {
+            id: "export_123".to_string(),
+            status: JobStatus::Success,
+            urls,
+            error: None,
+            fetched_at: None,
+        }
+    }
+
+    #[test]
+    fn test_export_job_deserializes_job_level_urls() {
+        let json = serde_json::json!({
+            "job": {
+                "id": "e08861ae-3b29-45db-8dc1-1fe0bf7f1cc8",
+                "status": "success",
+                "urls": [
+                    "https://export-download.canva.com/page-1.png",
+                    "https://export-download.canva.com/page-2.png"
+                ]
+            }
+        });
+
+        let response: GetDesignExportJobResponse =
+            serde_json::from_value(json).expect("Failed to deserialize");
+        let urls = response.job.export_urls();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[1].page, 2);
+        assert_eq!(urls[1].url, "https://export-download.canva.com/page-2.png");
+    }
+
+    #[test]
+    fn test_page_paths() {
+        assert_eq!(
+            page_paths(Path::new("out/design.pdf"), 1),
+            vec![PathBuf::from("out/design.pdf")]
+        );
+        assert_eq!(
+            page_paths(Path::new("out/design.png"), 2),
+            vec![
+                PathBuf::from("out/design-1.png"),
+                PathBuf::from("out/design-2.png")
+            ]
+        );
+        assert_eq!(
+            page_paths(Path::new("design"), 2),
+            vec![PathBuf::from("design-1"), PathBuf::from("design-2")]
+        );
+    }
+
+    #[test]
+    fn test_ensure_downloadable() {
+        assert!(ensure_downloadable(&success_job(vec!["https://x".to_string()])).is_ok());
+        assert!(ensure_downloadable(&success_job(Vec::new())).is_err());
+
+        let mut in_progress = success_job(vec!["https://x".to_string()]);
+        in_progress.status = JobStatus::InProgress;
+        assert!(ensure_downloadable(&in_progress).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_download_export_streams_pages_in_order() {
+        let mut server = mockito::Server::new_async().await;
+        let page_one = server
+            .mock("GET", "/download/1")
+            .match_header("authorization", mockito::Matcher::Missing)
+            .with_body("first,")
+            .create_async()
+            .await;
+        let page_two = server
+            .mock("GET", "/download/2")
+            .with_body("second")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(vec![
+            format!("{}/download/1", server.url()),
+            format!("{}/download/2", server.url()),
+        ]);
+
+        let mut progress = Vec::new();
+        let mut output = Vec::new();
+        let written = client
+            .exports()
+            .download_export_with_progress(&job, &mut output, |p| progress.push(p.clone()))
+            .await
+            .expect("Download should succeed");
+
+        assert_eq!(output, b"first,second");
+        assert_eq!(written, 12);
+        let last = progress.last().expect("progress reported");
+        assert_eq!((last.page, last.total_pages, last.total_bytes), (2, 2, 12));
+        page_one.assert_async().await;
+        page_two.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_download_document_normalizes_files() {
+        let mut server = mockito::Server::new_async().await;
+        let _pages = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(r"^/download/\d\.PNG".to_string()),
+            )
+            .with_body("page")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(vec![
+            format!("{}/download/1.PNG?sig=abc", server.url()),
+            format!("{}/download/2.PNG?sig=def", server.url()),
+        ]);
+
+        let document = client
+            .exports()
+            .download_document(&job)
+            .await
+            .expect("Download should succeed");
+
+        assert_eq!(document.job_id(), "export_123");
+        assert_eq!(document.extension(), Some("png"));
+        assert_eq!(document.pages().len(), 2);
+        assert_eq!(
+            document.file_names("deck"),
+            vec!["deck-1.png", "deck-2.png"]
+        );
+        assert_eq!(url_extension("https://x/download"), None);
+    }
+
+    #[tokio::test]
+    async fn test_download_document_with_rejects_files_over_the_cap() {
+        let mut server = mockito::Server::new_async().await;
+        let _small = server
+            .mock("GET", "/download/1.pdf")
+            .with_body("tiny")
+            .create_async()
+            .await;
+        let _large = server
+            .mock("GET", "/download/2.pdf")
+            .with_body("far too large")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let exports = client.exports();
+        let small = success_job(vec![format!("{}/download/1.pdf", server.url())]);
+        let both = success_job(vec![
+            format!("{}/download/1.pdf", server.url()),
+            format!("{}/download/2.pdf", server.url()),
+        ]);
+        let limits = DownloadLimits::sequential().with_max_file_bytes(8);
+
+        let document = exports
+            .download_document_with(&small, &limits)
+            .await
+            .expect("small file fits");
+        assert_eq!(document.pages()[0].bytes, b"tiny");
+
+        let error = exports
+            .download_document_with(&both, &limits)
+            .await
+            .expect_err("second file is over the cap");
+        assert!(error.to_string().contains("8 byte limit"));
+    }
+
+    #[tokio::test]
+    async fn test_download_refreshes_likely_expired_urls() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh_url = format!("{}/download/fresh.pdf", server.url());
+        let refresh = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_123", "status": "success", "urls": [fresh_url] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let fresh = server
+            .mock("GET", "/download/fresh.pdf")
+            .with_body("pdf")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let mut job = success_job(vec![format!("{}/download/stale.pdf", server.url())]);
+        job.fetched_at = Some(chrono::Utc::now() - chrono::Duration::hours(25));
+        assert!(job.is_likely_expired());
+
+        let mut bytes = Vec::new();
+        client
+            .exports()
+            .download_export(&job, &mut bytes)
+            .await
+            .expect("Download should succeed");
+
+        assert_eq!(bytes, b"pdf");
+        refresh.assert_async().await;
+        fresh.assert_async().await;
+
+        job.fetched_at = Some(chrono::Utc::now());
+        assert!(!job.is_likely_expired());
+    }
+
+    #[tokio::test]
+    async fn test_create_export_checked_lists_supported_formats() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/designs/DAF123/export-formats")
+            .with_body(serde_json::json!({ "formats": { "pdf": {}, "png": {} } }).to_string())
+            .create_async()
+            .await;
+        let create = server
+            .mock("POST", "/v1/exports")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "design_id": "DAF123",
+                "format": { "type": "png" }
+            })))
+            .with_body(
+                serde_json::json!({ "job": { "id": "export_123", "status": "in_progress" } })
+                    .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let exports = client.exports();
+
+        let unsupported = exports
+            .create_export_checked("DAF123", ExportFormat::mp4())
+            .await;
+        match unsupported {
+            Err(Error::UnsupportedExportFormat {
+                format, supported, ..
+            }) => {
+                assert_eq!(format, "mp4");
+                assert_eq!(supported, vec!["pdf", "png"]);
+            }
+            other => panic!("expected UnsupportedExportFormat, got {other:?}"),
+        }
+
+        let job = exports
+            .create_export_checked("DAF123", ExportFormat::png())
+            .await
+            .expect("png is supported");
+        assert_eq!(job.id, "export_123");
+        create.assert_async().await;
+
+        let invalid = exports
+            .create_export_checked("DAF123", ExportFormat::jpg(0))
+            .await;
+        assert!(matches!(invalid, Err(Error::Generic(_))));
+    }
+
+    #[tokio::test]
+    async fn test_download_export_refreshes_rejected_urls_once() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh_url = format!("{}/download/fresh.pdf", server.url());
+        let expired = server
+            .mock("GET", "/download/expired.pdf")
+            .with_status(403)
+            .expect(1)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_123", "status": "success", "urls": [fresh_url] }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let fresh = server
+            .mock("GET", "/download/fresh.pdf")
+            .with_body("pdf bytes")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(vec![format!("{}/download/expired.pdf", server.url())]);
+
+        let mut output = Vec::new();
+        let written = client
+            .exports()
+            .download_export(&job, &mut output)
+            .await
+            .expect("download after refresh");
+        assert_eq!(written, 9);
+        assert_eq!(output, b"pdf bytes");
+        expired.assert_async().await;
+        refresh.assert_async().await;
+        fresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_download_export_reports_http_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let _missing = server
+            .mock("GET", "/download/1")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(vec![format!("{}/download/1", server.url())]);
+
+        let result = client
+            .exports()
+            .download_export(&job, &mut Vec::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Download { status, .. }) if status == reqwest::StatusCode::NOT_FOUND
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_download_all_shares_one_refresh() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh_urls: Vec<String> = (1..=3)
+            .map(|page| format!("{}/download/fresh-{page}.png", server.url()))
+            .collect();
+        let _expired = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(r"^/download/old-\d\.png$".to_string()),
+            )
+            .with_status(403)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_123", "status": "success", "urls": fresh_urls }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let _fresh = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(r"^/download/fresh-\d\.png$".to_string()),
+            )
+            .with_body("png")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(
+            (1..=3)
+                .map(|page| format!("{}/download/old-{page}.png", server.url()))
+                .collect(),
+        );
+
+        let files = client
+            .exports()
+            .download_all(&job, &ExportDownloadOptions::new())
+            .await
+            .expect("download after refresh");
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|file| file.bytes == b"png"));
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_export_to_path_writes_file() {
+        let mut server = mockito::Server::new_async().await;
+        let download_url = format!("{}/download/design.pdf", server.url());
+        let _create = server
+            .mock("POST", "/v1/exports")
+            .with_body(
+                serde_json::json!({ "job": { "id": "export_123", "status": "in_progress" } })
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+        let _get = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_123", "status": "success", "urls": [download_url] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let _download = server
+            .mock("GET", "/download/design.pdf")
+            .with_body("%PDF-1.7")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let path = std::env::temp_dir().join(format!("canva-export-{}.pdf", uuid::Uuid::new_v4()));
+        let format = ExportFormat::Pdf {
+            export_quality: None,
+            size: None,
+            pages: None,
+        };
+
+        let paths = client
+            .exports()
+            .export_to_path("DAFVztcvd9z", format, &path)
+            .await
+            .expect("Export should succeed");
+
+        assert_eq!(paths, vec![path.clone()]);
+        let contents = std::fs::read(&path).expect("File should exist");
+        assert_eq!(contents, b"%PDF-1.7");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_download_export_to_path_respects_limits() {
+        let mut server = mockito::Server::new_async().await;
+        let mut mocks = Vec::new();
+        for page in 1..=3 {
+            mocks.push(
+                server
+                    .mock("GET", format!("/download/{page}.png").as_str())
+                    .with_body(format!("page-{page}"))
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(
+            (1..=3)
+                .map(|page| format!("{}/download/{page}.png", server.url()))
+                .collect(),
+        );
+        let path = std::env::temp_dir().join(format!("canva-pages-{}.png", uuid::Uuid::new_v4()));
+
+        let report = client
+            .exports()
+            .download_export_to_path(&job, &path, &DownloadLimits::new(4).with_max_per_host(1))
+            .await
+            .expect("Download should succeed");
+
+        assert_eq!(report.files.len(), 3);
+        assert_eq!(report.total_bytes, 18);
+        assert_eq!(report.peak_concurrency, 1);
+        assert_eq!(report.paths(), page_paths(&path, 3));
+        assert_eq!(report.files[0].host, "127.0.0.1");
+        for (page, file) in report.files.iter().enumerate() {
+            let contents = std::fs::read(&file.path).expect("File should exist");
+            assert_eq!(contents, format!("page-{}", page + 1).as_bytes());
+            let _ = std::fs::remove_file(&file.path);
+        }
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_export_to_path_refreshes_rejected_urls_once() {
+        let mut server = mockito::Server::new_async().await;
+        let expired = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(r"^/download/expired/\d\.png$".into()),
+            )
+            .with_status(403)
+            .expect(2)
+            .create_async()
+            .await;
+        let fresh_urls: Vec<String> = (1..=2)
+            .map(|page| format!("{}/download/fresh/{page}.png", server.url()))
+            .collect();
+        let refresh = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_123", "status": "success", "urls": fresh_urls }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let mut fresh = Vec::new();
+        for page in 1..=2 {
+            fresh.push(
+                server
+                    .mock("GET", format!("/download/fresh/{page}.png").as_str())
+                    .with_body(format!("page-{page}"))
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(
+            (1..=2)
+                .map(|page| format!("{}/download/expired/{page}.png", server.url()))
+                .collect(),
+        );
+        let path = std::env::temp_dir().join(format!("canva-pages-{}.png", uuid::Uuid::new_v4()));
+
+        let report = client
+            .exports()
+            .download_export_to_path(&job, &path, &DownloadLimits::default())
+            .await
+            .expect("download after refresh");
+
+        assert_eq!(report.total_bytes, 12);
+        for (page, file) in report.files.iter().enumerate() {
+            let contents = std::fs::read(&file.path).expect("File should exist");
+            assert_eq!(contents, format!("page-{}", page + 1).as_bytes());
+            let _ = std::fs::remove_file(&file.path);
+        }
+        expired.assert_async().await;
+        refresh.assert_async().await;
+        for mock in fresh {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_all_returns_metadata_in_page_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mut mocks = Vec::new();
+        for page in 1..=3 {
+            mocks.push(
+                server
+                    .mock("GET", format!("/download/{page}.PNG").as_str())
+                    .with_header("content-type", "image/png")
+                    .with_body(format!("page-{page}"))
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(
+            (1..=3)
+                .map(|page| format!("{}/download/{page}.PNG", server.url()))
+                .collect(),
+        );
+        let options = ExportDownloadOptions::new()
+            .with_limits(DownloadLimits::new(2))
+            .with_checksums(true);
+
+        let files = client
+            .exports()
+            .download_all(&job, &options)
+            .await
+            .expect("Download should succeed");
+
+        let pages: Vec<u32> = files.iter().map(|file| file.page).collect();
+        assert_eq!(pages, vec![1, 2, 3]);
+        assert_eq!(files[1].bytes, b"page-2");
+        assert_eq!(files[1].size, 6);
+        assert_eq!(files[1].content_type.as_deref(), Some("image/png"));
+        assert_eq!(files[1].extension.as_deref(), Some("png"));
+        assert_eq!(
+            files[0].sha256.as_deref(),
+            Some("0eb236e50de35c59c03b63629624351af778cc33fbc55a92254e3c29e58e6255")
+        );
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_all_skips_checksums_by_default() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/download/design.pdf")
+            .with_body("%PDF")
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = success_job(vec![format!("{}/download/design.pdf", server.url())]);
+
+        let files = client
+            .exports()
+            .download_all(&job, &ExportDownloadOptions::default())
+            .await
+            .expect("Download should succeed");
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].sha256, None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_export_job_can_be_cancelled_and_resumed() {
+        let mut server = mockito::Server::new_async().await;
+        let in_progress = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(r#"{"job":{"id":"export_123","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let exports = client.exports();
+
+        let timed_out = tokio::time::timeout(
+            std::time::Duration::from_millis(200),
+            exports.wait_for_export_job("export_123"),
+        )
+        .await;
+        assert!(timed_out.is_err());
+        in_progress.assert_async().await;
+        in_progress.remove_async().await;
+
+        let _done = server
+            .mock("GET", "/v1/exports/export_123")
+            .with_body(
+                r#"{"job":{"id":"export_123","status":"success","urls":["https://x/1.pdf"]}}"#,
+            )
+            .create_async()
+            .await;
+        let job = exports
+            .wait_for_export_job("export_123")
+            .await
+            .expect("Resumed wait should succeed");
+        assert_eq!(job.urls, vec!["https://x/1.pdf"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_designs_reports_each_design() {
+        use futures::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        for design_id in ["D1", "D2"] {
+            server
+                .mock("POST", "/v1/exports")
+                .match_body(mockito::Matcher::PartialJson(
+                    serde_json::json!({ "design_id": design_id }),
+                ))
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"job":{{"id":"export_{design_id}","status":"in_progress"}}}}"#
+                ))
+                .create_async()
+                .await;
+            server
+                .mock("GET", format!("/v1/exports/export_{design_id}").as_str())
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"job":{{"id":"export_{design_id}","status":"success","urls":["https://x/{design_id}.pdf"]}}}}"#
+                ))
+                .create_async()
+                .await;
+        }
+        server
+            .mock("POST", "/v1/exports")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "design_id": "missing" }),
+            ))
+            .with_status(404)
+            .with_body(r#"{"code":"NOT_FOUND","message":"No such design"}"#)
+            .create_async()
+            .await;
+        let client = Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client");
+        let exports = client.exports();
+
+        let mut results: Vec<(String, Result<ExportJob>)> = exports
+            .export_designs(["D1", "missing", "D2"], ExportFormat::pdf(), 2)
+            .collect()
+            .await;
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let ids: Vec<_> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["D1", "D2", "missing"]);
+        let urls = |index: usize| match &results[index].1 {
+            Ok(job) => job.urls.clone(),
+            Err(e) => panic!("export failed: {e}"),
+        };
+        assert_eq!(urls(0), vec!["https://x/D1.pdf"]);
+        assert_eq!(urls(1), vec!["https://x/D2.pdf"]);
+        assert!(matches!(results[2].1, Err(Error::Api { .. })));
+    }
 }
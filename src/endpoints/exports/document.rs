@@ -0,0 +1,395 @@
+//! Exported documents held in memory.
+//!
+//! Depending on the format and design, Canva returns a paged export either as
+//! one file containing every page or as one file per page. An
+//! [`ExportedDocument`] hides that difference: [`pages`](ExportedDocument::pages)
+//! lists the files in page order, [`file_names`](ExportedDocument::file_names)
+//! names them the same way for both shapes, and with the `pdf-merge` feature
+//! `merge_into_single_pdf` always produces one PDF.
+
+use crate::error::Result;
+use std::path::{Path, PathBuf};
+
+/// One file of an export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedPage {
+    /// 1-based position of the file in the export
+    pub number: u32,
+    /// File content
+    pub bytes: Vec<u8>,
+}
+
+/// One file of an export, downloaded with its metadata
+///
+/// Returned by [`ExportsApi::download_all`](super::ExportsApi::download_all).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedExport {
+    /// 1-based position of the file in the export
+    pub page: u32,
+    /// URL the file was downloaded from
+    pub url: String,
+    /// `Content-Type` the server sent, e.g. `application/pdf`
+    pub content_type: Option<String>,
+    /// Lowercase file extension from the download URL, e.g. `pdf`
+    pub extension: Option<String>,
+    /// File size in bytes
+    pub size: u64,
+    /// Hex SHA-256 of the content, if checksums were requested
+    pub sha256: Option<String>,
+    /// File content
+    pub bytes: Vec<u8>,
+}
+
+/// The files of a completed export, downloaded into memory
+///
+/// Created by [`ExportsApi::download_document`](super::ExportsApi::download_document).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedDocument {
+    job_id: String,
+    extension: Option<String>,
+    pages: Vec<ExportedPage>,
+}
+
+impl ExportedDocument {
+    /// Build a document from file contents in page order
+    ///
+    /// `extension` is the file extension without a dot, e.g. `"pdf"`.
+    pub fn new(job_id: impl Into<String>, extension: Option<String>, files: Vec<Vec<u8>>) -> Self {
+        Self {
+            job_id: job_id.into(),
+            extension,
+            pages: files
+                .into_iter()
+                .enumerate()
+                .map(|(index, bytes)| ExportedPage {
+                    number: index as u32 + 1,
+                    bytes,
+                })
+                .collect(),
+        }
+    }
+
+    /// ID of the export job the files came from
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// File extension of the exported files, if known
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// The exported files in page order
+    ///
+    /// A single-file export has one entry holding every page.
+    pub fn pages(&self) -> &[ExportedPage] {
+        &self.pages
+    }
+
+    /// Whether Canva returned the whole export as one file
+    pub fn is_single_file(&self) -> bool {
+        self.pages.len() == 1
+    }
+
+    /// Total size of all files in bytes
+    pub fn total_bytes(&self) -> u64 {
+        self.pages.iter().map(|page| page.bytes.len() as u64).sum()
+    }
+
+    /// File names for the pages, built from `stem`
+    ///
+    /// A single file is named `{stem}.{ext}`; several files are named
+    /// `{stem}-1.{ext}`, `{stem}-2.{ext}` and so on, matching
+    /// [`ExportsApi::export_to_path`](super::ExportsApi::export_to_path).
+    pub fn file_names(&self, stem: &str) -> Vec<String> {
+        let file_name = match &self.extension {
+            Some(ext) => format!("{stem}.{ext}"),
+            None => stem.to_string(),
+        };
+        super::page_paths(Path::new(&file_name), self.pages.len())
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// Write every page into `dir`, named as by [`file_names`](Self::file_names)
+    ///
+    /// Returns the paths written.
+    pub async fn write_to_dir(&self, dir: impl AsRef<Path>, stem: &str) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::with_capacity(self.pages.len());
+        for (page, name) in self.pages.iter().zip(self.file_names(stem)) {
+            let path = dir.as_ref().join(name);
+            tokio::fs::write(&path, &page.bytes).await?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Write the export as a single PDF
+    ///
+    /// A single-file export is written unchanged; per-page PDFs are merged
+    /// in page order. Fails if any file is not a PDF.
+    #[cfg(feature = "pdf-merge")]
+    pub fn merge_into_single_pdf<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        if let [page] = self.pages.as_slice() {
+            writer.write_all(&page.bytes)?;
+            return Ok(());
+        }
+        let files: Vec<&[u8]> = self
+            .pages
+            .iter()
+            .map(|page| page.bytes.as_slice())
+            .collect();
+        let mut merged = merge_pdfs(&files)
+            .map_err(|e| crate::error::Error::Generic(format!("Failed to merge PDF pages: {e}")))?;
+        merged.save_to(writer)?;
+        Ok(())
+    }
+}
+
+/// Page attributes a page takes from its ancestors in the page tree
+#[cfg(feature = "pdf-merge")]
+const INHERITABLE_PAGE_ATTRIBUTES: [&[u8]; 4] = [b"Resources", b"MediaBox", b"CropBox", b"Rotate"];
+
+/// Copy attributes the page inherits from its page tree onto the page itself
+///
+/// The source page trees are replaced when merging, so anything a page only
+/// had through an ancestor, such as a `MediaBox` set once for the whole
+/// document, would otherwise be lost.
+#[cfg(feature = "pdf-merge")]
+fn inherit_page_attributes(doc: &lopdf::Document, page: &mut lopdf::Dictionary) {
+    let parent = |node: &lopdf::Dictionary| node.get(b"Parent").and_then(|p| p.as_reference());
+    let mut next = parent(page).ok();
+    // Bounded so a malformed tree with a cycle cannot loop forever
+    for _ in 0..doc.objects.len() {
+        let Some(node) = next.and_then(|id| doc.get_dictionary(id).ok()) else {
+            break;
+        };
+        for key in INHERITABLE_PAGE_ATTRIBUTES {
+            if !page.has(key) {
+                if let Ok(value) = node.get(key) {
+                    page.set(key, value.clone());
+                }
+            }
+        }
+        next = parent(node).ok();
+    }
+}
+
+/// Merge PDFs into one document, keeping page order
+#[cfg(feature = "pdf-merge")]
+fn merge_pdfs(files: &[&[u8]]) -> lopdf::Result<lopdf::Document> {
+    use lopdf::{dictionary, Document, Object, ObjectId};
+
+    let mut max_id = 1;
+    let mut pages: Vec<(ObjectId, Object)> = Vec::new();
+    let mut objects = std::collections::BTreeMap::new();
+
+    for bytes in files {
+        let mut doc = Document::load_mem(bytes)?;
+        doc.renumber_objects_with(max_id);
+        max_id = doc.max_id + 1;
+        for object_id in doc.get_pages().into_values() {
+            let mut page = doc.get_dictionary(object_id)?.clone();
+            inherit_page_attributes(&doc, &mut page);
+            pages.push((object_id, Object::Dictionary(page)));
+        }
+        objects.extend(doc.objects);
+    }
+
+    let mut merged = Document::with_version("1.5");
+    let mut catalog: Option<(ObjectId, Object)> = None;
+    let mut pages_root: Option<ObjectId> = None;
+
+    for (object_id, object) in objects {
+        match object.type_name().unwrap_or_default() {
+            "Catalog" => {
+                catalog.get_or_insert((object_id, object));
+            }
+            "Pages" => {
+                pages_root.get_or_insert(object_id);
+            }
+            // Pages are re-parented below; outlines point into the old page trees
+            "Page" | "Outlines" | "Outline" => {}
+            _ => {
+                merged.objects.insert(object_id, object);
+            }
+        }
+    }
+
+    let (Some((catalog_id, catalog)), Some(pages_id)) = (catalog, pages_root) else {
+        return Err(lopdf::Error::Invalid(
+            "missing catalog or page tree".to_string(),
+        ));
+    };
+
+    let mut kids = Vec::with_capacity(pages.len());
+    for (object_id, page) in pages {
+        let mut page = page.as_dict()?.clone();
+        page.set("Parent", pages_id);
+        merged.objects.insert(object_id, Object::Dictionary(page));
+        kids.push(Object::Reference(object_id));
+    }
+
+    merged.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Count" => kids.len() as u32,
+            "Kids" => kids,
+        }),
+    );
+
+    let mut catalog = catalog.as_dict()?.clone();
+    catalog.set("Pages", pages_id);
+    catalog.remove(b"Outlines");
+    merged
+        .objects
+        .insert(catalog_id, Object::Dictionary(catalog));
+    merged.trailer.set("Root", catalog_id);
+
+    merged.max_id = merged.objects.keys().map(|(id, _)| *id).max().unwrap_or(0);
+    merged.renumber_objects();
+    Ok(merged)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_and_multi_file_naming() {
+        let single = ExportedDocument::new("job", Some("pdf".to_string()), vec![b"%PDF".to_vec()]);
+        assert!(single.is_single_file());
+        assert_eq!(single.file_names("deck"), vec!["deck.pdf"]);
+
+        let multi = ExportedDocument::new(
+            "job",
+            Some("png".to_string()),
+            vec![b"one".to_vec(), b"two".to_vec()],
+        );
+        assert!(!multi.is_single_file());
+        assert_eq!(multi.file_names("deck"), vec!["deck-1.png", "deck-2.png"]);
+        assert_eq!(multi.pages()[1].number, 2);
+        assert_eq!(multi.total_bytes(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_write_to_dir() {
+        let dir = std::env::temp_dir().join(format!("canva-document-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await.expect("create dir");
+        let document = ExportedDocument::new(
+            "job",
+            Some("png".to_string()),
+            vec![b"one".to_vec(), b"two".to_vec()],
+        );
+
+        let paths = document.write_to_dir(&dir, "deck").await.expect("write");
+
+        assert_eq!(paths, vec![dir.join("deck-1.png"), dir.join("deck-2.png")]);
+        assert_eq!(tokio::fs::read(&paths[1]).await.expect("read"), b"two");
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[cfg(feature = "pdf-merge")]
+    fn one_page_pdf(width: i64) -> Vec<u8> {
+        pdf_with_media_box(width, false)
+    }
+
+    /// A one-page PDF whose `MediaBox` is on the page, or on the page tree
+    /// root for the page to inherit
+    #[cfg(feature = "pdf-merge")]
+    fn pdf_with_media_box(width: i64, inherited: bool) -> Vec<u8> {
+        use lopdf::{dictionary, Document, Object};
+
+        let media_box: Vec<Object> = vec![0.into(), 0.into(), width.into(), 100.into()];
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let mut page = dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+        };
+        let mut pages = dictionary! {
+            "Type" => "Pages",
+            "Count" => 1,
+        };
+        if inherited {
+            pages.set("MediaBox", media_box);
+        } else {
+            page.set("MediaBox", media_box);
+        }
+        let page_id = doc.add_object(page);
+        pages.set("Kids", vec![page_id.into()]);
+        doc.objects.insert(pages_id, Object::Dictionary(pages));
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).expect("save");
+        bytes
+    }
+
+    #[cfg(feature = "pdf-merge")]
+    #[test]
+    fn test_merge_into_single_pdf_keeps_page_order() {
+        let document = ExportedDocument::new(
+            "job",
+            Some("pdf".to_string()),
+            vec![one_page_pdf(100), one_page_pdf(200), one_page_pdf(300)],
+        );
+        assert_eq!(merged_widths(&document), vec![100, 200, 300]);
+    }
+
+    #[cfg(feature = "pdf-merge")]
+    #[test]
+    fn test_merge_keeps_inherited_page_attributes() {
+        let document = ExportedDocument::new(
+            "job",
+            Some("pdf".to_string()),
+            vec![
+                pdf_with_media_box(100, true),
+                pdf_with_media_box(200, true),
+                one_page_pdf(300),
+            ],
+        );
+        assert_eq!(merged_widths(&document), vec![100, 200, 300]);
+    }
+
+    /// Width of each page's own `MediaBox` after merging
+    #[cfg(feature = "pdf-merge")]
+    fn merged_widths(document: &ExportedDocument) -> Vec<i64> {
+        let mut merged = Vec::new();
+        document.merge_into_single_pdf(&mut merged).expect("merge");
+
+        let merged = lopdf::Document::load_mem(&merged).expect("load merged");
+        merged
+            .get_pages()
+            .into_values()
+            .map(|page_id| {
+                let page = merged
+                    .get_object(page_id)
+                    .and_then(|page| page.as_dict())
+                    .expect("page");
+                page.get(b"MediaBox")
+                    .and_then(|media_box| media_box.as_array())
+                    .and_then(|media_box| media_box[2].as_i64())
+                    .expect("width")
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "pdf-merge")]
+    #[test]
+    fn test_merge_rejects_non_pdf_pages() {
+        let document = ExportedDocument::new(
+            "job",
+            Some("png".to_string()),
+            vec![b"one".to_vec(), b"two".to_vec()],
+        );
+        assert!(document.merge_into_single_pdf(&mut Vec::new()).is_err());
+    }
+}
@@ -2,14 +2,23 @@
 //!
 //! This module provides access to the Canva Folders API, allowing you to
 //! organize and manage content in folders.
+//!
+//! The API moves one item per call; [`FoldersApi::move_items`] moves a batch
+//! concurrently and reports which items failed.
 
 use crate::{
     client::Client,
     error::Result,
-    models::{Folder, FolderItemSummary},
+    models::{Folder, FolderItemSummary, Page, PageRequest},
+    query::QueryBuilder,
+    routes,
 };
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
+pub mod moves;
+pub use moves::{BulkMoveSummary, FailedMove, DEFAULT_MOVE_CONCURRENCY};
+
 /// Client for the Folders API
 #[derive(Debug, Clone)]
 pub struct FoldersApi {
@@ -18,6 +27,7 @@ pub struct FoldersApi {
 
 /// Request to create a folder
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateFolderRequest {
     /// The folder name
     pub name: String,
@@ -27,6 +37,7 @@ pub struct CreateFolderRequest {
 
 /// Response from creating a folder
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateFolderResponse {
     /// The created folder
     pub folder: Folder,
@@ -34,6 +45,7 @@ pub struct CreateFolderResponse {
 
 /// Request to update a folder
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateFolderRequest {
     /// The new folder name
     pub name: String,
@@ -41,6 +53,7 @@ pub struct UpdateFolderRequest {
 
 /// Response from updating a folder
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateFolderResponse {
     /// The updated folder
     pub folder: Folder,
@@ -48,23 +61,18 @@ pub struct UpdateFolderResponse {
 
 /// Response from getting a folder
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetFolderResponse {
     /// The folder
     pub folder: Folder,
 }
 
 /// Response from listing folder items
-#[derive(Debug, Clone, Deserialize)]
-pub struct ListFolderItemsResponse {
-    /// List of folder items
-    pub items: Vec<FolderItemSummary>,
-    /// Continuation token for pagination
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub continuation: Option<String>,
-}
+pub type ListFolderItemsResponse = Page<FolderItemSummary>;
 
 /// Request to move a folder item
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoveFolderItemRequest {
     /// The item ID to move
     pub item_id: String,
@@ -81,6 +89,44 @@ pub struct ListFolderItemsRequest {
     pub continuation: Option<String>,
 }
 
+impl PageRequest for ListFolderItemsRequest {
+    fn set_continuation(&mut self, continuation: Option<String>) {
+        self.continuation = continuation;
+    }
+}
+
+impl ListFolderItemsRequest {
+    /// Start building a request with no options set
+    pub fn builder() -> ListFolderItemsRequestBuilder {
+        ListFolderItemsRequestBuilder::default()
+    }
+}
+
+/// Builder for [`ListFolderItemsRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct ListFolderItemsRequestBuilder {
+    request: ListFolderItemsRequest,
+}
+
+impl ListFolderItemsRequestBuilder {
+    /// Continue from a previous page
+    pub fn continuation(mut self, continuation: impl Into<String>) -> Self {
+        self.request.continuation = Some(continuation.into());
+        self
+    }
+
+    /// Return at most `limit` items per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Finish the request
+    pub fn build(self) -> ListFolderItemsRequest {
+        self.request
+    }
+}
+
 impl FoldersApi {
     /// Create a new folders API client
     pub fn new(client: Client) -> Self {
@@ -95,8 +141,10 @@ impl FoldersApi {
         &self,
         request: &CreateFolderRequest,
     ) -> Result<CreateFolderResponse> {
-        let response = self.client.post("/v1/folders", request).await?;
-        Ok(response.json::<CreateFolderResponse>().await?)
+        let response = self.client.post(routes::FOLDERS, request).await?;
+        self.client
+            .read_json::<CreateFolderResponse>(response)
+            .await
     }
 
     /// Get a folder by ID
@@ -104,9 +152,9 @@ impl FoldersApi {
     /// **Required OAuth scope:** `folder:read`
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn get_folder(&self, folder_id: &str) -> Result<GetFolderResponse> {
-        let url = format!("/v1/folders/{folder_id}");
+        let url = routes::folder(folder_id);
         let response = self.client.get(&url).await?;
-        Ok(response.json::<GetFolderResponse>().await?)
+        self.client.read_json::<GetFolderResponse>(response).await
     }
 
     /// Update a folder
@@ -118,9 +166,11 @@ impl FoldersApi {
         folder_id: &str,
         request: &UpdateFolderRequest,
     ) -> Result<UpdateFolderResponse> {
-        let url = format!("/v1/folders/{folder_id}");
+        let url = routes::folder(folder_id);
         let response = self.client.patch(&url, request).await?;
-        Ok(response.json::<UpdateFolderResponse>().await?)
+        self.client
+            .read_json::<UpdateFolderResponse>(response)
+            .await
     }
 
     /// List items in a folder
@@ -132,27 +182,15 @@ impl FoldersApi {
         folder_id: &str,
         request: &ListFolderItemsRequest,
     ) -> Result<ListFolderItemsResponse> {
-        let mut query_params = Vec::new();
-
-        if let Some(limit) = request.limit {
-            query_params.push(format!("limit={limit}"));
-        }
-
-        if let Some(continuation) = &request.continuation {
-            query_params.push(format!(
-                "continuation={}",
-                urlencoding::encode(continuation)
-            ));
-        }
-
-        let url = if query_params.is_empty() {
-            format!("/v1/folders/{folder_id}/items")
-        } else {
-            format!("/v1/folders/{}/items?{}", folder_id, query_params.join("&"))
-        };
+        let url = QueryBuilder::new()
+            .param_opt("limit", request.limit)
+            .param_opt("continuation", request.continuation.as_deref())
+            .append_to(&routes::folder_items(folder_id));
 
         let response = self.client.get(&url).await?;
-        Ok(response.json::<ListFolderItemsResponse>().await?)
+        self.client
+            .read_json::<ListFolderItemsResponse>(response)
+            .await
     }
 
     /// Move a folder item
@@ -160,10 +198,46 @@ impl FoldersApi {
     /// **Required OAuth scope:** `folder:write`
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn move_folder_item(&self, request: &MoveFolderItemRequest) -> Result<()> {
-        let _response = self.client.post("/v1/folders/move", request).await?;
+        let _response = self.client.post(routes::FOLDERS_MOVE, request).await?;
         // The client already handles error responses, so if we get here, it's successful
         Ok(())
     }
+
+    /// Move several items into `to_folder_id`, [`DEFAULT_MOVE_CONCURRENCY`] at a time
+    ///
+    /// Every ID is attempted even if some fail; check
+    /// [`BulkMoveSummary::failed`] for the items that stayed where they were.
+    ///
+    /// **Required OAuth scope:** `folder:write`
+    pub async fn move_items<I>(&self, item_ids: I, to_folder_id: &str) -> BulkMoveSummary
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut outcomes: Vec<(usize, String, Result<()>)> =
+            futures::stream::iter(item_ids.into_iter().map(Into::into).enumerate())
+                .map(|(index, item_id): (usize, String)| async move {
+                    let request = MoveFolderItemRequest {
+                        item_id,
+                        to_folder_id: to_folder_id.to_string(),
+                    };
+                    let outcome = self.move_folder_item(&request).await;
+                    (index, request.item_id, outcome)
+                })
+                .buffer_unordered(DEFAULT_MOVE_CONCURRENCY)
+                .collect()
+                .await;
+        outcomes.sort_by_key(|(index, _, _)| *index);
+
+        let mut summary = BulkMoveSummary::default();
+        for (_, item_id, outcome) in outcomes {
+            match outcome {
+                Ok(()) => summary.moved.push(item_id),
+                Err(error) => summary.failed.push(FailedMove { item_id, error }),
+            }
+        }
+        summary
+    }
 }
 
 #[cfg(test)]
@@ -482,4 +556,39 @@ mod tests {
         assert!(update_serialized.contains(unicode_name));
         assert!(move_serialized.contains(unicode_id));
     }
+
+    #[tokio::test]
+    async fn test_move_items_reports_each_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let moved = server
+            .mock("POST", "/v1/folders/move")
+            .match_body(mockito::Matcher::Regex(r#""item_id":"keep_"#.to_string()))
+            .with_status(204)
+            .expect(2)
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/v1/folders/move")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"item_id": "gone", "to_folder_id": "FAF2"}),
+            ))
+            .with_status(404)
+            .with_body(r#"{"code":"not_found","message":"No such item"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("token"))
+            .expect("Failed to create client");
+        let summary = client
+            .folders()
+            .move_items(["keep_1", "gone", "keep_2"], "FAF2")
+            .await;
+
+        assert_eq!(summary.moved, vec!["keep_1", "keep_2"]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].item_id, "gone");
+        assert!(summary.failed[0].is_not_found());
+        assert!(!summary.is_success());
+        moved.assert_async().await;
+    }
 }
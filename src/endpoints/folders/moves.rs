@@ -0,0 +1,45 @@
+//! Moving many folder items at once.
+//!
+//! `POST /v1/folders/move` moves a single item. [`FoldersApi::move_items`]
+//! sends one move per item with a bounded number in flight, and keeps going
+//! when some of them fail. The returned [`BulkMoveSummary`] lists the items
+//! that moved and the ones that did not, with the error for each.
+//!
+//! [`FoldersApi::move_items`]: super::FoldersApi::move_items
+
+use crate::error::Error;
+
+/// Number of moves sent at once by `move_items`
+pub const DEFAULT_MOVE_CONCURRENCY: usize = 4;
+
+/// A folder item that could not be moved
+#[derive(Debug)]
+pub struct FailedMove {
+    /// ID of the item
+    pub item_id: String,
+    /// Why the move failed
+    pub error: Error,
+}
+
+impl FailedMove {
+    /// Whether the item did not exist or is not visible to the user
+    pub fn is_not_found(&self) -> bool {
+        self.error.is_not_found()
+    }
+}
+
+/// Outcome of a bulk move; both lists keep the order the IDs were given in
+#[derive(Debug, Default)]
+pub struct BulkMoveSummary {
+    /// IDs of the items now in the destination folder
+    pub moved: Vec<String>,
+    /// Items that could not be moved
+    pub failed: Vec<FailedMove>,
+}
+
+impl BulkMoveSummary {
+    /// Whether every item was moved
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
@@ -0,0 +1,451 @@
+//! Design Import API endpoints for the Canva Connect API.
+//!
+//! This module provides access to design import operations, which turn
+//! external files such as PDF, PPTX, DOCX or Keynote documents into new
+//! Canva designs. Imports run as asynchronous jobs, mirroring the asset
+//! upload flow.
+//!
+//! ## Available Operations
+//!
+//! | Operation | Method | Endpoint | Required Scope | Description |
+//! |-----------|---------|----------|----------------|-------------|
+//! | [`create_import_job`](DesignImportApi::create_import_job) | `POST` | `/v1/imports` | `design:content:write` | Import a design from binary data |
+//! | [`get_import_job`](DesignImportApi::get_import_job) | `GET` | `/v1/imports/{jobId}` | `design:content:read` | Check import job status |
+//! | [`create_url_import_job`](DesignImportApi::create_url_import_job) | `POST` | `/v1/url-imports` | `design:content:write` | Import a design from a public URL |
+//! | [`get_url_import_job`](DesignImportApi::get_url_import_job) | `GET` | `/v1/url-imports/{jobId}` | `design:content:read` | Check URL import job status |
+//! | [`wait_for_import_job`](DesignImportApi::wait_for_import_job) | N/A | Multiple calls | `design:content:read` | Wait for import completion |
+//! | [`wait_for_url_import_job`](DesignImportApi::wait_for_url_import_job) | N/A | Multiple calls | `design:content:read` | Wait for URL import completion |
+//!
+//! ## OAuth Scopes
+//!
+//! - **`design:content:write`** - Required for starting import jobs
+//! - **`design:content:read`** - Required for checking import job status
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, endpoints::imports::DesignImportMetadata};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-token"))?;
+//! let file_data = std::fs::read("slides.pptx")?;
+//!
+//! let metadata = DesignImportMetadata::new("Quarterly Review")
+//!     .with_mime_type("application/vnd.openxmlformats-officedocument.presentationml.presentation");
+//! let job = client.imports().create_import_job(file_data, metadata).await?;
+//! let designs = client.imports().wait_for_import_job(&job.id).await?;
+//!
+//! for design in designs {
+//!     println!("Imported design: {}", design.urls.edit_url);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::Client,
+    error::Result,
+    jobs::{PollableJob, WaitOptions},
+    models::*,
+    routes,
+};
+use serde::{Deserialize, Serialize};
+
+/// Design Import API client
+#[derive(Debug, Clone)]
+pub struct DesignImportApi {
+    client: Client,
+}
+
+impl DesignImportApi {
+    /// Create a new design import API client
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Create a design import job from binary file data
+    ///
+    /// **Required OAuth scope:** `design:content:write`
+    #[cfg_attr(feature = "observability", tracing::instrument(
+        skip(self, file_data),
+        fields(
+            canva.import.size_bytes = file_data.len(),
+        )
+    ))]
+    pub async fn create_import_job(
+        &self,
+        file_data: Vec<u8>,
+        metadata: DesignImportMetadata,
+    ) -> Result<DesignImportJob> {
+        let metadata_json = serde_json::to_string(&metadata)?;
+        let response = self
+            .client
+            .post_binary(
+                routes::IMPORTS,
+                file_data,
+                Some(("Import-Metadata", &metadata_json)),
+            )
+            .await?;
+        let job_response: DesignImportJobResponse = self.client.read_json(response).await?;
+        Ok(job_response.job)
+    }
+
+    /// Get the status of a design import job
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn get_import_job(&self, job_id: &str) -> Result<DesignImportJob> {
+        let path = routes::import_job(job_id);
+        let response: DesignImportJobResponse = self.client.get_json(&path).await?;
+        Ok(response.job)
+    }
+
+    /// Create a design import job from a publicly accessible URL
+    ///
+    /// **Required OAuth scope:** `design:content:write`
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn create_url_import_job(
+        &self,
+        request: CreateUrlImportJobRequest,
+    ) -> Result<DesignImportJob> {
+        let response: DesignImportJobResponse =
+            self.client.post_json(routes::URL_IMPORTS, &request).await?;
+        Ok(response.job)
+    }
+
+    /// Get the status of a URL design import job
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
+    pub async fn get_url_import_job(&self, job_id: &str) -> Result<DesignImportJob> {
+        let path = routes::url_import_job(job_id);
+        let response: DesignImportJobResponse = self.client.get_json(&path).await?;
+        Ok(response.job)
+    }
+
+    /// Wait for an import job to complete, returning the imported designs
+    ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_import_job(&self, job_id: &str) -> Result<Vec<DesignSummary>> {
+        self.client
+            .job_poller()
+            .wait(&self.pending_import_job(job_id))
+            .await
+    }
+
+    /// Like [`wait_for_import_job`](Self::wait_for_import_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_import_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<Vec<DesignSummary>> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_import_job(job_id))
+            .await
+    }
+
+    /// An import job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_import_job(&self, job_id: &str) -> PendingImportJob {
+        PendingImportJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
+        }
+    }
+
+    /// Wait for a URL import job to complete, returning the imported designs
+    ///
+    /// Cancellation safe: see [Cancellation Safety](crate#cancellation-safety).
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_url_import_job(&self, job_id: &str) -> Result<Vec<DesignSummary>> {
+        self.client
+            .job_poller()
+            .wait(&self.pending_url_import_job(job_id))
+            .await
+    }
+
+    /// Like [`wait_for_url_import_job`](Self::wait_for_url_import_job), but stops early as set in `options`
+    ///
+    /// See [`WaitOptions`] for the errors returned when the wait stops early.
+    ///
+    /// **Required OAuth scope:** `design:content:read`
+    pub async fn wait_for_url_import_job_with(
+        &self,
+        job_id: &str,
+        options: &WaitOptions,
+    ) -> Result<Vec<DesignSummary>> {
+        self.client
+            .job_poller()
+            .with_options(options)
+            .wait(&self.pending_url_import_job(job_id))
+            .await
+    }
+
+    /// A URL import job to wait for with a [`JobPoller`](crate::jobs::JobPoller)
+    pub fn pending_url_import_job(&self, job_id: &str) -> PendingUrlImportJob {
+        PendingUrlImportJob {
+            api: self.clone(),
+            job_id: job_id.to_string(),
+        }
+    }
+}
+
+/// A design import job that can be polled until it finishes
+///
+/// Returned by [`DesignImportApi::pending_import_job`]; its output is the
+/// imported designs.
+#[derive(Debug, Clone)]
+pub struct PendingImportJob {
+    api: DesignImportApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingImportJob {
+    type Output = Vec<DesignSummary>;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<Vec<DesignSummary>>> {
+        import_job_outcome(self.api.get_import_job(&self.job_id).await?)
+    }
+}
+
+/// A URL design import job that can be polled until it finishes
+///
+/// Returned by [`DesignImportApi::pending_url_import_job`]; its output is the
+/// imported designs.
+#[derive(Debug, Clone)]
+pub struct PendingUrlImportJob {
+    api: DesignImportApi,
+    job_id: String,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for PendingUrlImportJob {
+    type Output = Vec<DesignSummary>;
+
+    fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    async fn poll(&self) -> Result<Option<Vec<DesignSummary>>> {
+        import_job_outcome(self.api.get_url_import_job(&self.job_id).await?)
+    }
+}
+
+/// Map a finished import job to its designs, or `None` while it is still running
+pub(crate) fn import_job_outcome(job: DesignImportJob) -> Result<Option<Vec<DesignSummary>>> {
+    match job.status {
+        JobStatus::Success => job
+            .result
+            .map(|result| Some(result.designs))
+            .ok_or_else(|| {
+                crate::error::Error::Generic("Job succeeded but no design data".to_string())
+            }),
+        JobStatus::Failed => {
+            let error_msg = job
+                .error
+                .map(|e| format!("{}: {}", e.code, e.message))
+                .unwrap_or_else(|| "Job failed with unknown error".to_string());
+            Err(crate::error::Error::Generic(error_msg))
+        }
+        JobStatus::InProgress | JobStatus::Unknown => Ok(None),
+    }
+}
+
+/// Design import metadata, sent in the `Import-Metadata` header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignImportMetadata {
+    /// Design title, encoded in Base64
+    pub title_base64: String,
+    /// MIME type of the imported file; Canva detects it when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+impl DesignImportMetadata {
+    /// Create new metadata with the title automatically Base64 encoded
+    pub fn new(title: &str) -> Self {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        Self {
+            title_base64: STANDARD.encode(title.as_bytes()),
+            mime_type: None,
+        }
+    }
+
+    /// Set the MIME type of the imported file
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+}
+
+/// Request to create a URL design import job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreateUrlImportJobRequest {
+    /// A title for the design (maximum 255 characters)
+    pub title: String,
+    /// Public URL of the file to import
+    pub url: String,
+    /// MIME type of the imported file; Canva detects it when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// Response containing a design import job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignImportJobResponse {
+    /// The import job
+    pub job: DesignImportJob,
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::auth::AccessToken;
+    use serde_json::json;
+
+    #[test]
+    fn test_design_import_api_creation() {
+        let client = Client::new(AccessToken::new("test_token")).expect("Failed to create client");
+        let _imports_api = client.imports();
+    }
+
+    #[test]
+    fn test_import_metadata_encodes_title() {
+        let metadata = DesignImportMetadata::new("My Awesome Design 😍");
+        assert_eq!(metadata.title_base64, "TXkgQXdlc29tZSBEZXNpZ24g8J+YjQ==");
+
+        let json = serde_json::to_value(&metadata).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            json!({ "title_base64": "TXkgQXdlc29tZSBEZXNpZ24g8J+YjQ==" })
+        );
+    }
+
+    #[test]
+    fn test_import_metadata_with_mime_type() {
+        let metadata = DesignImportMetadata::new("Report").with_mime_type("application/pdf");
+        let json = serde_json::to_value(&metadata).expect("Failed to serialize");
+        assert_eq!(json["mime_type"], "application/pdf");
+    }
+
+    #[test]
+    fn test_url_import_request_serialization() {
+        let request = CreateUrlImportJobRequest {
+            title: "Keynote".to_string(),
+            url: "https://example.com/deck.key".to_string(),
+            mime_type: None,
+        };
+        let json = serde_json::to_value(&request).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            json!({ "title": "Keynote", "url": "https://example.com/deck.key" })
+        );
+    }
+
+    #[test]
+    fn test_import_job_deserialization_success() {
+        let json = json!({
+            "job": {
+                "id": "e08861ae-3b29-45db-8dc1-1fe0bf7f1cc8",
+                "status": "success",
+                "result": {
+                    "designs": [{
+                        "id": "DAGQm2AkzOk",
+                        "title": "My Awesome Design",
+                        "urls": {
+                            "edit_url": "https://www.canva.com/api/design/edit",
+                            "view_url": "https://www.canva.com/api/design/view"
+                        },
+                        "created_at": 1726198998,
+                        "updated_at": 1726199000,
+                        "page_count": 3
+                    }]
+                }
+            }
+        });
+
+        let response: DesignImportJobResponse =
+            serde_json::from_value(json).expect("Failed to deserialize");
+        let designs = import_job_outcome(response.job)
+            .expect("Job should succeed")
+            .expect("Job should be finished");
+        assert_eq!(designs.len(), 1);
+        assert_eq!(designs[0].id, "DAGQm2AkzOk");
+        assert_eq!(designs[0].page_count, Some(3));
+    }
+
+    #[test]
+    fn test_import_job_outcome_in_progress() {
+        let job: DesignImportJob = serde_json::from_value(json!({
+            "id": "job_123",
+            "status": "in_progress"
+        }))
+        .expect("Failed to deserialize");
+        assert!(import_job_outcome(job)
+            .expect("Job should not fail")
+            .is_none());
+    }
+
+    #[test]
+    fn test_import_job_outcome_failed() {
+        let job: DesignImportJob = serde_json::from_value(json!({
+            "id": "job_123",
+            "status": "failed",
+            "error": {
+                "code": "invalid_file",
+                "message": "The file is not a supported format."
+            }
+        }))
+        .expect("Failed to deserialize");
+
+        let error = import_job_outcome(job).expect_err("Job should fail");
+        assert_eq!(
+            error.to_string(),
+            "invalid_file: The file is not a supported format."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_import_job_sends_metadata_header() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/imports")
+            .match_header("content-type", "application/octet-stream")
+            .match_header("import-metadata", r#"{"title_base64":"UmVwb3J0"}"#)
+            .match_body("%PDF-1.7")
+            .with_status(200)
+            .with_body(r#"{"job":{"id":"job_123","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+
+        let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+            .expect("Failed to create client");
+        let job = client
+            .imports()
+            .create_import_job(b"%PDF-1.7".to_vec(), DesignImportMetadata::new("Report"))
+            .await
+            .expect("Failed to create import job");
+
+        assert_eq!(job.id, "job_123");
+        assert_eq!(job.status, JobStatus::InProgress);
+        mock.assert_async().await;
+    }
+}
@@ -13,6 +13,8 @@
 //! - [`designs`] - Create and manage Canva designs
 //! - [`exports`] - Export designs to various file formats
 //! - [`folders`] - Organize content in folders
+//! - `imports` - Import external files (PDF, PPTX, DOCX, ...) as Canva designs
+//!   (preview, requires the `experimental` feature)
 //! - [`user`] - User profile and account information
 //!
 //! ## Usage
@@ -36,19 +38,27 @@
 pub mod assets;
 pub mod autofill;
 pub mod brand_templates;
+#[cfg(feature = "experimental")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
 pub mod comments;
 pub mod designs;
 pub mod exports;
 pub mod folders;
+#[cfg(feature = "experimental")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+pub mod imports;
 pub mod user;
 
 pub use assets::AssetsApi;
 pub use autofill::AutofillApi;
 pub use brand_templates::BrandTemplatesApi;
+#[cfg(feature = "experimental")]
 pub use comments::CommentsApi;
 pub use designs::DesignsApi;
 pub use exports::ExportsApi;
 pub use folders::FoldersApi;
+#[cfg(feature = "experimental")]
+pub use imports::DesignImportApi;
 pub use user::UserApi;
 
 // All endpoints are now implemented
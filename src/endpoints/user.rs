@@ -12,6 +12,7 @@
 //! | [`get_me`](UserApi::get_me) | `GET` | `/v1/users/me` | None | Get basic user identification |
 //! | [`get_profile`](UserApi::get_profile) | `GET` | `/v1/users/me/profile` | `profile:read` | Get user profile information |
 //! | [`get_capabilities`](UserApi::get_capabilities) | `GET` | `/v1/users/me/capabilities` | `profile:read` | Get user capabilities |
+//! | [`get_all`](UserApi::get_all) | N/A | Multiple calls | `profile:read` | Get identity, profile and capabilities together |
 //!
 //! ## OAuth Scopes
 //!
@@ -24,8 +25,18 @@
 //! - **`autofill`** - Autofill APIs (Canva Enterprise users)
 //! - **`brand_template`** - Brand template APIs (Canva Enterprise users)
 //! - **`resize`** - Design resize APIs (Canva Pro+ users)
+//!
+//! To gate features on these, use [`Client::capabilities`], which caches
+//! the answer per access token, rather than calling
+//! [`get_capabilities`](UserApi::get_capabilities) each time.
+//!
+//! ## Profile Fields
+//!
+//! The profile endpoint only returns the user's display name. Canva does
+//! not expose their email address, locale or profile photo through the
+//! Connect API, so [`UserProfile`] has no fields for them.
 
-use crate::{client::Client, error::Result};
+use crate::{client::Client, error::Result, models::api_strings, routes};
 use serde::{Deserialize, Serialize};
 
 /// User API client
@@ -45,7 +56,7 @@ impl UserApi {
     /// **Required OAuth scope:** None (always accessible)
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn get_me(&self) -> Result<TeamUserSummary> {
-        let response: UsersMeResponse = self.client.get_json("/v1/users/me").await?;
+        let response: UsersMeResponse = self.client.get_json(routes::USER_ME).await?;
         Ok(response.team_user)
     }
 
@@ -54,7 +65,7 @@ impl UserApi {
     /// **Required OAuth scope:** `profile:read`
     #[cfg_attr(feature = "observability", tracing::instrument(skip(self)))]
     pub async fn get_profile(&self) -> Result<UserProfile> {
-        let response: UserProfileResponse = self.client.get_json("/v1/users/me/profile").await?;
+        let response: UserProfileResponse = self.client.get_json(routes::USER_PROFILE).await?;
         Ok(response.profile)
     }
 
@@ -63,13 +74,42 @@ impl UserApi {
     /// **Required OAuth scope:** `profile:read`
     pub async fn get_capabilities(&self) -> Result<Vec<Capability>> {
         let response: GetUserCapabilitiesResponse =
-            self.client.get_json("/v1/users/me/capabilities").await?;
+            self.client.get_json(routes::USER_CAPABILITIES).await?;
         Ok(response.capabilities)
     }
+
+    /// Get the user's identity, profile and capabilities in one call
+    ///
+    /// Sends the three requests concurrently and fails if any of them does.
+    ///
+    /// **Required OAuth scope:** `profile:read`
+    pub async fn get_all(&self) -> Result<UserInfo> {
+        let (user, profile, capabilities) =
+            futures::try_join!(self.get_me(), self.get_profile(), self.get_capabilities())?;
+        Ok(UserInfo {
+            user,
+            profile,
+            capabilities: UserCapabilities::new(capabilities),
+        })
+    }
+}
+
+/// Everything the API reports about the current user
+///
+/// Returned by [`UserApi::get_all`].
+#[derive(Debug, Clone)]
+pub struct UserInfo {
+    /// User and team IDs
+    pub user: TeamUserSummary,
+    /// Profile information
+    pub profile: UserProfile,
+    /// Features the user can use
+    pub capabilities: UserCapabilities,
 }
 
 /// Basic user identification containing user ID and team ID
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TeamUserSummary {
     /// The ID of the user
     pub user_id: String,
@@ -79,13 +119,16 @@ pub struct TeamUserSummary {
 
 /// User profile information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserProfile {
-    /// The name of the user as shown in the Canva UI
+    /// The name of the user as shown in the Canva UI (empty if Canva omits it)
+    #[serde(default)]
     pub display_name: String,
 }
 
 /// User capabilities that determine access to advanced features
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Capability {
     /// Capability required to call autofill APIs
@@ -98,10 +141,60 @@ pub enum Capability {
     /// Capability required to create design resize jobs
     /// Users on a Canva plan with premium features (such as Canva Pro) have this capability
     Resize,
+    /// A capability this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// The capabilities of a user, with checks for each feature they unlock
+///
+/// Returned by [`Client::capabilities`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserCapabilities {
+    capabilities: Vec<Capability>,
+}
+
+impl UserCapabilities {
+    /// Wrap a list of capabilities
+    pub fn new(capabilities: Vec<Capability>) -> Self {
+        Self { capabilities }
+    }
+
+    /// Whether the user has `capability`
+    pub fn contains(&self, capability: Capability) -> bool {
+        capability != Capability::Unknown && self.capabilities.contains(&capability)
+    }
+
+    /// Whether the user can call the autofill APIs
+    pub fn can_autofill(&self) -> bool {
+        self.contains(Capability::Autofill)
+    }
+
+    /// Whether the user can create design resize jobs
+    pub fn can_resize(&self) -> bool {
+        self.contains(Capability::Resize)
+    }
+
+    /// Whether the user can call the brand template APIs
+    pub fn can_use_brand_templates(&self) -> bool {
+        self.contains(Capability::BrandTemplate)
+    }
+
+    /// Every capability the API reported, including ones this crate does not know
+    pub fn as_slice(&self) -> &[Capability] {
+        &self.capabilities
+    }
+}
+
+impl From<Vec<Capability>> for UserCapabilities {
+    fn from(capabilities: Vec<Capability>) -> Self {
+        Self::new(capabilities)
+    }
 }
 
 /// Response from the users/me endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UsersMeResponse {
     /// Basic user identification
     pub team_user: TeamUserSummary,
@@ -109,6 +202,7 @@ pub struct UsersMeResponse {
 
 /// Response from the users/me/profile endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserProfileResponse {
     /// User profile information
     pub profile: UserProfile,
@@ -116,17 +210,15 @@ pub struct UserProfileResponse {
 
 /// Response from the users/me/capabilities endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetUserCapabilitiesResponse {
     /// List of user capabilities
+    #[serde(default)]
     pub capabilities: Vec<Capability>,
 }
 
-impl std::fmt::Display for Capability {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Capability::Autofill => write!(f, "autofill"),
-            Capability::BrandTemplate => write!(f, "brand_template"),
-            Capability::Resize => write!(f, "resize"),
-        }
-    }
-}
+api_strings!(Capability ("capability") {
+    Autofill => "autofill",
+    BrandTemplate => "brand_template",
+    Resize => "resize",
+} unknown: Unknown);
@@ -7,7 +7,12 @@ use thiserror::Error;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Main error type for the Canva Connect API client
+///
+/// New variants may be added in minor releases, so matches outside this
+/// crate need a wildcard arm. Prefer [`recovery_hint`](Error::recovery_hint)
+/// or [`is_retryable`](Error::is_retryable) to decide how to handle an error.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// HTTP request error
     #[error("HTTP request failed: {0}")]
@@ -17,13 +22,32 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// A response body that did not match the expected type
+    ///
+    /// `body` holds the start of the body, with credentials redacted, so the
+    /// unexpected payload can be inspected. It is capped at
+    /// [`ClientBuilder::captured_body_limit`](crate::ClientBuilder::captured_body_limit)
+    /// bytes and is `None` when capturing is disabled.
+    #[error("Failed to parse response: {source}")]
+    Deserialization {
+        /// What serde_json could not parse
+        source: serde_json::Error,
+        /// The response body, possibly truncated
+        body: Option<String>,
+    },
+
     /// API error response from Canva
-    #[error("API error: {code} - {message}")]
+    ///
+    /// Error responses without a JSON body, such as those from a gateway,
+    /// get their code from the HTTP status with [`ApiErrorCode::for_status`].
+    #[error("API error: {code} - {message}{}", context_suffix(.context))]
     Api {
         /// Error code from the API
         code: ApiErrorCode,
         /// Error message from the API
         message: String,
+        /// The request that got this response, when the client sent it
+        context: Option<Box<RequestContext>>,
     },
 
     /// Authentication error
@@ -53,6 +77,320 @@ pub enum Error {
     /// HTTP client build error
     #[error("Failed to build HTTP client: {0}")]
     ClientBuild(reqwest::Error),
+
+    /// Invalid client configuration
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+
+    /// Upload refused by the client's upload precheck before it was sent
+    #[error("Upload of {name} rejected: {rejection}")]
+    UploadRejected {
+        /// Name of the asset that was being uploaded
+        name: String,
+        /// Why the upload was refused
+        rejection: crate::uploads::UploadRejection,
+    },
+
+    /// File that Canva would not accept as an asset, caught before uploading
+    #[error("Invalid asset {name}: {reason}")]
+    InvalidAsset {
+        /// Name of the asset that was being uploaded
+        name: String,
+        /// What is wrong with the file
+        reason: String,
+    },
+
+    /// Job still running when a [`JobPoller`](crate::jobs::JobPoller) timeout ran out
+    #[error("Job {job_id} did not finish within {timeout:?}")]
+    JobTimeout {
+        /// ID of the job being waited for
+        job_id: String,
+        /// How long the poller waited
+        timeout: std::time::Duration,
+    },
+
+    /// Waiting for a job was cancelled through its
+    /// [`CancellationToken`](crate::jobs::CancellationToken), or because the
+    /// client was [shut down](crate::Client::shutdown)
+    #[error("Stopped waiting for job {job_id}: cancelled")]
+    Cancelled {
+        /// ID of the job being waited for
+        job_id: String,
+    },
+
+    /// A step of a multi-step [`workflow`](crate::workflows) failed
+    #[error("{step} failed: {source}")]
+    Workflow {
+        /// What the workflow was doing, e.g. `export design DAF123`
+        step: String,
+        /// The error the step failed with
+        source: Box<Error>,
+    },
+
+    /// Export refused before sending because the design cannot be exported in that format
+    ///
+    /// Returned by
+    /// [`ExportsApi::create_export_checked`](crate::endpoints::exports::ExportsApi::create_export_checked).
+    #[error(
+        "Design {design_id} cannot be exported as {format}; supported formats: {}",
+        .supported.join(", ")
+    )]
+    UnsupportedExportFormat {
+        /// ID of the design
+        design_id: String,
+        /// Name of the requested format, e.g. `mp4`
+        format: String,
+        /// Names of the formats the design can be exported as
+        supported: Vec<String>,
+    },
+
+    /// Request refused before sending because the token lacks a required scope
+    ///
+    /// Only returned with
+    /// [`ClientBuilder::scope_preflight`](crate::ClientBuilder::scope_preflight)
+    /// enabled; see [`operations`](crate::operations).
+    #[error("Access token is missing OAuth scopes: {}", .granted.missing(.required))]
+    MissingScope {
+        /// Scopes the operation requires
+        required: crate::auth::ScopeSet,
+        /// Scopes granted to the access token
+        granted: crate::auth::ScopeSet,
+    },
+
+    /// Request refused because [`Client::shutdown`](crate::Client::shutdown) was called
+    #[error("Client is shut down")]
+    ShutDown,
+
+    /// A pre-signed download URL, such as an export file, answered with an
+    /// error status
+    #[error("HTTP {status} error downloading {url}")]
+    Download {
+        /// Status of the final response, after retries
+        status: reqwest::StatusCode,
+        /// The URL without its query string, which holds the signature
+        url: String,
+    },
+}
+
+impl Error {
+    /// The request an API error came back from
+    ///
+    /// Looks through [`Error::Workflow`] to the step that failed. `None` for
+    /// errors that did not come from an API response.
+    pub fn request_context(&self) -> Option<&RequestContext> {
+        match self {
+            Error::Api { context, .. } => context.as_deref(),
+            Error::Workflow { source, .. } => source.request_context(),
+            _ => None,
+        }
+    }
+
+    /// HTTP method of the failed request
+    pub fn method(&self) -> Option<&reqwest::Method> {
+        self.request_context().map(|context| &context.method)
+    }
+
+    /// API path of the failed request, e.g. `/v1/designs/DAF123`
+    pub fn path(&self) -> Option<&str> {
+        self.request_context().map(|context| context.path.as_str())
+    }
+
+    /// Canva request ID of the failed request, for support tickets
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_context()?.request_id.as_deref()
+    }
+
+    /// Time from sending the request to getting the error response, retries included
+    pub fn elapsed(&self) -> Option<std::time::Duration> {
+        self.request_context().map(|context| context.elapsed)
+    }
+
+    /// What a caller can do about this error
+    ///
+    /// Decided from the Canva error code where it is known, otherwise from
+    /// the HTTP status, so callers do not need to match on messages. Looks
+    /// through [`Error::Workflow`] to the step that failed. Failed
+    /// [downloads](Error::Download) are worth retrying only on 408, 429 and
+    /// 5xx statuses. Errors the client cannot classify, including
+    /// [`Error::Generic`], are [`RecoveryHint::Permanent`].
+    ///
+    /// ```rust
+    /// use canva_connect::error::{ApiErrorCode, Error, RecoveryHint};
+    ///
+    /// let error = Error::Api {
+    ///     code: ApiErrorCode::Unknown("missing_scope".to_string()),
+    ///     message: "Missing scopes: [design:content:write]".to_string(),
+    ///     context: None,
+    /// };
+    /// assert_eq!(error.recovery_hint(), RecoveryHint::CheckScopes);
+    /// assert!(!error.is_retryable());
+    /// ```
+    pub fn recovery_hint(&self) -> RecoveryHint {
+        match self {
+            Error::Http(error) => {
+                if error.is_timeout() || error.is_connect() {
+                    RecoveryHint::Backoff
+                } else {
+                    error
+                        .status()
+                        .map_or(RecoveryHint::Permanent, RecoveryHint::for_status)
+                }
+            }
+            Error::Api { code, context, .. } => RecoveryHint::for_code(code)
+                .or_else(|| context.as_ref().map(|c| RecoveryHint::for_status(c.status)))
+                .unwrap_or(RecoveryHint::Permanent),
+            Error::Auth(_) => RecoveryHint::RefreshToken,
+            Error::RateLimit | Error::JobTimeout { .. } => RecoveryHint::Backoff,
+            Error::Io(error) => match error.kind() {
+                std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::Interrupted
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::ConnectionRefused => RecoveryHint::Backoff,
+                _ => RecoveryHint::Permanent,
+            },
+            Error::MissingScope { .. } => RecoveryHint::CheckScopes,
+            // Pre-signed URLs carry their own authorization, so a 401 or 403
+            // means the URL expired rather than that a scope is missing
+            Error::Download { status, .. } => {
+                if *status == reqwest::StatusCode::REQUEST_TIMEOUT
+                    || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                    || status.is_server_error()
+                {
+                    RecoveryHint::Backoff
+                } else {
+                    RecoveryHint::Permanent
+                }
+            }
+            Error::Workflow { source, .. } => source.recovery_hint(),
+            _ => RecoveryHint::Permanent,
+        }
+    }
+
+    /// Whether sending the same request again later may succeed
+    ///
+    /// True for [`RecoveryHint::Backoff`]: rate limits, server errors,
+    /// timeouts and dropped connections. A rejected token is not counted,
+    /// since the client already retries once with a refreshed token.
+    pub fn is_retryable(&self) -> bool {
+        self.recovery_hint() == RecoveryHint::Backoff
+    }
+
+    /// Whether the API said the resource does not exist
+    ///
+    /// Checks the `404` status when the client sent the request, and
+    /// otherwise the error code in any casing (Canva sends `not_found`).
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::Api {
+                context: Some(context),
+                ..
+            } => context.status == reqwest::StatusCode::NOT_FOUND,
+            Error::Api { code, .. } => code.to_string().eq_ignore_ascii_case("not_found"),
+            Error::Workflow { source, .. } => source.is_not_found(),
+            _ => false,
+        }
+    }
+
+    /// A [`Error::Download`] for `url`, leaving out the pre-signed query string
+    pub(crate) fn download(status: reqwest::StatusCode, url: &reqwest::Url) -> Self {
+        let mut shown = url.clone();
+        shown.set_query(None);
+        Error::Download {
+            status,
+            url: shown.to_string(),
+        }
+    }
+
+    /// Attach the request an API error came back from; other errors are returned unchanged
+    pub(crate) fn with_request_context(mut self, request_context: RequestContext) -> Self {
+        if let Error::Api { context, .. } = &mut self {
+            *context = Some(Box::new(request_context));
+        }
+        self
+    }
+}
+
+/// The request behind an [`Error::Api`]
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    /// HTTP method
+    pub method: reqwest::Method,
+    /// API path, without the base URL
+    pub path: String,
+    /// HTTP status of the error response
+    pub status: reqwest::StatusCode,
+    /// Value of the `x-request-id` response header
+    pub request_id: Option<String>,
+    /// Time from sending the request to getting the error response, retries included
+    pub elapsed: std::time::Duration,
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} returned {} after {:?}",
+            self.method, self.path, self.status, self.elapsed
+        )?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request ID {request_id}")?;
+        }
+        Ok(())
+    }
+}
+
+fn context_suffix(context: &Option<Box<RequestContext>>) -> String {
+    context
+        .as_ref()
+        .map(|context| format!(" ({context})"))
+        .unwrap_or_default()
+}
+
+/// What a caller can do about an [`Error`](enum@Error), from [`Error::recovery_hint`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecoveryHint {
+    /// The access token was rejected; refresh it or have the user sign in again
+    RefreshToken,
+    /// A temporary failure; try again after waiting
+    Backoff,
+    /// The token is missing an OAuth scope or the user lacks permission;
+    /// reconnect with the scopes the operation needs
+    CheckScopes,
+    /// Sending the same request again will fail the same way
+    Permanent,
+}
+
+impl RecoveryHint {
+    /// Hint for a Canva error code, if the code alone decides it
+    fn for_code(code: &ApiErrorCode) -> Option<Self> {
+        let hint = match code {
+            ApiErrorCode::Unauthorized => RecoveryHint::RefreshToken,
+            ApiErrorCode::Forbidden => RecoveryHint::CheckScopes,
+            ApiErrorCode::TooManyRequests
+            | ApiErrorCode::InternalServerError
+            | ApiErrorCode::ServiceUnavailable => RecoveryHint::Backoff,
+            // Canva sends lowercase codes, which are not mapped to variants
+            ApiErrorCode::Unknown(code) => match code.to_ascii_lowercase().as_str() {
+                "invalid_access_token" | "revoked_access_token" => RecoveryHint::RefreshToken,
+                "missing_scope" | "permission_denied" => RecoveryHint::CheckScopes,
+                "too_many_requests" | "internal_failure" => RecoveryHint::Backoff,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    /// Hint for an HTTP status
+    fn for_status(status: reqwest::StatusCode) -> Self {
+        match status.as_u16() {
+            401 => RecoveryHint::RefreshToken,
+            403 => RecoveryHint::CheckScopes,
+            408 | 429 | 500..=599 => RecoveryHint::Backoff,
+            _ => RecoveryHint::Permanent,
+        }
+    }
 }
 
 /// API error codes returned by the Canva Connect API
@@ -100,6 +438,28 @@ impl fmt::Display for ApiErrorCode {
     }
 }
 
+impl ApiErrorCode {
+    /// Code for an error response that had no JSON body, from its HTTP status
+    ///
+    /// Statuses without a matching variant become [`ApiErrorCode::Unknown`]
+    /// holding the status number, e.g. `"502"`.
+    pub fn for_status(status: reqwest::StatusCode) -> Self {
+        match status.as_u16() {
+            400 => ApiErrorCode::InvalidRequest,
+            401 => ApiErrorCode::Unauthorized,
+            403 => ApiErrorCode::Forbidden,
+            404 => ApiErrorCode::NotFound,
+            405 => ApiErrorCode::MethodNotAllowed,
+            409 => ApiErrorCode::Conflict,
+            422 => ApiErrorCode::UnprocessableEntity,
+            429 => ApiErrorCode::TooManyRequests,
+            500 => ApiErrorCode::InternalServerError,
+            503 => ApiErrorCode::ServiceUnavailable,
+            _ => ApiErrorCode::Unknown(status.as_str().to_string()),
+        }
+    }
+}
+
 impl From<String> for ApiErrorCode {
     fn from(code: String) -> Self {
         match code.as_str() {
@@ -132,6 +492,7 @@ impl From<ApiError> for Error {
         Error::Api {
             code: ApiErrorCode::from(api_error.code),
             message: api_error.message,
+            context: None,
         }
     }
 }
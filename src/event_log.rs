@@ -0,0 +1,193 @@
+//! Bounded in-memory log of recent API interactions.
+//!
+//! When enabled with [`ClientBuilder::event_log`](crate::client::ClientBuilder::event_log),
+//! the client records one [`ApiEvent`] per request in a ring buffer. Only
+//! metadata is kept: method, path, status, timing, retry count, the Canva
+//! request ID and the rate-limit headers. Request and response bodies, query
+//! strings and credentials are never recorded, so the log can be attached to
+//! support tickets as-is via [`Client::support_bundle`](crate::Client::support_bundle).
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder(AccessToken::new("your-token"))
+//!     .event_log(200)
+//!     .build()?;
+//!
+//! let _ = client.user().get_me().await;
+//!
+//! // One JSON object per line, oldest first
+//! std::fs::write("canva-support.jsonl", client.support_bundle()?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{error::Result, rate_limit::RateLimitInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Metadata about a single API request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiEvent {
+    /// When the request was started
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// HTTP method
+    pub method: String,
+    /// Request path, without query string
+    pub path: String,
+    /// HTTP status of the final response, if one was received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Canva request ID from the `x-request-id` response header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Total time spent on the request, including retries, in milliseconds
+    pub duration_ms: u64,
+    /// Number of attempts made, including the first
+    pub attempts: u32,
+    /// Transport error description, if no response was received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Rate-limit headers from the final response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+/// Ring buffer holding the most recent [`ApiEvent`]s
+#[derive(Debug)]
+pub struct EventLog {
+    capacity: usize,
+    events: Mutex<VecDeque<ApiEvent>>,
+}
+
+impl EventLog {
+    /// Create a log that keeps at most `capacity` events
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Maximum number of events kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record an event, evicting the oldest one when full
+    pub fn record(&self, event: ApiEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut events = self.lock();
+        while events.len() >= self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Copy of the recorded events, oldest first
+    pub fn events(&self) -> Vec<ApiEvent> {
+        self.lock().iter().cloned().collect()
+    }
+
+    /// Number of recorded events
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no events have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Remove all recorded events
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Render the recorded events as JSON lines, oldest first
+    pub fn to_jsonl(&self) -> Result<String> {
+        let mut output = String::new();
+        for event in self.lock().iter() {
+            output.push_str(&serde_json::to_string(event)?);
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<ApiEvent>> {
+        // Events are plain data, so a poisoned lock still holds a usable buffer
+        self.events
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn event(path: &str) -> ApiEvent {
+        ApiEvent {
+            timestamp: chrono::Utc::now(),
+            method: "GET".to_string(),
+            path: path.to_string(),
+            status: Some(200),
+            request_id: None,
+            duration_ms: 12,
+            attempts: 1,
+            error: None,
+            rate_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_event_log_evicts_oldest() {
+        let log = EventLog::new(2);
+        log.record(event("/v1/a"));
+        log.record(event("/v1/b"));
+        log.record(event("/v1/c"));
+
+        let paths: Vec<_> = log.events().into_iter().map(|e| e.path).collect();
+        assert_eq!(paths, vec!["/v1/b", "/v1/c"]);
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_zero_capacity_records_nothing() {
+        let log = EventLog::new(0);
+        log.record(event("/v1/a"));
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn test_to_jsonl_writes_one_event_per_line() {
+        let log = EventLog::new(10);
+        log.record(event("/v1/a"));
+        log.record(event("/v1/b"));
+
+        let jsonl = log.to_jsonl().expect("serializable");
+        let lines: Vec<_> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let parsed: ApiEvent = serde_json::from_str(lines[1]).expect("valid JSON line");
+        assert_eq!(parsed.path, "/v1/b");
+        assert!(!lines[0].contains("request_id"));
+    }
+
+    #[test]
+    fn test_clear() {
+        let log = EventLog::new(10);
+        log.record(event("/v1/a"));
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.to_jsonl().expect("serializable"), "");
+    }
+}
@@ -0,0 +1,16 @@
+//! Preview and experimental subsystems.
+//!
+//! Everything here is compiled only with the `experimental` feature and sits
+//! outside the crate's semver guarantees; see [`stability`](crate::stability).
+//! The same modules are reachable at their original paths while the feature
+//! is enabled.
+//!
+//! | Module | Tier |
+//! |--------|------|
+//! | [`comments`] | Preview |
+//! | [`imports`] | Preview |
+//! | [`workflows`] | Experimental |
+
+pub use crate::endpoints::comments;
+pub use crate::endpoints::imports;
+pub use crate::workflows;
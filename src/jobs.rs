@@ -0,0 +1,699 @@
+//! Polling of asynchronous API jobs.
+//!
+//! Uploads, exports, autofills, imports and resizes all run as jobs that are
+//! created with one request and then polled until they finish. Each kind of
+//! job implements [`PollableJob`], and a [`JobPoller`] drives any of them to
+//! completion:
+//!
+//! - the first status check happens immediately, then every
+//!   [interval](JobPoller::interval);
+//! - [`with_backoff`](JobPoller::with_backoff) stretches the interval after
+//!   each check, up to a ceiling, for jobs that are expected to take a while;
+//! - [`with_jitter`](JobPoller::with_jitter) varies each wait at random, so
+//!   jobs started together do not poll in lockstep;
+//! - [`with_timeout`](JobPoller::with_timeout) gives up with
+//!   [`Error::JobTimeout`] and
+//!   [`with_cancellation`](JobPoller::with_cancellation) stops with
+//!   [`Error::Cancelled`] when a [`CancellationToken`] fires.
+//!
+//! Neither a timeout nor a cancellation affects the job itself, which keeps
+//! running on Canva and can be waited for again.
+//!
+//! Polling is the only way to learn that a job finished. Canva Connect sends
+//! no webhooks for jobs: its only webhook payload is the notification for
+//! shares, comments, approvals, mentions and invites.
+//!
+//! The `wait_for_*` methods on the endpoint APIs use
+//! [`Client::job_poller`](crate::Client::job_poller), set up from the
+//! client's [`JobPollConfig`]; by default they check every two seconds and
+//! wait for as long as the job runs. Each has a `wait_for_*_with` variant taking [`WaitOptions`]
+//! for a timeout, a cancellation token or a different interval; build a
+//! poller yourself for full control, such as backoff.
+//!
+//! Applications that start many jobs can register them with a
+//! [`JobManager`], which polls them with a concurrency cap, broadcasts
+//! [`JobEvent`]s as they progress and can pick up saved jobs after a restart.
+//! [`JobPollConfig::batch`] suits such runs: it starts at one second, doubles
+//! up to thirty and jitters each wait by 20%. The manager and the bulk
+//! helpers ([`create_autofill_jobs_batch`](crate::endpoints::autofill::AutofillApi::create_autofill_jobs_batch),
+//! [`export_designs`](crate::endpoints::exports::ExportsApi::export_designs) and
+//! [`upload_directory`](crate::endpoints::assets::AssetsApi::upload_directory))
+//! use those settings when the client keeps the default config, and
+//! otherwise add that jitter to the client's config if it has none. Status checks
+//! go through the client's per-endpoint rate limits like any other request, so
+//! a large batch waits for quota rather than running into HTTP 429.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken, jobs::CancellationToken};
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-token"))?;
+//! let cancel = CancellationToken::new();
+//!
+//! let poller = client
+//!     .job_poller()
+//!     .with_backoff(1.5, Duration::from_secs(20))
+//!     .with_timeout(Duration::from_secs(600))
+//!     .with_cancellation(cancel.clone());
+//! let job = poller
+//!     .wait(&client.exports().pending_export_job("export-id"))
+//!     .await?;
+//! println!("Export ready: {:?}", job.urls);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use std::future::Future;
+use std::time::Duration;
+
+pub mod manager;
+pub use manager::{JobEvent, JobKind, JobManager, JobOutput, TrackedJob};
+pub use tokio_util::sync::CancellationToken;
+
+/// Default ceiling for the interval between status checks when backing off
+pub const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Jitter applied when many jobs are polled together, as a fraction of each wait
+pub const BATCH_POLL_JITTER: f64 = 0.2;
+
+/// Largest jitter accepted, so no wait shrinks to less than half its length
+pub const MAX_POLL_JITTER: f64 = 0.5;
+
+/// An asynchronous job whose status can be checked
+#[async_trait::async_trait]
+pub trait PollableJob: Send + Sync {
+    /// What the job produces once it has finished
+    type Output: Send;
+
+    /// The job's ID
+    fn id(&self) -> &str;
+
+    /// Check the job's status once
+    ///
+    /// Returns `Ok(None)` while the job is still running and the job's
+    /// output once it has finished. A job that failed, or a status check
+    /// that could not be made, is an error.
+    async fn poll(&self) -> Result<Option<Self::Output>>;
+}
+
+/// Timeout, cancellation and interval for the `wait_for_*_with` methods
+///
+/// Anything left unset falls back to the client's
+/// [job poller](crate::Client::job_poller).
+///
+/// A wait that stops early fails with [`Error::JobTimeout`] when the timeout
+/// runs out or [`Error::Cancelled`] when the token is cancelled; the job
+/// itself keeps running on Canva's side.
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken, Error};
+/// use canva_connect::jobs::{CancellationToken, WaitOptions};
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("your-token"))?;
+/// let cancel = CancellationToken::new();
+/// let options = WaitOptions::new()
+///     .with_timeout(Duration::from_secs(120))
+///     .with_cancellation(cancel.clone());
+///
+/// match client.exports().wait_for_export_job_with("export-id", &options).await {
+///     Ok(job) => println!("Export ready: {:?}", job.urls),
+///     Err(Error::JobTimeout { job_id, .. }) => println!("{job_id} is still running"),
+///     Err(Error::Cancelled { .. }) => println!("Stopped waiting"),
+///     Err(e) => return Err(e.into()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WaitOptions {
+    timeout: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+    poll_interval: Option<Duration>,
+}
+
+impl WaitOptions {
+    /// Wait as the client's job poller does
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up with [`Error::JobTimeout`] after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Stop with [`Error::Cancelled`] when `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Check the job's status every `interval` instead of the client's
+    /// [poll interval](crate::Client::poll_interval)
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = Some(interval);
+        self
+    }
+
+    /// How long to wait before giving up, if set
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Token that stops the wait, if set
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Interval between status checks, if overridden
+    pub fn poll_interval(&self) -> Option<Duration> {
+        self.poll_interval
+    }
+}
+
+/// Default interval between status checks
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Client-wide defaults for waiting on jobs
+///
+/// Set once with
+/// [`ClientBuilder::job_poll_config`](crate::ClientBuilder::job_poll_config);
+/// every `wait_for_*` method, [`JobManager`] and
+/// [`Client::job_poller`](crate::Client::job_poller) start from it, and
+/// [`WaitOptions`] override it for a single call. Clones of a client share
+/// the same settings.
+///
+/// ```rust
+/// use canva_connect::{Client, auth::AccessToken, jobs::JobPollConfig};
+/// use std::time::Duration;
+///
+/// let client = Client::builder(AccessToken::new("token"))
+///     .job_poll_config(
+///         JobPollConfig::new()
+///             .with_interval(Duration::from_secs(5))
+///             .with_backoff(1.5, Duration::from_secs(60))
+///             .with_timeout(Duration::from_secs(900)),
+///     )
+///     .build()
+///     .expect("valid client");
+/// assert_eq!(client.job_poller().timeout(), Some(Duration::from_secs(900)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobPollConfig {
+    interval: Duration,
+    backoff: f64,
+    max_interval: Duration,
+    jitter: f64,
+    timeout: Option<Duration>,
+}
+
+impl Default for JobPollConfig {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_POLL_INTERVAL,
+            backoff: 1.0,
+            max_interval: DEFAULT_MAX_POLL_INTERVAL,
+            jitter: 0.0,
+            timeout: None,
+        }
+    }
+}
+
+impl JobPollConfig {
+    /// Poll every [`DEFAULT_POLL_INTERVAL`] without backoff or timeout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Settings for runs with many jobs at once
+    ///
+    /// The first wait is one second and doubles after each check up to
+    /// [`DEFAULT_MAX_POLL_INTERVAL`], with each wait varied by
+    /// [`BATCH_POLL_JITTER`]. Short jobs are picked up quickly, while long
+    /// ones settle into infrequent checks spread across the window.
+    pub fn batch() -> Self {
+        Self::new()
+            .with_interval(Duration::from_secs(1))
+            .with_backoff(2.0, DEFAULT_MAX_POLL_INTERVAL)
+            .with_jitter(BATCH_POLL_JITTER)
+    }
+
+    /// Check job status every `interval`
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Multiply the interval by `multiplier` after each check, up to `max_interval`
+    ///
+    /// Multipliers below 1 are treated as 1, as in [`JobPoller::with_backoff`].
+    pub fn with_backoff(mut self, multiplier: f64, max_interval: Duration) -> Self {
+        self.backoff = if multiplier >= 1.0 { multiplier } else { 1.0 };
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Vary each wait at random by up to `fraction` of it either way
+    ///
+    /// Clamped to 0 to [`MAX_POLL_JITTER`], as in [`JobPoller::with_jitter`].
+    pub fn with_jitter(mut self, fraction: f64) -> Self {
+        self.jitter = clamp_jitter(fraction);
+        self
+    }
+
+    /// Give up on a job with [`Error::JobTimeout`] after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time before the second status check
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Factor the interval grows by after each check
+    pub fn backoff(&self) -> f64 {
+        self.backoff
+    }
+
+    /// Longest interval reached by backing off
+    pub fn max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    /// Largest random change to each wait, as a fraction of it
+    pub fn jitter(&self) -> f64 {
+        self.jitter
+    }
+
+    /// How long to wait for a job before giving up, if set
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// A [`JobPoller`] with these settings
+    pub fn poller(&self) -> JobPoller {
+        let poller = JobPoller::new(self.interval)
+            .with_backoff(self.backoff, self.max_interval)
+            .with_jitter(self.jitter);
+        match self.timeout {
+            Some(timeout) => poller.with_timeout(timeout),
+            None => poller,
+        }
+    }
+}
+
+/// How to wait for a [`PollableJob`]
+#[derive(Debug, Clone)]
+pub struct JobPoller {
+    interval: Duration,
+    backoff: f64,
+    max_interval: Duration,
+    jitter: f64,
+    timeout: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+    shutdown: Option<CancellationToken>,
+}
+
+impl JobPoller {
+    /// Check the job's status every `interval`, with no timeout
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            backoff: 1.0,
+            max_interval: DEFAULT_MAX_POLL_INTERVAL,
+            jitter: 0.0,
+            timeout: None,
+            cancellation: None,
+            shutdown: None,
+        }
+    }
+
+    /// Multiply the interval by `multiplier` after each check, up to `max_interval`
+    ///
+    /// Multipliers below 1 are treated as 1, which keeps the interval fixed.
+    pub fn with_backoff(mut self, multiplier: f64, max_interval: Duration) -> Self {
+        self.backoff = if multiplier >= 1.0 { multiplier } else { 1.0 };
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Vary each wait at random by up to `fraction` of it either way
+    ///
+    /// With a fraction of 0.2 a two second wait lasts between 1.6 and 2.4
+    /// seconds, so jobs started together drift apart instead of hitting the
+    /// API in bursts. Backoff grows from the unjittered wait. Fractions are
+    /// clamped to 0 to [`MAX_POLL_JITTER`]; a fraction of 1 would let some
+    /// waits shrink to nothing and poll back to back.
+    pub fn with_jitter(mut self, fraction: f64) -> Self {
+        self.jitter = clamp_jitter(fraction);
+        self
+    }
+
+    /// Give up with [`Error::JobTimeout`] if the job has not finished within `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Stop with [`Error::Cancelled`] as soon as `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Also stop with [`Error::Cancelled`] when the client's shutdown token is cancelled
+    pub(crate) fn with_shutdown(mut self, token: CancellationToken) -> Self {
+        self.shutdown = Some(token);
+        self
+    }
+
+    /// Apply the settings made in `options`, keeping the others
+    pub fn with_options(mut self, options: &WaitOptions) -> Self {
+        if let Some(interval) = options.poll_interval {
+            self.interval = interval;
+        }
+        if let Some(timeout) = options.timeout {
+            self.timeout = Some(timeout);
+        }
+        if let Some(token) = &options.cancellation {
+            self.cancellation = Some(token.clone());
+        }
+        self
+    }
+
+    /// Time before the second status check
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Factor the interval grows by after each check
+    pub fn backoff(&self) -> f64 {
+        self.backoff
+    }
+
+    /// Longest interval reached by backing off
+    ///
+    /// An [`interval`](Self::interval) above this is used as is.
+    pub fn max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    /// Largest random change to each wait, as a fraction of it
+    pub fn jitter(&self) -> f64 {
+        self.jitter
+    }
+
+    /// Add [`BATCH_POLL_JITTER`] unless jitter is already set
+    pub(crate) fn for_batch(self) -> Self {
+        if self.jitter > 0.0 {
+            self
+        } else {
+            self.with_jitter(BATCH_POLL_JITTER)
+        }
+    }
+
+    /// How long to wait before giving up, if set
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Poll `job` until it finishes, times out or is cancelled
+    ///
+    /// With the `observability` feature this runs in a `job.wait` span, and
+    /// each status check in a `job.poll` child span recording its `attempt`.
+    #[cfg_attr(feature = "observability", tracing::instrument(
+        name = "job.wait",
+        skip_all,
+        fields(canva.job_id = job.id())
+    ))]
+    pub async fn wait<J>(&self, job: &J) -> Result<J::Output>
+    where
+        J: PollableJob + ?Sized,
+    {
+        let polling = self.limit(job.id(), self.poll_until_done(job));
+        let cancelled = async {
+            match (&self.cancellation, &self.shutdown) {
+                (Some(token), Some(shutdown)) => tokio::select! {
+                    _ = token.cancelled() => {}
+                    _ = shutdown.cancelled() => {}
+                },
+                (Some(token), None) | (None, Some(token)) => token.cancelled().await,
+                (None, None) => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            biased;
+            _ = cancelled => Err(Error::Cancelled {
+                job_id: job.id().to_string(),
+            }),
+            result = polling => result,
+        }
+    }
+
+    async fn limit<T>(&self, job_id: &str, polling: impl Future<Output = Result<T>>) -> Result<T> {
+        match self.timeout {
+            Some(timeout) => {
+                tokio::time::timeout(timeout, polling)
+                    .await
+                    .map_err(|_| Error::JobTimeout {
+                        job_id: job_id.to_string(),
+                        timeout,
+                    })?
+            }
+            None => polling.await,
+        }
+    }
+
+    async fn poll_until_done<J>(&self, job: &J) -> Result<J::Output>
+    where
+        J: PollableJob + ?Sized,
+    {
+        let mut delay = self.interval;
+        #[cfg(feature = "observability")]
+        let mut attempt: u32 = 0;
+        loop {
+            let poll = job.poll();
+            #[cfg(feature = "observability")]
+            let poll = {
+                attempt += 1;
+                tracing::Instrument::instrument(poll, tracing::info_span!("job.poll", attempt))
+            };
+            if let Some(output) = poll.await? {
+                return Ok(output);
+            }
+            tokio::time::sleep(self.jittered(delay)).await;
+            delay = self.next_delay(delay);
+        }
+    }
+
+    fn jittered(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let factor =
+            1.0 + rand::Rng::gen_range(&mut rand::thread_rng(), -self.jitter..=self.jitter);
+        Duration::from_secs_f64(delay.as_secs_f64() * factor)
+    }
+
+    fn next_delay(&self, delay: Duration) -> Duration {
+        if delay >= self.max_interval {
+            return delay;
+        }
+        let grown = delay.as_secs_f64() * self.backoff;
+        Duration::from_secs_f64(grown.min(self.max_interval.as_secs_f64()))
+    }
+}
+
+fn clamp_jitter(fraction: f64) -> f64 {
+    if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, MAX_POLL_JITTER)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    /// Finishes on the given check, recording when each check happened
+    #[derive(Debug)]
+    struct CountingJob {
+        done_on: u32,
+        checks: AtomicU32,
+        checked_at: Mutex<Vec<tokio::time::Instant>>,
+    }
+
+    impl CountingJob {
+        fn new(done_on: u32) -> Self {
+            Self {
+                done_on,
+                checks: AtomicU32::new(0),
+                checked_at: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn gaps(&self) -> Vec<Duration> {
+            let checked_at = self.checked_at.lock().expect("lock");
+            checked_at.windows(2).map(|w| w[1] - w[0]).collect()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl PollableJob for CountingJob {
+        type Output = u32;
+
+        fn id(&self) -> &str {
+            "job_1"
+        }
+
+        async fn poll(&self) -> Result<Option<u32>> {
+            self.checked_at
+                .lock()
+                .expect("lock")
+                .push(tokio::time::Instant::now());
+            let check = self.checks.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok((check >= self.done_on).then_some(check))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polls_at_fixed_interval_until_done() {
+        let job = CountingJob::new(3);
+
+        let output = JobPoller::new(Duration::from_secs(2))
+            .wait(&job)
+            .await
+            .expect("done");
+
+        assert_eq!(output, 3);
+        assert_eq!(job.gaps(), vec![Duration::from_secs(2); 2]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_backoff_grows_up_to_ceiling() {
+        let job = CountingJob::new(5);
+
+        JobPoller::new(Duration::from_secs(1))
+            .with_backoff(2.0, Duration::from_secs(5))
+            .wait(&job)
+            .await
+            .expect("done");
+
+        let secs: Vec<u64> = job.gaps().iter().map(Duration::as_secs).collect();
+        assert_eq!(secs, vec![1, 2, 4, 5]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_stops_polling() {
+        let job = CountingJob::new(u32::MAX);
+
+        let result = JobPoller::new(Duration::from_secs(2))
+            .with_timeout(Duration::from_secs(5))
+            .wait(&job)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::JobTimeout { ref job_id, timeout })
+                if job_id == "job_1" && timeout == Duration::from_secs(5)
+        ));
+        assert_eq!(job.checks.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancellation_stops_polling() {
+        let job = CountingJob::new(u32::MAX);
+        let token = CancellationToken::new();
+        let poller = JobPoller::new(Duration::from_secs(2)).with_cancellation(token.clone());
+
+        let canceller = async {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            token.cancel();
+        };
+        let (result, ()) = tokio::join!(poller.wait(&job), canceller);
+
+        assert!(matches!(result, Err(Error::Cancelled { ref job_id }) if job_id == "job_1"));
+        assert_eq!(job.checks.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_options_override_only_what_they_set() {
+        let poller = JobPoller::new(Duration::from_secs(2))
+            .with_timeout(Duration::from_secs(60))
+            .with_options(&WaitOptions::new().with_poll_interval(Duration::from_secs(5)));
+        assert_eq!(poller.interval(), Duration::from_secs(5));
+        assert_eq!(poller.timeout(), Some(Duration::from_secs(60)));
+
+        let poller = poller.with_options(&WaitOptions::new().with_timeout(Duration::from_secs(1)));
+        assert_eq!(poller.interval(), Duration::from_secs(5));
+        assert_eq!(poller.timeout(), Some(Duration::from_secs(1)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_jitter_varies_each_wait_within_bounds() {
+        let job = CountingJob::new(40);
+
+        JobPoller::new(Duration::from_secs(10))
+            .with_jitter(0.2)
+            .wait(&job)
+            .await
+            .expect("done");
+
+        let gaps = job.gaps();
+        assert!(gaps
+            .iter()
+            .all(|gap| (Duration::from_secs(8)..=Duration::from_secs(12)).contains(gap)));
+        assert!(gaps.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_batch_config_backs_off_with_jitter() {
+        let poller = JobPollConfig::batch().poller();
+        assert_eq!(poller.interval(), Duration::from_secs(1));
+        assert_eq!(poller.backoff(), 2.0);
+        assert_eq!(poller.max_interval(), DEFAULT_MAX_POLL_INTERVAL);
+        assert_eq!(poller.jitter(), BATCH_POLL_JITTER);
+
+        assert_eq!(
+            JobPoller::new(Duration::ZERO).with_jitter(1.0).jitter(),
+            MAX_POLL_JITTER
+        );
+        assert_eq!(
+            JobPollConfig::new().with_jitter(3.0).jitter(),
+            MAX_POLL_JITTER
+        );
+        assert_eq!(
+            JobPoller::new(Duration::ZERO)
+                .with_jitter(0.3)
+                .for_batch()
+                .jitter(),
+            0.3
+        );
+    }
+
+    #[test]
+    fn test_backoff_below_one_keeps_interval() {
+        let poller =
+            JobPoller::new(Duration::from_secs(2)).with_backoff(0.5, Duration::from_secs(9));
+        assert_eq!(poller.backoff(), 1.0);
+        assert_eq!(
+            poller.next_delay(Duration::from_secs(2)),
+            Duration::from_secs(2)
+        );
+    }
+}
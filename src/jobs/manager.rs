@@ -0,0 +1,573 @@
+//! A registry of outstanding jobs with progress events.
+
+use super::{JobPoller, PollableJob};
+use crate::endpoints::assets::upload_job_outcome;
+use crate::endpoints::autofill::ensure_succeeded;
+use crate::endpoints::designs::resize_job_outcome;
+use crate::endpoints::exports::export_job_outcome;
+#[cfg(feature = "experimental")]
+use crate::endpoints::imports::import_job_outcome;
+use crate::error::Result;
+use crate::models::{
+    Asset, DesignAutofillJob, DesignAutofillStatus, DesignSummary, ExportJob, JobStatus,
+};
+use crate::Client;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::broadcast;
+
+/// Number of events a slow [`JobManager::subscribe`] receiver can fall behind by
+const EVENT_CAPACITY: usize = 256;
+
+/// The kind of a tracked job, which decides how its status is checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    /// Asset upload from file data
+    AssetUpload,
+    /// Asset upload from a URL
+    UrlAssetUpload,
+    /// Brand template autofill
+    Autofill,
+    /// Design export
+    Export,
+    /// Design import from file data
+    Import,
+    /// Design import from a URL
+    UrlImport,
+    /// Design resize
+    Resize,
+}
+
+/// A job known to a [`JobManager`]
+///
+/// Serializable so the outstanding jobs can be saved and handed to
+/// [`JobManager::resume`] after a restart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackedJob {
+    /// Job ID
+    pub id: String,
+    /// What kind of job this is
+    pub kind: JobKind,
+    /// Last known status
+    pub status: JobStatus,
+    /// When the job was first tracked
+    pub started_at: DateTime<Utc>,
+    /// When the job was seen to finish
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Number of status checks made so far
+    #[serde(default)]
+    pub checks: u32,
+    /// Why the job failed, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TrackedJob {
+    fn new(id: String, kind: JobKind) -> Self {
+        Self {
+            id,
+            kind,
+            status: JobStatus::InProgress,
+            started_at: Utc::now(),
+            finished_at: None,
+            checks: 0,
+            error: None,
+        }
+    }
+
+    /// Whether the job has succeeded or failed
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, JobStatus::Success | JobStatus::Failed)
+    }
+}
+
+/// Progress of the jobs in a [`JobManager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JobEvent {
+    /// A job was added with [`track`](JobManager::track) or
+    /// [`resume`](JobManager::resume)
+    Tracked(TrackedJob),
+    /// A status check found the job still running
+    StillRunning(TrackedJob),
+    /// The job succeeded or failed; see its [`status`](TrackedJob::status)
+    Finished(TrackedJob),
+}
+
+/// What a finished job produced
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum JobOutput {
+    /// The uploaded asset
+    Asset(Asset),
+    /// The finished autofill job, with the created design
+    Autofill(DesignAutofillJob),
+    /// The finished export job, with its download URLs
+    Export(ExportJob),
+    /// The imported designs
+    Designs(Vec<DesignSummary>),
+    /// The resized copy of a design
+    Design(DesignSummary),
+}
+
+/// Tracks outstanding jobs across an application
+///
+/// Register jobs as they are created with [`track`](Self::track), watch
+/// their progress with [`subscribe`](Self::subscribe), and poll everything
+/// still running with [`await_all`](Self::await_all). Clones share the same
+/// registry, so one manager can be handed to every task that starts jobs.
+///
+/// Only a job Canva reports as failed is recorded as failed. If waiting ends
+/// for another reason, such as a timeout, cancellation or a network error,
+/// the job stays outstanding and the error is returned with it. Save
+/// [`jobs`](Self::jobs) and pass them to [`resume`](Self::resume) to carry
+/// on polling after a restart.
+///
+/// ## Examples
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken};
+/// use canva_connect::jobs::{JobEvent, JobKind, JobManager};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("your-token"))?;
+/// let manager = JobManager::new(client.clone());
+///
+/// let mut events = manager.subscribe();
+/// tokio::spawn(async move {
+///     while let Ok(event) = events.recv().await {
+///         if let JobEvent::Finished(job) = event {
+///             println!("{} finished: {:?}", job.id, job.status);
+///         }
+///     }
+/// });
+///
+/// for design_id in ["DAF1", "DAF2", "DAF3"] {
+///     let request = canva_connect::endpoints::exports::CreateDesignExportJobRequest {
+///         design_id: design_id.to_string(),
+///         format: canva_connect::models::ExportFormat::pdf(),
+///     };
+///     let job = client.exports().create_design_export_job(&request).await?.job;
+///     manager.track(JobKind::Export, &job.id);
+/// }
+///
+/// for (job, result) in manager.await_all(2).await {
+///     println!("{}: {}", job.id, if result.is_ok() { "ok" } else { "failed" });
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JobManager {
+    client: Client,
+    poller: JobPoller,
+    jobs: Arc<Mutex<HashMap<String, TrackedJob>>>,
+    events: broadcast::Sender<JobEvent>,
+}
+
+impl JobManager {
+    /// Create a manager that checks jobs on the client's behalf
+    ///
+    /// A client with the default [`JobPollConfig`](super::JobPollConfig)
+    /// polls with [`JobPollConfig::batch`](super::JobPollConfig::batch).
+    /// Otherwise the client's [job poller](Client::job_poller) is used, with
+    /// each wait varied by [`BATCH_POLL_JITTER`](super::BATCH_POLL_JITTER)
+    /// unless it sets jitter, so the tracked jobs do not poll in step.
+    pub fn new(client: Client) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            poller: client.batch_job_poller(),
+            client,
+            jobs: Arc::default(),
+            events,
+        }
+    }
+
+    /// Wait for jobs with `poller` instead, e.g. to back off or time out
+    pub fn with_poller(mut self, poller: JobPoller) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Start tracking a job that has just been created
+    ///
+    /// Tracking a job that is already known and still running has no
+    /// effect; a finished job with the same ID is replaced.
+    pub fn track(&self, kind: JobKind, job_id: &str) -> TrackedJob {
+        let mut jobs = self.lock();
+        if let Some(existing) = jobs.get(job_id).filter(|job| !job.is_finished()) {
+            return existing.clone();
+        }
+        let job = TrackedJob::new(job_id.to_string(), kind);
+        jobs.insert(job.id.clone(), job.clone());
+        drop(jobs);
+        self.emit(JobEvent::Tracked(job.clone()));
+        job
+    }
+
+    /// Track jobs saved from an earlier run
+    ///
+    /// Jobs keep their recorded start time and status; those still in
+    /// progress are polled by the next [`await_all`](Self::await_all).
+    pub fn resume(&self, jobs: impl IntoIterator<Item = TrackedJob>) {
+        for job in jobs {
+            self.lock().insert(job.id.clone(), job.clone());
+            self.emit(JobEvent::Tracked(job));
+        }
+    }
+
+    /// Receive an event for every change to a tracked job
+    ///
+    /// Receivers that fall more than 256 events behind miss the oldest ones.
+    pub fn subscribe(&self) -> broadcast::Receiver<JobEvent> {
+        self.events.subscribe()
+    }
+
+    /// A job by ID, if tracked
+    pub fn get(&self, job_id: &str) -> Option<TrackedJob> {
+        self.lock().get(job_id).cloned()
+    }
+
+    /// Every tracked job, oldest first
+    pub fn jobs(&self) -> Vec<TrackedJob> {
+        let mut jobs: Vec<TrackedJob> = self.lock().values().cloned().collect();
+        jobs.sort_by(|a, b| a.started_at.cmp(&b.started_at).then(a.id.cmp(&b.id)));
+        jobs
+    }
+
+    /// Tracked jobs that are still running, oldest first
+    pub fn outstanding(&self) -> Vec<TrackedJob> {
+        self.jobs()
+            .into_iter()
+            .filter(|job| !job.is_finished())
+            .collect()
+    }
+
+    /// Stop tracking jobs that have finished
+    pub fn clear_finished(&self) {
+        self.lock().retain(|_, job| !job.is_finished());
+    }
+
+    /// Poll every outstanding job until it finishes
+    ///
+    /// At most `concurrency` jobs are polled at a time. Returns each job's
+    /// final record with its output, in the order the jobs finished.
+    pub async fn await_all(&self, concurrency: usize) -> Vec<(TrackedJob, Result<JobOutput>)> {
+        futures::stream::iter(self.outstanding())
+            .map(|job| self.await_job(job))
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    async fn await_job(&self, job: TrackedJob) -> (TrackedJob, Result<JobOutput>) {
+        let managed = ManagedJob {
+            manager: self,
+            id: job.id,
+            kind: job.kind,
+        };
+        let result = self.poller.wait(&managed).await;
+        let record = match &result {
+            Ok(_) => {
+                let finished = self.update(&managed.id, managed.kind, |job| {
+                    job.status = JobStatus::Success;
+                    job.finished_at = Some(Utc::now());
+                });
+                self.emit(JobEvent::Finished(finished.clone()));
+                finished
+            }
+            // A failed job was recorded by the check that saw it; anything
+            // else (timeouts, cancellation, network errors) leaves it pending
+            Err(_) => self.update(&managed.id, managed.kind, |_| {}),
+        };
+        (record, result)
+    }
+
+    /// Check a job's status once, counting the check
+    ///
+    /// Errors fetching the status are returned without touching the record;
+    /// only a job Canva reports as failed is recorded as [`JobStatus::Failed`].
+    async fn check(&self, kind: JobKind, job_id: &str) -> Result<Option<JobOutput>> {
+        let outcome = match kind {
+            JobKind::AssetUpload => {
+                upload_job_outcome(self.client.assets().get_upload_job(job_id).await?)
+                    .map(|asset| asset.map(JobOutput::Asset))
+            }
+            JobKind::UrlAssetUpload => {
+                upload_job_outcome(self.client.assets().get_url_upload_job(job_id).await?)
+                    .map(|asset| asset.map(JobOutput::Asset))
+            }
+            JobKind::Autofill => {
+                let job = self.client.autofill().get_autofill_job(job_id).await?;
+                match job.status {
+                    DesignAutofillStatus::InProgress | DesignAutofillStatus::Unknown => Ok(None),
+                    DesignAutofillStatus::Success | DesignAutofillStatus::Failed => {
+                        ensure_succeeded(job).map(|job| Some(JobOutput::Autofill(job)))
+                    }
+                }
+            }
+            JobKind::Export => export_job_outcome(
+                self.client
+                    .exports()
+                    .get_design_export_job(job_id)
+                    .await?
+                    .job,
+            )
+            .map(|job| job.map(JobOutput::Export)),
+            #[cfg(feature = "experimental")]
+            JobKind::Import => {
+                import_job_outcome(self.client.imports().get_import_job(job_id).await?)
+                    .map(|designs| designs.map(JobOutput::Designs))
+            }
+            #[cfg(feature = "experimental")]
+            JobKind::UrlImport => {
+                import_job_outcome(self.client.imports().get_url_import_job(job_id).await?)
+                    .map(|designs| designs.map(JobOutput::Designs))
+            }
+            #[cfg(not(feature = "experimental"))]
+            JobKind::Import | JobKind::UrlImport => {
+                return Err(crate::Error::Generic(
+                    "Import jobs need the `experimental` feature".to_string(),
+                ))
+            }
+            JobKind::Resize => {
+                resize_job_outcome(self.client.designs().get_resize_job(job_id).await?)
+                    .map(|design| design.map(JobOutput::Design))
+            }
+        };
+        let checked = self.update(job_id, kind, |job| {
+            job.checks += 1;
+            if let Err(e) = &outcome {
+                job.status = JobStatus::Failed;
+                job.error = Some(e.to_string());
+                job.finished_at = Some(Utc::now());
+            }
+        });
+        match &outcome {
+            Ok(None) => self.emit(JobEvent::StillRunning(checked)),
+            Err(_) => self.emit(JobEvent::Finished(checked)),
+            Ok(Some(_)) => {}
+        }
+        outcome
+    }
+
+    /// Apply `change` to a job's record and return the result
+    fn update(
+        &self,
+        job_id: &str,
+        kind: JobKind,
+        change: impl FnOnce(&mut TrackedJob),
+    ) -> TrackedJob {
+        let mut jobs = self.lock();
+        match jobs.get_mut(job_id) {
+            Some(job) => {
+                change(job);
+                job.clone()
+            }
+            // Cleared while being polled: report on a detached record
+            None => {
+                let mut job = TrackedJob::new(job_id.to_string(), kind);
+                change(&mut job);
+                job
+            }
+        }
+    }
+
+    fn emit(&self, event: JobEvent) {
+        // Sending only fails when nobody is subscribed
+        let _ = self.events.send(event);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, TrackedJob>> {
+        self.jobs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A tracked job as seen by the [`JobPoller`]
+struct ManagedJob<'a> {
+    manager: &'a JobManager,
+    id: String,
+    kind: JobKind,
+}
+
+#[async_trait::async_trait]
+impl PollableJob for ManagedJob<'_> {
+    type Output = JobOutput;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn poll(&self) -> Result<Option<JobOutput>> {
+        self.manager.check(self.kind, &self.id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::auth::AccessToken;
+    use crate::testing::{MockResponse, MockTransport};
+    use reqwest::Method;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn export_job(id: &str, status: &str) -> MockResponse {
+        MockResponse::json(
+            200,
+            &json!({ "job": { "id": id, "status": status, "urls": [] } }),
+        )
+    }
+
+    fn manager(transport: &MockTransport) -> JobManager {
+        let client = Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .build()
+            .expect("client");
+        JobManager::new(client).with_poller(JobPoller::new(Duration::ZERO))
+    }
+
+    #[tokio::test]
+    async fn test_await_all_polls_outstanding_jobs_and_reports_events() {
+        let transport = MockTransport::new();
+        transport
+            .on(
+                Method::GET,
+                "/rest/v1/exports/E1",
+                export_job("E1", "in_progress"),
+            )
+            .on(
+                Method::GET,
+                "/rest/v1/exports/E1",
+                export_job("E1", "success"),
+            )
+            .on(
+                Method::GET,
+                "/rest/v1/exports/E2",
+                export_job("E2", "failed"),
+            );
+        let manager = manager(&transport);
+        let mut events = manager.subscribe();
+
+        manager.track(JobKind::Export, "E1");
+        manager.track(JobKind::Export, "E2");
+        manager.track(JobKind::Export, "E1");
+        assert_eq!(manager.outstanding().len(), 2);
+
+        let results = manager.await_all(1).await;
+        assert_eq!(results.len(), 2);
+        let e1 = manager.get("E1").expect("E1 tracked");
+        assert_eq!(e1.status, JobStatus::Success);
+        assert_eq!(e1.checks, 2);
+        assert!(e1.finished_at.is_some());
+        let e2 = manager.get("E2").expect("E2 tracked");
+        assert_eq!(e2.status, JobStatus::Failed);
+        assert!(e2.error.is_some());
+        assert!(results.iter().any(|(job, result)| job.id == "E1"
+            && matches!(result, Ok(JobOutput::Export(export)) if export.id == "E1")));
+        assert!(manager.outstanding().is_empty());
+
+        let mut seen = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            seen.push(match event {
+                JobEvent::Tracked(job) => format!("tracked {}", job.id),
+                JobEvent::StillRunning(job) => format!("running {}", job.id),
+                JobEvent::Finished(job) => format!("finished {}", job.id),
+            });
+        }
+        assert_eq!(
+            seen,
+            [
+                "tracked E1",
+                "tracked E2",
+                "running E1",
+                "finished E1",
+                "finished E2"
+            ]
+        );
+
+        manager.clear_finished();
+        assert!(manager.jobs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resume_polls_saved_jobs_that_are_still_running() {
+        let transport = MockTransport::new();
+        transport.on(
+            Method::GET,
+            "/rest/v1/resizes/R1",
+            MockResponse::json(
+                200,
+                &json!({ "job": { "id": "R1", "status": "success", "result": { "design": {
+                    "id": "DAF1",
+                    "urls": { "edit_url": "e", "view_url": "v" },
+                    "created_at": 1,
+                    "updated_at": 1
+                } } } }),
+            ),
+        );
+
+        let before_restart = manager(&transport);
+        before_restart.track(JobKind::Resize, "R1");
+        before_restart.track(JobKind::Export, "E9");
+        let saved = serde_json::to_string(&before_restart.jobs()).expect("serialize");
+        let mut jobs: Vec<TrackedJob> = serde_json::from_str(&saved).expect("deserialize");
+        for job in jobs.iter_mut().filter(|job| job.id == "E9") {
+            job.status = JobStatus::Success;
+        }
+
+        let after_restart = manager(&transport);
+        after_restart.resume(jobs);
+        let results = after_restart.await_all(4).await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(&results[0].1, Ok(JobOutput::Design(design)) if design.id == "DAF1"));
+        assert!(transport
+            .requests_to(Method::GET, "/rest/v1/exports/E9")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_errors_other_than_a_failed_job_leave_it_outstanding() {
+        let transport = MockTransport::new();
+        transport
+            .on(
+                Method::GET,
+                "/rest/v1/exports/E1",
+                MockResponse::json(404, &json!({ "code": "not_found", "message": "Not found" })),
+            )
+            .on(
+                Method::GET,
+                "/rest/v1/exports/E2",
+                export_job("E2", "in_progress"),
+            );
+        let manager = manager(&transport);
+        manager.track(JobKind::Export, "E1");
+        let e1 = manager.await_all(1).await;
+        assert!(e1[0].1.is_err());
+
+        let manager = manager.with_poller(
+            JobPoller::new(Duration::from_millis(5)).with_timeout(Duration::from_millis(20)),
+        );
+        manager.track(JobKind::Export, "E2");
+        let results = manager.await_all(2).await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, result)| result.is_err()));
+        for job in manager.jobs() {
+            assert_eq!(job.status, JobStatus::InProgress, "{}", job.id);
+            assert!(job.error.is_none());
+            assert!(job.finished_at.is_none());
+        }
+        assert_eq!(manager.outstanding().len(), 2);
+    }
+}
@@ -1,4 +1,5 @@
 #![deny(clippy::unwrap_used, clippy::expect_used, clippy::unimplemented)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
 //! # Canva Connect API Client
 //!
@@ -11,7 +12,10 @@
 //! - **Type safety** - Strongly typed API with comprehensive error handling
 //! - **OAuth 2.0 authentication** - Full support for Canva's OAuth flow
 //! - **Rate limiting** - Built-in rate limiting to respect API quotas
-//! - **Async job handling** - Support for long-running operations like uploads and exports
+//! - **Debug logging** - Opt-in request/response logging with credentials redacted (see [`debug_log`])
+//! - **Response caching** - Optional `ETag`-aware cache for repeated reads (see [`cache`])
+//! - **Async job handling** - Support for long-running operations like uploads and exports, with
+//!   configurable polling, timeouts and cancellation (see [`jobs`])
 //! - **Enterprise features** - Support for brand templates and autofill APIs
 //!
 //! ## Installation
@@ -77,10 +81,15 @@
 //!   - List user's designs with search and filtering
 //!   - Get design metadata and URLs
 //!   - Create new designs from presets or custom dimensions
+//! - **`endpoints::imports`** (preview) - Import external files as Canva designs
+//!   - Import PDF, PPTX, DOCX and other documents from files or URLs
+//!   - Poll import jobs until the designs are ready
 //! - **[`endpoints::user`]** - User profile and account information
 //!   - Get user profile details
 //!   - Check user capabilities and features
 //!   - User identification and verification
+//! - **`workflows`** (experimental) - Common multi-step journeys in one call
+//!   - Find a brand template by title and autofill it
 //!
 //! ### Enterprise Endpoints (Coming Soon)
 //! - **Brand Templates** - Work with brand templates and corporate designs
@@ -101,6 +110,55 @@
 //! - `design:content:read` - Read access to design content
 //! - `design:content:write` - Write access to design content
 //!
+//! ## Stability
+//!
+//! Stable modules are available at the crate root and follow semver. Preview
+//! and experimental subsystems ([`experimental`]: comments, imports and workflows) need
+//! the `experimental` feature, which is on by default. Building with
+//! `default-features = false` keeps a project on stable APIs only; see
+//! [`stability`] for the tiers.
+//!
+//! ## Cancellation Safety
+//!
+//! Every future returned by this crate can be dropped at any `.await` point,
+//! for example when it loses a `tokio::select!` race or hits a
+//! `tokio::time::timeout`, without leaving the client in a bad state:
+//!
+//! - **Job waiters** (`wait_for_*`) only poll job status. Dropping one stops
+//!   the polling; the job keeps running on Canva and calling the waiter again
+//!   with the same job ID picks up where it left off. To stop after a
+//!   deadline or on a signal, use the `wait_for_*_with` variants, which take
+//!   [`jobs::WaitOptions`].
+//! - **Token requests** ([`auth::OAuthClient::refresh_token`] and
+//!   [`auth::OAuthClient::exchange_code_with_pkce`]) run on a separate task,
+//!   so a rotated refresh token or a consumed authorization code is always
+//!   stored even if the caller stops waiting.
+//! - **Rate limiting** capacity is only consumed when a request is actually
+//!   sent, and no internal lock is held across network or file I/O except
+//!   the rate limiter's own queue, which is released on drop.
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-access-token"))?;
+//! let exports = client.exports();
+//!
+//! tokio::select! {
+//!     job = exports.wait_for_export_job("export-id") => println!("done: {:?}", job?.urls),
+//!     _ = tokio::time::sleep(Duration::from_secs(30)) => {
+//!         // Still running on Canva; wait_for_export_job can be called again later
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To stop a service cleanly, [`Client::shutdown`] refuses new requests,
+//! stops job waiters and waits a bounded time for requests already sent.
+//!
 //! ## Examples
 //!
 //! ### Asset Upload from File
@@ -224,12 +282,7 @@
 //!     // Create export job
 //!     let export_request = CreateDesignExportJobRequest {
 //!         design_id: "design-id".to_string(),
-//!         format: ExportFormat::Png {
-//!             export_quality: None,
-//!             height: None,
-//!             width: None,
-//!             pages: None,
-//!         },
+//!         format: ExportFormat::png(),
 //!     };
 //!     
 //!     let export_job = client.exports().create_design_export_job(&export_request).await?;
@@ -254,7 +307,7 @@
 //!         
 //!     match client.assets().get("invalid-id").await {
 //!         Ok(asset) => println!("Asset: {}", asset.name),
-//!         Err(Error::Api { code, message }) => {
+//!         Err(Error::Api { code, message, .. }) => {
 //!             println!("API error {}: {}", code, message);
 //!         }
 //!         Err(Error::Http(e)) => {
@@ -285,6 +338,13 @@
 //! }
 //! ```
 //!
+//! Each endpoint also has its own bucket matching Canva's documented quota;
+//! see [`rate_limit::EndpointRateLimits`] to change them. The client also
+//! follows the server's lead: it waits out `Retry-After` on HTTP 429 and slows
+//! down as `X-RateLimit-Remaining` runs low. Register a
+//! [`rate_limit::ThrottleListener`] to observe those pauses, or read
+//! [`Client::last_rate_limit_info`] for the quota left.
+//!
 //! For more comprehensive examples, see the `examples/` directory in the repository:
 //! - [`examples/asset_upload.rs`] - File-based asset upload with progress tracking
 //! - [`examples/url_asset_upload.rs`] - URL-based asset upload with metadata updates
@@ -295,14 +355,36 @@
 //! - [`examples/observability.rs`] - OpenTelemetry tracing integration
 
 pub mod auth;
+pub mod cache;
 pub mod client;
+pub mod compat;
+pub mod config;
+pub mod debug_log;
+pub mod downloads;
 pub mod endpoints;
 pub mod error;
+pub mod event_log;
+#[cfg(feature = "experimental")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+pub mod experimental;
+pub mod jobs;
+pub mod links;
 pub mod models;
 pub mod observability;
+pub mod operations;
+pub mod query;
 pub mod rate_limit;
+pub mod retry;
+pub mod routes;
+pub mod stability;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+pub mod uploads;
+#[cfg(feature = "experimental")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
+pub mod workflows;
 
-pub use client::Client;
+pub use client::{Client, ClientBuilder};
 pub use error::{Error, Result};
 pub use models::*;
 
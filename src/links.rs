@@ -0,0 +1,306 @@
+//! "Open in Canva" deep links.
+//!
+//! Designs and brand templates come with edit, view and create URLs. A
+//! [`DeepLink`] wraps one of them after checking that it points at Canva over
+//! HTTPS, and adds tracking parameters such as UTM tags without disturbing
+//! the signed parameters Canva already put in the URL.
+//!
+//! With the `qrcode` feature, links can also be rendered as QR codes for
+//! kiosks and printed material.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("your-token"))?;
+//! let design = client.designs().get("DAFVztcvd9z").await?.design;
+//!
+//! let link = design
+//!     .edit_link()?
+//!     .utm_source("intranet")
+//!     .utm_campaign("spring-launch")
+//!     .to_string();
+//! println!("Open in Canva: {link}");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use crate::models::{BrandTemplate, Design, DesignLinks, DesignSummary};
+use std::fmt;
+
+/// Hosts deep links may point at, including their subdomains
+const CANVA_HOSTS: &[&str] = &["canva.com", "canva.cn"];
+
+/// A validated link into the Canva editor or viewer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeepLink {
+    url: reqwest::Url,
+}
+
+impl DeepLink {
+    /// Parse and validate a Canva URL
+    ///
+    /// Fails with [`Error::InvalidUrl`] if `url` does not parse, and with
+    /// [`Error::Generic`] if it is not an HTTPS URL on a Canva domain.
+    pub fn parse(url: &str) -> Result<Self> {
+        let url = reqwest::Url::parse(url)?;
+        if url.scheme() != "https" {
+            return Err(Error::Generic(format!(
+                "Deep link must use https, got {}",
+                url.scheme()
+            )));
+        }
+        let host = url.host_str().unwrap_or_default();
+        let is_canva = CANVA_HOSTS
+            .iter()
+            .any(|canva| host == *canva || host.ends_with(&format!(".{canva}")));
+        if !is_canva {
+            return Err(Error::Generic(format!(
+                "Deep link host {host:?} is not a Canva domain"
+            )));
+        }
+        Ok(Self { url })
+    }
+
+    /// Add a query parameter, replacing any existing value for `name`
+    ///
+    /// The rest of the query is kept exactly as it was, so signed values
+    /// such as the `ui` token in design URLs are not re-encoded.
+    pub fn param(mut self, name: &str, value: &str) -> Self {
+        let mut query: Vec<String> = self
+            .url
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty() && !is_pair_named(pair, name))
+            .map(str::to_string)
+            .collect();
+        query.push(
+            url::form_urlencoded::Serializer::new(String::new())
+                .append_pair(name, value)
+                .finish(),
+        );
+        self.url.set_query(Some(&query.join("&")));
+        self
+    }
+
+    /// Set `utm_source`, e.g. the name of the tool linking to Canva
+    pub fn utm_source(self, source: &str) -> Self {
+        self.param("utm_source", source)
+    }
+
+    /// Set `utm_medium`, e.g. `email` or `kiosk`
+    pub fn utm_medium(self, medium: &str) -> Self {
+        self.param("utm_medium", medium)
+    }
+
+    /// Set `utm_campaign`
+    pub fn utm_campaign(self, campaign: &str) -> Self {
+        self.param("utm_campaign", campaign)
+    }
+
+    /// Set `utm_content`, to tell apart links in the same campaign
+    pub fn utm_content(self, content: &str) -> Self {
+        self.param("utm_content", content)
+    }
+
+    /// The link as a URL
+    pub fn url(&self) -> &reqwest::Url {
+        &self.url
+    }
+
+    /// The link as a string
+    pub fn as_str(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Render the link as a QR code in SVG format
+    ///
+    /// `min_size` is the minimum width and height in pixels.
+    #[cfg(feature = "qrcode")]
+    pub fn to_qr_svg(&self, min_size: u32) -> Result<String> {
+        use qrcode::render::svg;
+
+        Ok(self
+            .qr_code()?
+            .render::<svg::Color<'_>>()
+            .min_dimensions(min_size, min_size)
+            .build())
+    }
+
+    /// Render the link as a QR code drawn with Unicode block characters
+    ///
+    /// Suitable for printing to a terminal.
+    #[cfg(feature = "qrcode")]
+    pub fn to_qr_text(&self) -> Result<String> {
+        use qrcode::render::unicode;
+
+        Ok(self
+            .qr_code()?
+            .render::<unicode::Dense1x2>()
+            .quiet_zone(true)
+            .build())
+    }
+
+    #[cfg(feature = "qrcode")]
+    fn qr_code(&self) -> Result<qrcode::QrCode> {
+        qrcode::QrCode::new(self.as_str().as_bytes())
+            .map_err(|e| Error::Generic(format!("Failed to encode QR code: {e}")))
+    }
+}
+
+impl fmt::Display for DeepLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for DeepLink {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl DesignLinks {
+    /// Link that opens the design in the Canva editor
+    pub fn edit_link(&self) -> Result<DeepLink> {
+        DeepLink::parse(&self.edit_url)
+    }
+
+    /// Link that opens the design in the Canva viewer
+    pub fn view_link(&self) -> Result<DeepLink> {
+        DeepLink::parse(&self.view_url)
+    }
+}
+
+impl Design {
+    /// Link that opens the design in the Canva editor
+    ///
+    /// Design URLs are temporary, valid for 30 days after they were fetched.
+    pub fn edit_link(&self) -> Result<DeepLink> {
+        self.urls.edit_link()
+    }
+
+    /// Link that opens the design in the Canva viewer
+    ///
+    /// Design URLs are temporary, valid for 30 days after they were fetched.
+    pub fn view_link(&self) -> Result<DeepLink> {
+        self.urls.view_link()
+    }
+}
+
+impl DesignSummary {
+    /// Link that opens the design in the Canva editor
+    pub fn edit_link(&self) -> Result<DeepLink> {
+        self.urls.edit_link()
+    }
+
+    /// Link that opens the design in the Canva viewer
+    pub fn view_link(&self) -> Result<DeepLink> {
+        self.urls.view_link()
+    }
+}
+
+impl BrandTemplate {
+    /// Link that previews the brand template
+    pub fn view_link(&self) -> Result<DeepLink> {
+        DeepLink::parse(&self.view_url)
+    }
+
+    /// Link that creates a new design from the brand template
+    pub fn create_link(&self) -> Result<DeepLink> {
+        DeepLink::parse(&self.create_url)
+    }
+}
+
+/// Whether a raw `key=value` query pair has the key `name` once decoded
+fn is_pair_named(pair: &str, name: &str) -> bool {
+    url::form_urlencoded::parse(pair.as_bytes())
+        .next()
+        .is_some_and(|(key, _)| key == name)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    const EDIT_URL: &str = "https://www.canva.com/api/design/eyJhbGc/edit?ui=eyJ";
+
+    #[test]
+    fn test_accepts_canva_hosts_only() {
+        assert!(DeepLink::parse(EDIT_URL).is_ok());
+        assert!(DeepLink::parse("https://canva.cn/design/abc/view").is_ok());
+        assert!(DeepLink::parse("http://www.canva.com/design/abc/view").is_err());
+        assert!(DeepLink::parse("https://notcanva.com/design/abc").is_err());
+        assert!(DeepLink::parse("https://canva.com.evil.example/design").is_err());
+        assert!(matches!(
+            DeepLink::parse("not a url"),
+            Err(Error::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn test_utm_parameters_keep_existing_query() {
+        let link = DeepLink::parse(EDIT_URL)
+            .expect("valid link")
+            .utm_source("intranet")
+            .utm_medium("email")
+            .utm_source("portal");
+
+        let pairs: Vec<(String, String)> = link
+            .url()
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("ui".to_string(), "eyJ".to_string()),
+                ("utm_medium".to_string(), "email".to_string()),
+                ("utm_source".to_string(), "portal".to_string()),
+            ]
+        );
+        assert!(link
+            .to_string()
+            .starts_with("https://www.canva.com/api/design/"));
+    }
+
+    #[test]
+    fn test_param_keeps_existing_query_encoding() {
+        let link = DeepLink::parse("https://www.canva.com/design/D1/edit?ui=a%2Bb~c*d&x=1+2")
+            .expect("valid link")
+            .param("utm_source", "my tool")
+            .param("x", "3");
+
+        assert_eq!(
+            link.url().query(),
+            Some("ui=a%2Bb~c*d&utm_source=my+tool&x=3")
+        );
+    }
+
+    #[test]
+    fn test_design_links() {
+        let links = DesignLinks {
+            edit_url: EDIT_URL.to_string(),
+            view_url: "https://www.canva.com/api/design/eyJhbGc/view".to_string(),
+        };
+        assert_eq!(links.edit_link().expect("edit").as_str(), EDIT_URL);
+        assert!(links.view_link().expect("view").as_str().ends_with("/view"));
+    }
+
+    #[cfg(feature = "qrcode")]
+    #[test]
+    fn test_qr_rendering() {
+        let link = DeepLink::parse(EDIT_URL).expect("valid link");
+        let svg = link.to_qr_svg(200).expect("svg");
+        assert!(svg.contains("<svg"));
+        assert!(!link.to_qr_text().expect("text").is_empty());
+    }
+}
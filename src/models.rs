@@ -1,10 +1,109 @@
 //! Data models for the Canva Connect API
+//!
+//! With the `schemars` feature, these models and the request and response
+//! types of the endpoint modules implement `schemars::JsonSchema`, so JSON
+//! Schemas can be generated from them:
+//!
+//! ```rust
+//! # #[cfg(feature = "schemars")]
+//! # {
+//! use canva_connect::models::Design;
+//!
+//! let schema = schemars::schema_for!(Design);
+//! println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+//! # }
+//! ```
+//!
+//! Timestamps are described as integer Unix seconds, which is how they are
+//! serialized.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Give an enum of API string values `ALL`, `as_str`, `Display` and `FromStr`
+///
+/// `Display` writes the value as the API spells it and `FromStr` reads it
+/// back, ignoring ASCII case. A variant named after `unknown` stands for
+/// values newer than the crate: it displays as `unknown` and is never parsed.
+macro_rules! api_strings {
+    (
+        $name:ident ($what:literal) {
+            $($variant:ident => $value:literal),+ $(,)?
+        }
+        $(unknown: $unknown:ident)?
+    ) => {
+        impl $name {
+            /// Every value the API defines
+            pub const ALL: [$name; <[&str]>::len(&[$($value),+])] = [$($name::$variant),+];
+
+            /// The value as the API spells it
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $($name::$unknown => "unknown",)?
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::Error;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                Self::ALL
+                    .into_iter()
+                    .find(|value| value.as_str().eq_ignore_ascii_case(s))
+                    .ok_or_else(|| {
+                        crate::Error::Generic(format!(
+                            "Unknown {} {s:?}; expected one of {}",
+                            $what,
+                            Self::ALL.map(|value| value.as_str()).join(", ")
+                        ))
+                    })
+            }
+        }
+    };
+}
+pub(crate) use api_strings;
+
+pub mod borrowed;
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+mod chart_data;
+
+/// Conversion from the `chrono` timestamps used by the models to the
+/// [`time`] crate
+///
+/// Every model timestamp is a `chrono::DateTime<Utc>`; bring this trait into
+/// scope to hand them to code built on `time` instead.
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub trait ToOffsetDateTime {
+    /// The same instant as a UTC [`time::OffsetDateTime`]
+    ///
+    /// Fails only for dates outside the years -9999 to 9999 that `time`
+    /// supports, which the API never returns.
+    fn to_offset_date_time(&self) -> crate::Result<time::OffsetDateTime>;
+}
+
+#[cfg(feature = "time")]
+impl ToOffsetDateTime for chrono::DateTime<chrono::Utc> {
+    fn to_offset_date_time(&self) -> crate::Result<time::OffsetDateTime> {
+        let nanos = i128::from(self.timestamp()) * 1_000_000_000
+            + i128::from(self.timestamp_subsec_nanos());
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .map_err(|e| crate::Error::Generic(format!("Timestamp out of range: {e}")))
+    }
+}
+
 /// Asset metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Asset {
     /// Asset ID
     pub id: String,
@@ -19,14 +118,20 @@ pub struct Asset {
     pub thumbnail: Option<Thumbnail>,
     /// Asset creation timestamp
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Asset last updated timestamp
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Fields this version of the crate does not know about, kept as sent
+    #[serde(flatten, default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Asset type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum AssetType {
     /// Image asset
@@ -35,10 +140,20 @@ pub enum AssetType {
     Video,
     /// Audio asset
     Audio,
+    /// An asset type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
+api_strings!(AssetType ("asset type") {
+    Image => "image",
+    Video => "video",
+    Audio => "audio",
+} unknown: Unknown);
+
 /// Thumbnail information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Thumbnail {
     /// Thumbnail URL
     pub url: String,
@@ -50,6 +165,7 @@ pub struct Thumbnail {
 
 /// Design metadata (full details)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Design {
     /// Design ID
     pub id: String,
@@ -63,16 +179,22 @@ pub struct Design {
     pub urls: DesignLinks,
     /// Design creation timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Design last updated timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub updated_at: chrono::DateTime<chrono::Utc>,
     /// Total number of pages in the design
     pub page_count: Option<u32>,
+    /// Fields this version of the crate does not know about, kept as sent
+    #[serde(flatten, default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Design summary (basic details without owner)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DesignSummary {
     /// Design ID
     pub id: String,
@@ -84,16 +206,22 @@ pub struct DesignSummary {
     pub urls: DesignLinks,
     /// Design creation timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Design last updated timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub updated_at: chrono::DateTime<chrono::Utc>,
     /// Total number of pages in the design
     pub page_count: Option<u32>,
+    /// Fields this version of the crate does not know about, kept as sent
+    #[serde(flatten, default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Team user summary containing user and team IDs
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TeamUserSummary {
     /// User ID
     pub user_id: String,
@@ -103,6 +231,7 @@ pub struct TeamUserSummary {
 
 /// Design URLs for editing and viewing
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DesignLinks {
     /// Temporary edit URL (valid for 30 days)
     pub edit_url: String,
@@ -110,30 +239,79 @@ pub struct DesignLinks {
     pub view_url: String,
 }
 
-/// Request to list designs
+/// Filters for listing designs, defined alongside [`DesignsApi::list_with`](crate::endpoints::designs::DesignsApi::list_with)
+pub use crate::endpoints::designs::ListDesignsRequest;
+
+/// One page of results from a list endpoint
+///
+/// Every list endpoint returns its items together with a continuation token
+/// when more results are available. Pass the request back through
+/// [`next_request`](Self::next_request) to fetch the following page:
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken};
+/// use canva_connect::endpoints::designs::ListDesignsRequest;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("token"))?;
+/// let mut request = Some(ListDesignsRequest::default());
+/// while let Some(current) = request {
+///     let page = client.designs().list_with(&current).await?;
+///     for design in &page.items {
+///         println!("{}", design.id);
+///     }
+///     request = page.next_request(&current);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ListDesignsRequest {
-    /// Search query
-    pub query: Option<String>,
-    /// Continuation token for pagination
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Token for the next page; `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub continuation: Option<String>,
-    /// Filter by ownership
-    pub ownership: Option<OwnershipType>,
-    /// Sort order
-    pub sort_by: Option<SortByType>,
 }
 
-/// Response for listing designs
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GetListDesignResponse {
-    /// List of designs
-    pub items: Vec<Design>,
-    /// Continuation token for next page
-    pub continuation: Option<String>,
+impl<T> Page<T> {
+    /// Whether another page follows this one
+    pub fn has_more(&self) -> bool {
+        self.continuation.is_some()
+    }
+
+    /// `request` continued from this page, or `None` if this is the last page
+    pub fn next_request<R: PageRequest>(&self, request: &R) -> Option<R> {
+        let continuation = self.continuation.clone()?;
+        let mut next = request.clone();
+        next.set_continuation(Some(continuation));
+        Some(next)
+    }
 }
 
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// A list request that can be continued from a [`Page`]
+pub trait PageRequest: Clone {
+    /// Set the continuation token to resume listing from
+    fn set_continuation(&mut self, continuation: Option<String>);
+}
+
+/// Response for listing designs
+pub type GetListDesignResponse = Page<Design>;
+
 /// Request to create a design
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignRequest {
     /// Design type configuration
     pub design_type: Option<DesignTypeInput>,
@@ -143,8 +321,18 @@ pub struct CreateDesignRequest {
     pub title: Option<String>,
 }
 
+impl CreateDesignRequest {
+    /// Check the design type, if any, with [`DesignTypeInput::validate`]
+    pub fn validate(&self) -> crate::Result<()> {
+        self.design_type
+            .as_ref()
+            .map_or(Ok(()), DesignTypeInput::validate)
+    }
+}
+
 /// Response for creating a design
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignResponse {
     /// Created design
     pub design: Design,
@@ -152,13 +340,34 @@ pub struct CreateDesignResponse {
 
 /// Response for getting a design
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetDesignResponse {
     /// Design data
     pub design: Design,
 }
 
+/// A page of a design
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignPage {
+    /// Position of the page in the design, starting at 1
+    pub index: u32,
+    /// Page thumbnail (if available)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<Thumbnail>,
+}
+
+/// Response for listing the pages of a design
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GetDesignPagesResponse {
+    /// The requested pages, in order
+    pub items: Vec<DesignPage>,
+}
+
 /// Design type input for creating designs (tagged union)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum DesignTypeInput {
     /// Preset design type
@@ -175,8 +384,50 @@ pub enum DesignTypeInput {
     },
 }
 
+/// Smallest width or height of a custom-sized design, in pixels
+pub const MIN_DESIGN_DIMENSION: u32 = 40;
+/// Largest width or height of a custom-sized design, in pixels
+pub const MAX_DESIGN_DIMENSION: u32 = 8000;
+/// Largest area of a resized design, in pixels
+pub const MAX_RESIZE_AREA: u64 = 25_000_000;
+
+impl DesignTypeInput {
+    /// Check custom dimensions against Canva's limits
+    ///
+    /// Width and height must each be between [`MIN_DESIGN_DIMENSION`] and
+    /// [`MAX_DESIGN_DIMENSION`] pixels, and presets must be ones this crate
+    /// knows how to send. Fails with [`Error::Generic`](crate::Error::Generic)
+    /// describing the problem.
+    pub fn validate(&self) -> crate::Result<()> {
+        match self {
+            DesignTypeInput::Preset {
+                name: PresetDesignTypeName::Unknown,
+            } => Err(crate::Error::Generic(
+                "Invalid design type: unknown preset".to_string(),
+            )),
+            DesignTypeInput::Preset { .. } => Ok(()),
+            DesignTypeInput::Custom { width, height } => {
+                let range = MIN_DESIGN_DIMENSION..=MAX_DESIGN_DIMENSION;
+                if range.contains(width) && range.contains(height) {
+                    Ok(())
+                } else {
+                    Err(crate::Error::Generic(format!(
+                        "Invalid design size {width}x{height}: width and height must be \
+                         {MIN_DESIGN_DIMENSION} to {MAX_DESIGN_DIMENSION} pixels"
+                    )))
+                }
+            }
+        }
+    }
+}
+
 /// Preset design type names
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// These are the presets the Connect API accepts; other formats, such as
+/// social media posts, are created with [`DesignTypeInput::Custom`] at the
+/// format's size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PresetDesignTypeName {
     /// Document
@@ -185,10 +436,138 @@ pub enum PresetDesignTypeName {
     Whiteboard,
     /// Presentation
     Presentation,
+    /// A preset this version of the crate does not know about
+    ///
+    /// Only produced when deserializing; requests using it fail validation.
+    #[serde(other)]
+    Unknown,
 }
 
-/// Ownership filter for designs
+api_strings!(PresetDesignTypeName ("design type") {
+    Doc => "doc",
+    Whiteboard => "whiteboard",
+    Presentation => "presentation",
+} unknown: Unknown);
+
+/// Request to create a design resize job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreateDesignResizeJobRequest {
+    /// ID of the design to resize
+    pub design_id: String,
+    /// Target design type, either a preset or custom dimensions
+    pub design_type: DesignTypeInput,
+}
+
+impl CreateDesignResizeJobRequest {
+    /// Resize a design to a preset design type
+    pub fn preset(design_id: impl Into<String>, name: PresetDesignTypeName) -> Self {
+        Self {
+            design_id: design_id.into(),
+            design_type: DesignTypeInput::Preset { name },
+        }
+    }
+
+    /// Resize a design to custom dimensions in pixels
+    pub fn custom(design_id: impl Into<String>, width: u32, height: u32) -> Self {
+        Self {
+            design_id: design_id.into(),
+            design_type: DesignTypeInput::Custom { width, height },
+        }
+    }
+
+    /// Check the target type with [`DesignTypeInput::validate`]
+    ///
+    /// Custom sizes must also cover at most [`MAX_RESIZE_AREA`] pixels.
+    pub fn validate(&self) -> crate::Result<()> {
+        self.design_type.validate()?;
+        if let DesignTypeInput::Custom { width, height } = self.design_type {
+            if u64::from(width) * u64::from(height) > MAX_RESIZE_AREA {
+                return Err(crate::Error::Generic(format!(
+                    "Invalid design size {width}x{height}: a resized design can have at \
+                     most {MAX_RESIZE_AREA} pixels"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Response from creating a design resize job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreateDesignResizeJobResponse {
+    /// The resize job
+    pub job: DesignResizeJob,
+}
+
+/// Response from getting a design resize job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GetDesignResizeJobResponse {
+    /// The resize job
+    pub job: DesignResizeJob,
+}
+
+/// Design resize job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignResizeJob {
+    /// Job ID
+    pub id: String,
+    /// Job status
+    pub status: JobStatus,
+    /// Job result (present when the job succeeds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<DesignResizeJobResult>,
+    /// Job error (present when the job fails)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<DesignResizeError>,
+}
+
+/// Result of a successful design resize job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignResizeJobResult {
+    /// The resized copy, saved to the user's root folder
+    pub design: DesignSummary,
+}
+
+/// Design resize error details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignResizeError {
+    /// Error code
+    pub code: DesignResizeErrorCode,
+    /// Human-readable error message
+    pub message: String,
+}
+
+/// Design resize error codes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DesignResizeErrorCode {
+    /// Thumbnail generation failed
+    ThumbnailGenerationError,
+    /// The design could not be resized
+    DesignResizeError,
+    /// The resized design could not be created
+    CreateDesignError,
+    /// An error code this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+api_strings!(DesignResizeErrorCode ("resize error code") {
+    ThumbnailGenerationError => "thumbnail_generation_error",
+    DesignResizeError => "design_resize_error",
+    CreateDesignError => "create_design_error",
+} unknown: Unknown);
+
+/// Ownership filter for designs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum OwnershipType {
     /// Any designs (owned or shared)
@@ -199,8 +578,15 @@ pub enum OwnershipType {
     Shared,
 }
 
+api_strings!(OwnershipType ("ownership") {
+    Any => "any",
+    Owned => "owned",
+    Shared => "shared",
+});
+
 /// Sort order for designs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SortByType {
     /// Sort by relevance
@@ -215,8 +601,17 @@ pub enum SortByType {
     TitleAscending,
 }
 
+api_strings!(SortByType ("sort order") {
+    Relevance => "relevance",
+    ModifiedDescending => "modified_descending",
+    ModifiedAscending => "modified_ascending",
+    TitleDescending => "title_descending",
+    TitleAscending => "title_ascending",
+});
+
 /// Brand template metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BrandTemplate {
     /// Brand template ID
     pub id: String,
@@ -230,14 +625,20 @@ pub struct BrandTemplate {
     pub create_url: String,
     /// Brand template creation timestamp
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Brand template last updated timestamp
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Fields this version of the crate does not know about, kept as sent
+    #[serde(flatten, default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Brand template URLs
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BrandTemplateUrls {
     /// Edit URL
     pub edit_url: String,
@@ -247,6 +648,7 @@ pub struct BrandTemplateUrls {
 
 /// Brand template dataset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BrandTemplateDataset {
     /// Dataset fields (keyed by field name)
     pub dataset: HashMap<String, DataField>,
@@ -254,6 +656,7 @@ pub struct BrandTemplateDataset {
 
 /// Dataset field definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum DataField {
     /// Text field
@@ -283,26 +686,38 @@ pub enum DataField {
         /// Whether the field is required
         required: Option<bool>,
     },
+    /// A field type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
 /// Folder metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Folder {
     /// Folder ID
     pub id: String,
     /// Folder name
     pub name: String,
-    /// When the folder was created (Unix timestamp)
-    pub created_at: i64,
-    /// When the folder was last updated (Unix timestamp)
-    pub updated_at: i64,
+    /// When the folder was created
+    #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the folder was last updated
+    #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
     /// Folder thumbnail (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<Thumbnail>,
+    /// Fields this version of the crate does not know about, kept as sent
+    #[serde(flatten, default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// User profile information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct User {
     /// User ID
     pub id: String,
@@ -318,6 +733,7 @@ pub struct User {
 
 /// Team information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Team {
     /// Team ID
     pub id: String,
@@ -327,6 +743,7 @@ pub struct Team {
 
 /// Comment thread
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommentThread {
     /// Thread ID
     pub id: String,
@@ -338,14 +755,17 @@ pub struct CommentThread {
     pub author: Option<SimpleUser>,
     /// Thread creation timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Thread last updated timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Simple user information for comments
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SimpleUser {
     /// User ID
     pub id: String,
@@ -355,6 +775,7 @@ pub struct SimpleUser {
 
 /// Comment thread type (tagged union)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CommentThreadType {
     /// Regular comment
@@ -375,10 +796,14 @@ pub enum CommentThreadType {
         /// Suggestion status
         status: SuggestionStatus,
     },
+    /// A thread type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
 /// Comment content
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommentContent {
     /// Comment content in plaintext
     pub plaintext: String,
@@ -388,6 +813,7 @@ pub struct CommentContent {
 
 /// Comment reply
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommentReply {
     /// Reply ID
     pub id: String,
@@ -397,6 +823,7 @@ pub struct CommentReply {
     pub content: CommentContent,
     /// Reply timestamp (Unix timestamp in seconds)
     #[serde(with = "chrono::serde::ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// User mentions in the reply
     pub mentions: std::collections::HashMap<String, UserMention>,
@@ -404,6 +831,7 @@ pub struct CommentReply {
 
 /// User mention in a comment
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserMention {
     /// The mention tag in the format user_id:team_id
     pub tag: String,
@@ -413,6 +841,7 @@ pub struct UserMention {
 
 /// Suggested edit in a suggestion thread
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SuggestedEdit {
     /// Edit ID
     pub id: String,
@@ -424,7 +853,8 @@ pub struct SuggestedEdit {
 }
 
 /// Suggestion status
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SuggestionStatus {
     /// Suggestion is pending
@@ -433,10 +863,20 @@ pub enum SuggestionStatus {
     Accepted,
     /// Suggestion has been rejected
     Rejected,
+    /// A status this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
+api_strings!(SuggestionStatus ("suggestion status") {
+    Pending => "pending",
+    Accepted => "accepted",
+    Rejected => "rejected",
+} unknown: Unknown);
+
 /// Response from creating a comment thread
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateThreadResponse {
     /// The created thread
     pub thread: CommentThread,
@@ -444,6 +884,7 @@ pub struct CreateThreadResponse {
 
 /// Export format (tagged union)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ExportFormat {
     /// PDF format
@@ -519,8 +960,165 @@ pub enum ExportFormat {
     },
 }
 
+impl ExportFormat {
+    /// PDF with Canva's default quality and paper size
+    pub fn pdf() -> Self {
+        ExportFormat::Pdf {
+            export_quality: None,
+            size: None,
+            pages: None,
+        }
+    }
+
+    /// JPG at the given compression quality (1-100), at the design's own size
+    pub fn jpg(quality: u8) -> Self {
+        ExportFormat::Jpg {
+            export_quality: None,
+            quality,
+            height: None,
+            width: None,
+            pages: None,
+        }
+    }
+
+    /// PNG at the design's own size
+    pub fn png() -> Self {
+        ExportFormat::Png {
+            export_quality: None,
+            height: None,
+            width: None,
+            pages: None,
+        }
+    }
+
+    /// PowerPoint presentation
+    pub fn pptx() -> Self {
+        ExportFormat::Pptx {
+            export_quality: None,
+            pages: None,
+        }
+    }
+
+    /// Animated GIF
+    pub fn gif() -> Self {
+        ExportFormat::Gif {
+            export_quality: None,
+            pages: None,
+        }
+    }
+
+    /// MP4 video
+    pub fn mp4() -> Self {
+        ExportFormat::Mp4 {
+            export_quality: None,
+            pages: None,
+        }
+    }
+
+    /// Print-ready PDF on A4 paper, at pro quality
+    pub fn pdf_print_a4() -> Self {
+        Self::pdf()
+            .size(ExportPageSize::A4)
+            .export_quality(ExportQuality::Pro)
+    }
+
+    /// Print-ready PDF on US Letter paper, at pro quality
+    pub fn pdf_print_letter() -> Self {
+        Self::pdf()
+            .size(ExportPageSize::Letter)
+            .export_quality(ExportQuality::Pro)
+    }
+
+    /// 1080x1080 JPG at quality 90, for square social media posts
+    pub fn social_square_jpg() -> Self {
+        Self::jpg(90).width(1080).height(1080)
+    }
+
+    /// 1080x1920 JPG at quality 90, for vertical stories and reels covers
+    pub fn social_story_jpg() -> Self {
+        Self::jpg(90).width(1080).height(1920)
+    }
+
+    /// 1920x1080 PNG, for slides and video thumbnails
+    pub fn full_hd_png() -> Self {
+        Self::png().width(1920).height(1080)
+    }
+
+    /// Set the export quality
+    pub fn export_quality(mut self, quality: ExportQuality) -> Self {
+        match &mut self {
+            ExportFormat::Pdf { export_quality, .. }
+            | ExportFormat::Jpg { export_quality, .. }
+            | ExportFormat::Png { export_quality, .. }
+            | ExportFormat::Pptx { export_quality, .. }
+            | ExportFormat::Gif { export_quality, .. }
+            | ExportFormat::Mp4 { export_quality, .. } => *export_quality = Some(quality),
+        }
+        self
+    }
+
+    /// Export only these pages (1-indexed)
+    pub fn pages(mut self, selected: impl IntoIterator<Item = u32>) -> Self {
+        let selected = Some(selected.into_iter().collect());
+        match &mut self {
+            ExportFormat::Pdf { pages, .. }
+            | ExportFormat::Jpg { pages, .. }
+            | ExportFormat::Png { pages, .. }
+            | ExportFormat::Pptx { pages, .. }
+            | ExportFormat::Gif { pages, .. }
+            | ExportFormat::Mp4 { pages, .. } => *pages = selected,
+        }
+        self
+    }
+
+    /// Set the width in pixels; ignored by formats other than JPG and PNG
+    pub fn width(mut self, pixels: u32) -> Self {
+        if let ExportFormat::Jpg { width, .. } | ExportFormat::Png { width, .. } = &mut self {
+            *width = Some(pixels);
+        }
+        self
+    }
+
+    /// Set the height in pixels; ignored by formats other than JPG and PNG
+    pub fn height(mut self, pixels: u32) -> Self {
+        if let ExportFormat::Jpg { height, .. } | ExportFormat::Png { height, .. } = &mut self {
+            *height = Some(pixels);
+        }
+        self
+    }
+
+    /// Set the paper size; ignored by formats other than PDF
+    pub fn size(mut self, paper: ExportPageSize) -> Self {
+        if let ExportFormat::Pdf { size, .. } = &mut self {
+            *size = Some(paper);
+        }
+        self
+    }
+
+    /// Set the JPEG compression quality (1-100); ignored by formats other than JPG
+    pub fn quality(mut self, value: u8) -> Self {
+        if let ExportFormat::Jpg { quality, .. } = &mut self {
+            *quality = value;
+        }
+        self
+    }
+
+    /// The format's name as the API spells it, e.g. `pdf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf { .. } => "pdf",
+            ExportFormat::Jpg { .. } => "jpg",
+            ExportFormat::Png { .. } => "png",
+            ExportFormat::Pptx { .. } => "pptx",
+            ExportFormat::Gif { .. } => "gif",
+            ExportFormat::Mp4 { .. } => "mp4",
+        }
+    }
+}
+
 /// Export page size for PDF exports
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ExportPageSize {
     /// A4 paper size
@@ -533,8 +1131,16 @@ pub enum ExportPageSize {
     Legal,
 }
 
+api_strings!(ExportPageSize ("page size") {
+    A4 => "a4",
+    A3 => "a3",
+    Letter => "letter",
+    Legal => "legal",
+});
+
 /// Export quality
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ExportQuality {
     /// Regular quality
@@ -543,8 +1149,14 @@ pub enum ExportQuality {
     Pro,
 }
 
+api_strings!(ExportQuality ("export quality") {
+    Regular => "regular",
+    Pro => "pro",
+});
+
 /// Job status
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum JobStatus {
     /// Job is in progress
     #[serde(rename = "in_progress")]
@@ -555,10 +1167,23 @@ pub enum JobStatus {
     /// Job failed
     #[serde(rename = "failed")]
     Failed,
+    /// A status this version of the crate does not know about
+    ///
+    /// Treated as still running, so polling carries on until the job
+    /// reaches a state the crate understands.
+    #[serde(other)]
+    Unknown,
 }
 
+api_strings!(JobStatus ("job status") {
+    InProgress => "in_progress",
+    Success => "success",
+    Failed => "failed",
+} unknown: Unknown);
+
 /// Base job response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Job<T> {
     /// Job ID
     pub id: String,
@@ -572,6 +1197,7 @@ pub struct Job<T> {
 
 /// Asset upload job response (has different structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssetUploadJob {
     /// Job ID
     pub id: String,
@@ -585,6 +1211,7 @@ pub struct AssetUploadJob {
 
 /// Wrapper for job responses from the API
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct JobResponse<T> {
     /// The job data
     pub job: Job<T>,
@@ -592,6 +1219,7 @@ pub struct JobResponse<T> {
 
 /// Wrapper for asset upload job responses from the API
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssetUploadJobResponse {
     /// The asset upload job data
     pub job: AssetUploadJob,
@@ -599,6 +1227,7 @@ pub struct AssetUploadJobResponse {
 
 /// Job error
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct JobError {
     /// Error code
     pub code: String,
@@ -608,6 +1237,7 @@ pub struct JobError {
 
 /// Asset upload job result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssetUploadResult {
     /// Created asset
     pub asset: Asset,
@@ -615,6 +1245,10 @@ pub struct AssetUploadResult {
 
 /// Export job result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[deprecated(
+    since = "0.1.0",
+    note = "export jobs return download URLs directly; use ExportJob::urls or ExportJob::export_urls"
+)]
 pub struct ExportResult {
     /// Export URLs
     pub urls: Vec<ExportUrl>,
@@ -622,6 +1256,7 @@ pub struct ExportResult {
 
 /// Export URL
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ExportUrl {
     /// Page number
     pub page: u32,
@@ -629,11 +1264,71 @@ pub struct ExportUrl {
     pub url: String,
 }
 
-/// Export job containing status and results
-pub type ExportJob = Job<ExportResult>;
+/// Export job containing status and download URLs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportJob {
+    /// Job ID
+    pub id: String,
+    /// Job status
+    pub status: JobStatus,
+    /// Download URLs, one per exported file in page order (present when status is Success)
+    ///
+    /// These URLs expire 24 hours after the export completes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+    /// Job error (present when status is Failed)
+    pub error: Option<JobError>,
+    /// When this client received the job, and with it the download URLs
+    ///
+    /// Not sent by the API; set by the export endpoints and kept when the
+    /// job is serialized, so a saved job still knows how old its URLs are.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "chrono::serde::ts_seconds_option"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+    pub fetched_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// How long export download URLs stay valid
+pub const EXPORT_URL_LIFETIME: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Headroom [`ExportJob::is_likely_expired`] leaves for a download to finish
+const EXPORT_URL_EXPIRY_MARGIN: chrono::Duration = chrono::Duration::minutes(10);
+
+impl ExportJob {
+    /// Whether the download URLs have probably expired, or will within a few minutes
+    ///
+    /// Based on [`fetched_at`](Self::fetched_at) and [`EXPORT_URL_LIFETIME`].
+    /// Jobs without a `fetched_at`, such as ones built by hand, are assumed
+    /// to be fresh.
+    pub fn is_likely_expired(&self) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| {
+            let lifetime = chrono::Duration::from_std(EXPORT_URL_LIFETIME)
+                .unwrap_or(chrono::Duration::MAX)
+                - EXPORT_URL_EXPIRY_MARGIN;
+            chrono::Utc::now() >= fetched_at + lifetime
+        })
+    }
+
+    /// Download URLs paired with their 1-based page numbers
+    pub fn export_urls(&self) -> Vec<ExportUrl> {
+        self.urls
+            .iter()
+            .enumerate()
+            .map(|(index, url)| ExportUrl {
+                page: index as u32 + 1,
+                url: url.clone(),
+            })
+            .collect()
+    }
+}
 
 /// Folder item summary (tagged union for different item types)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FolderItemSummary {
     /// Folder item
@@ -651,6 +1346,9 @@ pub enum FolderItemSummary {
         /// Image details
         image: Asset, // Using Asset for now, could be specific ImageItem
     },
+    /// An item type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
 /// Legacy folder item for compatibility
@@ -658,13 +1356,15 @@ pub type FolderItem = FolderItemSummary;
 
 /// Autofill job result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutofillResult {
     /// Created design
-    pub design: Design,
+    pub design: DesignSummary,
 }
 
 /// Request to create a design autofill job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignAutofillJobRequest {
     /// ID of the input brand template
     pub brand_template_id: String,
@@ -676,6 +1376,7 @@ pub struct CreateDesignAutofillJobRequest {
 
 /// Response from creating a design autofill job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateDesignAutofillJobResponse {
     /// The autofill job
     pub job: DesignAutofillJob,
@@ -683,6 +1384,7 @@ pub struct CreateDesignAutofillJobResponse {
 
 /// Response from getting a design autofill job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GetDesignAutofillJobResponse {
     /// The autofill job
     pub job: DesignAutofillJob,
@@ -690,6 +1392,7 @@ pub struct GetDesignAutofillJobResponse {
 
 /// Details about the autofill job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DesignAutofillJob {
     /// ID of the asynchronous job
     pub id: String,
@@ -702,7 +1405,8 @@ pub struct DesignAutofillJob {
 }
 
 /// Status of the design autofill job
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum DesignAutofillStatus {
     /// Job is still in progress
@@ -711,21 +1415,38 @@ pub enum DesignAutofillStatus {
     Success,
     /// Job failed
     Failed,
+    /// A status this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
+api_strings!(DesignAutofillStatus ("autofill status") {
+    InProgress => "in_progress",
+    Success => "success",
+    Failed => "failed",
+} unknown: Unknown);
+
 /// Result of the design autofill job
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
+// Only `CreateDesign` is ever populated, so boxing it would cost an
+// allocation for no gain
+#[allow(clippy::large_enum_variant)]
 pub enum DesignAutofillJobResult {
     /// Design has been created and saved to user's root folder
     CreateDesign {
         /// The created design
-        design: Design,
+        design: DesignSummary,
     },
+    /// A result type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
 /// If the autofill job fails, this object provides details about the error
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutofillError {
     /// Error code
     pub code: AutofillErrorCode,
@@ -734,7 +1455,8 @@ pub struct AutofillError {
 }
 
 /// Autofill error codes
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AutofillErrorCode {
     /// General autofill error
@@ -743,10 +1465,88 @@ pub enum AutofillErrorCode {
     ThumbnailGenerationError,
     /// Create design error
     CreateDesignError,
+    /// An error code this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+api_strings!(AutofillErrorCode ("autofill error code") {
+    AutofillError => "autofill_error",
+    ThumbnailGenerationError => "thumbnail_generation_error",
+    CreateDesignError => "create_design_error",
+} unknown: Unknown);
+
+/// Design import job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignImportJob {
+    /// Job ID
+    pub id: String,
+    /// Job status
+    pub status: JobStatus,
+    /// Job result (present when the job succeeds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<DesignImportJobResult>,
+    /// Job error (present when the job fails)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<DesignImportError>,
 }
 
+/// Result of a successful design import job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignImportJobResult {
+    /// Designs created from the imported file
+    ///
+    /// Usually contains one design; imports with many pages or assets are
+    /// split into several designs.
+    pub designs: Vec<DesignSummary>,
+}
+
+/// Design import error details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DesignImportError {
+    /// Error code
+    pub code: DesignImportErrorCode,
+    /// Human-readable error message
+    pub message: String,
+}
+
+/// Design import error codes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DesignImportErrorCode {
+    /// Too many designs are being created
+    DesignCreationThrottled,
+    /// Too many imports are in progress
+    DesignImportThrottled,
+    /// The same file was already imported
+    DuplicateImport,
+    /// Internal Canva error
+    InternalError,
+    /// The file is not a supported or valid import format
+    InvalidFile,
+    /// The file could not be fetched from the URL
+    FetchFailed,
+    /// An error code this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+api_strings!(DesignImportErrorCode ("import error code") {
+    DesignCreationThrottled => "design_creation_throttled",
+    DesignImportThrottled => "design_import_throttled",
+    DuplicateImport => "duplicate_import",
+    InternalError => "internal_error",
+    InvalidFile => "invalid_file",
+    FetchFailed => "fetch_failed",
+} unknown: Unknown);
+
 /// The data field to autofill
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum DatasetValue {
     /// Image data field
@@ -768,6 +1568,7 @@ pub enum DatasetValue {
 
 /// Tabular data, structured in rows of cells
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DataTable {
     /// Rows of data (first row usually contains column headers)
     pub rows: Vec<DataTableRow>,
@@ -775,6 +1576,7 @@ pub struct DataTable {
 
 /// A single row of tabular data
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DataTableRow {
     /// Cells of data in row (all rows must have the same number of cells)
     pub cells: Vec<DataTableCell>,
@@ -782,6 +1584,7 @@ pub struct DataTableRow {
 
 /// A single tabular data cell
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum DataTableCell {
     /// String data cell
@@ -806,8 +1609,208 @@ pub enum DataTableCell {
     },
 }
 
+impl DatasetValue {
+    /// Insert `text` into a text field
+    pub fn text(text: impl Into<String>) -> Self {
+        DatasetValue::Text { text: text.into() }
+    }
+
+    /// Insert the image asset `asset_id` into an image field
+    pub fn image(asset_id: impl Into<String>) -> Self {
+        DatasetValue::Image {
+            asset_id: asset_id.into(),
+        }
+    }
+
+    /// Fill a chart field with `chart_data`
+    pub fn chart(chart_data: DataTable) -> Self {
+        DatasetValue::Chart { chart_data }
+    }
+}
+
+impl DataTable {
+    /// Start building a table row by row
+    ///
+    /// ```rust
+    /// use canva_connect::models::DataTable;
+    ///
+    /// let table = DataTable::builder()
+    ///     .header(["Month", "Sales", "Target met"])
+    ///     .row(("Jan", 1500.75, true))
+    ///     .row(("Feb", 980, false))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(table.rows.len(), 3);
+    /// ```
+    pub fn builder() -> DataTableBuilder {
+        DataTableBuilder::default()
+    }
+
+    /// Check that the table has rows and that every row has as many cells as the first
+    pub fn validate(&self) -> crate::Result<()> {
+        let Some(first) = self.rows.first() else {
+            return Err(invalid_table("table has no rows"));
+        };
+        let width = first.cells.len();
+        if width == 0 {
+            return Err(invalid_table("row 1 has no cells"));
+        }
+        for (index, row) in self.rows.iter().enumerate().skip(1) {
+            if row.cells.len() != width {
+                return Err(invalid_table(&format!(
+                    "row {} has {} cells, expected {width}",
+                    index + 1,
+                    row.cells.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid_table(reason: &str) -> crate::Error {
+    crate::Error::Generic(format!("Invalid data table: {reason}"))
+}
+
+/// Builder for a [`DataTable`]
+///
+/// Rows are tuples, arrays or vectors of values that convert into a
+/// [`DataTableCell`]: strings become string cells, numbers number cells,
+/// `bool`s boolean cells and `DateTime<Utc>`s date cells. `None` gives an
+/// empty cell of the same type.
+#[derive(Debug, Clone, Default)]
+pub struct DataTableBuilder {
+    rows: Vec<DataTableRow>,
+}
+
+impl DataTableBuilder {
+    /// Add a row of column names
+    pub fn header<I, S>(self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.row(
+            names
+                .into_iter()
+                .map(|name| DataTableCell::from(name.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Add a row of cells
+    pub fn row(mut self, row: impl Into<DataTableRow>) -> Self {
+        self.rows.push(row.into());
+        self
+    }
+
+    /// Finish the table, checking that all rows have the same number of cells
+    pub fn build(self) -> crate::Result<DataTable> {
+        let table = DataTable { rows: self.rows };
+        table.validate()?;
+        Ok(table)
+    }
+}
+
+/// A Rust value that fills one kind of [`DataTableCell`]
+///
+/// Strings fill string cells, numbers number cells, `bool`s boolean cells
+/// and `DateTime<Utc>`s date cells.
+pub trait DataTableValue {
+    /// The cell holding `self`
+    fn into_cell(self) -> DataTableCell;
+
+    /// An empty cell of the kind this type fills
+    fn empty_cell() -> DataTableCell;
+}
+
+macro_rules! data_table_values {
+    ($($kind:ident: $value:ty => |$v:ident| $convert:expr;)*) => {$(
+        impl DataTableValue for $value {
+            fn into_cell(self) -> DataTableCell {
+                let $v = self;
+                DataTableCell::$kind {
+                    value: Some($convert),
+                }
+            }
+
+            fn empty_cell() -> DataTableCell {
+                DataTableCell::$kind { value: None }
+            }
+        }
+
+        impl From<$value> for DataTableCell {
+            fn from(value: $value) -> Self {
+                value.into_cell()
+            }
+        }
+
+        impl From<Option<$value>> for DataTableCell {
+            fn from(value: Option<$value>) -> Self {
+                value.map_or_else(<$value>::empty_cell, DataTableValue::into_cell)
+            }
+        }
+    )*};
+}
+
+data_table_values! {
+    String: String => |v| v;
+    String: &str => |v| v.to_string();
+    Number: f64 => |v| v;
+    Number: f32 => |v| f64::from(v);
+    Number: i32 => |v| f64::from(v);
+    Number: u32 => |v| f64::from(v);
+    Number: i16 => |v| f64::from(v);
+    Number: u16 => |v| f64::from(v);
+    Number: i8 => |v| f64::from(v);
+    Number: u8 => |v| f64::from(v);
+    Boolean: bool => |v| v;
+    Date: chrono::DateTime<chrono::Utc> => |v| v.timestamp();
+}
+
+impl<T: Into<DataTableCell>> From<Vec<T>> for DataTableRow {
+    fn from(cells: Vec<T>) -> Self {
+        DataTableRow {
+            cells: cells.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T: Into<DataTableCell>, const N: usize> From<[T; N]> for DataTableRow {
+    fn from(cells: [T; N]) -> Self {
+        DataTableRow {
+            cells: cells.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+macro_rules! tuple_rows {
+    ($(($($cell:ident),+))*) => {$(
+        impl<$($cell: Into<DataTableCell>),+> From<($($cell,)+)> for DataTableRow {
+            #[allow(non_snake_case)]
+            fn from(($($cell,)+): ($($cell,)+)) -> Self {
+                DataTableRow {
+                    cells: vec![$($cell.into()),+],
+                }
+            }
+        }
+    )*};
+}
+
+tuple_rows! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+}
+
 /// Dataset filter
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum DatasetFilter {
     /// Any items
@@ -817,3 +1820,9 @@ pub enum DatasetFilter {
     /// Only items with empty datasets
     Empty,
 }
+
+api_strings!(DatasetFilter ("dataset filter") {
+    Any => "any",
+    NonEmpty => "non_empty",
+    Empty => "empty",
+});
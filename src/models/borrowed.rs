@@ -0,0 +1,213 @@
+//! Borrowed views of list responses.
+//!
+//! Deserializing a large page of designs into [`Design`] allocates a `String`
+//! for every ID, title and URL. The `*Ref` types here borrow those strings
+//! from the response body instead, and only allocate for strings the JSON
+//! escapes. Fetch the body with
+//! [`DesignsApi::list_raw`](crate::endpoints::designs::DesignsApi::list_raw)
+//! and parse it with [`ListDesignsResponseRef::from_slice`]:
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::endpoints::designs::ListDesignsRequest;
+//! use canva_connect::models::borrowed::ListDesignsResponseRef;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(AccessToken::new("token"))?;
+//! let body = client.designs().list_raw(&ListDesignsRequest::default()).await?;
+//! let page = ListDesignsResponseRef::from_slice(&body)?;
+//! for design in &page.items {
+//!     println!("{} {}", design.id, design.title.as_deref().unwrap_or("untitled"));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Call `into_owned` on anything that has to outlive the body.
+
+use super::{Design, DesignLinks, Page, TeamUserSummary, Thumbnail};
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+
+/// Response for listing designs, borrowing from the response body
+pub type ListDesignsResponseRef<'a> = Page<DesignRef<'a>>;
+
+impl<'a> Page<DesignRef<'a>> {
+    /// Parse a `GET /v1/designs` response body
+    pub fn from_slice(body: &'a [u8]) -> crate::Result<Self> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    /// Copy every design into an owned [`Design`]
+    pub fn into_owned(self) -> Page<Design> {
+        Page {
+            items: self.items.into_iter().map(DesignRef::into_owned).collect(),
+            continuation: self.continuation,
+        }
+    }
+}
+
+/// [`Design`] borrowing its strings from the response body
+#[derive(Debug, Clone, Deserialize)]
+pub struct DesignRef<'a> {
+    /// Design ID
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    /// Design title
+    #[serde(borrow, default, deserialize_with = "optional_borrowed")]
+    pub title: Option<Cow<'a, str>>,
+    /// Design owner
+    #[serde(borrow)]
+    pub owner: TeamUserSummaryRef<'a>,
+    /// Design thumbnail
+    #[serde(borrow)]
+    pub thumbnail: Option<ThumbnailRef<'a>>,
+    /// Design URLs
+    #[serde(borrow)]
+    pub urls: DesignLinksRef<'a>,
+    /// Design creation timestamp (Unix timestamp in seconds)
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Design last updated timestamp (Unix timestamp in seconds)
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Total number of pages in the design
+    pub page_count: Option<u32>,
+}
+
+impl DesignRef<'_> {
+    /// Copy into an owned [`Design`]
+    ///
+    /// Unknown fields are not read by [`DesignRef`], so
+    /// [`Design::extra`] is left empty.
+    pub fn into_owned(self) -> Design {
+        Design {
+            id: self.id.into_owned(),
+            title: self.title.map(Cow::into_owned),
+            owner: self.owner.into_owned(),
+            thumbnail: self.thumbnail.map(ThumbnailRef::into_owned),
+            urls: self.urls.into_owned(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            page_count: self.page_count,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// [`TeamUserSummary`] borrowing its IDs from the response body
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeamUserSummaryRef<'a> {
+    /// User ID
+    #[serde(borrow)]
+    pub user_id: Cow<'a, str>,
+    /// Team ID
+    #[serde(borrow)]
+    pub team_id: Cow<'a, str>,
+}
+
+impl TeamUserSummaryRef<'_> {
+    /// Copy into an owned [`TeamUserSummary`]
+    pub fn into_owned(self) -> TeamUserSummary {
+        TeamUserSummary {
+            user_id: self.user_id.into_owned(),
+            team_id: self.team_id.into_owned(),
+        }
+    }
+}
+
+/// [`Thumbnail`] borrowing its URL from the response body
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThumbnailRef<'a> {
+    /// Thumbnail URL
+    #[serde(borrow)]
+    pub url: Cow<'a, str>,
+    /// Thumbnail width
+    pub width: u32,
+    /// Thumbnail height
+    pub height: u32,
+}
+
+impl ThumbnailRef<'_> {
+    /// Copy into an owned [`Thumbnail`]
+    pub fn into_owned(self) -> Thumbnail {
+        Thumbnail {
+            url: self.url.into_owned(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// [`DesignLinks`] borrowing its URLs from the response body
+#[derive(Debug, Clone, Deserialize)]
+pub struct DesignLinksRef<'a> {
+    /// Temporary edit URL (valid for 30 days)
+    #[serde(borrow)]
+    pub edit_url: Cow<'a, str>,
+    /// Temporary view URL (valid for 30 days)
+    #[serde(borrow)]
+    pub view_url: Cow<'a, str>,
+}
+
+impl DesignLinksRef<'_> {
+    /// Copy into an owned [`DesignLinks`]
+    pub fn into_owned(self) -> DesignLinks {
+        DesignLinks {
+            edit_url: self.edit_url.into_owned(),
+            view_url: self.view_url.into_owned(),
+        }
+    }
+}
+
+/// Deserialize an optional string, borrowing it unless the JSON escapes it
+///
+/// serde only borrows a `Cow` that is a field itself; inside an `Option` it
+/// always copies, so the string goes through a borrowing newtype instead.
+fn optional_borrowed<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Ok(Option::<Borrowed<'a>>::deserialize(deserializer)?.map(|borrowed| borrowed.0))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn design_with_title(title: serde_json::Value) -> Vec<u8> {
+        let mut design = crate::testing::fixtures::design("https://example.com", "DAF1");
+        design["title"] = title;
+        json!({ "items": [design] }).to_string().into_bytes()
+    }
+
+    #[test]
+    fn test_unescaped_title_is_borrowed() {
+        let body = design_with_title(json!("Summer sale"));
+        let page = ListDesignsResponseRef::from_slice(&body).expect("parse");
+
+        assert!(matches!(
+            page.items[0].title,
+            Some(Cow::Borrowed("Summer sale"))
+        ));
+    }
+
+    #[test]
+    fn test_missing_or_null_title_is_none() {
+        let body = design_with_title(serde_json::Value::Null);
+        let page = ListDesignsResponseRef::from_slice(&body).expect("parse");
+        assert!(page.items[0].title.is_none());
+
+        let mut design = crate::testing::fixtures::design("https://example.com", "DAF1");
+        design.as_object_mut().expect("object").remove("title");
+        let body = json!({ "items": [design] }).to_string();
+        let page = ListDesignsResponseRef::from_slice(body.as_bytes()).expect("parse");
+        assert!(page.items[0].title.is_none());
+    }
+}
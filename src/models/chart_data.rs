@@ -0,0 +1,376 @@
+//! Building chart data from CSV files and serializable rows.
+//!
+//! Each column gets one cell type, picked from its values: numbers if every
+//! non-empty value is a number, booleans if every one is `true` or `false`,
+//! dates if every one is an RFC 3339 timestamp or a `YYYY-MM-DD` date, and
+//! strings otherwise. Empty values become empty cells of the column's type.
+//! The header row is always strings.
+//!
+//! ```rust
+//! use canva_connect::models::{DataTable, DataTableCell};
+//!
+//! let csv = "Month,Sales,Launched\nJan,1500.75,2024-01-15\nFeb,,2024-02-01\n";
+//! let table = DataTable::from_csv(csv.as_bytes()).unwrap();
+//! assert!(matches!(table.rows[2].cells[1], DataTableCell::Number { value: None }));
+//! ```
+
+use super::{DataTable, DataTableCell, DataTableRow};
+use crate::error::{Error, Result};
+use chrono::{DateTime, NaiveDate};
+use serde::Serialize;
+use serde_json::Value;
+
+impl DataTable {
+    /// Read a table from CSV, using the first record as the header row
+    ///
+    /// Fails if the CSV is malformed or its records have different numbers
+    /// of fields.
+    pub fn from_csv<R: std::io::Read>(reader: R) -> Result<DataTable> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let mut records = reader
+            .records()
+            .map(|record| record.map(|r| r.iter().map(str::to_string).collect::<Vec<_>>()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| invalid_csv(&e.to_string()))?;
+        if records.is_empty() {
+            return Err(invalid_csv("no header row"));
+        }
+        let header = records.remove(0);
+
+        let kinds: Vec<Kind> = (0..header.len())
+            .map(|column| {
+                Kind::of_text(
+                    records
+                        .iter()
+                        .map(|record| record[column].as_str())
+                        .filter(|value| !value.is_empty()),
+                )
+            })
+            .collect();
+        let rows = records.iter().map(|record| {
+            record
+                .iter()
+                .zip(&kinds)
+                .map(|(value, kind)| kind.text_cell(value))
+                .collect()
+        });
+        table(header, rows)
+    }
+
+    /// Build a table from a slice of structs, one row each
+    ///
+    /// Field names become the header row, in declaration order. Fields must
+    /// be numbers, booleans, strings, dates or options of these; nested
+    /// structs and sequences are rejected. String fields holding dates, such
+    /// as `DateTime<Utc>` or `NaiveDate`, become date cells.
+    ///
+    /// ```rust
+    /// use canva_connect::models::DataTable;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Sales {
+    ///     month: &'static str,
+    ///     total: f64,
+    /// }
+    ///
+    /// let table = DataTable::from_serialize(&[
+    ///     Sales { month: "Jan", total: 1500.75 },
+    ///     Sales { month: "Feb", total: 980.0 },
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(table.rows.len(), 3);
+    /// ```
+    pub fn from_serialize<T: Serialize>(rows: &[T]) -> Result<DataTable> {
+        let Some(first) = rows.first() else {
+            return Err(invalid_rows("no rows"));
+        };
+        let header = field_names(first)?;
+
+        let values = rows
+            .iter()
+            .map(|row| match serde_json::to_value(row)? {
+                Value::Object(mut fields) => Ok(header
+                    .iter()
+                    .map(|name| fields.remove(name).unwrap_or(Value::Null))
+                    .collect::<Vec<_>>()),
+                _ => Err(invalid_rows("rows must be structs or maps")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let kinds = (0..header.len())
+            .map(|column| {
+                Kind::of_json(
+                    header[column].as_str(),
+                    values.iter().map(|row| &row[column]),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let rows = values.iter().map(|row| {
+            row.iter()
+                .zip(&kinds)
+                .map(|(value, kind)| kind.json_cell(value))
+                .collect()
+        });
+        table(header, rows)
+    }
+}
+
+/// Field names of `row` in serialization order
+///
+/// `serde_json` maps sort their keys, so the order comes from the header
+/// record the CSV serializer writes instead.
+fn field_names<T: Serialize>(row: &T) -> Result<Vec<String>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .serialize(row)
+        .map_err(|e| invalid_rows(&e.to_string()))?;
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| invalid_rows(&e.to_string()))?;
+    let header = csv::Reader::from_reader(bytes.as_slice())
+        .headers()
+        .map_err(|e| invalid_rows(&e.to_string()))?
+        .iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if header.iter().all(String::is_empty) {
+        return Err(invalid_rows("rows must be structs or maps"));
+    }
+    Ok(header)
+}
+
+fn table(header: Vec<String>, rows: impl Iterator<Item = Vec<DataTableCell>>) -> Result<DataTable> {
+    let table = DataTable {
+        rows: std::iter::once(DataTableRow::from(header))
+            .chain(rows.map(|cells| DataTableRow { cells }))
+            .collect(),
+    };
+    table.validate()?;
+    Ok(table)
+}
+
+/// The cell type of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    String,
+    Number,
+    Boolean,
+    Date,
+}
+
+impl Kind {
+    fn of_text<'a>(values: impl Iterator<Item = &'a str> + Clone) -> Kind {
+        let all = |check: fn(&str) -> bool| values.clone().all(check);
+        if values.clone().next().is_none() {
+            Kind::String
+        } else if all(|value| value.parse::<f64>().is_ok()) {
+            Kind::Number
+        } else if all(|value| parse_bool(value).is_some()) {
+            Kind::Boolean
+        } else if all(|value| parse_date(value).is_some()) {
+            Kind::Date
+        } else {
+            Kind::String
+        }
+    }
+
+    fn of_json<'a>(name: &str, values: impl Iterator<Item = &'a Value>) -> Result<Kind> {
+        let mut kind = None;
+        for value in values {
+            let this = match value {
+                Value::Null => continue,
+                Value::Number(_) => Kind::Number,
+                Value::Bool(_) => Kind::Boolean,
+                Value::String(text) if parse_date(text).is_some() => Kind::Date,
+                Value::String(_) => Kind::String,
+                Value::Array(_) | Value::Object(_) => {
+                    return Err(invalid_rows(&format!(
+                        "field {name:?} is not a number, boolean, string or date"
+                    )))
+                }
+            };
+            kind = Some(match kind {
+                None => this,
+                Some(kind) if kind == this => kind,
+                // Mixed dates and other strings, or mixed types, fall back to text
+                Some(_) => Kind::String,
+            });
+        }
+        Ok(kind.unwrap_or(Kind::String))
+    }
+
+    fn text_cell(self, value: &str) -> DataTableCell {
+        let present = !value.is_empty();
+        match self {
+            Kind::String => DataTableCell::String {
+                value: present.then(|| value.to_string()),
+            },
+            Kind::Number => DataTableCell::Number {
+                value: value.parse().ok(),
+            },
+            Kind::Boolean => DataTableCell::Boolean {
+                value: parse_bool(value),
+            },
+            Kind::Date => DataTableCell::Date {
+                value: parse_date(value),
+            },
+        }
+    }
+
+    fn json_cell(self, value: &Value) -> DataTableCell {
+        match self {
+            Kind::String => DataTableCell::String {
+                value: match value {
+                    Value::Null => None,
+                    Value::String(text) => Some(text.clone()),
+                    other => Some(other.to_string()),
+                },
+            },
+            Kind::Number => DataTableCell::Number {
+                value: value.as_f64(),
+            },
+            Kind::Boolean => DataTableCell::Boolean {
+                value: value.as_bool(),
+            },
+            Kind::Date => DataTableCell::Date {
+                value: value.as_str().and_then(parse_date),
+            },
+        }
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    if value.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Unix timestamp of an RFC 3339 timestamp or a `YYYY-MM-DD` date at midnight UTC
+fn parse_date(value: &str) -> Option<i64> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.timestamp());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|midnight| midnight.and_utc().timestamp())
+}
+
+fn invalid_csv(reason: &str) -> Error {
+    Error::Generic(format!("Invalid CSV chart data: {reason}"))
+}
+
+fn invalid_rows(reason: &str) -> Error {
+    Error::Generic(format!("Cannot build chart data: {reason}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_columns_get_one_cell_type_each() {
+        let csv = "Month, Sales, Target met, Launched\n\
+                   Jan, 1500.75, true, 2024-01-01\n\
+                   Feb, , FALSE, 2024-02-01T00:00:00Z\n\
+                   Mar, 7, , \n";
+
+        let table = DataTable::from_csv(csv.as_bytes()).expect("valid CSV");
+
+        assert_eq!(
+            serde_json::to_value(&table.rows[2]).expect("serializes"),
+            serde_json::json!({ "cells": [
+                { "type": "string", "value": "Feb" },
+                { "type": "number", "value": null },
+                { "type": "boolean", "value": false },
+                { "type": "date", "value": 1_706_745_600 },
+            ]})
+        );
+        assert!(matches!(
+            &table.rows[0].cells[3],
+            DataTableCell::String { value: Some(name) } if name == "Launched"
+        ));
+        assert!(matches!(
+            table.rows[3].cells[3],
+            DataTableCell::Date { value: None }
+        ));
+    }
+
+    #[test]
+    fn test_csv_with_ragged_or_missing_rows_is_rejected() {
+        assert!(DataTable::from_csv("a,b\n1\n".as_bytes()).is_err());
+        assert!(DataTable::from_csv("".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_struct_fields_become_columns_in_declaration_order() {
+        #[derive(Serialize)]
+        struct Row {
+            region: &'static str,
+            revenue: Option<f64>,
+            zip: String,
+            day: NaiveDate,
+        }
+        let day = NaiveDate::from_ymd_opt(2024, 1, 1).expect("valid date");
+
+        let table = DataTable::from_serialize(&[
+            Row {
+                region: "North",
+                revenue: Some(12.5),
+                zip: "02134".to_string(),
+                day,
+            },
+            Row {
+                region: "South",
+                revenue: None,
+                zip: "10001".to_string(),
+                day,
+            },
+        ])
+        .expect("flat rows");
+
+        assert_eq!(
+            serde_json::to_value(&table).expect("serializes"),
+            serde_json::json!({ "rows": [
+                { "cells": [
+                    { "type": "string", "value": "region" },
+                    { "type": "string", "value": "revenue" },
+                    { "type": "string", "value": "zip" },
+                    { "type": "string", "value": "day" },
+                ]},
+                { "cells": [
+                    { "type": "string", "value": "North" },
+                    { "type": "number", "value": 12.5 },
+                    { "type": "string", "value": "02134" },
+                    { "type": "date", "value": 1_704_067_200 },
+                ]},
+                { "cells": [
+                    { "type": "string", "value": "South" },
+                    { "type": "number", "value": null },
+                    { "type": "string", "value": "10001" },
+                    { "type": "date", "value": 1_704_067_200 },
+                ]},
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_nested_fields_and_empty_slices_are_rejected() {
+        #[derive(Serialize)]
+        struct Nested {
+            values: Vec<u32>,
+        }
+
+        assert!(DataTable::from_serialize(&[Nested { values: vec![1] }]).is_err());
+        assert!(DataTable::from_serialize::<Nested>(&[]).is_err());
+        assert!(DataTable::from_serialize(&[1, 2]).is_err());
+    }
+}
@@ -33,15 +33,69 @@
 //! Ok(())
 //! # }
 //! ```
+//!
+//! ## Workflows and jobs
+//!
+//! Waiting for a job opens a `job.wait` span with one `job.poll` child per
+//! status check, numbered by its `attempt` field. Wrap several calls in a
+//! `workflow_span` to group them under one parent.
+//!
+//! ## Leaving the feature on
+//!
+//! Spans cost next to nothing until a subscriber is installed: `tracing`
+//! checks each callsite once and skips disabled spans without evaluating
+//! their fields. [`try_init_tracing`] never fails, so applications can call
+//! it unconditionally and run with or without a collector:
+//!
+//! ```rust,no_run
+//! use canva_connect::observability::{try_init_tracing, TracingStatus};
+//!
+//! # async fn example() {
+//! // Uses OTEL_EXPORTER_OTLP_ENDPOINT if set, console logging otherwise
+//! let status = try_init_tracing("canva-connect-app", None).await;
+//! if let TracingStatus::Logging { reason } = &status {
+//!     eprintln!("Traces are not exported: {reason}");
+//! }
+//! // Keep `status` alive; it holds the exporter guard when exporting
+//! # }
+//! ```
 
 #[cfg(feature = "observability")]
 pub use self::implementation::*;
 
+/// What [`try_init_tracing`] set up
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TracingStatus {
+    /// Spans are exported over OTLP; keep the guard alive to flush them on exit
+    Otlp(TracingGuard),
+    /// Events are logged to the console only
+    Logging {
+        /// Why spans are not exported
+        reason: String,
+    },
+    /// A subscriber was already installed and was left in place
+    External,
+    /// The `observability` feature is disabled
+    Disabled,
+}
+
+impl TracingStatus {
+    /// Whether spans are exported to an OTLP collector
+    pub fn is_exporting(&self) -> bool {
+        matches!(self, Self::Otlp(_))
+    }
+}
+
 #[cfg(feature = "observability")]
 mod implementation {
+    use super::TracingStatus;
     use opentelemetry_otlp::WithExportConfig;
     use tracing_opentelemetry::OpenTelemetryLayer;
-    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+    /// Environment variable read by [`try_init_tracing`] when no endpoint is given
+    const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
 
     /// Initialize distributed tracing with OpenTelemetry.
     ///
@@ -113,10 +167,86 @@ mod implementation {
         Ok(TracingGuard)
     }
 
+    /// Initialize tracing, falling back to console logging instead of failing.
+    ///
+    /// Does nothing if a subscriber is already installed. Otherwise exports to
+    /// `otlp_endpoint`, or to `OTEL_EXPORTER_OTLP_ENDPOINT` when `None`. If
+    /// neither is set or the exporter cannot be installed, installs a console
+    /// subscriber filtered by `RUST_LOG` (default `info`) and reports why.
+    pub async fn try_init_tracing(
+        service_name: &str,
+        otlp_endpoint: Option<&str>,
+    ) -> TracingStatus {
+        if is_tracing_active() {
+            return TracingStatus::External;
+        }
+
+        let endpoint = otlp_endpoint
+            .map(str::to_string)
+            .or_else(|| std::env::var(OTLP_ENDPOINT_ENV).ok())
+            .filter(|endpoint| !endpoint.trim().is_empty());
+        let reason = match endpoint {
+            Some(endpoint) => match init_tracing(service_name, &endpoint).await {
+                Ok(guard) => return TracingStatus::Otlp(guard),
+                Err(e) => e,
+            },
+            None => format!("no OTLP endpoint configured; set {OTLP_ENDPOINT_ENV} to export"),
+        };
+
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+        match tracing_subscriber::fmt().with_env_filter(filter).try_init() {
+            Ok(()) => {
+                tracing::debug!("Tracing degraded to console logging: {}", reason);
+                TracingStatus::Logging { reason }
+            }
+            // Lost a race with another initializer
+            Err(_) => TracingStatus::External,
+        }
+    }
+
+    /// Whether a tracing subscriber is installed for the current thread.
+    ///
+    /// When this returns `false`, the client's spans and events are disabled
+    /// and skipped without formatting any fields.
+    pub fn is_tracing_active() -> bool {
+        tracing::dispatcher::get_default(|dispatch| {
+            !dispatch.is::<tracing::subscriber::NoSubscriber>()
+        })
+    }
+
+    /// A span grouping the steps of a multi-step workflow.
+    ///
+    /// Requests and job polls made while the span is entered, or by futures
+    /// [instrumented](tracing::Instrument::instrument) with it, appear as its
+    /// children, so a trace shows an upload, the wait for it and the update
+    /// after it as one operation. The span is named `workflow` and records
+    /// `name` in its `canva.workflow` field; the crate's own
+    /// [`workflows`](crate::workflows) use the same shape.
+    ///
+    /// ```rust,no_run
+    /// use canva_connect::{Client, auth::AccessToken, observability::workflow_span};
+    /// use tracing::Instrument;
+    ///
+    /// # async fn example(client: Client) -> canva_connect::Result<()> {
+    /// async {
+    ///     let job = client.exports().wait_for_export_job("export-id").await?;
+    ///     client.exports().download_export(&job, &mut Vec::new()).await?;
+    ///     Ok::<_, canva_connect::Error>(())
+    /// }
+    /// .instrument(workflow_span("export_download"))
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn workflow_span(name: &str) -> tracing::Span {
+        tracing::info_span!("workflow", canva.workflow = name)
+    }
+
     /// Guard that ensures proper cleanup of tracing resources.
     ///
     /// Keep this alive for the duration of your application to ensure
     /// traces are properly flushed when the application exits.
+    #[derive(Debug)]
     pub struct TracingGuard;
 
     impl Drop for TracingGuard {
@@ -128,13 +258,48 @@ mod implementation {
 
     // Note: reqwest-tracing middleware is complex to configure with newer versions
     // We'll rely on manual instrumentation in the client for now
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_detects_installed_subscriber() {
+            assert!(!is_tracing_active());
+            let subscriber = tracing_subscriber::registry();
+            tracing::subscriber::with_default(subscriber, || assert!(is_tracing_active()));
+        }
+
+        #[test]
+        fn test_workflow_span_records_name() {
+            let subscriber = tracing_subscriber::registry();
+            tracing::subscriber::with_default(subscriber, || {
+                let span = workflow_span("asset_upload");
+                assert_eq!(span.metadata().map(|meta| meta.name()), Some("workflow"));
+                assert!(span
+                    .metadata()
+                    .is_some_and(|meta| meta.fields().field("canva.workflow").is_some()));
+            });
+        }
+
+        #[tokio::test]
+        async fn test_try_init_keeps_existing_subscriber() {
+            let _default = tracing::subscriber::set_default(tracing_subscriber::registry());
+            let status = try_init_tracing("test", Some("http://localhost:4317")).await;
+            assert!(matches!(status, TracingStatus::External));
+            assert!(!status.is_exporting());
+        }
+    }
 }
 
 #[cfg(not(feature = "observability"))]
 mod no_op {
     //! No-op implementations when observability feature is disabled.
 
+    use super::TracingStatus;
+
     /// No-op tracing guard when observability is disabled.
+    #[derive(Debug)]
     pub struct TracingGuard;
 
     /// Initialize tracing (no-op when observability feature is disabled).
@@ -145,6 +310,19 @@ mod no_op {
         eprintln!("Warning: Observability feature not enabled. Tracing will not be active.");
         Ok(TracingGuard)
     }
+
+    /// Initialize tracing (always [`TracingStatus::Disabled`] without the observability feature).
+    pub async fn try_init_tracing(
+        _service_name: &str,
+        _otlp_endpoint: Option<&str>,
+    ) -> TracingStatus {
+        TracingStatus::Disabled
+    }
+
+    /// Whether a tracing subscriber is installed (always `false` without the observability feature).
+    pub fn is_tracing_active() -> bool {
+        false
+    }
 }
 
 #[cfg(not(feature = "observability"))]
@@ -0,0 +1,368 @@
+//! The Canva Connect API operations this crate calls, and the scopes they need.
+//!
+//! Every endpoint method documents its required OAuth scopes; [`Operation`]
+//! makes the same information available at runtime, so an integration can
+//! check a token's grants before calling an endpoint, or request exactly the
+//! scopes the operations it uses require.
+//!
+//! ```rust
+//! use canva_connect::auth::{Scope, ScopeSet};
+//! use canva_connect::operations::{scopes_for, Operation};
+//!
+//! let required = scopes_for(Operation::CreateResizeJob);
+//! assert!(required.contains(Scope::DesignContentWrite));
+//!
+//! let operation = Operation::matching(&reqwest::Method::GET, "/v1/designs/DAF123?x=1");
+//! assert_eq!(operation, Some(Operation::GetDesign));
+//! ```
+//!
+//! [`ClientBuilder::scope_preflight`](crate::ClientBuilder::scope_preflight)
+//! uses this table to fail requests the token cannot make with
+//! [`Error::MissingScope`](crate::Error::MissingScope) instead of sending them.
+
+use crate::auth::{Scope, ScopeSet};
+use reqwest::Method;
+
+/// One HTTP endpoint of the Canva Connect API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// `GET /v1/users/me`
+    GetMe,
+    /// `GET /v1/users/me/profile`
+    GetUserProfile,
+    /// `GET /v1/users/me/capabilities`
+    GetUserCapabilities,
+    /// `GET /v1/assets/{assetId}`
+    GetAsset,
+    /// `PATCH /v1/assets/{assetId}`
+    UpdateAsset,
+    /// `DELETE /v1/assets/{assetId}`
+    DeleteAsset,
+    /// `POST /v1/asset-uploads`
+    CreateAssetUploadJob,
+    /// `GET /v1/asset-uploads/{jobId}`
+    GetAssetUploadJob,
+    /// `POST /v1/url-asset-uploads`
+    CreateUrlAssetUploadJob,
+    /// `GET /v1/url-asset-uploads/{jobId}`
+    GetUrlAssetUploadJob,
+    /// `POST /v1/autofills`
+    CreateAutofillJob,
+    /// `GET /v1/autofills/{jobId}`
+    GetAutofillJob,
+    /// `GET /v1/brand-templates`
+    ListBrandTemplates,
+    /// `GET /v1/brand-templates/{brandTemplateId}`
+    GetBrandTemplate,
+    /// `GET /v1/brand-templates/{brandTemplateId}/dataset`
+    GetBrandTemplateDataset,
+    /// `POST /v1/designs/{designId}/comments`
+    CreateThread,
+    /// `GET /v1/designs/{designId}/comments/{threadId}`
+    GetThread,
+    /// `POST /v1/designs/{designId}/comments/{threadId}/replies`
+    CreateReply,
+    /// `GET /v1/designs/{designId}/comments/{threadId}/replies`
+    ListReplies,
+    /// `GET /v1/designs/{designId}/comments/{threadId}/replies/{replyId}`
+    GetReply,
+    /// `GET /v1/designs`
+    ListDesigns,
+    /// `POST /v1/designs`
+    CreateDesign,
+    /// `GET /v1/designs/{designId}`
+    GetDesign,
+    /// `GET /v1/designs/{designId}/pages` (preview)
+    GetDesignPages,
+    /// `GET /v1/designs/{designId}/export-formats`
+    GetExportFormats,
+    /// `POST /v1/exports`
+    CreateExportJob,
+    /// `GET /v1/exports/{exportId}`
+    GetExportJob,
+    /// `POST /v1/folders`
+    CreateFolder,
+    /// `GET /v1/folders/{folderId}`
+    GetFolder,
+    /// `PATCH /v1/folders/{folderId}`
+    UpdateFolder,
+    /// `DELETE /v1/folders/{folderId}`
+    DeleteFolder,
+    /// `GET /v1/folders/{folderId}/items`
+    ListFolderItems,
+    /// `POST /v1/folders/move`
+    MoveFolderItem,
+    /// `POST /v1/imports`
+    CreateImportJob,
+    /// `GET /v1/imports/{jobId}`
+    GetImportJob,
+    /// `POST /v1/url-imports`
+    CreateUrlImportJob,
+    /// `GET /v1/url-imports/{jobId}`
+    GetUrlImportJob,
+    /// `POST /v1/resizes`
+    CreateResizeJob,
+    /// `GET /v1/resizes/{jobId}`
+    GetResizeJob,
+}
+
+impl Operation {
+    /// Every operation, in declaration order
+    pub const ALL: [Operation; 39] = [
+        Operation::GetMe,
+        Operation::GetUserProfile,
+        Operation::GetUserCapabilities,
+        Operation::GetAsset,
+        Operation::UpdateAsset,
+        Operation::DeleteAsset,
+        Operation::CreateAssetUploadJob,
+        Operation::GetAssetUploadJob,
+        Operation::CreateUrlAssetUploadJob,
+        Operation::GetUrlAssetUploadJob,
+        Operation::CreateAutofillJob,
+        Operation::GetAutofillJob,
+        Operation::ListBrandTemplates,
+        Operation::GetBrandTemplate,
+        Operation::GetBrandTemplateDataset,
+        Operation::CreateThread,
+        Operation::GetThread,
+        Operation::CreateReply,
+        Operation::ListReplies,
+        Operation::GetReply,
+        Operation::ListDesigns,
+        Operation::CreateDesign,
+        Operation::GetDesign,
+        Operation::GetDesignPages,
+        Operation::GetExportFormats,
+        Operation::CreateExportJob,
+        Operation::GetExportJob,
+        Operation::CreateFolder,
+        Operation::GetFolder,
+        Operation::UpdateFolder,
+        Operation::DeleteFolder,
+        Operation::ListFolderItems,
+        Operation::MoveFolderItem,
+        Operation::CreateImportJob,
+        Operation::GetImportJob,
+        Operation::CreateUrlImportJob,
+        Operation::GetUrlImportJob,
+        Operation::CreateResizeJob,
+        Operation::GetResizeJob,
+    ];
+
+    /// Method, path template and scopes, as listed in Canva's API reference
+    fn spec(self) -> (Method, &'static str, &'static [Scope]) {
+        use Scope::*;
+        match self {
+            Operation::GetMe => (Method::GET, "/v1/users/me", &[]),
+            Operation::GetUserProfile => (Method::GET, "/v1/users/me/profile", &[ProfileRead]),
+            Operation::GetUserCapabilities => {
+                (Method::GET, "/v1/users/me/capabilities", &[ProfileRead])
+            }
+            Operation::GetAsset => (Method::GET, "/v1/assets/{assetId}", &[AssetRead]),
+            Operation::UpdateAsset => (Method::PATCH, "/v1/assets/{assetId}", &[AssetWrite]),
+            Operation::DeleteAsset => (Method::DELETE, "/v1/assets/{assetId}", &[AssetWrite]),
+            Operation::CreateAssetUploadJob => (Method::POST, "/v1/asset-uploads", &[AssetWrite]),
+            Operation::GetAssetUploadJob => {
+                (Method::GET, "/v1/asset-uploads/{jobId}", &[AssetRead])
+            }
+            Operation::CreateUrlAssetUploadJob => {
+                (Method::POST, "/v1/url-asset-uploads", &[AssetWrite])
+            }
+            Operation::GetUrlAssetUploadJob => {
+                (Method::GET, "/v1/url-asset-uploads/{jobId}", &[AssetRead])
+            }
+            Operation::CreateAutofillJob => (Method::POST, "/v1/autofills", &[DesignContentWrite]),
+            Operation::GetAutofillJob => (Method::GET, "/v1/autofills/{jobId}", &[DesignMetaRead]),
+            Operation::ListBrandTemplates => {
+                (Method::GET, "/v1/brand-templates", &[BrandTemplateMetaRead])
+            }
+            Operation::GetBrandTemplate => (
+                Method::GET,
+                "/v1/brand-templates/{brandTemplateId}",
+                &[BrandTemplateMetaRead],
+            ),
+            Operation::GetBrandTemplateDataset => (
+                Method::GET,
+                "/v1/brand-templates/{brandTemplateId}/dataset",
+                &[BrandTemplateContentRead],
+            ),
+            Operation::CreateThread => (
+                Method::POST,
+                "/v1/designs/{designId}/comments",
+                &[CommentWrite],
+            ),
+            Operation::GetThread => (
+                Method::GET,
+                "/v1/designs/{designId}/comments/{threadId}",
+                &[CommentRead],
+            ),
+            Operation::CreateReply => (
+                Method::POST,
+                "/v1/designs/{designId}/comments/{threadId}/replies",
+                &[CommentWrite],
+            ),
+            Operation::ListReplies => (
+                Method::GET,
+                "/v1/designs/{designId}/comments/{threadId}/replies",
+                &[CommentRead],
+            ),
+            Operation::GetReply => (
+                Method::GET,
+                "/v1/designs/{designId}/comments/{threadId}/replies/{replyId}",
+                &[CommentRead],
+            ),
+            Operation::ListDesigns => (Method::GET, "/v1/designs", &[DesignMetaRead]),
+            Operation::CreateDesign => (Method::POST, "/v1/designs", &[DesignContentWrite]),
+            Operation::GetDesign => (Method::GET, "/v1/designs/{designId}", &[DesignMetaRead]),
+            Operation::GetDesignPages => (
+                Method::GET,
+                "/v1/designs/{designId}/pages",
+                &[DesignContentRead],
+            ),
+            Operation::GetExportFormats => (
+                Method::GET,
+                "/v1/designs/{designId}/export-formats",
+                &[DesignContentRead],
+            ),
+            Operation::CreateExportJob => (Method::POST, "/v1/exports", &[DesignContentRead]),
+            Operation::GetExportJob => {
+                (Method::GET, "/v1/exports/{exportId}", &[DesignContentRead])
+            }
+            Operation::CreateFolder => (Method::POST, "/v1/folders", &[FolderWrite]),
+            Operation::GetFolder => (Method::GET, "/v1/folders/{folderId}", &[FolderRead]),
+            Operation::UpdateFolder => (Method::PATCH, "/v1/folders/{folderId}", &[FolderWrite]),
+            Operation::DeleteFolder => (Method::DELETE, "/v1/folders/{folderId}", &[FolderWrite]),
+            Operation::ListFolderItems => {
+                (Method::GET, "/v1/folders/{folderId}/items", &[FolderRead])
+            }
+            Operation::MoveFolderItem => (Method::POST, "/v1/folders/move", &[FolderWrite]),
+            Operation::CreateImportJob => (Method::POST, "/v1/imports", &[DesignContentWrite]),
+            Operation::GetImportJob => (Method::GET, "/v1/imports/{jobId}", &[DesignContentRead]),
+            Operation::CreateUrlImportJob => {
+                (Method::POST, "/v1/url-imports", &[DesignContentWrite])
+            }
+            Operation::GetUrlImportJob => {
+                (Method::GET, "/v1/url-imports/{jobId}", &[DesignContentRead])
+            }
+            Operation::CreateResizeJob => (
+                Method::POST,
+                "/v1/resizes",
+                &[DesignContentRead, DesignContentWrite],
+            ),
+            Operation::GetResizeJob => (
+                Method::GET,
+                "/v1/resizes/{jobId}",
+                &[DesignContentRead, DesignContentWrite],
+            ),
+        }
+    }
+
+    /// The operation's HTTP method
+    pub fn method(self) -> Method {
+        self.spec().0
+    }
+
+    /// The operation's path template, e.g. `/v1/designs/{designId}`
+    pub fn path(self) -> &'static str {
+        self.spec().1
+    }
+
+    /// The OAuth scopes a token needs to call this operation
+    ///
+    /// Empty for operations every token may call, such as [`Operation::GetMe`].
+    pub fn required_scopes(self) -> ScopeSet {
+        self.spec().2.iter().cloned().collect()
+    }
+
+    /// Find the operation a request to `path` belongs to
+    ///
+    /// `path` is the API path such as `/v1/designs/DAF123`; a query string is
+    /// ignored. Returns `None` for paths this crate does not know.
+    pub fn matching(method: &Method, path: &str) -> Option<Operation> {
+        let path = path.split('?').next().unwrap_or_default();
+        let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+        Operation::ALL
+            .into_iter()
+            .filter(|operation| operation.method() == *method)
+            .filter_map(|operation| {
+                Some((template_specificity(operation.path(), &parts)?, operation))
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, operation)| operation)
+    }
+}
+
+/// The OAuth scopes a token needs to call `operation`
+///
+/// Shorthand for [`Operation::required_scopes`].
+pub fn scopes_for(operation: Operation) -> ScopeSet {
+    operation.required_scopes()
+}
+
+/// Number of literal segments if `parts` matches `template`, so that
+/// `/v1/folders/move` wins over `/v1/folders/{folderId}`
+fn template_specificity(template: &str, parts: &[&str]) -> Option<usize> {
+    let segments: Vec<&str> = template.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.len() != parts.len() {
+        return None;
+    }
+    let mut literals = 0;
+    for (segment, part) in segments.iter().zip(parts) {
+        if segment.starts_with('{') && segment.ends_with('}') {
+            continue;
+        }
+        if segment != part {
+            return None;
+        }
+        literals += 1;
+    }
+    Some(literals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_operation_matches_its_own_template() {
+        for operation in Operation::ALL {
+            assert_eq!(
+                Operation::matching(&operation.method(), operation.path()),
+                Some(operation)
+            );
+        }
+    }
+
+    #[test]
+    fn test_matching_prefers_literal_segments_and_ignores_query() {
+        assert_eq!(
+            Operation::matching(&Method::POST, "/v1/folders/move"),
+            Some(Operation::MoveFolderItem)
+        );
+        assert_eq!(
+            Operation::matching(&Method::GET, "/v1/designs?query=cats&limit=10"),
+            Some(Operation::ListDesigns)
+        );
+        assert_eq!(
+            Operation::matching(&Method::GET, "/v1/designs/DAF1/comments/T1/replies"),
+            Some(Operation::ListReplies)
+        );
+        assert_eq!(Operation::matching(&Method::PUT, "/v1/designs"), None);
+        assert_eq!(Operation::matching(&Method::GET, "/v1/unknown"), None);
+    }
+
+    #[test]
+    fn test_scopes_match_documented_requirements() {
+        assert!(scopes_for(Operation::GetMe).is_empty());
+        assert_eq!(
+            scopes_for(Operation::CreateResizeJob).to_string(),
+            "design:content:read design:content:write"
+        );
+        assert_eq!(
+            scopes_for(Operation::GetBrandTemplateDataset).to_string(),
+            "brandtemplate:content:read"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! URL query string building.
+//!
+//! [`QueryBuilder`] collects query parameters, skips the ones left unset and
+//! percent-encodes keys and values, so endpoints never format query strings
+//! by hand. Values are anything implementing [`QueryValue`]: strings,
+//! numbers, booleans and the API's filter enums, which are written as their
+//! API names.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use canva_connect::query::QueryBuilder;
+//! use canva_connect::models::SortByType;
+//!
+//! let path = QueryBuilder::new()
+//!     .param("query", "summer sale")
+//!     .param("sort_by", SortByType::ModifiedDescending)
+//!     .param_opt("limit", None::<u32>)
+//!     .append_to("/v1/designs");
+//! assert_eq!(path, "/v1/designs?query=summer%20sale&sort_by=modified_descending");
+//! ```
+
+use crate::models::{OwnershipType, SortByType};
+use std::borrow::Cow;
+use std::fmt;
+
+/// A value that can be written into a query string
+pub trait QueryValue {
+    /// The value as it appears in the query string, before percent-encoding
+    fn to_query_value(&self) -> Cow<'_, str>;
+}
+
+impl QueryValue for str {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl QueryValue for String {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<T: QueryValue + ?Sized> QueryValue for &T {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        (**self).to_query_value()
+    }
+}
+
+macro_rules! display_query_value {
+    ($($ty:ty),*) => {
+        $(
+            impl QueryValue for $ty {
+                fn to_query_value(&self) -> Cow<'_, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_query_value!(bool, u8, u16, u32, u64, usize, i32, i64);
+
+impl QueryValue for OwnershipType {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self {
+            OwnershipType::Any => "any",
+            OwnershipType::Owned => "owned",
+            OwnershipType::Shared => "shared",
+        })
+    }
+}
+
+impl QueryValue for SortByType {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self {
+            SortByType::Relevance => "relevance",
+            SortByType::ModifiedDescending => "modified_descending",
+            SortByType::ModifiedAscending => "modified_ascending",
+            SortByType::TitleDescending => "title_descending",
+            SortByType::TitleAscending => "title_ascending",
+        })
+    }
+}
+
+/// Builds a percent-encoded query string
+///
+/// Parameters keep the order they were added in. [`Display`](fmt::Display)
+/// writes the query string without a leading `?`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryBuilder {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryBuilder {
+    /// Create an empty query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a parameter
+    pub fn param(mut self, key: &str, value: impl QueryValue) -> Self {
+        self.pairs
+            .push((key.to_string(), value.to_query_value().into_owned()));
+        self
+    }
+
+    /// Add a parameter if `value` is set
+    pub fn param_opt(self, key: &str, value: Option<impl QueryValue>) -> Self {
+        match value {
+            Some(value) => self.param(key, value),
+            None => self,
+        }
+    }
+
+    /// Whether no parameters were added
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Parameters added so far, unencoded
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.pairs
+    }
+
+    /// `path` followed by the query string, or `path` alone if there are no
+    /// parameters
+    pub fn append_to(&self, path: &str) -> String {
+        if self.is_empty() {
+            return path.to_string();
+        }
+        let separator = if path.contains('?') { '&' } else { '?' };
+        format!("{path}{separator}{self}")
+    }
+}
+
+impl fmt::Display for QueryBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            write!(
+                f,
+                "{}={}",
+                urlencoding::encode(key),
+                urlencoding::encode(value)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_leaves_path_alone() {
+        let query = QueryBuilder::new()
+            .param_opt("limit", None::<u32>)
+            .param_opt("continuation", None::<&str>);
+        assert!(query.is_empty());
+        assert_eq!(
+            query.append_to("/v1/folders/f1/items"),
+            "/v1/folders/f1/items"
+        );
+    }
+
+    #[test]
+    fn test_values_are_encoded_in_order() {
+        let query = QueryBuilder::new()
+            .param_opt("continuation", Some("a+b/c=d&e 🎨"))
+            .param("limit", 25u32)
+            .param("ownership", OwnershipType::Shared)
+            .param("flag", true);
+        assert_eq!(
+            query.to_string(),
+            "continuation=a%2Bb%2Fc%3Dd%26e%20%F0%9F%8E%A8&limit=25&ownership=shared&flag=true"
+        );
+        assert_eq!(query.pairs()[0].1, "a+b/c=d&e 🎨");
+    }
+
+    #[test]
+    fn test_append_to_path_with_query() {
+        let query = QueryBuilder::new().param("limit", 5u32);
+        assert_eq!(query.append_to("/v1/x?a=1"), "/v1/x?a=1&limit=5");
+    }
+}
@@ -1,28 +1,150 @@
 //! Rate limiting utilities for the Canva Connect API
+//!
+//! [`ApiRateLimiter`] is a token bucket implemented with the generic cell rate
+//! algorithm. It measures time with [`tokio::time::Instant`] and waits with
+//! [`tokio::time::sleep`], so under a paused tokio clock
+//! (`#[tokio::test(start_paused = true)]` or `tokio::time::pause()`) its
+//! behavior is fully deterministic.
+//!
+//! Waiters are served in arrival order: a request that starts waiting first is
+//! released first, even when later requests arrive while it is waiting. The
+//! exception is [`Priority`]: a waiting request is only released once no
+//! request of a higher priority is waiting, so interactive calls made with
+//! [`Client::with_priority`](crate::Client::with_priority) overtake a queue of
+//! background work.
+//!
+//! Canva also limits each endpoint separately, for example 10 autofill jobs
+//! per minute but 60 autofill status checks. [`EndpointRateLimits`] keeps one
+//! bucket per route pattern on top of the client-wide limiter. The client
+//! uses [`EndpointRateLimits::canva_defaults`] unless told otherwise:
+//!
+//! ```rust
+//! use canva_connect::{Client, auth::AccessToken, rate_limit::EndpointRateLimits};
+//! use reqwest::Method;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let limits = EndpointRateLimits::canva_defaults()
+//!     // Our organisation has a higher autofill quota
+//!     .set(Method::POST, "/v1/autofills", 30);
+//!
+//! let client = Client::builder(AccessToken::new("your-token"))
+//!     .endpoint_rate_limits(limits)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
 
-use governor::{Quota, RateLimiter};
-use nonzero_ext::nonzero;
-use std::num::NonZeroU32;
+use reqwest::Method;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{Mutex, Notify};
+use tokio::time::Instant;
+
+/// How urgently a request should be released by a rate limiter
+///
+/// Ordered from lowest to highest, so `Priority::High > Priority::Low`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Background work that can wait, such as bulk exports
+    Low,
+    /// The default for requests
+    #[default]
+    Normal,
+    /// Interactive requests a user is waiting on
+    High,
+}
+
+impl Priority {
+    const ALL: [Priority; 3] = [Priority::Low, Priority::Normal, Priority::High];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Counts a request as waiting in its lane for as long as it is alive
+struct Waiting<'a> {
+    limiter: &'a ApiRateLimiter,
+    priority: Priority,
+}
+
+impl<'a> Waiting<'a> {
+    fn new(limiter: &'a ApiRateLimiter, priority: Priority) -> Self {
+        limiter.waiting[priority.index()].fetch_add(1, Ordering::SeqCst);
+        Self { limiter, priority }
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.limiter.waiting[self.priority.index()].fetch_sub(1, Ordering::SeqCst);
+        // Lower lanes may have been held back by this request
+        self.limiter.released.notify_waiters();
+    }
+}
 
 /// Rate limiter for API requests
 #[derive(Debug)]
 pub struct ApiRateLimiter {
-    limiter: RateLimiter<
-        governor::state::direct::NotKeyed,
-        governor::state::InMemoryState,
-        governor::clock::DefaultClock,
-    >,
+    requests_per_minute: u32,
+    burst: u32,
+    /// Time between two requests at the sustained rate
+    interval: Duration,
+    /// How far ahead of the sustained rate requests may run
+    tolerance: Duration,
+    /// Theoretical arrival time of the next request; `None` until first use
+    state: std::sync::Mutex<Option<Instant>>,
+    /// One queue per [`Priority`]; the head of a queue holds its lock while waiting
+    lanes: [Mutex<()>; 3],
+    /// Number of requests waiting in each lane, including the head
+    waiting: [AtomicUsize; 3],
+    /// Notified whenever a request stops waiting
+    released: Notify,
+    /// No request is released before this instant, set from server feedback
+    paused_until: std::sync::Mutex<Option<Instant>>,
 }
 
 impl ApiRateLimiter {
     /// Create a new rate limiter with the given rate limit per minute
+    ///
+    /// Up to `requests_per_minute` requests may be made in a burst before
+    /// requests are spaced out evenly.
     pub fn new(requests_per_minute: u32) -> Self {
-        let quota =
-            Quota::per_minute(NonZeroU32::new(requests_per_minute).unwrap_or(nonzero!(60u32)));
-        let limiter = RateLimiter::direct(quota);
+        let requests_per_minute = if requests_per_minute == 0 {
+            60
+        } else {
+            requests_per_minute
+        };
+        Self::with_burst(requests_per_minute, requests_per_minute)
+    }
 
-        Self { limiter }
+    /// Create a rate limiter with an explicit burst size
+    ///
+    /// `burst` is the number of requests allowed back to back after the
+    /// limiter has been idle. Zero values fall back to 60 requests per minute
+    /// and a burst of one.
+    pub fn with_burst(requests_per_minute: u32, burst: u32) -> Self {
+        let requests_per_minute = if requests_per_minute == 0 {
+            60
+        } else {
+            requests_per_minute
+        };
+        let burst = burst.max(1);
+        let interval = Duration::from_secs(60) / requests_per_minute;
+
+        Self {
+            requests_per_minute,
+            burst,
+            interval,
+            tolerance: interval * (burst - 1),
+            state: std::sync::Mutex::new(None),
+            lanes: Default::default(),
+            waiting: Default::default(),
+            released: Notify::new(),
+            paused_until: std::sync::Mutex::new(None),
+        }
     }
 
     /// Create a conservative rate limiter (30 requests per minute)
@@ -35,14 +157,124 @@ impl ApiRateLimiter {
         Self::new(100)
     }
 
+    /// Sustained number of requests allowed per minute
+    pub fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute
+    }
+
+    /// Number of requests allowed back to back after the limiter has been idle
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
     /// Wait until a request can be made
+    ///
+    /// Waits with [`Priority::Normal`]. This is cancellation safe: dropping
+    /// the future before it completes does not consume any capacity.
     pub async fn wait_for_request(&self) {
-        self.limiter.until_ready().await;
+        self.wait_for_request_with_priority(Priority::Normal).await;
+    }
+
+    /// Wait until a request of the given priority can be made
+    ///
+    /// Requests of the same priority are released in arrival order. A request
+    /// is held back while any request of a higher priority is waiting.
+    /// Cancellation safe, like [`wait_for_request`](Self::wait_for_request).
+    pub async fn wait_for_request_with_priority(&self, priority: Priority) {
+        let _waiting = Waiting::new(self, priority);
+        // The lane lock is held while sleeping so each lane is served in FIFO order
+        let _head = self.lanes[priority.index()].lock().await;
+        loop {
+            // Registered before checking so a release in between is not missed
+            let released = self.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            if let Some(remaining) = self.pause_remaining() {
+                tokio::time::sleep(remaining).await;
+                continue;
+            }
+            if self.higher_priority_waiting(priority) {
+                released.await;
+                continue;
+            }
+            let acquired = self.try_acquire(&mut self.lock_state(), Instant::now());
+            match acquired {
+                Ok(()) => return,
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
     }
 
     /// Check if a request can be made immediately
+    ///
+    /// Returns `true` and consumes capacity for one request if it can. Always
+    /// `false` while other requests are waiting.
     pub fn can_make_request(&self) -> bool {
-        self.limiter.check().is_ok()
+        if self.pause_remaining().is_some() || self.waiting_requests() > 0 {
+            return false;
+        }
+        self.try_acquire(&mut self.lock_state(), Instant::now())
+            .is_ok()
+    }
+
+    /// Number of requests currently waiting, across all priorities
+    pub fn waiting_requests(&self) -> usize {
+        self.waiting
+            .iter()
+            .map(|waiting| waiting.load(Ordering::SeqCst))
+            .sum()
+    }
+
+    fn higher_priority_waiting(&self, priority: Priority) -> bool {
+        Priority::ALL
+            .iter()
+            .filter(|other| **other > priority)
+            .any(|other| self.waiting[other.index()].load(Ordering::SeqCst) > 0)
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Hold back all requests for `duration`
+    ///
+    /// Used when the server asks the client to slow down. Pauses do not
+    /// shorten each other: a pause ending earlier than the current one has no
+    /// effect.
+    pub fn pause_for(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut paused_until = self.lock_pause();
+        if *paused_until < Some(until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    /// Time left until the current pause ends, if requests are paused
+    pub fn pause_remaining(&self) -> Option<Duration> {
+        let until = (*self.lock_pause())?;
+        let now = Instant::now();
+        (until > now).then(|| until - now)
+    }
+
+    fn lock_pause(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        self.paused_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Take capacity for one request, or return how long to wait for it
+    fn try_acquire(&self, state: &mut Option<Instant>, now: Instant) -> Result<(), Duration> {
+        let tat = state.map_or(now, |tat| tat.max(now));
+        let allowed_at = now + self.tolerance;
+        if tat <= allowed_at {
+            *state = Some(tat + self.interval);
+            Ok(())
+        } else {
+            Err(tat - allowed_at)
+        }
     }
 }
 
@@ -52,8 +284,221 @@ impl Default for ApiRateLimiter {
     }
 }
 
-/// Rate limit information from API response headers
+/// Per-endpoint limits, as documented by Canva, in requests per minute per user
+const CANVA_ENDPOINT_LIMITS: &[(&str, &str, u32)] = &[
+    ("GET", "/v1/users/me", 10),
+    ("GET", "/v1/users/me/profile", 10),
+    ("GET", "/v1/users/me/capabilities", 10),
+    ("GET", "/v1/assets/{assetId}", 100),
+    ("PATCH", "/v1/assets/{assetId}", 30),
+    ("DELETE", "/v1/assets/{assetId}", 30),
+    ("POST", "/v1/asset-uploads", 30),
+    ("GET", "/v1/asset-uploads/{jobId}", 180),
+    ("POST", "/v1/url-asset-uploads", 30),
+    ("GET", "/v1/url-asset-uploads/{jobId}", 180),
+    ("POST", "/v1/autofills", 10),
+    ("GET", "/v1/autofills/{jobId}", 60),
+    ("GET", "/v1/brand-templates", 100),
+    ("GET", "/v1/brand-templates/{brandTemplateId}", 100),
+    ("GET", "/v1/brand-templates/{brandTemplateId}/dataset", 100),
+    ("POST", "/v1/designs/{designId}/comments", 100),
+    ("GET", "/v1/designs/{designId}/comments/{threadId}", 100),
+    (
+        "POST",
+        "/v1/designs/{designId}/comments/{threadId}/replies",
+        20,
+    ),
+    (
+        "GET",
+        "/v1/designs/{designId}/comments/{threadId}/replies",
+        100,
+    ),
+    (
+        "GET",
+        "/v1/designs/{designId}/comments/{threadId}/replies/{replyId}",
+        100,
+    ),
+    ("GET", "/v1/designs", 100),
+    ("POST", "/v1/designs", 20),
+    ("GET", "/v1/designs/{designId}", 100),
+    ("GET", "/v1/designs/{designId}/pages", 100),
+    ("GET", "/v1/designs/{designId}/export-formats", 100),
+    ("POST", "/v1/exports", 20),
+    ("GET", "/v1/exports/{exportId}", 120),
+    ("POST", "/v1/folders", 20),
+    ("GET", "/v1/folders/{folderId}", 100),
+    ("PATCH", "/v1/folders/{folderId}", 20),
+    ("DELETE", "/v1/folders/{folderId}", 20),
+    ("GET", "/v1/folders/{folderId}/items", 100),
+    ("POST", "/v1/folders/move", 100),
+    ("POST", "/v1/imports", 20),
+    ("GET", "/v1/imports/{jobId}", 120),
+    ("POST", "/v1/url-imports", 20),
+    ("GET", "/v1/url-imports/{jobId}", 120),
+    ("POST", "/v1/resizes", 20),
+    ("GET", "/v1/resizes/{jobId}", 120),
+];
+
+/// One segment of a route pattern such as `/v1/designs/{designId}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RouteSegment {
+    Literal(String),
+    Param,
+}
+
+/// A rate limit for one method and route pattern
 #[derive(Debug, Clone)]
+struct EndpointRule {
+    method: Method,
+    route: String,
+    segments: Vec<RouteSegment>,
+    limiter: Arc<ApiRateLimiter>,
+}
+
+impl EndpointRule {
+    /// Number of literal segments if `path` matches, used to pick the most specific rule
+    fn specificity(&self, method: &Method, path: &[&str]) -> Option<usize> {
+        if self.method != *method || self.segments.len() != path.len() {
+            return None;
+        }
+        let mut literals = 0;
+        for (segment, part) in self.segments.iter().zip(path) {
+            match segment {
+                RouteSegment::Literal(literal) if literal == part => literals += 1,
+                RouteSegment::Literal(_) => return None,
+                RouteSegment::Param => {}
+            }
+        }
+        Some(literals)
+    }
+}
+
+fn parse_route(route: &str) -> Vec<RouteSegment> {
+    route
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if segment.starts_with('{') && segment.ends_with('}') {
+                RouteSegment::Param
+            } else {
+                RouteSegment::Literal(segment.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Rate limits for individual API endpoints
+///
+/// Each rule pairs an HTTP method and a route pattern, where `{name}`
+/// matches any single path segment, with its own [`ApiRateLimiter`]. A
+/// request waits on the most specific matching rule; requests that match no
+/// rule are only limited by the client-wide limiter. Clones share their
+/// buckets.
+#[derive(Debug, Clone, Default)]
+pub struct EndpointRateLimits {
+    rules: Vec<EndpointRule>,
+}
+
+impl EndpointRateLimits {
+    /// Create an empty set of limits, leaving every endpoint unlimited
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits matching the per-user quotas in Canva's API reference
+    ///
+    /// Canva may change its quotas; override individual routes with
+    /// [`set`](Self::set) if your integration has different ones.
+    pub fn canva_defaults() -> Self {
+        CANVA_ENDPOINT_LIMITS
+            .iter()
+            .fold(Self::new(), |limits, (method, route, per_minute)| {
+                let method = Method::from_bytes(method.as_bytes()).unwrap_or(Method::GET);
+                limits.set(method, route, *per_minute)
+            })
+    }
+
+    /// Limit `method` requests to `route` to `requests_per_minute`
+    ///
+    /// Replaces any existing rule for the same method and route; parameter
+    /// names do not matter, so `/v1/designs/{id}` replaces
+    /// `/v1/designs/{designId}`.
+    pub fn set(self, method: Method, route: &str, requests_per_minute: u32) -> Self {
+        self.with_limiter(method, route, ApiRateLimiter::new(requests_per_minute))
+    }
+
+    /// Like [`set`](Self::set), with a limiter configured by the caller
+    pub fn with_limiter(mut self, method: Method, route: &str, limiter: ApiRateLimiter) -> Self {
+        let rule = EndpointRule {
+            segments: parse_route(route),
+            route: route.to_string(),
+            method,
+            limiter: Arc::new(limiter),
+        };
+        match self
+            .rules
+            .iter_mut()
+            .find(|existing| existing.method == rule.method && existing.segments == rule.segments)
+        {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+        self
+    }
+
+    /// Remove the limit for `method` requests to `route`, if there is one
+    pub fn remove(mut self, method: &Method, route: &str) -> Self {
+        let segments = parse_route(route);
+        self.rules
+            .retain(|rule| rule.method != *method || rule.segments != segments);
+        self
+    }
+
+    /// The limiter that applies to a request, if any
+    ///
+    /// `path` is the API path such as `/v1/designs/DAF123`; a query string is
+    /// ignored.
+    pub fn limiter_for(&self, method: &Method, path: &str) -> Option<&ApiRateLimiter> {
+        let path = path.split('?').next().unwrap_or_default();
+        let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+        self.rules
+            .iter()
+            .filter_map(|rule| Some((rule.specificity(method, &parts)?, rule)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, rule)| rule.limiter.as_ref())
+    }
+
+    /// Wait until a request to `path` can be made under its endpoint limit
+    ///
+    /// Returns immediately for endpoints without a limit. Cancellation safe,
+    /// like [`ApiRateLimiter::wait_for_request`].
+    pub async fn wait_for_request(&self, method: &Method, path: &str) {
+        self.wait_for_request_with_priority(method, path, Priority::Normal)
+            .await;
+    }
+
+    /// Like [`wait_for_request`](Self::wait_for_request), waiting with `priority`
+    pub async fn wait_for_request_with_priority(
+        &self,
+        method: &Method,
+        path: &str,
+        priority: Priority,
+    ) {
+        if let Some(limiter) = self.limiter_for(method, path) {
+            limiter.wait_for_request_with_priority(priority).await;
+        }
+    }
+
+    /// The configured rules as method, route pattern and limiter
+    pub fn routes(&self) -> impl Iterator<Item = (&Method, &str, &ApiRateLimiter)> {
+        self.rules
+            .iter()
+            .map(|rule| (&rule.method, rule.route.as_str(), rule.limiter.as_ref()))
+    }
+}
+
+/// Rate limit information from API response headers
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct RateLimitInfo {
     /// Number of requests remaining in the current window
     pub remaining: Option<u32>,
@@ -93,7 +538,10 @@ impl RateLimitInfo {
     pub fn is_near_limit(&self) -> bool {
         match (self.remaining, self.limit) {
             (Some(remaining), Some(limit)) => {
-                let usage_percentage = (limit - remaining) as f64 / limit as f64;
+                if limit == 0 {
+                    return true;
+                }
+                let usage_percentage = limit.saturating_sub(remaining) as f64 / limit as f64;
                 usage_percentage > 0.8 // 80% usage
             }
             _ => false,
@@ -112,3 +560,78 @@ impl RateLimitInfo {
         })
     }
 }
+
+/// Parse the `Retry-After` header
+///
+/// Accepts both forms allowed by HTTP: a number of seconds, or an HTTP date.
+/// A date in the past yields a zero duration.
+pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get("retry-after")?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Why the client held back requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThrottleReason {
+    /// The server rejected a request with HTTP 429 and a `Retry-After` header
+    RetryAfter,
+    /// No requests remain in the current window; waiting for it to reset
+    QuotaExhausted,
+    /// Few requests remain; spreading them out until the window resets
+    NearLimit,
+}
+
+/// A pause the client applied in response to rate limit headers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrottleEvent {
+    /// HTTP method of the request whose response caused the pause
+    pub method: String,
+    /// Path of that request, without query string
+    pub path: String,
+    /// What in the response caused the pause
+    pub reason: ThrottleReason,
+    /// How long requests are held back
+    pub delay: Duration,
+    /// Rate limit headers from the response
+    pub rate_limit: RateLimitInfo,
+}
+
+/// Observes pauses the client applies from server rate limit feedback
+///
+/// Register one with
+/// [`ClientBuilder::throttle_listener`](crate::ClientBuilder::throttle_listener).
+/// The listener is called on the request path, so it should return quickly.
+pub trait ThrottleListener: fmt::Debug + Send + Sync {
+    /// Called each time the client pauses requests
+    fn on_throttle(&self, event: &ThrottleEvent);
+}
+
+/// Decide how long to hold back requests after a response
+pub(crate) fn server_throttle(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> Option<(ThrottleReason, Duration, RateLimitInfo)> {
+    let info = RateLimitInfo::from_headers(headers);
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        if let Some(delay) = retry_after(headers) {
+            return Some((ThrottleReason::RetryAfter, delay, info));
+        }
+    }
+    let until_reset = info.time_until_reset()?;
+    match info.remaining? {
+        0 => Some((ThrottleReason::QuotaExhausted, until_reset, info)),
+        remaining if info.is_near_limit() => {
+            Some((ThrottleReason::NearLimit, until_reset / remaining, info))
+        }
+        _ => None,
+    }
+}
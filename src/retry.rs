@@ -0,0 +1,224 @@
+//! Retry policies for transient API failures.
+//!
+//! A [`RetryPolicy`] describes how many times a request is re-sent after a
+//! transient failure and how long to back off between attempts. Policies are
+//! configured through [`ClientBuilder::retry_policy`](crate::client::ClientBuilder::retry_policy).
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use canva_connect::retry::RetryPolicy;
+//! use std::time::Duration;
+//!
+//! let policy = RetryPolicy::new(3)
+//!     .with_initial_backoff(Duration::from_millis(250))
+//!     .with_max_backoff(Duration::from_secs(5));
+//! assert_eq!(policy.max_retries(), 3);
+//! ```
+
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+/// Policy controlling how failed requests are retried
+///
+/// Idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE`, `OPTIONS`) are
+/// retried when the API responds with `429 Too Many Requests`,
+/// `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`, or
+/// when the connection fails or times out before a response is received.
+///
+/// Other requests, such as the `POST`s that start export, upload, autofill
+/// and import jobs, may already have been processed after a read timeout,
+/// 502 or 504. Sending them again could create a second job, so they are
+/// only retried when the connection could not be made or the API answered
+/// 429 or 503, where the request was not processed.
+///
+/// Backoff grows exponentially from the initial delay up to the maximum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+}
+
+impl RetryPolicy {
+    /// Create a policy that retries up to `max_retries` times with exponential backoff
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+
+    /// Create a policy that never retries
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the maximum number of retries after the initial attempt
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the upper bound for the delay between retries
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the factor the delay is multiplied by after each retry
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Maximum number of retries after the initial attempt
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Delay before the first retry
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Upper bound for the delay between retries
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Delay to wait before the given retry (1-based)
+    pub fn backoff_for(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_backoff.as_secs_f64()))
+    }
+
+    /// Whether a response with the given status should be retried
+    pub fn should_retry_status(&self, status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Whether a transport error should be retried
+    pub fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Whether a request with the given method should be retried after a response with `status`
+    ///
+    /// Like [`should_retry_status`](Self::should_retry_status) for idempotent
+    /// methods; other methods are only retried on 429 and 503.
+    pub fn should_retry_status_for(&self, method: &Method, status: StatusCode) -> bool {
+        if is_idempotent(method) {
+            self.should_retry_status(status)
+        } else {
+            matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            )
+        }
+    }
+
+    /// Whether a request with the given method should be retried after a transport error
+    ///
+    /// Like [`should_retry_error`](Self::should_retry_error) for idempotent
+    /// methods; other methods are only retried when the connection failed.
+    pub fn should_retry_error_for(&self, method: &Method, error: &reqwest::Error) -> bool {
+        if is_idempotent(method) {
+            self.should_retry_error(error)
+        } else {
+            error.is_connect()
+        }
+    }
+}
+
+/// Whether sending a request with this method twice has the same effect as sending it once
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+impl Default for RetryPolicy {
+    /// The default policy does not retry, matching the behavior of [`Client::new`](crate::Client::new)
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_does_not_retry() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_retries(), 0);
+        assert_eq!(policy, RetryPolicy::none());
+    }
+
+    #[test]
+    fn test_backoff_grows_exponentially() {
+        let policy = RetryPolicy::new(5).with_initial_backoff(Duration::from_millis(100));
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_for(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_for(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_backoff(Duration::from_secs(1))
+            .with_max_backoff(Duration::from_secs(3));
+        assert_eq!(policy.backoff_for(5), Duration::from_secs(3));
+        assert_eq!(policy.backoff_for(u32::MAX), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_multiplier_is_at_least_one() {
+        let policy = RetryPolicy::new(3)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_multiplier(0.5);
+        assert_eq!(policy.backoff_for(3), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        let policy = RetryPolicy::new(1);
+        assert!(policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.should_retry_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(policy.should_retry_status(StatusCode::BAD_GATEWAY));
+        assert!(policy.should_retry_status(StatusCode::GATEWAY_TIMEOUT));
+        assert!(!policy.should_retry_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!policy.should_retry_status(StatusCode::BAD_REQUEST));
+        assert!(!policy.should_retry_status(StatusCode::UNAUTHORIZED));
+    }
+
+    #[test]
+    fn test_non_idempotent_methods_retry_only_unprocessed_requests() {
+        let policy = RetryPolicy::new(1);
+        assert!(policy.should_retry_status_for(&Method::GET, StatusCode::BAD_GATEWAY));
+        assert!(policy.should_retry_status_for(&Method::DELETE, StatusCode::GATEWAY_TIMEOUT));
+        assert!(policy.should_retry_status_for(&Method::POST, StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.should_retry_status_for(&Method::POST, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.should_retry_status_for(&Method::POST, StatusCode::BAD_GATEWAY));
+        assert!(!policy.should_retry_status_for(&Method::POST, StatusCode::GATEWAY_TIMEOUT));
+        assert!(!policy.should_retry_status_for(&Method::PATCH, StatusCode::BAD_GATEWAY));
+    }
+}
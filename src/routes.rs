@@ -0,0 +1,219 @@
+//! Paths of the Canva Connect API endpoints.
+//!
+//! The endpoint modules build every request path from this module, so each
+//! path is spelled in one place and a rename on Canva's side is a one-line
+//! change. Fixed paths are constants; paths with IDs are functions that
+//! percent-encode each ID, so an ID can never add a path segment.
+//!
+//! Every route belongs to one [`Operation`](crate::operations::Operation);
+//! the tests check that each constructor produces a path matching its
+//! operation's template.
+//!
+//! ```rust
+//! use canva_connect::routes;
+//!
+//! assert_eq!(routes::autofill_job("job 1"), "/v1/autofills/job%201");
+//! assert_eq!(routes::FOLDERS_MOVE, "/v1/folders/move");
+//! ```
+
+use urlencoding::encode;
+
+/// `/v1/users/me`
+pub const USER_ME: &str = "/v1/users/me";
+/// `/v1/users/me/profile`
+pub const USER_PROFILE: &str = "/v1/users/me/profile";
+/// `/v1/users/me/capabilities`
+pub const USER_CAPABILITIES: &str = "/v1/users/me/capabilities";
+
+/// `/v1/asset-uploads`
+pub const ASSET_UPLOADS: &str = "/v1/asset-uploads";
+/// `/v1/url-asset-uploads`
+pub const URL_ASSET_UPLOADS: &str = "/v1/url-asset-uploads";
+/// `/v1/autofills`
+pub const AUTOFILLS: &str = "/v1/autofills";
+/// `/v1/brand-templates`
+pub const BRAND_TEMPLATES: &str = "/v1/brand-templates";
+/// `/v1/designs`
+pub const DESIGNS: &str = "/v1/designs";
+/// `/v1/exports`
+pub const EXPORTS: &str = "/v1/exports";
+/// `/v1/folders`
+pub const FOLDERS: &str = "/v1/folders";
+/// `/v1/folders/move`
+pub const FOLDERS_MOVE: &str = "/v1/folders/move";
+/// `/v1/imports`
+pub const IMPORTS: &str = "/v1/imports";
+/// `/v1/url-imports`
+pub const URL_IMPORTS: &str = "/v1/url-imports";
+/// `/v1/resizes`
+pub const RESIZES: &str = "/v1/resizes";
+
+/// `/v1/assets/{assetId}`
+pub fn asset(asset_id: &str) -> String {
+    format!("/v1/assets/{}", encode(asset_id))
+}
+
+/// `/v1/asset-uploads/{jobId}`
+pub fn asset_upload_job(job_id: &str) -> String {
+    format!("{ASSET_UPLOADS}/{}", encode(job_id))
+}
+
+/// `/v1/url-asset-uploads/{jobId}`
+pub fn url_asset_upload_job(job_id: &str) -> String {
+    format!("{URL_ASSET_UPLOADS}/{}", encode(job_id))
+}
+
+/// `/v1/autofills/{jobId}`
+pub fn autofill_job(job_id: &str) -> String {
+    format!("{AUTOFILLS}/{}", encode(job_id))
+}
+
+/// `/v1/brand-templates/{brandTemplateId}`
+pub fn brand_template(brand_template_id: &str) -> String {
+    format!("{BRAND_TEMPLATES}/{}", encode(brand_template_id))
+}
+
+/// `/v1/brand-templates/{brandTemplateId}/dataset`
+pub fn brand_template_dataset(brand_template_id: &str) -> String {
+    format!("{}/dataset", brand_template(brand_template_id))
+}
+
+/// `/v1/designs/{designId}`
+pub fn design(design_id: &str) -> String {
+    format!("{DESIGNS}/{}", encode(design_id))
+}
+
+/// `/v1/designs/{designId}/pages`
+pub fn design_pages(design_id: &str) -> String {
+    format!("{}/pages", design(design_id))
+}
+
+/// `/v1/designs/{designId}/export-formats`
+pub fn design_export_formats(design_id: &str) -> String {
+    format!("{}/export-formats", design(design_id))
+}
+
+/// `/v1/designs/{designId}/comments`
+pub fn comment_threads(design_id: &str) -> String {
+    format!("{}/comments", design(design_id))
+}
+
+/// `/v1/designs/{designId}/comments/{threadId}`
+pub fn comment_thread(design_id: &str, thread_id: &str) -> String {
+    format!("{}/{}", comment_threads(design_id), encode(thread_id))
+}
+
+/// `/v1/designs/{designId}/comments/{threadId}/replies`
+pub fn comment_replies(design_id: &str, thread_id: &str) -> String {
+    format!("{}/replies", comment_thread(design_id, thread_id))
+}
+
+/// `/v1/designs/{designId}/comments/{threadId}/replies/{replyId}`
+pub fn comment_reply(design_id: &str, thread_id: &str, reply_id: &str) -> String {
+    format!(
+        "{}/{}",
+        comment_replies(design_id, thread_id),
+        encode(reply_id)
+    )
+}
+
+/// `/v1/exports/{exportId}`
+pub fn export_job(export_id: &str) -> String {
+    format!("{EXPORTS}/{}", encode(export_id))
+}
+
+/// `/v1/folders/{folderId}`
+pub fn folder(folder_id: &str) -> String {
+    format!("{FOLDERS}/{}", encode(folder_id))
+}
+
+/// `/v1/folders/{folderId}/items`
+pub fn folder_items(folder_id: &str) -> String {
+    format!("{}/items", folder(folder_id))
+}
+
+/// `/v1/imports/{jobId}`
+pub fn import_job(job_id: &str) -> String {
+    format!("{IMPORTS}/{}", encode(job_id))
+}
+
+/// `/v1/url-imports/{jobId}`
+pub fn url_import_job(job_id: &str) -> String {
+    format!("{URL_IMPORTS}/{}", encode(job_id))
+}
+
+/// `/v1/resizes/{jobId}`
+pub fn resize_job(job_id: &str) -> String {
+    format!("{RESIZES}/{}", encode(job_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::Operation;
+
+    #[test]
+    fn test_routes_match_their_operations() {
+        let routes = [
+            (USER_ME.to_string(), Operation::GetMe),
+            (USER_PROFILE.to_string(), Operation::GetUserProfile),
+            (
+                USER_CAPABILITIES.to_string(),
+                Operation::GetUserCapabilities,
+            ),
+            (asset("A1"), Operation::GetAsset),
+            (ASSET_UPLOADS.to_string(), Operation::CreateAssetUploadJob),
+            (asset_upload_job("J1"), Operation::GetAssetUploadJob),
+            (
+                URL_ASSET_UPLOADS.to_string(),
+                Operation::CreateUrlAssetUploadJob,
+            ),
+            (url_asset_upload_job("J1"), Operation::GetUrlAssetUploadJob),
+            (AUTOFILLS.to_string(), Operation::CreateAutofillJob),
+            (autofill_job("J1"), Operation::GetAutofillJob),
+            (BRAND_TEMPLATES.to_string(), Operation::ListBrandTemplates),
+            (brand_template("B1"), Operation::GetBrandTemplate),
+            (
+                brand_template_dataset("B1"),
+                Operation::GetBrandTemplateDataset,
+            ),
+            (comment_threads("D1"), Operation::CreateThread),
+            (comment_thread("D1", "T1"), Operation::GetThread),
+            (comment_replies("D1", "T1"), Operation::ListReplies),
+            (comment_reply("D1", "T1", "R1"), Operation::GetReply),
+            (DESIGNS.to_string(), Operation::CreateDesign),
+            (design("D1"), Operation::GetDesign),
+            (design_pages("D1"), Operation::GetDesignPages),
+            (design_export_formats("D1"), Operation::GetExportFormats),
+            (EXPORTS.to_string(), Operation::CreateExportJob),
+            (export_job("E1"), Operation::GetExportJob),
+            (FOLDERS.to_string(), Operation::CreateFolder),
+            (folder("F1"), Operation::GetFolder),
+            (folder_items("F1"), Operation::ListFolderItems),
+            (FOLDERS_MOVE.to_string(), Operation::MoveFolderItem),
+            (IMPORTS.to_string(), Operation::CreateImportJob),
+            (import_job("J1"), Operation::GetImportJob),
+            (URL_IMPORTS.to_string(), Operation::CreateUrlImportJob),
+            (url_import_job("J1"), Operation::GetUrlImportJob),
+            (RESIZES.to_string(), Operation::CreateResizeJob),
+            (resize_job("J1"), Operation::GetResizeJob),
+        ];
+
+        for (path, operation) in routes {
+            assert_eq!(
+                Operation::matching(&operation.method(), &path),
+                Some(operation),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ids_are_percent_encoded() {
+        assert_eq!(design("a/b?c"), "/v1/designs/a%2Fb%3Fc");
+        assert_eq!(
+            comment_reply("D 1", "T#1", "R1"),
+            "/v1/designs/D%201/comments/T%231/replies/R1"
+        );
+    }
+}
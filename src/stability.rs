@@ -0,0 +1,113 @@
+//! Stability tiers of the public API.
+//!
+//! Every endpoint API reports how settled it is through
+//! [`ApiStability::stability`]:
+//!
+//! | Tier | Meaning |
+//! |------|---------|
+//! | [`Stable`](Stability::Stable) | Follows semver; breaking changes only in a new major version |
+//! | [`Preview`](Stability::Preview) | Wraps a Canva preview API; may change when Canva changes it |
+//! | [`Experimental`](Stability::Experimental) | A crate-level design still being tried out; may change in any release |
+//!
+//! Preview and experimental subsystems are compiled only with the
+//! `experimental` feature, which is on by default. They are also
+//! re-exported from [`experimental`](crate::experimental). To make sure a
+//! project only depends on stable APIs, turn off default features in CI:
+//!
+//! ```toml
+//! [dependencies]
+//! canva-connect = { version = "0.1", default-features = false, features = ["rustls-tls"] }
+//! ```
+//!
+//! Any use of a preview or experimental API then fails to compile.
+
+use std::fmt;
+
+/// How settled an API is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stability {
+    /// May change in any release
+    Experimental,
+    /// Wraps a Canva preview API and may change with it
+    Preview,
+    /// Follows semver
+    Stable,
+}
+
+impl Stability {
+    /// Whether the API follows semver
+    pub fn is_stable(self) -> bool {
+        self == Self::Stable
+    }
+}
+
+impl fmt::Display for Stability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Experimental => "experimental",
+            Self::Preview => "preview",
+            Self::Stable => "stable",
+        })
+    }
+}
+
+/// Reports the stability tier of an endpoint API
+///
+/// ```rust
+/// use canva_connect::{Client, auth::AccessToken, stability::{ApiStability, Stability}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("token"))?;
+/// assert_eq!(client.assets().stability(), Stability::Stable);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ApiStability {
+    /// Stability tier of every operation on this API
+    const STABILITY: Stability;
+
+    /// Stability tier of every operation on this API
+    fn stability(&self) -> Stability {
+        Self::STABILITY
+    }
+}
+
+macro_rules! impl_stability {
+    ($($api:ty => $tier:ident),* $(,)?) => {
+        $(
+            impl ApiStability for $api {
+                const STABILITY: Stability = Stability::$tier;
+            }
+        )*
+    };
+}
+
+impl_stability! {
+    crate::endpoints::AssetsApi => Stable,
+    crate::endpoints::AutofillApi => Stable,
+    crate::endpoints::BrandTemplatesApi => Stable,
+    crate::endpoints::DesignsApi => Stable,
+    crate::endpoints::ExportsApi => Stable,
+    crate::endpoints::FoldersApi => Stable,
+    crate::endpoints::UserApi => Stable,
+}
+
+#[cfg(feature = "experimental")]
+impl_stability! {
+    crate::endpoints::CommentsApi => Preview,
+    crate::endpoints::DesignImportApi => Preview,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiers_are_ordered_by_stability() {
+        assert!(Stability::Stable > Stability::Preview);
+        assert!(Stability::Preview > Stability::Experimental);
+        assert!(Stability::Stable.is_stable());
+        assert!(!Stability::Preview.is_stable());
+        assert_eq!(Stability::Preview.to_string(), "preview");
+    }
+}
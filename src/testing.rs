@@ -0,0 +1,387 @@
+//! Test doubles for code that uses the Canva Connect client.
+//!
+//! Requires the `test-util` feature. [`MockTransport`] replaces the network:
+//! plug it into a [`Client`](crate::Client) with
+//! [`ClientBuilder::transport`](crate::ClientBuilder::transport), register
+//! canned responses per method and path, and inspect the requests the client
+//! made afterwards.
+//!
+//! [`MockCanvaServer`] goes one step further and runs the API on a local
+//! HTTP server, answering every operation with realistic [`fixtures`] until
+//! told otherwise. Point a client at [`MockCanvaServer::url`] to test code
+//! end to end, downloads included.
+//!
+//! The server is built on `mockito` rather than `wiremock`: the crate's own
+//! tests already use `mockito`, so enabling `test-util` does not add a second
+//! mock server library. It listens on a plain local URL, so code under test
+//! needs nothing beyond [`MockCanvaServer::url`] either way.
+//!
+//! To test against real responses without a network, record them once with
+//! a [`RecordingTransport`] and replay them with a [`ReplayTransport`]; see
+//! the [`cassette`] module.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::testing::{MockResponse, MockTransport};
+//! use reqwest::Method;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let transport = MockTransport::new();
+//! transport.on(
+//!     Method::GET,
+//!     "/rest/v1/users/me",
+//!     MockResponse::json(200, &serde_json::json!({
+//!         "team_user": { "user_id": "u1", "team_id": "t1" }
+//!     })),
+//! );
+//!
+//! let client = Client::builder(AccessToken::new("test-token"))
+//!     .transport(transport.clone())
+//!     .build()?;
+//! let me = client.user().get_me().await?;
+//! assert_eq!(me.user_id, "u1");
+//!
+//! let requests = transport.requests();
+//! assert_eq!(requests[0].path, "/rest/v1/users/me");
+//! assert_eq!(requests[0].header("authorization"), Some("Bearer test-token"));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::HttpTransport;
+use crate::error::Result;
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub mod cassette;
+pub mod fixtures;
+pub mod server;
+pub use cassette::{Cassette, CassetteTransport, Interaction, RecordingTransport, ReplayTransport};
+pub use server::{MockCanvaServer, TEST_TOKEN};
+
+/// A canned HTTP response served by [`MockTransport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// A response with the given status and an empty body
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// A response with the given status and a JSON body
+    pub fn json(status: u16, body: &impl serde::Serialize) -> Self {
+        Self::new(status)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    /// A Canva-style error response, e.g. `MockResponse::error(404, "not_found", "No design")`
+    pub fn error(status: u16, code: &str, message: &str) -> Self {
+        Self::json(
+            status,
+            &serde_json::json!({ "code": code, "message": message }),
+        )
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the response body
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    fn into_response(self) -> Result<reqwest::Response> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body)
+            .map_err(|e| crate::error::Error::Generic(format!("Invalid mock response: {e}")))?;
+        Ok(response.into())
+    }
+}
+
+/// A request captured by [`MockTransport`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Full request URL
+    pub url: String,
+    /// URL path, without the query string
+    pub path: String,
+    /// URL query string, if any
+    pub query: Option<String>,
+    /// Request headers, including `Authorization`
+    pub headers: HeaderMap,
+    /// Request body; empty for requests without one
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Value of a header, if present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// Parse the body as JSON
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    method: Method,
+    path: String,
+    responses: VecDeque<MockResponse>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// An [`HttpTransport`] that serves canned responses and records requests
+///
+/// Responses registered for the same method and path are served in order,
+/// and the last one keeps being served once the others are used up.
+/// Requests with no matching route get a `404` Canva error response.
+/// Clones share their routes and recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// Create a transport with no routes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `response` for requests with `method` to `path`
+    ///
+    /// `path` is the full URL path as listed in the Canva API reference,
+    /// e.g. `/rest/v1/users/me`. It matches the path and query string if it
+    /// contains a `?`, and the path alone otherwise.
+    pub fn on(&self, method: Method, path: &str, response: MockResponse) -> &Self {
+        let mut state = self.lock();
+        match state
+            .routes
+            .iter_mut()
+            .find(|route| route.method == method && route.path == path)
+        {
+            Some(route) => route.responses.push_back(response),
+            None => state.routes.push(MockRoute {
+                method,
+                path: path.to_string(),
+                responses: VecDeque::from([response]),
+            }),
+        }
+        self
+    }
+
+    /// All requests received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Requests received for `method` and `path`
+    pub fn requests_to(&self, method: Method, path: &str) -> Vec<RecordedRequest> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|request| request.method == method && request.path == path)
+            .cloned()
+            .collect()
+    }
+
+    /// Forget all recorded requests, keeping the routes
+    pub fn clear_requests(&self) {
+        self.lock().requests.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for MockTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let url = request.url();
+        let recorded = RecordedRequest {
+            method: request.method().clone(),
+            url: url.to_string(),
+            path: url.path().to_string(),
+            query: url.query().map(str::to_string),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec)
+                .unwrap_or_default(),
+        };
+        let path_and_query = match &recorded.query {
+            Some(query) => format!("{}?{query}", recorded.path),
+            None => recorded.path.clone(),
+        };
+
+        let mut state = self.lock();
+        // A route with a query string is more specific than one without
+        let route_index = [&path_and_query, &recorded.path]
+            .into_iter()
+            .find_map(|path| {
+                state
+                    .routes
+                    .iter()
+                    .position(|route| route.method == recorded.method && &route.path == path)
+            });
+        let response = route_index
+            .map(|index| &mut state.routes[index])
+            .and_then(|route| {
+                if route.responses.len() > 1 {
+                    route.responses.pop_front()
+                } else {
+                    route.responses.front().cloned()
+                }
+            })
+            .unwrap_or_else(|| {
+                MockResponse::error(
+                    404,
+                    "not_found",
+                    &format!("No mock response for {} {path_and_query}", recorded.method),
+                )
+            });
+        state.requests.push(recorded);
+        drop(state);
+
+        response.into_response()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::{auth::AccessToken, error::Error, Client};
+
+    fn client(transport: &MockTransport) -> Client {
+        Client::builder(AccessToken::new("test-token"))
+            .transport(transport.clone())
+            .build()
+            .expect("Failed to create client")
+    }
+
+    #[tokio::test]
+    async fn test_serves_responses_in_order_then_repeats_last() {
+        let transport = MockTransport::new();
+        transport
+            .on(
+                Method::GET,
+                "/rest/v1/thing",
+                MockResponse::new(200).with_body("one"),
+            )
+            .on(
+                Method::GET,
+                "/rest/v1/thing",
+                MockResponse::new(200).with_body("two"),
+            );
+        let client = client(&transport);
+
+        for expected in ["one", "two", "two"] {
+            let body = client
+                .get("/v1/thing")
+                .await
+                .expect("request")
+                .text()
+                .await
+                .expect("body");
+            assert_eq!(body, expected);
+        }
+        assert_eq!(
+            transport.requests_to(Method::GET, "/rest/v1/thing").len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_records_request_details() {
+        let transport = MockTransport::new();
+        transport.on(
+            Method::POST,
+            "/rest/v1/folders",
+            MockResponse::json(200, &serde_json::json!({ "ok": true })),
+        );
+        let client = client(&transport);
+
+        client
+            .post("/v1/folders", &serde_json::json!({ "name": "Reports" }))
+            .await
+            .expect("request");
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.header("authorization"), Some("Bearer test-token"));
+        let body: serde_json::Value = request.json().expect("JSON body");
+        assert_eq!(body["name"], "Reports");
+    }
+
+    #[tokio::test]
+    async fn test_matches_query_when_route_has_one() {
+        let transport = MockTransport::new();
+        transport
+            .on(
+                Method::GET,
+                "/rest/v1/designs?query=cats",
+                MockResponse::new(200).with_body("cats"),
+            )
+            .on(
+                Method::GET,
+                "/rest/v1/designs",
+                MockResponse::new(200).with_body("all"),
+            );
+        let client = client(&transport);
+
+        let cats = client.get("/v1/designs?query=cats").await.expect("request");
+        assert_eq!(cats.text().await.expect("body"), "cats");
+        let all = client.get("/v1/designs?query=dogs").await.expect("request");
+        assert_eq!(all.text().await.expect("body"), "all");
+        assert_eq!(transport.requests()[1].query.as_deref(), Some("query=dogs"));
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_request_is_not_found() {
+        let transport = MockTransport::new();
+        let client = client(&transport);
+
+        let result = client.get("/v1/missing").await;
+        assert!(
+            matches!(result, Err(Error::Api { ref message, .. }) if message.contains("GET /rest/v1/missing"))
+        );
+        assert_eq!(transport.requests().len(), 1);
+    }
+}
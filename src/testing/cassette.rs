@@ -0,0 +1,510 @@
+//! Recording real API traffic and replaying it without a network.
+//!
+//! A [`Cassette`] is a JSON file of request and response pairs. Point a
+//! client at the real API through a [`RecordingTransport`] once to fill it,
+//! commit the file, then run the same code through a [`ReplayTransport`] in
+//! CI. [`CassetteTransport::from_env`] picks between the two based on the
+//! [`RECORD_ENV`] environment variable, so one test serves both purposes:
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::testing::CassetteTransport;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // CANVA_RECORD=1 records against the real API; otherwise replays
+//! let transport = CassetteTransport::from_env("tests/cassettes/me.json")?;
+//! let token = std::env::var("CANVA_ACCESS_TOKEN").unwrap_or_default();
+//! let client = Client::builder(AccessToken::new(token))
+//!     .transport(transport)
+//!     .build()?;
+//! let me = client.user().get_me().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Recordings are sanitized before they are written: credential headers
+//! such as `Authorization` and `Set-Cookie` are dropped, and token fields
+//! (`access_token`, `refresh_token`, `client_secret`, ...) in URLs and
+//! bodies are replaced with [`REDACTED`].
+//! Pre-signed URLs on `*.canva.com` hosts other than the API, such as export
+//! downloads and thumbnails, have their whole query string replaced, both in
+//! response bodies and in the paths of the requests that fetch them.
+//!
+//! Replay matches requests on method, path and query string, ignoring the
+//! host, so a cassette recorded against the real API replays for any base
+//! URL. Responses recorded for the same request are served in order, and
+//! the last one keeps being served once the others are used up.
+
+use crate::client::HttpTransport;
+use crate::debug_log::{is_listed, redact_form, redact_json, redact_url, REDACTED, SECRET_HEADERS};
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Environment variable that makes [`CassetteTransport::from_env`] record
+///
+/// Any value other than empty or `0` turns recording on.
+pub const RECORD_ENV: &str = "CANVA_RECORD";
+
+/// Body fields replaced with [`REDACTED`](crate::debug_log::REDACTED) before recording
+///
+/// `code` is left alone because Canva error responses use it for the error
+/// code.
+const SECRET_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "code_verifier",
+    "client_secret",
+    "password",
+];
+
+/// One recorded request and the response it got
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// HTTP method
+    pub method: String,
+    /// URL path and query string, with token-like parameters redacted
+    pub path: String,
+    /// Request body, redacted; kept for reference and not used for matching
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+    /// Response status code
+    pub status: u16,
+    /// Response headers, without credentials
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Response body, as text or, if `base64` is set, base64
+    #[serde(default)]
+    pub body: String,
+    /// Whether `body` is base64-encoded binary data
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub base64: bool,
+}
+
+impl Interaction {
+    fn response(&self) -> Result<reqwest::Response> {
+        let body = if self.base64 {
+            STANDARD
+                .decode(&self.body)
+                .map_err(|e| Error::Generic(format!("Invalid base64 body in cassette: {e}")))?
+        } else {
+            self.body.clone().into_bytes()
+        };
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(body)
+            .map_err(|e| Error::Generic(format!("Invalid response in cassette: {e}")))?;
+        Ok(response.into())
+    }
+}
+
+/// Recorded interactions, stored as a JSON file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in the order they were recorded
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Read a cassette file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read(path)
+            .map_err(|e| Error::Config(format!("Cannot read cassette {}: {e}", path.display())))?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Write the cassette, creating parent directories as needed
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// An [`HttpTransport`] that sends requests on and records the exchanges
+///
+/// The cassette file is rewritten after every response, so a test that
+/// fails halfway still leaves what it recorded. Clones share the cassette.
+#[derive(Debug, Clone)]
+pub struct RecordingTransport {
+    inner: Arc<dyn HttpTransport>,
+    path: PathBuf,
+    cassette: Arc<Mutex<Cassette>>,
+}
+
+impl RecordingTransport {
+    /// Record to `path`, sending requests with a default [`reqwest::Client`]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self::with_inner(path, reqwest::Client::new())
+    }
+
+    /// Record to `path`, sending requests with `inner`
+    pub fn with_inner(path: impl Into<PathBuf>, inner: impl HttpTransport + 'static) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            path: path.into(),
+            cassette: Arc::default(),
+        }
+    }
+
+    /// The interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cassette> {
+        self.cassette
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for RecordingTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let method = request.method().to_string();
+        let path = path_and_query(request.url());
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .filter(|body| !body.is_empty())
+            .map(|body| sanitize_body(body).0);
+
+        let response = self.inner.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await?;
+
+        let (body, base64) = sanitize_body(&bytes);
+        let interaction = Interaction {
+            method,
+            path,
+            request_body,
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                .filter(|(name, _)| !SECRET_HEADERS.contains(&name.as_str()))
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body,
+            base64,
+        };
+        {
+            let mut cassette = self.lock();
+            cassette.interactions.push(interaction);
+            cassette.save(&self.path)?;
+        }
+
+        let mut rebuilt = http::Response::builder().status(status);
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers;
+        }
+        let rebuilt = rebuilt
+            .body(bytes.to_vec())
+            .map_err(|e| Error::Generic(format!("Cannot rebuild recorded response: {e}")))?;
+        Ok(rebuilt.into())
+    }
+}
+
+/// An [`HttpTransport`] that answers from a [`Cassette`] without a network
+///
+/// Requests that were never recorded fail with [`Error::Generic`] naming
+/// the method and path. Clones share which interactions have been served.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    state: Arc<Mutex<Vec<(Interaction, bool)>>>,
+}
+
+impl ReplayTransport {
+    /// Replay the cassette at `path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+
+    /// Replay `cassette`
+    pub fn new(cassette: Cassette) -> Self {
+        let state = cassette
+            .interactions
+            .into_iter()
+            .map(|interaction| (interaction, false))
+            .collect();
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Recorded interactions that have not been served yet
+    pub fn unused(&self) -> Vec<Interaction> {
+        self.lock()
+            .iter()
+            .filter(|(_, used)| !used)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<(Interaction, bool)>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for ReplayTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let method = request.method().as_str();
+        let path = path_and_query(request.url());
+        let mut state = self.lock();
+        let matches =
+            |interaction: &Interaction| interaction.method == method && interaction.path == path;
+        let index = state
+            .iter()
+            .position(|(interaction, used)| !used && matches(interaction))
+            .or_else(|| {
+                state
+                    .iter()
+                    .rposition(|(interaction, _)| matches(interaction))
+            })
+            .ok_or_else(|| {
+                Error::Generic(format!(
+                    "No recorded response for {method} {path} in cassette"
+                ))
+            })?;
+        state[index].1 = true;
+        state[index].0.response()
+    }
+}
+
+/// Records or replays depending on the environment
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub enum CassetteTransport {
+    /// Sending requests and recording them
+    Record(RecordingTransport),
+    /// Answering from a recording
+    Replay(ReplayTransport),
+}
+
+impl CassetteTransport {
+    /// Record to `path` if [`RECORD_ENV`] is set, otherwise replay from it
+    pub fn from_env(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let record = std::env::var(RECORD_ENV)
+            .map(|value| !value.is_empty() && value != "0")
+            .unwrap_or(false);
+        if record {
+            Ok(Self::Record(RecordingTransport::new(path)))
+        } else {
+            Ok(Self::Replay(ReplayTransport::from_file(path)?))
+        }
+    }
+
+    /// Whether requests go to the network
+    pub fn is_recording(&self) -> bool {
+        matches!(self, Self::Record(_))
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for CassetteTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        match self {
+            Self::Record(transport) => transport.execute(request).await,
+            Self::Replay(transport) => transport.execute(request).await,
+        }
+    }
+}
+
+/// Path and query of `url`, with token-like query parameters redacted
+fn path_and_query(url: &url::Url) -> String {
+    let mut url = url.clone();
+    redact_signed_query(&mut url);
+    let redacted = redact_url(&url);
+    let Ok(redacted) = url::Url::parse(&redacted) else {
+        return url.path().to_string();
+    };
+    match redacted.query() {
+        Some(query) => format!("{}?{query}", redacted.path()),
+        None => redacted.path().to_string(),
+    }
+}
+
+/// A body as cassette text, and whether it had to be base64-encoded
+fn sanitize_body(body: &[u8]) -> (String, bool) {
+    if let Ok(mut json) = serde_json::from_slice::<serde_json::Value>(body) {
+        redact_json(&mut json, SECRET_FIELDS);
+        redact_signed_urls(&mut json);
+        return (json.to_string(), false);
+    }
+    match std::str::from_utf8(body) {
+        Ok(text) => (
+            redact_form(text, |key| is_listed(key, SECRET_FIELDS)),
+            false,
+        ),
+        Err(_) => (STANDARD.encode(body), true),
+    }
+}
+
+/// Replace the query of every pre-signed Canva URL in a JSON body
+fn redact_signed_urls(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(text) => {
+            if let Ok(mut url) = url::Url::parse(text) {
+                if redact_signed_query(&mut url) {
+                    *text = url.to_string();
+                }
+            }
+        }
+        serde_json::Value::Object(map) => map.values_mut().for_each(redact_signed_urls),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_signed_urls),
+        _ => {}
+    }
+}
+
+/// Replace the query string of a `*.canva.com` URL outside the API, whose
+/// query holds the signature; returns whether anything was replaced
+///
+/// API URLs keep their query, since replay matches requests on it.
+fn redact_signed_query(url: &mut url::Url) -> bool {
+    let signed = url.host_str().is_some_and(|host| {
+        let canva = host == "canva.com" || host.ends_with(".canva.com");
+        let api = host == "api.canva.com" || host.ends_with(".api.canva.com");
+        canva && !api
+    });
+    if !signed || url.query().is_none() {
+        return false;
+    }
+    url.set_query(Some(REDACTED));
+    true
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockResponse, MockTransport};
+    use crate::{auth::AccessToken, Client};
+    use reqwest::Method;
+
+    fn cassette_path() -> PathBuf {
+        std::env::temp_dir().join(format!("canva-cassette-{}.json", uuid::Uuid::new_v4()))
+    }
+
+    fn client(transport: impl HttpTransport + 'static) -> Client {
+        Client::builder(AccessToken::new("secret-token"))
+            .transport(transport)
+            .build()
+            .expect("Failed to create client")
+    }
+
+    #[tokio::test]
+    async fn test_records_sanitized_and_replays() {
+        let upstream = MockTransport::new();
+        upstream.on(
+            Method::GET,
+            "/rest/v1/users/me",
+            MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "team_user": { "user_id": "u1", "team_id": "t1" },
+                    "access_token": "leaked"
+                }),
+            )
+            .with_header("set-cookie", "session=abc"),
+        );
+        upstream.on(
+            Method::GET,
+            "/rest/v1/designs/missing",
+            MockResponse::error(404, "not_found", "Design not found"),
+        );
+        let path = cassette_path();
+
+        let recorder = RecordingTransport::with_inner(&path, upstream);
+        let recording = client(recorder.clone());
+        let me = recording.user().get_me().await.expect("recorded call");
+        assert_eq!(me.user_id, "u1");
+        assert!(recording.designs().get("missing").await.is_err());
+
+        let saved = std::fs::read_to_string(&path).expect("cassette written");
+        assert!(!saved.contains("secret-token"));
+        assert!(!saved.contains("leaked"));
+        assert!(!saved.contains("session=abc"));
+        assert_eq!(recorder.cassette().interactions.len(), 2);
+
+        let replay = ReplayTransport::from_file(&path).expect("cassette loads");
+        let replaying = Client::builder(AccessToken::new("other-token"))
+            .base_url("http://localhost:1/rest")
+            .transport(replay.clone())
+            .build()
+            .expect("Failed to create client");
+        let me = replaying.user().get_me().await.expect("replayed call");
+        assert_eq!(me.user_id, "u1");
+        let missing = replaying.designs().get("missing").await;
+        assert!(matches!(missing, Err(Error::Api { .. })));
+        assert!(replay.unused().is_empty());
+
+        let unknown = replaying.folders().get_folder("nope").await;
+        assert!(
+            matches!(unknown, Err(Error::Generic(message)) if message.contains("/rest/v1/folders/nope"))
+        );
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_sanitize_body() {
+        assert_eq!(
+            sanitize_body(b"grant_type=refresh_token&refresh_token=abc"),
+            (
+                "grant_type=refresh_token&refresh_token=[REDACTED]".to_string(),
+                false
+            )
+        );
+        assert_eq!(
+            sanitize_body(br#"{"code":"not_found"}"#),
+            (r#"{"code":"not_found"}"#.to_string(), false)
+        );
+        assert_eq!(sanitize_body(&[0xff, 0x00]), ("/wA=".to_string(), true));
+    }
+
+    #[test]
+    fn test_redacts_signed_canva_urls() {
+        let body = br#"{"job":{"urls":["https://export-download.canva.com/a/1.png?X-Amz-Signature=s3cr3t"]},"next":"https://api.canva.com/rest/v1/designs?continuation=c1","other":"https://example.com/x?sig=kept"}"#;
+        let (sanitized, _) = sanitize_body(body);
+        assert!(!sanitized.contains("s3cr3t"));
+        assert!(sanitized.contains("https://export-download.canva.com/a/1.png?[REDACTED]"));
+        assert!(sanitized.contains("continuation=c1"));
+        assert!(sanitized.contains("sig=kept"));
+
+        let download =
+            url::Url::parse("https://export-download.canva.com/a/1.png?X-Amz-Signature=s3cr3t")
+                .expect("valid URL");
+        let replayed = url::Url::parse("https://export-download.canva.com/a/1.png?[REDACTED]")
+            .expect("valid URL");
+        assert_eq!(path_and_query(&download), "/a/1.png?[REDACTED]");
+        assert_eq!(path_and_query(&replayed), path_and_query(&download));
+    }
+
+    #[test]
+    fn test_path_and_query_redacts_tokens() {
+        let url = url::Url::parse("https://api.canva.com/rest/v1/x?limit=2&access_token=abc")
+            .expect("valid URL");
+        assert_eq!(
+            path_and_query(&url),
+            "/rest/v1/x?limit=2&access_token=%5BREDACTED%5D"
+        );
+    }
+}
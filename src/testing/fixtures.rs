@@ -0,0 +1,310 @@
+//! Realistic response bodies for the Canva Connect API.
+//!
+//! [`MockCanvaServer`](super::MockCanvaServer) serves these by default. They
+//! are also handy on their own, e.g. as the starting point for a
+//! [`MockResponse`](super::MockResponse) that tweaks a single field.
+//!
+//! Every function returns the resource itself, not the envelope the endpoint
+//! wraps it in; [`response_body`] builds the full body for an operation.
+//! Jobs are reported as already finished so that `wait_for_*` helpers return
+//! on the first poll.
+
+use crate::operations::Operation;
+use serde_json::{json, Value};
+
+/// `created_at` used by every fixture (2023-11-14T22:13:20Z)
+pub const CREATED_AT: i64 = 1_700_000_000;
+/// `updated_at` used by every fixture, one day after [`CREATED_AT`]
+pub const UPDATED_AT: i64 = CREATED_AT + 86_400;
+
+/// User ID of the authenticated fixture user
+pub const USER_ID: &str = "oUnPjZ2k2yuhftbWF7873o";
+/// Team ID of the authenticated fixture user
+pub const TEAM_ID: &str = "oBpVhLW22VrqtwKgaayRbP";
+
+/// The authenticated user, as returned by `GET /v1/users/me`
+pub fn team_user() -> Value {
+    json!({ "user_id": USER_ID, "team_id": TEAM_ID })
+}
+
+/// The authenticated user's profile
+pub fn user_profile() -> Value {
+    json!({ "display_name": "Jane Doe" })
+}
+
+/// Capabilities of the authenticated user
+pub fn capabilities() -> Value {
+    json!(["autofill", "brand_template", "resize"])
+}
+
+/// A thumbnail hosted on the mock server
+pub fn thumbnail(base_url: &str, name: &str) -> Value {
+    json!({
+        "width": 595,
+        "height": 335,
+        "url": format!("{base_url}/downloads/thumbnails/{name}.png"),
+    })
+}
+
+/// An image asset
+pub fn asset(base_url: &str, asset_id: &str) -> Value {
+    json!({
+        "type": "image",
+        "id": asset_id,
+        "name": "My Awesome Upload",
+        "tags": ["image", "holiday", "best day ever"],
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "thumbnail": thumbnail(base_url, asset_id),
+    })
+}
+
+/// A finished asset upload job
+pub fn asset_upload_job(base_url: &str, job_id: &str) -> Value {
+    json!({
+        "id": job_id,
+        "status": "success",
+        "asset": asset(base_url, "Msd59349ff"),
+    })
+}
+
+/// A design owned by the fixture user
+pub fn design(base_url: &str, design_id: &str) -> Value {
+    let mut design = design_summary(base_url, design_id);
+    design["owner"] = team_user();
+    design
+}
+
+/// A design as it appears in listings and job results
+pub fn design_summary(base_url: &str, design_id: &str) -> Value {
+    json!({
+        "id": design_id,
+        "title": "My summer holiday",
+        "thumbnail": thumbnail(base_url, design_id),
+        "urls": {
+            "edit_url": format!("https://www.canva.com/api/design/{design_id}/edit"),
+            "view_url": format!("https://www.canva.com/api/design/{design_id}/view"),
+        },
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "page_count": 3,
+    })
+}
+
+/// The pages of a three-page design
+pub fn design_pages(base_url: &str, design_id: &str) -> Value {
+    (1..=3)
+        .map(|index| {
+            json!({
+                "index": index,
+                "thumbnail": thumbnail(base_url, &format!("{design_id}-{index}")),
+            })
+        })
+        .collect()
+}
+
+/// A brand template
+pub fn brand_template(base_url: &str, brand_template_id: &str) -> Value {
+    json!({
+        "id": brand_template_id,
+        "title": "Advertisement Template",
+        "view_url": format!("https://www.canva.com/design/{brand_template_id}/view"),
+        "create_url": format!("https://www.canva.com/design/{brand_template_id}/remix"),
+        "thumbnail": thumbnail(base_url, brand_template_id),
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+    })
+}
+
+/// A brand template dataset with one field of each type
+pub fn brand_template_dataset() -> Value {
+    json!({
+        "cute_pet_image_of_the_day": { "type": "image" },
+        "cute_pet_witty_pet_says": { "type": "text" },
+        "cute_pet_sales_chart": { "type": "chart" },
+    })
+}
+
+/// A finished autofill job
+pub fn autofill_job(base_url: &str, job_id: &str) -> Value {
+    json!({
+        "id": job_id,
+        "status": "success",
+        "result": {
+            "type": "create_design",
+            "design": design(base_url, "DAFVztcvd9z"),
+        },
+    })
+}
+
+/// A comment thread on a design
+pub fn comment_thread(design_id: &str, thread_id: &str) -> Value {
+    json!({
+        "id": thread_id,
+        "design_id": design_id,
+        "thread_type": {
+            "type": "comment",
+            "content": {
+                "plaintext": "Great work!",
+                "markdown": "*Great work!*",
+            },
+            "mentions": {},
+        },
+        "author": { "id": USER_ID, "display_name": "Jane Doe" },
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+    })
+}
+
+/// A reply in a comment thread
+pub fn comment_reply(reply_id: &str) -> Value {
+    json!({
+        "id": reply_id,
+        "author": { "id": USER_ID, "display_name": "Jane Doe" },
+        "content": { "plaintext": "Thanks!" },
+        "mentions": {},
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+    })
+}
+
+/// A finished PDF export job with one download URL per page
+pub fn export_job(base_url: &str, export_id: &str) -> Value {
+    json!({
+        "id": export_id,
+        "status": "success",
+        "urls": [
+            format!("{base_url}/downloads/exports/{export_id}/1.pdf"),
+            format!("{base_url}/downloads/exports/{export_id}/2.pdf"),
+        ],
+    })
+}
+
+/// Every export format, all available
+pub fn export_formats() -> Value {
+    json!({
+        "pdf": {},
+        "jpg": {},
+        "png": {},
+        "svg": {},
+        "pptx": {},
+        "gif": {},
+        "mp4": {},
+    })
+}
+
+/// A folder
+pub fn folder(base_url: &str, folder_id: &str) -> Value {
+    json!({
+        "id": folder_id,
+        "name": "My awesome holiday",
+        "created_at": CREATED_AT,
+        "updated_at": UPDATED_AT,
+        "thumbnail": thumbnail(base_url, folder_id),
+    })
+}
+
+/// The contents of a folder: one subfolder, one design and one image
+pub fn folder_items(base_url: &str) -> Value {
+    json!([
+        { "type": "folder", "folder": folder(base_url, "FAF2lZtloor") },
+        { "type": "design", "design": design_summary(base_url, "DAFVztcvd9z") },
+        { "type": "image", "image": asset(base_url, "Msd59349ff") },
+    ])
+}
+
+/// A finished import job that produced one design
+pub fn import_job(base_url: &str, job_id: &str) -> Value {
+    json!({
+        "id": job_id,
+        "status": "success",
+        "result": { "designs": [design_summary(base_url, "DAGQm2AkzOk")] },
+    })
+}
+
+/// A finished resize job
+pub fn resize_job(base_url: &str, job_id: &str) -> Value {
+    json!({
+        "id": job_id,
+        "status": "success",
+        "result": { "design": design_summary(base_url, "DAGirp_1ZUA") },
+    })
+}
+
+/// The full response body [`MockCanvaServer`](super::MockCanvaServer) serves for `operation`
+///
+/// `path` is the request path, used to echo IDs back in the resource.
+/// Returns `None` for operations that respond with an empty body.
+pub fn response_body(operation: Operation, base_url: &str, path: &str) -> Option<Value> {
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    // Paths look like `v1/<collection>/<id>/<sub-collection>/<id>/...`
+    let id = |index: usize| segments.get(index).copied().unwrap_or("id");
+    let body = match operation {
+        Operation::GetMe => json!({ "team_user": team_user() }),
+        Operation::GetUserProfile => json!({ "profile": user_profile() }),
+        Operation::GetUserCapabilities => json!({ "capabilities": capabilities() }),
+        Operation::GetAsset | Operation::UpdateAsset => {
+            json!({ "asset": asset(base_url, id(2)) })
+        }
+        Operation::DeleteAsset | Operation::DeleteFolder | Operation::MoveFolderItem => {
+            return None
+        }
+        Operation::CreateAssetUploadJob | Operation::CreateUrlAssetUploadJob => {
+            json!({ "job": asset_upload_job(base_url, "e08861ae-3b29-45db-8dc1-1fe0bf7f1cc8") })
+        }
+        Operation::GetAssetUploadJob | Operation::GetUrlAssetUploadJob => {
+            json!({ "job": asset_upload_job(base_url, id(2)) })
+        }
+        Operation::CreateAutofillJob => {
+            json!({ "job": autofill_job(base_url, "450a76e7-f96f-43ae-9c37-0e1ce492ac72") })
+        }
+        Operation::GetAutofillJob => json!({ "job": autofill_job(base_url, id(2)) }),
+        Operation::ListBrandTemplates => json!({
+            "items": [
+                brand_template(base_url, "DEMzWSwy3BI"),
+                brand_template(base_url, "DEMzWSwy3BJ"),
+            ],
+        }),
+        Operation::GetBrandTemplate => {
+            json!({ "brand_template": brand_template(base_url, id(2)) })
+        }
+        Operation::GetBrandTemplateDataset => json!({ "dataset": brand_template_dataset() }),
+        Operation::CreateThread => json!({ "thread": comment_thread(id(2), "KeAbiEAjZEj") }),
+        Operation::GetThread => json!({ "thread": comment_thread(id(2), id(4)) }),
+        Operation::CreateReply => json!({ "reply": comment_reply("KeAZEAjijEb") }),
+        Operation::GetReply => json!({ "reply": comment_reply(id(6)) }),
+        Operation::ListReplies => json!({
+            "items": [comment_reply("KeAZEAjijEb"), comment_reply("KeAZEAjijEc")],
+        }),
+        Operation::ListDesigns => json!({
+            "items": [
+                design(base_url, "DAFVztcvd9z"),
+                design(base_url, "DAGQm2AkzOk"),
+            ],
+        }),
+        Operation::CreateDesign => json!({ "design": design(base_url, "DAFVztcvd9z") }),
+        Operation::GetDesign => json!({ "design": design(base_url, id(2)) }),
+        Operation::GetDesignPages => json!({ "items": design_pages(base_url, id(2)) }),
+        Operation::GetExportFormats => json!({ "formats": export_formats() }),
+        Operation::CreateExportJob => {
+            json!({ "job": export_job(base_url, "e08861ae-3b29-45db-8dc1-1fe0bf7f1cc9") })
+        }
+        Operation::GetExportJob => json!({ "job": export_job(base_url, id(2)) }),
+        Operation::CreateFolder => json!({ "folder": folder(base_url, "FAF2lZtloor") }),
+        Operation::GetFolder | Operation::UpdateFolder => {
+            json!({ "folder": folder(base_url, id(2)) })
+        }
+        Operation::ListFolderItems => json!({ "items": folder_items(base_url) }),
+        Operation::CreateImportJob | Operation::CreateUrlImportJob => {
+            json!({ "job": import_job(base_url, "e08861ae-3b29-45db-8dc1-1fe0bf7f1cca") })
+        }
+        Operation::GetImportJob | Operation::GetUrlImportJob => {
+            json!({ "job": import_job(base_url, id(2)) })
+        }
+        Operation::CreateResizeJob => {
+            json!({ "job": resize_job(base_url, "e08861ae-3b29-45db-8dc1-1fe0bf7f1ccb") })
+        }
+        Operation::GetResizeJob => json!({ "job": resize_job(base_url, id(2)) }),
+    };
+    Some(body)
+}
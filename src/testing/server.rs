@@ -0,0 +1,609 @@
+//! A local HTTP server that speaks the Canva Connect API.
+
+use super::{fixtures, MockResponse, RecordedRequest};
+use crate::auth::AccessToken;
+use crate::error::Result;
+use crate::operations::Operation;
+use crate::rate_limit::{ApiRateLimiter, EndpointRateLimits};
+use crate::{Client, ClientBuilder};
+use mockito::{Matcher, Mock, ServerGuard};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Method;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Access token that [`MockCanvaServer::client`] authenticates with
+pub const TEST_TOKEN: &str = "test-token";
+
+/// A mock Canva Connect API listening on localhost
+///
+/// Unlike [`MockTransport`](super::MockTransport), requests go over real
+/// HTTP, so the whole client stack is exercised: connection handling,
+/// retries, rate limiting and downloads from the URLs in responses.
+///
+/// Every [`Operation`] answers with a [`fixtures`] body as soon as the
+/// server starts. Export and thumbnail URLs point back at the server, under
+/// `/downloads/`, and serve a few placeholder bytes. Use
+/// [`on`](Self::on) to replace the response for one operation.
+///
+/// ## Examples
+///
+/// ```rust
+/// use canva_connect::operations::Operation;
+/// use canva_connect::testing::{MockCanvaServer, MockResponse};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut server = MockCanvaServer::start().await;
+/// server.on(
+///     Operation::GetDesign,
+///     MockResponse::error(404, "design_not_found", "Design not found"),
+/// );
+///
+/// let client = server.client()?;
+/// assert_eq!(client.user().get_me().await?.team_id, "oBpVhLW22VrqtwKgaayRbP");
+/// assert!(client.designs().get("DAF123").await.is_err());
+///
+/// server.assert_called(Operation::GetMe, 1);
+/// assert_eq!(server.requests_to(Operation::GetDesign)[0].path, "/v1/designs/DAF123");
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockCanvaServer {
+    server: ServerGuard,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    // Kept for the server's lifetime so the mocks stay registered
+    mocks: Vec<Mock>,
+}
+
+impl std::fmt::Debug for MockCanvaServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockCanvaServer")
+            .field("url", &self.server.url())
+            .field("mocks", &self.mocks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockCanvaServer {
+    /// Start a server with fixture responses for every operation
+    pub async fn start() -> Self {
+        let mut server = Self {
+            server: mockito::Server::new_async().await,
+            requests: Arc::default(),
+            mocks: Vec::new(),
+        };
+        for operation in Operation::ALL {
+            server.register_fixture(operation);
+        }
+        server.register_downloads();
+        server
+    }
+
+    /// Base URL to pass to [`Client::with_base_url`] or
+    /// [`ClientBuilder::base_url`](crate::ClientBuilder::base_url)
+    pub fn url(&self) -> String {
+        self.server.url()
+    }
+
+    /// A client builder pointed at this server, authenticated with [`TEST_TOKEN`]
+    ///
+    /// Client-side rate limiting is turned off so tests don't wait on
+    /// Canva's per-minute limits; set a limiter on the builder to test
+    /// throttling.
+    pub fn client_builder(&self) -> ClientBuilder {
+        Client::builder(AccessToken::new(TEST_TOKEN))
+            .base_url(self.url())
+            .rate_limiter(ApiRateLimiter::new(u32::MAX))
+            .endpoint_rate_limits(EndpointRateLimits::new())
+    }
+
+    /// A client built from [`client_builder`](Self::client_builder)
+    pub fn client(&self) -> Result<Client> {
+        self.client_builder().build()
+    }
+
+    /// Serve `response` for `operation` instead of the fixture
+    ///
+    /// The latest response registered for an operation wins.
+    pub fn on(&mut self, operation: Operation, response: MockResponse) -> &mut Self {
+        let MockResponse {
+            status,
+            headers,
+            body,
+        } = response;
+        let mut mock = self
+            .operation_mock(operation, usize::from(status))
+            .with_body(body);
+        for (name, value) in &headers {
+            mock = mock.with_header(name.as_str(), value);
+        }
+        self.mocks.push(mock.create());
+        self
+    }
+
+    /// All requests received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().clone()
+    }
+
+    /// Requests received for `operation`, oldest first
+    pub fn requests_to(&self, operation: Operation) -> Vec<RecordedRequest> {
+        self.lock()
+            .iter()
+            .filter(|request| {
+                let path_and_query = match &request.query {
+                    Some(query) => format!("{}?{query}", request.path),
+                    None => request.path.clone(),
+                };
+                Operation::matching(&request.method, &path_and_query) == Some(operation)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Forget all recorded requests, keeping the registered responses
+    pub fn clear_requests(&self) {
+        self.lock().clear();
+    }
+
+    /// Panic unless `operation` was called exactly `times` times
+    #[track_caller]
+    pub fn assert_called(&self, operation: Operation, times: usize) {
+        let calls = self.requests_to(operation).len();
+        assert_eq!(
+            calls,
+            times,
+            "expected {times} call(s) to {} {}, got {calls}; received: {:?}",
+            operation.method(),
+            operation.path(),
+            self.lock()
+                .iter()
+                .map(|request| format!("{} {}", request.method, request.path))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    /// Panic if `operation` was called at all
+    #[track_caller]
+    pub fn assert_not_called(&self, operation: Operation) {
+        self.assert_called(operation, 0);
+    }
+
+    fn register_fixture(&mut self, operation: Operation) {
+        let base_url = self.url();
+        let status = if fixtures::response_body(operation, &base_url, "").is_some() {
+            200
+        } else {
+            204
+        };
+        let mock = self
+            .operation_mock(operation, status)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |request| {
+                fixtures::response_body(operation, &base_url, request.path())
+                    .map(|body| body.to_string().into_bytes())
+                    .unwrap_or_default()
+            })
+            .create();
+        self.mocks.push(mock);
+    }
+
+    fn register_downloads(&mut self) {
+        let requests = Arc::clone(&self.requests);
+        let base_url = self.url();
+        let mock = self
+            .server
+            .mock("GET", Matcher::Regex("^/downloads/".to_string()))
+            .with_status_code_from_request(move |request| {
+                record(&requests, &base_url, request);
+                200
+            })
+            .with_header("content-type", "application/octet-stream")
+            .with_body_from_request(|request| format!("mock file {}", request.path()).into_bytes())
+            .expect_at_least(0)
+            .create();
+        self.mocks.push(mock);
+    }
+
+    /// A mock for `operation` that records requests and answers with `status`
+    ///
+    /// `expect_at_least(0)` stops mockito preferring an older, never-hit mock
+    /// over a newer one, which is what lets [`on`](Self::on) override.
+    fn operation_mock(&mut self, operation: Operation, status: usize) -> Mock {
+        let requests = Arc::clone(&self.requests);
+        let base_url = self.url();
+        self.server
+            .mock(operation.method().as_str(), Matcher::Any)
+            .match_request(move |request| {
+                Method::from_bytes(request.method().as_bytes())
+                    .ok()
+                    .and_then(|method| Operation::matching(&method, request.path_and_query()))
+                    == Some(operation)
+            })
+            .with_status_code_from_request(move |request| {
+                record(&requests, &base_url, request);
+                status
+            })
+            .expect_at_least(0)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<RecordedRequest>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Record `request`; called once per request, from the status callback
+fn record(requests: &Mutex<Vec<RecordedRequest>>, base_url: &str, request: &mockito::Request) {
+    let mut headers = HeaderMap::new();
+    for (name, value) in request.headers() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_str().as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    let path_and_query = request.path_and_query();
+    let recorded = RecordedRequest {
+        method: Method::from_bytes(request.method().as_bytes()).unwrap_or(Method::GET),
+        url: format!("{base_url}{path_and_query}"),
+        path: request.path().to_string(),
+        query: path_and_query
+            .split_once('?')
+            .map(|(_, query)| query.to_string()),
+        headers,
+        body: request.body().cloned().unwrap_or_default(),
+    };
+    requests
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(recorded);
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::endpoints::assets::{
+        AssetUploadMetadata, CreateUrlAssetUploadJobRequest, UpdateAssetRequest,
+    };
+    use crate::endpoints::brand_templates::ListBrandTemplatesRequest;
+    #[cfg(feature = "experimental")]
+    use crate::endpoints::comments::{CreateReplyRequest, CreateThreadRequest, ListRepliesRequest};
+    #[cfg(feature = "experimental")]
+    use crate::endpoints::designs::GetDesignPagesRequest;
+    use crate::endpoints::designs::ListDesignsRequest;
+    use crate::endpoints::exports::CreateDesignExportJobRequest;
+    use crate::endpoints::folders::{
+        CreateFolderRequest, ListFolderItemsRequest, MoveFolderItemRequest, UpdateFolderRequest,
+    };
+    #[cfg(feature = "experimental")]
+    use crate::endpoints::imports::{CreateUrlImportJobRequest, DesignImportMetadata};
+    use crate::error::Error;
+    use crate::models::{
+        CreateDesignRequest, CreateDesignResizeJobRequest, ExportFormat, FolderItemSummary,
+        JobStatus, PresetDesignTypeName,
+    };
+    use std::collections::HashMap;
+
+    // Exercises every operation so a fixture that stops deserializing fails here
+    #[tokio::test]
+    async fn test_fixtures_cover_every_operation() {
+        let server = MockCanvaServer::start().await;
+        let client = server.client().expect("client");
+
+        let me = client.user().get_me().await.expect("get_me");
+        assert_eq!(me.user_id, fixtures::USER_ID);
+        client.user().get_profile().await.expect("get_profile");
+        assert_eq!(
+            client
+                .user()
+                .get_capabilities()
+                .await
+                .expect("get_capabilities")
+                .len(),
+            3
+        );
+
+        let assets = client.assets();
+        assert_eq!(
+            assets.get("Masset1").await.expect("get asset").id,
+            "Masset1"
+        );
+        assets
+            .update(
+                "Masset1",
+                UpdateAssetRequest {
+                    name: Some("Renamed".to_string()),
+                    tags: None,
+                },
+            )
+            .await
+            .expect("update asset");
+        assets.delete("Masset1").await.expect("delete asset");
+        let job = assets
+            .create_upload_job(
+                b"\x89PNG\r\n\x1a\n".to_vec(),
+                AssetUploadMetadata::new("a.png", vec![]),
+            )
+            .await
+            .expect("create upload job");
+        assets
+            .get_upload_job(&job.id)
+            .await
+            .expect("get upload job");
+        let job = assets
+            .create_url_upload_job(CreateUrlAssetUploadJobRequest {
+                url: "https://example.com/a.png".to_string(),
+                name: "a.png".to_string(),
+            })
+            .await
+            .expect("create url upload job");
+        assets
+            .wait_for_url_upload_job(&job.id)
+            .await
+            .expect("get url upload job");
+
+        let job = client
+            .autofill()
+            .create_autofill_job("DEMtemplate", HashMap::new(), None)
+            .await
+            .expect("create autofill job");
+        client
+            .autofill()
+            .get_autofill_job(&job.id)
+            .await
+            .expect("get autofill job");
+
+        let templates = client.brand_templates();
+        let listed = templates
+            .list(&ListBrandTemplatesRequest::default())
+            .await
+            .expect("list brand templates");
+        assert_eq!(listed.items.len(), 2);
+        let template = templates.get("DEMtemplate").await.expect("get template");
+        assert_eq!(template.brand_template.id, "DEMtemplate");
+        let dataset = templates
+            .get_dataset("DEMtemplate")
+            .await
+            .expect("get dataset");
+        assert_eq!(dataset.dataset.len(), 3);
+
+        #[cfg(feature = "experimental")]
+        exercise_comments(&client).await;
+
+        let designs = client.designs();
+        let listed = designs
+            .list_with(&ListDesignsRequest::default())
+            .await
+            .expect("list designs");
+        assert_eq!(listed.items.len(), 2);
+        designs
+            .create(CreateDesignRequest {
+                design_type: None,
+                asset_id: None,
+                title: Some("New".to_string()),
+            })
+            .await
+            .expect("create design");
+        let design = designs.get("DAFdesign").await.expect("get design");
+        assert_eq!(design.design.id, "DAFdesign");
+        #[cfg(feature = "experimental")]
+        {
+            let pages = designs
+                .get_pages("DAFdesign", &GetDesignPagesRequest::default())
+                .await
+                .expect("get design pages");
+            assert_eq!(pages.items.len(), 3);
+        }
+        let job = designs
+            .create_resize_job(CreateDesignResizeJobRequest::preset(
+                "DAFdesign",
+                PresetDesignTypeName::Presentation,
+            ))
+            .await
+            .expect("create resize job");
+        designs
+            .wait_for_resize_job(&job.id)
+            .await
+            .expect("get resize job");
+
+        let exports = client.exports();
+        exports
+            .get_design_export_formats("DAFdesign")
+            .await
+            .expect("get export formats");
+        let job = exports
+            .create_design_export_job(&CreateDesignExportJobRequest {
+                design_id: "DAFdesign".to_string(),
+                format: ExportFormat::pdf(),
+            })
+            .await
+            .expect("create export job");
+        let job = exports
+            .get_design_export_job(&job.job.id)
+            .await
+            .expect("get export job");
+        assert!(matches!(job.job.status, JobStatus::Success));
+
+        let folders = client.folders();
+        folders
+            .create_folder(&CreateFolderRequest {
+                name: "Reports".to_string(),
+                parent_folder_id: "root".to_string(),
+            })
+            .await
+            .expect("create folder");
+        folders.get_folder("FAFfolder").await.expect("get folder");
+        folders
+            .update_folder(
+                "FAFfolder",
+                &UpdateFolderRequest {
+                    name: "Renamed".to_string(),
+                },
+            )
+            .await
+            .expect("update folder");
+        let items = folders
+            .list_folder_items("FAFfolder", &ListFolderItemsRequest::default())
+            .await
+            .expect("list folder items");
+        assert!(matches!(items.items[2], FolderItemSummary::Image { .. }));
+        folders
+            .move_folder_item(&MoveFolderItemRequest {
+                item_id: "DAFdesign".to_string(),
+                to_folder_id: "FAFfolder".to_string(),
+            })
+            .await
+            .expect("move folder item");
+        client
+            .delete("/v1/folders/FAFfolder")
+            .await
+            .expect("delete folder");
+
+        #[cfg(feature = "experimental")]
+        exercise_imports(&client).await;
+
+        for operation in Operation::ALL {
+            let experimental = matches!(
+                operation,
+                Operation::GetDesignPages
+                    | Operation::CreateThread
+                    | Operation::GetThread
+                    | Operation::CreateReply
+                    | Operation::ListReplies
+                    | Operation::GetReply
+                    | Operation::CreateImportJob
+                    | Operation::GetImportJob
+                    | Operation::CreateUrlImportJob
+                    | Operation::GetUrlImportJob
+            );
+            let expected = if experimental && cfg!(not(feature = "experimental")) {
+                0
+            } else {
+                1
+            };
+            server.assert_called(operation, expected);
+        }
+    }
+
+    #[cfg(feature = "experimental")]
+    async fn exercise_comments(client: &Client) {
+        let comments = client.comments();
+        comments
+            .create_thread(
+                "DAFdesign",
+                &CreateThreadRequest {
+                    message_plaintext: "Nice".to_string(),
+                    assignee_id: None,
+                },
+            )
+            .await
+            .expect("create thread");
+        let thread = comments
+            .get_thread("DAFdesign", "KThread")
+            .await
+            .expect("get thread");
+        assert_eq!(thread.thread.design_id, "DAFdesign");
+        comments
+            .create_reply(
+                "DAFdesign",
+                "KThread",
+                &CreateReplyRequest {
+                    message_plaintext: "Thanks".to_string(),
+                },
+            )
+            .await
+            .expect("create reply");
+        comments
+            .list_replies("DAFdesign", "KThread", &ListRepliesRequest::default())
+            .await
+            .expect("list replies");
+        let reply = comments
+            .get_reply("DAFdesign", "KThread", "KReply")
+            .await
+            .expect("get reply");
+        assert_eq!(reply.reply.id, "KReply");
+    }
+
+    #[cfg(feature = "experimental")]
+    async fn exercise_imports(client: &Client) {
+        let imports = client.imports();
+        let job = imports
+            .create_import_job(b"%PDF".to_vec(), DesignImportMetadata::new("Imported"))
+            .await
+            .expect("create import job");
+        imports
+            .get_import_job(&job.id)
+            .await
+            .expect("get import job");
+        let job = imports
+            .create_url_import_job(CreateUrlImportJobRequest {
+                title: "Imported".to_string(),
+                url: "https://example.com/a.pdf".to_string(),
+                mime_type: None,
+            })
+            .await
+            .expect("create url import job");
+        let designs = imports
+            .wait_for_url_import_job(&job.id)
+            .await
+            .expect("get url import job");
+        assert_eq!(designs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_on_overrides_fixture_and_records_requests() {
+        let mut server = MockCanvaServer::start().await;
+        server.on(
+            Operation::GetDesign,
+            MockResponse::error(404, "design_not_found", "Design not found"),
+        );
+        let client = server.client().expect("client");
+
+        let result = client.designs().get("DAFmissing").await;
+        assert!(
+            matches!(result, Err(Error::Api { ref code, .. }) if code.to_string() == "design_not_found")
+        );
+        client.user().get_me().await.expect("fixture still served");
+
+        let requests = server.requests_to(Operation::GetDesign);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/v1/designs/DAFmissing");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some(format!("Bearer {TEST_TOKEN}").as_str())
+        );
+        server.assert_not_called(Operation::ListDesigns);
+
+        server.clear_requests();
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_serves_export_downloads() {
+        let server = MockCanvaServer::start().await;
+        let client = server.client().expect("client");
+        let exports = client.exports();
+
+        let job = exports
+            .wait_for_export_job("EXPjob")
+            .await
+            .expect("export job");
+        let mut bytes = Vec::new();
+        exports
+            .download_export(&job, &mut bytes)
+            .await
+            .expect("download");
+
+        assert!(!bytes.is_empty());
+        let downloads: Vec<_> = server
+            .requests()
+            .into_iter()
+            .filter(|request| request.path.starts_with("/downloads/exports/EXPjob/"))
+            .collect();
+        assert_eq!(downloads.len(), 2);
+    }
+}
@@ -0,0 +1,430 @@
+//! Policy hooks for asset uploads.
+//!
+//! Some organisations must scan every file before it leaves their network.
+//! An [`UploadPrecheck`] registered with
+//! [`ClientBuilder::upload_precheck`](crate::ClientBuilder::upload_precheck)
+//! runs before each asset upload, ahead of any network call, and can reject
+//! the upload with an [`UploadRejection`]. A rejected upload fails with
+//! [`Error::UploadRejected`] and nothing is sent to Canva.
+//!
+//! The hook borrows the upload's bytes rather than receiving its own copy,
+//! and [`UploadCandidate::chunks`] lets scanners feed incremental digests or
+//! streaming scanners without building further buffers.
+//!
+//! Before any precheck runs, file uploads are checked against the formats and
+//! sizes Canva accepts (see [`validate_asset`]), so an unsupported file fails
+//! with [`Error::InvalidAsset`] instead of a failed upload job. Turn this
+//! off with
+//! [`ClientBuilder::validate_uploads`](crate::ClientBuilder::validate_uploads).
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use canva_connect::{Client, auth::AccessToken};
+//! use canva_connect::uploads::{UploadCandidate, UploadPrecheck, UploadRejection};
+//!
+//! #[derive(Debug)]
+//! struct NoExecutables;
+//!
+//! #[async_trait::async_trait]
+//! impl UploadPrecheck for NoExecutables {
+//!     async fn check(&self, upload: &UploadCandidate<'_>) -> Result<(), UploadRejection> {
+//!         if upload.name().ends_with(".exe") {
+//!             return Err(UploadRejection::new("Executables are not allowed").with_policy("file-types"));
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder(AccessToken::new("your-token"))
+//!     .upload_precheck(NoExecutables)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Error;
+use std::fmt;
+
+/// Whether an asset is an image or a video
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    /// Still or animated image
+    Image,
+    /// Video
+    Video,
+}
+
+impl AssetKind {
+    /// Largest file Canva accepts for this kind of asset, in bytes
+    pub fn max_size(self) -> u64 {
+        match self {
+            Self::Image => 50 * 1024 * 1024,
+            Self::Video => 500 * 1024 * 1024,
+        }
+    }
+}
+
+/// A file format Canva accepts for asset uploads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AssetFormat {
+    /// JPEG image
+    Jpeg,
+    /// PNG image
+    Png,
+    /// GIF image
+    Gif,
+    /// TIFF image
+    Tiff,
+    /// WebP image
+    WebP,
+    /// HEIC image
+    Heic,
+    /// MP4 video
+    Mp4,
+    /// M4V video
+    M4v,
+    /// QuickTime (MOV) video
+    QuickTime,
+    /// Matroska (MKV) video
+    Matroska,
+    /// WebM video
+    WebM,
+    /// MPEG program stream video
+    Mpeg,
+}
+
+impl AssetFormat {
+    /// Detect the format from the first bytes of a file
+    ///
+    /// Returns `None` for formats Canva does not accept as assets.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        let starts = |magic: &[u8]| bytes.starts_with(magic);
+        if starts(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if starts(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if starts(b"GIF87a") || starts(b"GIF89a") {
+            Some(Self::Gif)
+        } else if starts(b"II*\0") || starts(b"MM\0*") {
+            Some(Self::Tiff)
+        } else if starts(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+            Some(Self::WebP)
+        } else if starts(&[0x1A, 0x45, 0xDF, 0xA3]) {
+            // EBML header; the DocType tells WebM and Matroska apart
+            let header = &bytes[..bytes.len().min(64)];
+            if header.windows(4).any(|window| window == b"webm") {
+                Some(Self::WebM)
+            } else {
+                Some(Self::Matroska)
+            }
+        } else if starts(&[0x00, 0x00, 0x01, 0xBA]) || starts(&[0x00, 0x00, 0x01, 0xB3]) {
+            Some(Self::Mpeg)
+        } else {
+            Self::detect_iso_media(bytes)
+        }
+    }
+
+    /// Formats built on the ISO base media file format, identified by brand
+    fn detect_iso_media(bytes: &[u8]) -> Option<Self> {
+        match bytes.get(4..8)? {
+            b"ftyp" => match bytes.get(8..12)? {
+                b"heic" | b"heix" | b"hevc" | b"hevx" | b"heim" | b"heis" => Some(Self::Heic),
+                b"M4V " | b"M4VH" | b"M4VP" => Some(Self::M4v),
+                b"qt  " => Some(Self::QuickTime),
+                b"isom" | b"iso2" | b"iso4" | b"iso5" | b"iso6" | b"mp41" | b"mp42" | b"avc1"
+                | b"dash" | b"MSNV" => Some(Self::Mp4),
+                _ => None,
+            },
+            // QuickTime files written before the ftyp atom existed
+            b"moov" | b"mdat" | b"wide" | b"free" => Some(Self::QuickTime),
+            _ => None,
+        }
+    }
+
+    /// MIME type of the format
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Gif => "image/gif",
+            Self::Tiff => "image/tiff",
+            Self::WebP => "image/webp",
+            Self::Heic => "image/heic",
+            Self::Mp4 => "video/mp4",
+            Self::M4v => "video/x-m4v",
+            Self::QuickTime => "video/quicktime",
+            Self::Matroska => "video/x-matroska",
+            Self::WebM => "video/webm",
+            Self::Mpeg => "video/mpeg",
+        }
+    }
+
+    /// The format with the given MIME type, ignoring parameters such as `charset`
+    ///
+    /// Returns `None` for types Canva does not accept as assets, and for
+    /// generic types such as `application/octet-stream`.
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        let essence = mime_type.split(';').next()?.trim().to_ascii_lowercase();
+        let format = match essence.as_str() {
+            "image/jpeg" | "image/jpg" | "image/pjpeg" => Self::Jpeg,
+            "image/png" => Self::Png,
+            "image/gif" => Self::Gif,
+            "image/tiff" => Self::Tiff,
+            "image/webp" => Self::WebP,
+            "image/heic" | "image/heif" => Self::Heic,
+            "video/mp4" => Self::Mp4,
+            "video/x-m4v" => Self::M4v,
+            "video/quicktime" => Self::QuickTime,
+            "video/x-matroska" => Self::Matroska,
+            "video/webm" => Self::WebM,
+            "video/mpeg" => Self::Mpeg,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// Whether the format is an image or a video
+    pub fn kind(self) -> AssetKind {
+        match self {
+            Self::Jpeg | Self::Png | Self::Gif | Self::Tiff | Self::WebP | Self::Heic => {
+                AssetKind::Image
+            }
+            Self::Mp4 | Self::M4v | Self::QuickTime | Self::Matroska | Self::WebM | Self::Mpeg => {
+                AssetKind::Video
+            }
+        }
+    }
+}
+
+/// Check that a file is in a format and size Canva accepts as an asset
+///
+/// Returns the detected format, or [`Error::InvalidAsset`] describing why the
+/// file would be rejected. `name` is only used in the error.
+pub fn validate_asset(name: &str, bytes: &[u8]) -> crate::Result<AssetFormat> {
+    let invalid = |reason: String| Error::InvalidAsset {
+        name: name.to_string(),
+        reason,
+    };
+    if bytes.is_empty() {
+        return Err(invalid("file is empty".to_string()));
+    }
+    let format = AssetFormat::detect(bytes).ok_or_else(|| {
+        invalid(
+            "unsupported file type; Canva accepts JPEG, PNG, GIF, TIFF, WebP and HEIC images \
+             and MP4, M4V, MOV, MKV, WebM and MPEG videos"
+                .to_string(),
+        )
+    })?;
+    let max_size = format.kind().max_size();
+    let size = bytes.len() as u64;
+    if size > max_size {
+        return Err(invalid(format!(
+            "{} is {} MB, over the {} MB limit",
+            format.mime_type(),
+            size.div_ceil(1024 * 1024),
+            max_size / (1024 * 1024)
+        )));
+    }
+    Ok(format)
+}
+
+/// Where the content of an upload comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UploadSource<'a> {
+    /// The file content, uploaded directly
+    Bytes(&'a [u8]),
+    /// A URL Canva will fetch the file from
+    Url(&'a str),
+}
+
+/// An upload about to be sent to Canva
+#[derive(Debug, Clone)]
+pub struct UploadCandidate<'a> {
+    name: &'a str,
+    tags: &'a [String],
+    source: UploadSource<'a>,
+}
+
+impl<'a> UploadCandidate<'a> {
+    pub(crate) fn new(name: &'a str, tags: &'a [String], source: UploadSource<'a>) -> Self {
+        Self { name, tags, source }
+    }
+
+    /// Name the asset will have in Canva
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Tags the asset will have in Canva
+    pub fn tags(&self) -> &[String] {
+        self.tags
+    }
+
+    /// Where the content comes from
+    pub fn source(&self) -> UploadSource<'a> {
+        self.source
+    }
+
+    /// Size of the content in bytes, if it is uploaded directly
+    pub fn size(&self) -> Option<u64> {
+        match self.source {
+            UploadSource::Bytes(bytes) => Some(bytes.len() as u64),
+            UploadSource::Url(_) => None,
+        }
+    }
+
+    /// The content in chunks of at most `chunk_size` bytes
+    ///
+    /// Yields nothing for URL uploads. A `chunk_size` of 0 is treated as 1.
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = &'a [u8]> {
+        let bytes: &'a [u8] = match self.source {
+            UploadSource::Bytes(bytes) => bytes,
+            UploadSource::Url(_) => &[],
+        };
+        bytes.chunks(chunk_size.max(1))
+    }
+}
+
+/// Why an [`UploadPrecheck`] refused an upload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadRejection {
+    /// Human-readable reason
+    pub reason: String,
+    /// Name of the policy that rejected the upload, if any
+    pub policy: Option<String>,
+}
+
+impl UploadRejection {
+    /// Reject an upload for the given reason
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            policy: None,
+        }
+    }
+
+    /// Record which policy rejected the upload
+    pub fn with_policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+}
+
+impl fmt::Display for UploadRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.policy {
+            Some(policy) => write!(f, "{} (policy: {policy})", self.reason),
+            None => f.write_str(&self.reason),
+        }
+    }
+}
+
+/// Inspects uploads before they are sent to Canva
+///
+/// Implementations that cannot reach a decision, for example because a
+/// scanning service is unavailable, should reject the upload rather than
+/// let it through.
+#[async_trait::async_trait]
+pub trait UploadPrecheck: fmt::Debug + Send + Sync {
+    /// Allow the upload, or reject it with a reason
+    async fn check(&self, upload: &UploadCandidate<'_>) -> Result<(), UploadRejection>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidate_chunks() {
+        let tags = Vec::new();
+        let candidate = UploadCandidate::new("a.png", &tags, UploadSource::Bytes(b"abcde"));
+        assert_eq!(candidate.size(), Some(5));
+        let chunks: Vec<&[u8]> = candidate.chunks(2).collect();
+        assert_eq!(chunks, vec![&b"ab"[..], b"cd", b"e"]);
+
+        let from_url = UploadCandidate::new("a.png", &tags, UploadSource::Url("https://x"));
+        assert_eq!(from_url.size(), None);
+        assert_eq!(from_url.chunks(0).count(), 0);
+    }
+
+    #[test]
+    fn test_detects_formats_from_magic_bytes() {
+        let cases: &[(&[u8], AssetFormat)] = &[
+            (b"\xFF\xD8\xFF\xE0rest", AssetFormat::Jpeg),
+            (b"\x89PNG\r\n\x1a\nrest", AssetFormat::Png),
+            (b"GIF89arest", AssetFormat::Gif),
+            (b"MM\0*rest", AssetFormat::Tiff),
+            (b"RIFF\0\0\0\0WEBPVP8 ", AssetFormat::WebP),
+            (b"\0\0\0\x18ftypheic", AssetFormat::Heic),
+            (b"\0\0\0\x18ftypisom", AssetFormat::Mp4),
+            (b"\0\0\0\x18ftypM4V ", AssetFormat::M4v),
+            (b"\0\0\0\x14ftypqt  ", AssetFormat::QuickTime),
+            (b"\x1A\x45\xDF\xA3\x42\x82\x84webm", AssetFormat::WebM),
+            (
+                b"\x1A\x45\xDF\xA3\x42\x82\x88matroska",
+                AssetFormat::Matroska,
+            ),
+            (b"\0\0\x01\xBArest", AssetFormat::Mpeg),
+        ];
+        for (bytes, format) in cases {
+            assert_eq!(AssetFormat::detect(bytes), Some(*format), "{format:?}");
+        }
+        assert_eq!(AssetFormat::detect(b"%PDF-1.7"), None);
+        assert_eq!(AssetFormat::detect(b"\0\0"), None);
+    }
+
+    #[test]
+    fn test_format_from_mime_type() {
+        assert_eq!(
+            AssetFormat::from_mime_type("image/PNG"),
+            Some(AssetFormat::Png)
+        );
+        assert_eq!(
+            AssetFormat::from_mime_type("video/mp4; codecs=avc1"),
+            Some(AssetFormat::Mp4)
+        );
+        assert_eq!(AssetFormat::from_mime_type("application/pdf"), None);
+    }
+
+    #[test]
+    fn test_validate_asset() {
+        let png = b"\x89PNG\r\n\x1a\nrest";
+        assert_eq!(validate_asset("a.png", png).ok(), Some(AssetFormat::Png));
+
+        match validate_asset("doc.pdf", b"%PDF-1.7") {
+            Err(Error::InvalidAsset { name, reason }) => {
+                assert_eq!(name, "doc.pdf");
+                assert!(reason.contains("unsupported file type"));
+            }
+            other => panic!("expected InvalidAsset, got {other:?}"),
+        }
+        assert!(matches!(
+            validate_asset("empty.png", b""),
+            Err(Error::InvalidAsset { .. })
+        ));
+
+        let mut huge = png.to_vec();
+        huge.resize(AssetKind::Image.max_size() as usize + 1, 0);
+        match validate_asset("huge.png", &huge) {
+            Err(Error::InvalidAsset { reason, .. }) => {
+                assert_eq!(reason, "image/png is 51 MB, over the 50 MB limit");
+            }
+            other => panic!("expected InvalidAsset, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rejection_display() {
+        assert_eq!(UploadRejection::new("Too large").to_string(), "Too large");
+        assert_eq!(
+            UploadRejection::new("Malware found")
+                .with_policy("av-scan")
+                .to_string(),
+            "Malware found (policy: av-scan)"
+        );
+    }
+}
@@ -0,0 +1,1014 @@
+//! Multi-step workflows built from several API calls.
+//!
+//! Each workflow packages a common journey into one call, with the same
+//! rate limiting, retries and tracing as the individual endpoints.
+//!
+//! | Workflow | Steps | Required Scopes |
+//! |----------|-------|-----------------|
+//! | [`find_template_and_autofill`] | List brand templates, get dataset, autofill, wait | `brandtemplate:meta:read`, `brandtemplate:content:read`, `design:content:write`, `design:meta:read` |
+//! | [`render_template_to_file`] | Autofill, wait, export, wait, download | `design:content:write`, `design:meta:read`, `design:content:read` |
+//! | [`duplicate_design`] | Export as PPTX, wait, import from the export URL, wait | `design:content:read`, `design:content:write` |
+//!
+//! A failing step is reported as [`Error::Workflow`], naming the step and
+//! wrapping the underlying error.
+//!
+//! With the `observability` feature each workflow runs in a `workflow` span
+//! like those from `observability::workflow_span`, with its requests and job
+//! polls as children.
+
+use crate::{
+    client::Client,
+    endpoints::{
+        autofill,
+        brand_templates::ListBrandTemplatesRequest,
+        exports::{CreateDesignExportJobRequest, ExportProgress},
+        imports::CreateUrlImportJobRequest,
+    },
+    error::{Error, Result},
+    models::{
+        BrandTemplate, DataField, DatasetValue, DesignAutofillJobResult, DesignSummary,
+        ExportFormat, ExportJob,
+    },
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Options for [`find_template_and_autofill`]
+#[derive(Debug, Clone, Default)]
+pub struct AutofillOptions {
+    /// Title for the created design; Canva picks one if `None`
+    pub design_title: Option<String>,
+    /// Only match templates whose title equals the query, ignoring case,
+    /// instead of templates whose title contains it
+    pub exact_title: bool,
+    /// Fail instead of dropping data fields the template cannot use
+    pub strict: bool,
+}
+
+/// A data field left out of an autofill request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedField {
+    /// Name of the data field
+    pub name: String,
+    /// Why it was left out
+    pub reason: String,
+}
+
+/// How the provided data mapped onto the template's dataset
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutofillReport {
+    /// Provided fields the template does not have, sorted by name
+    pub ignored_fields: Vec<String>,
+    /// Provided fields the template has but cannot take, such as text for an
+    /// image field, sorted by name
+    pub skipped_fields: Vec<SkippedField>,
+    /// Optional template fields no data was provided for, sorted by name
+    pub unfilled_fields: Vec<String>,
+}
+
+impl AutofillReport {
+    /// Whether every provided field was used
+    pub fn is_clean(&self) -> bool {
+        self.ignored_fields.is_empty() && self.skipped_fields.is_empty()
+    }
+}
+
+/// Result of [`find_template_and_autofill`]
+#[derive(Debug, Clone)]
+pub struct AutofillOutcome {
+    /// The brand template that was used
+    pub template: BrandTemplate,
+    /// The design created from it
+    pub design: DesignSummary,
+    /// Fields that were dropped or left empty
+    pub report: AutofillReport,
+}
+
+/// Find a brand template by title and create a design from it
+///
+/// 1. Searches the user's brand templates for `title_query` and keeps those
+///    whose title contains it (or equals it, with
+///    [`AutofillOptions::exact_title`]), ignoring case. If several match, an
+///    exact title match wins; otherwise the query is ambiguous and the call
+///    fails listing the candidates.
+/// 2. Fetches the template's dataset and checks `data` against it. Fields the
+///    template lacks or cannot take are dropped and reported, or fail the call
+///    with [`AutofillOptions::strict`]. Missing required fields always fail
+///    the call, before any job is created.
+/// 3. Runs the autofill job and waits for it at the client's
+///    [poll interval](Client::poll_interval).
+///
+/// **Required OAuth scopes:** `brandtemplate:meta:read`,
+/// `brandtemplate:content:read`, `design:content:write`, `design:meta:read`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken, models::DatasetValue};
+/// use canva_connect::workflows::{find_template_and_autofill, AutofillOptions};
+/// use std::collections::HashMap;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("token"))?;
+/// let data = HashMap::from([(
+///     "headline".to_string(),
+///     DatasetValue::Text { text: "Spring sale".to_string() },
+/// )]);
+///
+/// let outcome = find_template_and_autofill(
+///     &client,
+///     "Sale poster",
+///     data,
+///     AutofillOptions::default(),
+/// )
+/// .await?;
+///
+/// println!("Created {} from {}", outcome.design.id, outcome.template.title);
+/// for field in &outcome.report.ignored_fields {
+///     println!("Template has no field {field}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "observability", tracing::instrument(
+    name = "workflow",
+    skip(client, data, options),
+    fields(
+        canva.workflow = "find_template_and_autofill",
+        canva.autofill.fields = data.len(),
+    )
+))]
+pub async fn find_template_and_autofill(
+    client: &Client,
+    title_query: &str,
+    data: HashMap<String, DatasetValue>,
+    options: AutofillOptions,
+) -> Result<AutofillOutcome> {
+    let step = format!("find brand template {title_query:?}");
+    let template = find_template(client, title_query, options.exact_title)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+
+    #[cfg(feature = "observability")]
+    tracing::info!(template_id = %template.id, "Found brand template");
+
+    let step = format!("match data to brand template {}", template.id);
+    let dataset = client
+        .brand_templates()
+        .get_dataset(&template.id)
+        .await
+        .map_err(|e| workflow_error(&step, e))?
+        .dataset;
+    let (data, report) =
+        match_dataset(&template, &dataset, data).map_err(|e| workflow_error(&step, e))?;
+    if options.strict && !report.is_clean() {
+        let e = Error::Generic(format!(
+            "Data does not fit brand template {}: {}",
+            template.id,
+            describe_unused(&report)
+        ));
+        return Err(workflow_error(&step, e));
+    }
+
+    #[cfg(feature = "observability")]
+    tracing::info!(
+        ignored = report.ignored_fields.len(),
+        skipped = report.skipped_fields.len(),
+        "Starting autofill job"
+    );
+
+    let step = format!("autofill brand template {}", template.id);
+    let job = client
+        .autofill()
+        .autofill_row(&template.id, data, options.design_title)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    let Some(DesignAutofillJobResult::CreateDesign { design }) = job.result else {
+        let e = Error::Generic(format!("Autofill job {} finished without a design", job.id));
+        return Err(workflow_error(&step, e));
+    };
+
+    Ok(AutofillOutcome {
+        template,
+        design,
+        report,
+    })
+}
+
+/// Progress of [`render_template_to_file_with_progress`], in the order events occur
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RenderProgress {
+    /// The autofill job was created
+    AutofillStarted {
+        /// ID of the autofill job
+        job_id: String,
+    },
+    /// The autofill job finished and created a design
+    DesignCreated {
+        /// ID of the new design
+        design_id: String,
+    },
+    /// The export job was created
+    ExportStarted {
+        /// ID of the export job
+        export_id: String,
+    },
+    /// A chunk of an exported file was written to disk
+    Downloading(ExportProgress),
+}
+
+/// Result of [`render_template_to_file`]
+#[derive(Debug, Clone)]
+pub struct RenderOutcome {
+    /// The design created from the brand template
+    pub design: DesignSummary,
+    /// The completed export job
+    pub export: ExportJob,
+    /// Files written, one per exported page for per-page formats
+    pub paths: Vec<PathBuf>,
+}
+
+/// Fill a brand template with `data` and save the resulting design as a file
+///
+/// Runs the autofill job, exports the new design in `format` and streams
+/// the export to `output_path`, which is named like
+/// [`ExportsApi::export_to_path`](crate::endpoints::exports::ExportsApi::export_to_path)
+/// names files. Both jobs are polled at the client's
+/// [poll interval](Client::poll_interval).
+///
+/// **Required OAuth scopes:** `design:content:write`, `design:meta:read`,
+/// `design:content:read`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken, models::{DatasetValue, ExportFormat}};
+/// use canva_connect::workflows::render_template_to_file;
+/// use std::collections::HashMap;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("token"))?;
+/// let data = HashMap::from([(
+///     "name".to_string(),
+///     DatasetValue::Text { text: "Ada Lovelace".to_string() },
+/// )]);
+///
+/// let outcome =
+///     render_template_to_file(&client, "DAF_template", data, ExportFormat::pdf_print_a4(), "badge.pdf")
+///         .await?;
+/// println!("Wrote {:?} from design {}", outcome.paths, outcome.design.id);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn render_template_to_file(
+    client: &Client,
+    template_id: &str,
+    data: HashMap<String, DatasetValue>,
+    format: ExportFormat,
+    output_path: impl AsRef<Path>,
+) -> Result<RenderOutcome> {
+    render_template_to_file_with_progress(client, template_id, data, format, output_path, |_| {})
+        .await
+}
+
+/// Like [`render_template_to_file`], reporting each step and download chunk
+#[cfg_attr(feature = "observability", tracing::instrument(
+    name = "workflow",
+    skip(client, data, format, output_path, on_progress),
+    fields(
+        canva.workflow = "render_template_to_file",
+        canva.brand_template_id = template_id,
+    )
+))]
+pub async fn render_template_to_file_with_progress<F>(
+    client: &Client,
+    template_id: &str,
+    data: HashMap<String, DatasetValue>,
+    format: ExportFormat,
+    output_path: impl AsRef<Path>,
+    mut on_progress: F,
+) -> Result<RenderOutcome>
+where
+    F: FnMut(&RenderProgress),
+{
+    let step = format!("autofill brand template {template_id}");
+    let autofill_api = client.autofill();
+    let job = autofill_api
+        .create_autofill_job(template_id, data, None)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    on_progress(&RenderProgress::AutofillStarted {
+        job_id: job.id.clone(),
+    });
+    let job = autofill_api
+        .wait_for_autofill_job(&job.id, None)
+        .await
+        .and_then(autofill::ensure_succeeded)
+        .map_err(|e| workflow_error(&step, e))?;
+    let Some(DesignAutofillJobResult::CreateDesign { design }) = job.result else {
+        let e = Error::Generic(format!("Autofill job {} finished without a design", job.id));
+        return Err(workflow_error(&step, e));
+    };
+    on_progress(&RenderProgress::DesignCreated {
+        design_id: design.id.clone(),
+    });
+
+    let step = format!("export design {}", design.id);
+    let exports = client.exports();
+    let request = CreateDesignExportJobRequest {
+        design_id: design.id.clone(),
+        format,
+    };
+    let created = exports
+        .create_design_export_job(&request)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    on_progress(&RenderProgress::ExportStarted {
+        export_id: created.job.id.clone(),
+    });
+    let export = exports
+        .wait_for_export_job(&created.job.id)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+
+    let step = format!("download export {}", export.id);
+    let paths = exports
+        .save_export(&export, output_path.as_ref(), &mut |progress| {
+            on_progress(&RenderProgress::Downloading(progress.clone()))
+        })
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+
+    Ok(RenderOutcome {
+        design,
+        export,
+        paths,
+    })
+}
+
+/// MIME type of the PowerPoint files [`duplicate_design`] round-trips through
+const PPTX_MIME_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation";
+
+/// Copy a design into a new design titled `new_title`
+///
+/// The Connect API has no endpoint for copying a design, so this exports
+/// the design as PowerPoint and imports the export's download URL as a new
+/// design. The copy is a conversion rather than an exact clone: text and
+/// images come through, but animations, effects and elements PowerPoint
+/// cannot represent may be flattened or lost. For exact copies of a
+/// layout, create designs from a brand template with
+/// [`find_template_and_autofill`] instead.
+///
+/// Both jobs are polled at the client's [poll interval](Client::poll_interval).
+///
+/// **Required OAuth scopes:** `design:content:read`, `design:content:write`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use canva_connect::{Client, auth::AccessToken};
+/// use canva_connect::workflows::duplicate_design;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(AccessToken::new("token"))?;
+/// let copy = duplicate_design(&client, "DAFVztcvd9z", "Q3 review (copy)").await?;
+/// println!("Edit the copy at {}", copy.urls.edit_url);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "observability", tracing::instrument(
+    name = "workflow",
+    skip(client, new_title),
+    fields(canva.workflow = "duplicate_design")
+))]
+pub async fn duplicate_design(
+    client: &Client,
+    design_id: &str,
+    new_title: &str,
+) -> Result<DesignSummary> {
+    let step = format!("export design {design_id}");
+    let exports = client.exports();
+    let request = CreateDesignExportJobRequest {
+        design_id: design_id.to_string(),
+        format: ExportFormat::pptx(),
+    };
+    let created = exports
+        .create_design_export_job(&request)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    let export = exports
+        .wait_for_export_job(&created.job.id)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    let Some(url) = export.urls.first() else {
+        let e = Error::Generic(format!("Export job {} finished without a file", export.id));
+        return Err(workflow_error(&step, e));
+    };
+
+    let step = format!("import export {}", export.id);
+    let imports = client.imports();
+    let job = imports
+        .create_url_import_job(CreateUrlImportJobRequest {
+            title: new_title.to_string(),
+            url: url.clone(),
+            mime_type: Some(PPTX_MIME_TYPE.to_string()),
+        })
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    let designs = imports
+        .wait_for_url_import_job(&job.id)
+        .await
+        .map_err(|e| workflow_error(&step, e))?;
+    designs.into_iter().next().ok_or_else(|| {
+        let e = Error::Generic(format!("Import job {} created no design", job.id));
+        workflow_error(&step, e)
+    })
+}
+
+fn workflow_error(step: &str, source: Error) -> Error {
+    Error::Workflow {
+        step: step.to_string(),
+        source: Box::new(source),
+    }
+}
+
+/// Find the single brand template matching `query`
+async fn find_template(client: &Client, query: &str, exact: bool) -> Result<BrandTemplate> {
+    // Canva's search narrows the listing; its matches are then held to the
+    // documented title rules
+    let mut request = Some(ListBrandTemplatesRequest {
+        query: Some(query.to_string()),
+        continuation: None,
+        limit: Some(100),
+    });
+    let lowered = query.to_lowercase();
+    let mut matches = Vec::new();
+    while let Some(current) = request {
+        let page = client.brand_templates().list(&current).await?;
+        request = page.next_request(&current);
+        matches.extend(page.items.into_iter().filter(|template| {
+            let title = template.title.to_lowercase();
+            if exact {
+                title == lowered
+            } else {
+                title.contains(&lowered)
+            }
+        }));
+    }
+
+    if matches.len() > 1 {
+        let exact_matches: Vec<usize> = matches
+            .iter()
+            .enumerate()
+            .filter(|(_, template)| template.title.to_lowercase() == lowered)
+            .map(|(index, _)| index)
+            .collect();
+        if let [index] = exact_matches[..] {
+            return Ok(matches.swap_remove(index));
+        }
+        let titles: Vec<String> = matches
+            .iter()
+            .map(|template| format!("{:?} ({})", template.title, template.id))
+            .collect();
+        return Err(Error::Generic(format!(
+            "{} brand templates match {query:?}: {}",
+            matches.len(),
+            titles.join(", ")
+        )));
+    }
+    matches
+        .pop()
+        .ok_or_else(|| Error::Generic(format!("No brand template matches {query:?}")))
+}
+
+/// Keep the data fields the template can take and report the rest
+fn match_dataset(
+    template: &BrandTemplate,
+    dataset: &HashMap<String, DataField>,
+    data: HashMap<String, DatasetValue>,
+) -> Result<(HashMap<String, DatasetValue>, AutofillReport)> {
+    let mut report = AutofillReport::default();
+    let mut usable = HashMap::new();
+    for (name, value) in data {
+        match dataset.get(&name) {
+            None => report.ignored_fields.push(name),
+            Some(field) if !accepts(field, &value) => report.skipped_fields.push(SkippedField {
+                reason: format!(
+                    "template field is {}, value is {}",
+                    field_kind(field),
+                    value_kind(&value)
+                ),
+                name,
+            }),
+            Some(_) => {
+                usable.insert(name, value);
+            }
+        }
+    }
+
+    let mut missing = Vec::new();
+    for (name, field) in dataset {
+        if usable.contains_key(name) {
+            continue;
+        }
+        if is_required(field) {
+            missing.push(name.clone());
+        } else {
+            report.unfilled_fields.push(name.clone());
+        }
+    }
+    if !missing.is_empty() {
+        missing.sort();
+        return Err(Error::Generic(format!(
+            "Missing required fields for brand template {}: {}",
+            template.id,
+            missing.join(", ")
+        )));
+    }
+
+    report.ignored_fields.sort();
+    report.skipped_fields.sort_by(|a, b| a.name.cmp(&b.name));
+    report.unfilled_fields.sort();
+    Ok((usable, report))
+}
+
+fn accepts(field: &DataField, value: &DatasetValue) -> bool {
+    matches!(
+        (field, value),
+        (DataField::Text { .. }, DatasetValue::Text { .. })
+            | (DataField::Image { .. }, DatasetValue::Image { .. })
+            | (DataField::Chart { .. }, DatasetValue::Chart { .. })
+    )
+}
+
+fn is_required(field: &DataField) -> bool {
+    match field {
+        DataField::Text { required, .. }
+        | DataField::Image { required, .. }
+        | DataField::Chart { required, .. } => required.unwrap_or(false),
+        DataField::Unknown => false,
+    }
+}
+
+fn field_kind(field: &DataField) -> &'static str {
+    match field {
+        DataField::Text { .. } => "text",
+        DataField::Image { .. } => "image",
+        DataField::Chart { .. } => "chart",
+        DataField::Unknown => "unknown",
+    }
+}
+
+fn value_kind(value: &DatasetValue) -> &'static str {
+    match value {
+        DatasetValue::Text { .. } => "text",
+        DatasetValue::Image { .. } => "image",
+        DatasetValue::Chart { .. } => "chart",
+    }
+}
+
+fn describe_unused(report: &AutofillReport) -> String {
+    report
+        .ignored_fields
+        .iter()
+        .map(|name| format!("{name} (not in template)"))
+        .chain(
+            report
+                .skipped_fields
+                .iter()
+                .map(|field| format!("{} ({})", field.name, field.reason)),
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::auth::AccessToken;
+
+    fn template_json(id: &str, title: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "title": title,
+            "view_url": "https://www.canva.com/brand-templates/view",
+            "create_url": "https://www.canva.com/brand-templates/create",
+            "created_at": 1_700_000_000,
+            "updated_at": 1_700_000_000
+        })
+    }
+
+    fn text(value: &str) -> DatasetValue {
+        DatasetValue::Text {
+            text: value.to_string(),
+        }
+    }
+
+    async fn mock_templates(server: &mut mockito::Server) {
+        server
+            .mock("GET", "/v1/brand-templates")
+            .match_query(mockito::Matcher::Regex(
+                "^query=[^&]+&limit=100$".into(),
+            ))
+            .with_body(
+                serde_json::json!({
+                    "items": [template_json("tpl_1", "Sale poster"), template_json("tpl_2", "Staff card")],
+                    "continuation": "page_2"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates")
+            .match_query(mockito::Matcher::Regex(
+                "^query=[^&]+&continuation=page_2&limit=100$".into(),
+            ))
+            .with_body(
+                serde_json::json!({ "items": [template_json("tpl_3", "Sale poster (old)")] })
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/brand-templates/tpl_1/dataset")
+            .with_body(
+                serde_json::json!({
+                    "dataset": {
+                        "headline": { "type": "text", "required": true },
+                        "photo": { "type": "image" },
+                        "footer": { "type": "text" }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+    }
+
+    fn client(server: &mockito::Server) -> Client {
+        Client::builder(AccessToken::new("test_token"))
+            .base_url(server.url())
+            .build()
+            .expect("Failed to create client")
+    }
+
+    #[tokio::test]
+    async fn test_find_template_and_autofill() {
+        let mut server = mockito::Server::new_async().await;
+        mock_templates(&mut server).await;
+        let autofill = server
+            .mock("POST", "/v1/autofills")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "brand_template_id": "tpl_1",
+                "title": "Spring",
+                "data": { "headline": { "type": "text", "text": "Sale!" } }
+            })))
+            .with_body(r#"{"job":{"id":"job_1","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/autofills/job_1")
+            .with_body(
+                serde_json::json!({
+                    "job": {
+                        "id": "job_1",
+                        "status": "success",
+                        "result": {
+                            "type": "create_design",
+                            "design": {
+                                "id": "design_1",
+                                "title": "Spring",
+                                "owner": { "user_id": "u", "team_id": "t" },
+                                "urls": {
+                                    "edit_url": "https://www.canva.com/edit",
+                                    "view_url": "https://www.canva.com/view"
+                                },
+                                "created_at": 1_700_000_000,
+                                "updated_at": 1_700_000_000
+                            }
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let data = HashMap::from([
+            ("headline".to_string(), text("Sale!")),
+            ("photo".to_string(), text("not an asset")),
+            ("unknown".to_string(), text("?")),
+        ]);
+        let options = AutofillOptions {
+            design_title: Some("Spring".to_string()),
+            ..AutofillOptions::default()
+        };
+        let outcome = find_template_and_autofill(&client(&server), "sale POSTER", data, options)
+            .await
+            .expect("workflow");
+
+        assert_eq!(outcome.template.id, "tpl_1");
+        assert_eq!(outcome.design.id, "design_1");
+        assert_eq!(outcome.report.ignored_fields, vec!["unknown"]);
+        assert_eq!(
+            outcome.report.skipped_fields,
+            vec![SkippedField {
+                name: "photo".to_string(),
+                reason: "template field is image, value is text".to_string(),
+            }]
+        );
+        assert_eq!(outcome.report.unfilled_fields, vec!["footer", "photo"]);
+        autofill.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_strict_and_missing_fields_fail_before_autofill() {
+        let mut server = mockito::Server::new_async().await;
+        mock_templates(&mut server).await;
+        let autofill = server
+            .mock("POST", "/v1/autofills")
+            .expect(0)
+            .create_async()
+            .await;
+        let client = client(&server);
+
+        let strict = AutofillOptions {
+            strict: true,
+            ..AutofillOptions::default()
+        };
+        let data = HashMap::from([
+            ("headline".to_string(), text("Sale!")),
+            ("unknown".to_string(), text("?")),
+        ]);
+        let error = find_template_and_autofill(&client, "Sale poster", data, strict)
+            .await
+            .expect_err("strict mode rejects unknown fields");
+        assert!(error.to_string().contains("unknown (not in template)"));
+
+        let data = HashMap::from([("footer".to_string(), text("Fine print"))]);
+        let error = find_template_and_autofill(&client, "Sale poster", data, Default::default())
+            .await
+            .expect_err("headline is required");
+        assert!(error.to_string().contains("Missing required fields"));
+        assert!(error.to_string().contains("headline"));
+
+        autofill.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_ambiguous_and_unknown_titles() {
+        let mut server = mockito::Server::new_async().await;
+        mock_templates(&mut server).await;
+        let client = client(&server);
+
+        let error = find_template_and_autofill(&client, "sale", HashMap::new(), Default::default())
+            .await
+            .expect_err("two templates match");
+        assert!(error.to_string().contains("2 brand templates match"));
+
+        let exact = AutofillOptions {
+            exact_title: true,
+            ..AutofillOptions::default()
+        };
+        let error = find_template_and_autofill(&client, "SALE", HashMap::new(), exact)
+            .await
+            .expect_err("no exact match");
+        assert!(
+            matches!(&error, Error::Workflow { step, .. } if step == r#"find brand template "SALE""#)
+        );
+        assert!(error
+            .to_string()
+            .contains(r#"No brand template matches "SALE""#));
+    }
+
+    #[tokio::test]
+    async fn test_find_template_and_autofill_wraps_api_errors() {
+        let mut server = mockito::Server::new_async().await;
+        mock_templates(&mut server).await;
+        server
+            .mock("POST", "/v1/autofills")
+            .with_status(500)
+            .with_body(r#"{"code":"internal_failure","message":"Try again"}"#)
+            .create_async()
+            .await;
+
+        let data = HashMap::from([("headline".to_string(), text("Sale!"))]);
+        let error =
+            find_template_and_autofill(&client(&server), "Sale poster", data, Default::default())
+                .await
+                .expect_err("autofill fails");
+        assert!(
+            matches!(&error, Error::Workflow { step, .. } if step == "autofill brand template tpl_1")
+        );
+        assert_eq!(error.path(), Some("/v1/autofills"));
+        assert!(error.is_retryable());
+    }
+
+    async fn mock_autofill_to_design(server: &mut mockito::Server) {
+        server
+            .mock("POST", "/v1/autofills")
+            .with_body(r#"{"job":{"id":"job_9","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/autofills/job_9")
+            .with_body(
+                serde_json::json!({
+                    "job": {
+                        "id": "job_9",
+                        "status": "success",
+                        "result": {
+                            "type": "create_design",
+                            "design": {
+                                "id": "design_9",
+                                "owner": { "user_id": "u", "team_id": "t" },
+                                "urls": {
+                                    "edit_url": "https://www.canva.com/edit",
+                                    "view_url": "https://www.canva.com/view"
+                                },
+                                "created_at": 1_700_000_000,
+                                "updated_at": 1_700_000_000
+                            }
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/v1/exports")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "design_id": "design_9" }),
+            ))
+            .with_body(r#"{"job":{"id":"export_9","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_render_template_to_file_reports_each_step() {
+        let mut server = mockito::Server::new_async().await;
+        mock_autofill_to_design(&mut server).await;
+        let download_url = format!("{}/files/badge.pdf", server.url());
+        server
+            .mock("GET", "/v1/exports/export_9")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_9", "status": "success", "urls": [download_url] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/files/badge.pdf")
+            .with_body("%PDF-badge")
+            .create_async()
+            .await;
+
+        let path = std::env::temp_dir().join(format!("canva-render-{}.pdf", uuid::Uuid::new_v4()));
+        let mut events = Vec::new();
+        let outcome = render_template_to_file_with_progress(
+            &client(&server),
+            "tpl_1",
+            HashMap::from([("headline".to_string(), text("Hi"))]),
+            ExportFormat::pdf(),
+            &path,
+            |event| events.push(event.clone()),
+        )
+        .await
+        .expect("workflow");
+
+        assert_eq!(outcome.design.id, "design_9");
+        assert_eq!(outcome.paths, vec![path.clone()]);
+        assert_eq!(
+            std::fs::read(&path).expect("rendered file"),
+            b"%PDF-badge".to_vec()
+        );
+        assert_eq!(
+            events[..3],
+            [
+                RenderProgress::AutofillStarted {
+                    job_id: "job_9".to_string()
+                },
+                RenderProgress::DesignCreated {
+                    design_id: "design_9".to_string()
+                },
+                RenderProgress::ExportStarted {
+                    export_id: "export_9".to_string()
+                },
+            ]
+        );
+        assert!(matches!(
+            events.last(),
+            Some(RenderProgress::Downloading(progress)) if progress.total_bytes == 10
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_render_template_to_file_names_the_failed_step() {
+        let mut server = mockito::Server::new_async().await;
+        mock_autofill_to_design(&mut server).await;
+        server
+            .mock("GET", "/v1/exports/export_9")
+            .with_status(404)
+            .with_body(r#"{"code":"not_found","message":"export expired"}"#)
+            .create_async()
+            .await;
+
+        let path = std::env::temp_dir().join("canva-render-unused.pdf");
+        let error = render_template_to_file(
+            &client(&server),
+            "tpl_1",
+            HashMap::new(),
+            ExportFormat::pdf(),
+            &path,
+        )
+        .await
+        .expect_err("export lookup fails");
+
+        match &error {
+            Error::Workflow { step, source } => {
+                assert_eq!(step, "export design design_9");
+                assert!(matches!(**source, Error::Api { .. }));
+            }
+            other => panic!("expected a workflow error, got {other:?}"),
+        }
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_design_imports_the_pptx_export() {
+        let mut server = mockito::Server::new_async().await;
+        let export_url = format!("{}/files/deck.pptx?signature=abc", server.url());
+        server
+            .mock("POST", "/v1/exports")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "design_id": "DAF1",
+                "format": { "type": "pptx" }
+            })))
+            .with_body(r#"{"job":{"id":"export_1","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/exports/export_1")
+            .with_body(
+                serde_json::json!({
+                    "job": { "id": "export_1", "status": "success", "urls": [export_url] }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let import = server
+            .mock("POST", "/v1/url-imports")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "title": "Deck (copy)",
+                "url": export_url,
+                "mime_type": PPTX_MIME_TYPE
+            })))
+            .with_body(r#"{"job":{"id":"import_1","status":"in_progress"}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/url-imports/import_1")
+            .with_body(
+                serde_json::json!({
+                    "job": {
+                        "id": "import_1",
+                        "status": "success",
+                        "result": { "designs": [{
+                            "id": "DAF2",
+                            "title": "Deck (copy)",
+                            "urls": {
+                                "edit_url": "https://www.canva.com/edit",
+                                "view_url": "https://www.canva.com/view"
+                            },
+                            "created_at": 1_700_000_000,
+                            "updated_at": 1_700_000_000
+                        }]}
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let copy = duplicate_design(&client(&server), "DAF1", "Deck (copy)")
+            .await
+            .expect("workflow");
+
+        assert_eq!(copy.id, "DAF2");
+        assert_eq!(copy.title.as_deref(), Some("Deck (copy)"));
+        import.assert_async().await;
+    }
+}
@@ -0,0 +1,247 @@
+use canva_connect::{
+    auth::AccessToken,
+    cache::{CacheConfig, MemoryCache},
+    endpoints::assets::UpdateAssetRequest,
+    Client,
+};
+use mockito::Matcher;
+use std::sync::Arc;
+use std::time::Duration;
+
+const ASSET: &str = r#"{"asset":{"id":"asset_1","name":"Logo","tags":[],"type":"image","created_at":1700000000,"updated_at":1700000000}}"#;
+
+fn client(server: &mockito::Server, config: CacheConfig) -> Client {
+    Client::builder(AccessToken::new("test_token"))
+        .base_url(server.url())
+        .cache(config)
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_stale_response_is_revalidated_with_etag() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/v1/assets/asset_1")
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("etag", "\"v1\"")
+        .with_body(ASSET)
+        .expect(1)
+        .create_async()
+        .await;
+    let revalidated = server
+        .mock("GET", "/v1/assets/asset_1")
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = client(&server, CacheConfig::new());
+
+    for _ in 0..3 {
+        let asset = client.assets().get("asset_1").await.expect("asset");
+        assert_eq!(asset.name, "Logo");
+    }
+
+    first.assert_async().await;
+    revalidated.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fresh_response_is_served_without_request() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/assets/asset_1")
+        .with_status(200)
+        .with_body(ASSET)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = client(
+        &server,
+        CacheConfig::new().with_ttl(Duration::from_secs(60)),
+    );
+
+    client.assets().get("asset_1").await.expect("asset");
+    client.assets().get("asset_1").await.expect("cached asset");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_write_invalidates_cached_response() {
+    let mut server = mockito::Server::new_async().await;
+    let get = server
+        .mock("GET", "/v1/assets/asset_1")
+        .with_status(200)
+        .with_body(ASSET)
+        .expect(2)
+        .create_async()
+        .await;
+    let patch = server
+        .mock("PATCH", "/v1/assets/asset_1")
+        .with_status(200)
+        .with_body(ASSET)
+        .create_async()
+        .await;
+    let client = client(
+        &server,
+        CacheConfig::new().with_ttl(Duration::from_secs(60)),
+    );
+
+    client.assets().get("asset_1").await.expect("asset");
+    client
+        .assets()
+        .update(
+            "asset_1",
+            UpdateAssetRequest {
+                name: Some("Logo".to_string()),
+                tags: None,
+            },
+        )
+        .await
+        .expect("update");
+    client.assets().get("asset_1").await.expect("asset");
+
+    get.assert_async().await;
+    patch.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_response_without_etag_is_not_cached_without_ttl() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/assets/asset_1")
+        .with_status(200)
+        .with_body(ASSET)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = client(&server, CacheConfig::new());
+
+    client.assets().get("asset_1").await.expect("asset");
+    client.assets().get("asset_1").await.expect("asset");
+
+    mock.assert_async().await;
+    let key = client.cache_key("/v1/assets/asset_1").await.expect("key");
+    assert!(client.cache().expect("cache").get(&key).await.is_none());
+}
+
+#[tokio::test]
+async fn test_custom_store_is_used() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/v1/assets/asset_1")
+        .with_status(200)
+        .with_header("etag", "\"v1\"")
+        .with_body(ASSET)
+        .create_async()
+        .await;
+    let client = client(&server, CacheConfig::new().with_store(MemoryCache::new(1)));
+
+    client.assets().get("asset_1").await.expect("asset");
+
+    let key = client.cache_key("/v1/assets/asset_1").await.expect("key");
+    let cached = client
+        .cache()
+        .expect("cache")
+        .get(&key)
+        .await
+        .expect("entry");
+    assert_eq!(cached.etag(), Some("\"v1\""));
+}
+
+#[tokio::test]
+async fn test_job_status_is_not_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/exports/export_1")
+        .with_status(200)
+        .with_header("etag", "\"v1\"")
+        .with_body(r#"{"job":{"id":"export_1","status":"in_progress"}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = client(
+        &server,
+        CacheConfig::new().with_ttl(Duration::from_secs(60)),
+    );
+
+    client
+        .exports()
+        .get_design_export_job("export_1")
+        .await
+        .expect("job");
+    client
+        .exports()
+        .get_design_export_job("export_1")
+        .await
+        .expect("job");
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_shared_store_is_keyed_by_token() {
+    let mut server = mockito::Server::new_async().await;
+    let alice = server
+        .mock("GET", "/v1/assets/asset_1")
+        .match_header("authorization", "Bearer alice")
+        .with_status(200)
+        .with_body(ASSET)
+        .create_async()
+        .await;
+    let bob = server
+        .mock("GET", "/v1/assets/asset_1")
+        .match_header("authorization", "Bearer bob")
+        .with_status(200)
+        .with_body(ASSET)
+        .create_async()
+        .await;
+    let store = Arc::new(MemoryCache::default());
+    let client_for = |token: &str| {
+        Client::builder(AccessToken::new(token))
+            .base_url(server.url())
+            .cache(
+                CacheConfig::new()
+                    .with_ttl(Duration::from_secs(60))
+                    .with_store(Arc::clone(&store)),
+            )
+            .build()
+            .expect("Failed to create client")
+    };
+
+    client_for("alice")
+        .assets()
+        .get("asset_1")
+        .await
+        .expect("asset");
+    client_for("bob")
+        .assets()
+        .get("asset_1")
+        .await
+        .expect("asset");
+    client_for("alice")
+        .assets()
+        .get("asset_1")
+        .await
+        .expect("cached asset");
+
+    alice.assert_async().await;
+    bob.assert_async().await;
+}
+
+#[test]
+fn test_cache_config_defaults() {
+    let config = CacheConfig::default();
+    assert_eq!(config.ttl(), Duration::ZERO);
+    assert_eq!(
+        config.max_entries(),
+        canva_connect::cache::DEFAULT_MAX_ENTRIES
+    );
+    assert!(Client::new(AccessToken::new("token"))
+        .expect("client")
+        .cache()
+        .is_none());
+}
@@ -73,3 +73,154 @@ fn test_asset_upload_metadata_creation() {
     assert!(!metadata.name_base64.is_empty());
     assert!(metadata.tags.contains(&"design".to_string()));
 }
+
+#[tokio::test]
+async fn test_request_json_calls_unwrapped_endpoints() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Brand {
+        id: String,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Brands {
+        items: Vec<Brand>,
+    }
+
+    let mut server = mockito::Server::new_async().await;
+    let list = server
+        .mock("GET", "/v1/brands")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("limit".into(), "10".into()),
+            mockito::Matcher::UrlEncoded("query".into(), "spring & summer".into()),
+        ]))
+        .match_header("authorization", "Bearer test_token")
+        .with_status(200)
+        .with_body(r#"{"items":[{"id":"brand_1"}]}"#)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/v1/brands")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({ "name": "Autumn" }),
+        ))
+        .with_status(200)
+        .with_body(r#"{"id":"brand_2"}"#)
+        .create_async()
+        .await;
+    let delete = server
+        .mock("DELETE", "/v1/brands/brand_2")
+        .with_status(204)
+        .create_async()
+        .await;
+    let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+        .expect("Failed to create client");
+
+    let brands: Brands = client
+        .request_json(
+            reqwest::Method::GET,
+            "/v1/brands",
+            &[("limit", "10"), ("query", "spring & summer")],
+            None::<&()>,
+        )
+        .await
+        .expect("list");
+    assert_eq!(brands.items[0].id, "brand_1");
+
+    let brand: Brand = client
+        .request_json(
+            reqwest::Method::POST,
+            "/v1/brands",
+            &[],
+            Some(&serde_json::json!({ "name": "Autumn" })),
+        )
+        .await
+        .expect("create");
+    assert_eq!(brand.id, "brand_2");
+
+    let () = client
+        .request_json(
+            reqwest::Method::DELETE,
+            "/v1/brands/brand_2",
+            &[],
+            None::<&()>,
+        )
+        .await
+        .expect("delete");
+
+    list.assert_async().await;
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_json_maps_api_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/v1/preview/things")
+        .with_status(404)
+        .with_body(r#"{"code":"NOT_FOUND","message":"No such thing"}"#)
+        .create_async()
+        .await;
+    let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+        .expect("Failed to create client");
+
+    let result: Result<serde_json::Value, Error> = client
+        .request_json(reqwest::Method::GET, "/v1/preview/things", &[], None::<&()>)
+        .await;
+
+    assert!(matches!(result, Err(Error::Api { .. })));
+}
+
+#[tokio::test]
+async fn test_plain_text_error_responses_are_classified_by_status() {
+    let mut server = mockito::Server::new_async().await;
+    let _unavailable = server
+        .mock("GET", "/v1/designs")
+        .with_status(503)
+        .with_body("upstream connect error")
+        .create_async()
+        .await;
+    let _rate_limited = server
+        .mock("GET", "/v1/folders/FAF123")
+        .with_status(429)
+        .with_body("Too Many Requests")
+        .create_async()
+        .await;
+    let _not_found = server
+        .mock("GET", "/v1/assets/missing")
+        .with_status(404)
+        .create_async()
+        .await;
+    let client = Client::with_base_url(server.url(), AccessToken::new("test_token"))
+        .expect("Failed to create client");
+
+    let unavailable = client
+        .get("/v1/designs")
+        .await
+        .expect_err("503 should fail");
+    assert_eq!(
+        unavailable.recovery_hint(),
+        canva_connect::error::RecoveryHint::Backoff
+    );
+    assert!(unavailable.is_retryable());
+
+    let rate_limited = client
+        .get("/v1/folders/FAF123")
+        .await
+        .expect_err("429 should fail");
+    assert!(rate_limited.is_retryable());
+    assert!(matches!(
+        rate_limited,
+        Error::Api {
+            code: canva_connect::error::ApiErrorCode::TooManyRequests,
+            ..
+        }
+    ));
+
+    let not_found = client
+        .get("/v1/assets/missing")
+        .await
+        .expect_err("404 should fail");
+    assert!(not_found.is_not_found());
+    assert!(!not_found.is_retryable());
+}
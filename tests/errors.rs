@@ -81,6 +81,7 @@ fn test_api_error_creation() {
     let error = Error::Api {
         code: ApiErrorCode::NotFound,
         message: "Resource not found".to_string(),
+        context: None,
     };
 
     let error_str = error.to_string();
@@ -88,6 +89,21 @@ fn test_api_error_creation() {
     assert!(error_str.contains("Resource not found"));
 }
 
+#[test]
+fn test_api_error_without_context() {
+    let error = Error::from(ApiError {
+        code: "NOT_FOUND".to_string(),
+        message: "Resource not found".to_string(),
+    });
+
+    assert!(error.request_context().is_none());
+    assert!(error.request_id().is_none());
+    assert_eq!(
+        error.to_string(),
+        "API error: NOT_FOUND - Resource not found"
+    );
+}
+
 #[test]
 fn test_api_error_from_api_error_struct() {
     let api_error = ApiError {
@@ -97,7 +113,7 @@ fn test_api_error_from_api_error_struct() {
 
     let error = Error::from(api_error);
     match error {
-        Error::Api { code, message } => {
+        Error::Api { code, message, .. } => {
             assert_eq!(code, ApiErrorCode::Unauthorized);
             assert_eq!(message, "Invalid credentials");
         }
@@ -182,3 +198,125 @@ fn test_result_type_alias() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), "success");
 }
+
+fn api_error(code: &str, status: u16) -> Error {
+    Error::Api {
+        code: ApiErrorCode::from(code.to_string()),
+        message: "failed".to_string(),
+        context: Some(Box::new(RequestContext {
+            method: reqwest::Method::GET,
+            path: "/v1/designs".to_string(),
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            request_id: None,
+            elapsed: std::time::Duration::ZERO,
+        })),
+    }
+}
+
+#[test]
+fn test_recovery_hint_from_canva_codes() {
+    assert_eq!(
+        api_error("invalid_access_token", 401).recovery_hint(),
+        RecoveryHint::RefreshToken
+    );
+    assert_eq!(
+        api_error("missing_scope", 403).recovery_hint(),
+        RecoveryHint::CheckScopes
+    );
+    assert_eq!(
+        api_error("too_many_requests", 429).recovery_hint(),
+        RecoveryHint::Backoff
+    );
+    assert_eq!(
+        api_error("not_found", 404).recovery_hint(),
+        RecoveryHint::Permanent
+    );
+}
+
+#[test]
+fn test_recovery_hint_falls_back_to_status() {
+    assert_eq!(
+        api_error("some_new_code", 503).recovery_hint(),
+        RecoveryHint::Backoff
+    );
+    assert_eq!(
+        api_error("some_new_code", 401).recovery_hint(),
+        RecoveryHint::RefreshToken
+    );
+    assert_eq!(
+        api_error("some_new_code", 400).recovery_hint(),
+        RecoveryHint::Permanent
+    );
+
+    let without_context = Error::Api {
+        code: ApiErrorCode::Unknown("some_new_code".to_string()),
+        message: "failed".to_string(),
+        context: None,
+    };
+    assert_eq!(without_context.recovery_hint(), RecoveryHint::Permanent);
+}
+
+#[test]
+fn test_api_error_code_for_status() {
+    use reqwest::StatusCode;
+    assert_eq!(
+        ApiErrorCode::for_status(StatusCode::SERVICE_UNAVAILABLE),
+        ApiErrorCode::ServiceUnavailable
+    );
+    assert_eq!(
+        ApiErrorCode::for_status(StatusCode::TOO_MANY_REQUESTS),
+        ApiErrorCode::TooManyRequests
+    );
+    assert_eq!(
+        ApiErrorCode::for_status(StatusCode::BAD_GATEWAY),
+        ApiErrorCode::Unknown("502".to_string())
+    );
+}
+
+#[test]
+fn test_recovery_hint_for_downloads() {
+    let download = |status: u16| Error::Download {
+        status: reqwest::StatusCode::from_u16(status).expect("valid status"),
+        url: "https://export-download.canva.com/file.pdf".to_string(),
+    };
+    for status in [408, 429, 500, 503] {
+        assert_eq!(download(status).recovery_hint(), RecoveryHint::Backoff);
+    }
+    for status in [400, 401, 403, 404] {
+        assert_eq!(download(status).recovery_hint(), RecoveryHint::Permanent);
+    }
+}
+
+#[test]
+fn test_is_retryable() {
+    assert!(Error::RateLimit.is_retryable());
+    assert!(api_error("internal_failure", 500).is_retryable());
+    assert!(Error::Io(std::io::ErrorKind::TimedOut.into()).is_retryable());
+    assert!(!Error::Io(std::io::ErrorKind::NotFound.into()).is_retryable());
+    assert!(!api_error("invalid_access_token", 401).is_retryable());
+    assert!(!Error::ShutDown.is_retryable());
+    assert!(!Error::Generic("bad input".to_string()).is_retryable());
+
+    let workflow = Error::Workflow {
+        step: "export design DAF123".to_string(),
+        source: Box::new(api_error("too_many_requests", 429)),
+    };
+    assert!(workflow.is_retryable());
+}
+
+#[test]
+fn test_is_not_found() {
+    assert!(api_error("not_found", 404).is_not_found());
+    assert!(api_error("design_not_found", 404).is_not_found());
+    assert!(!api_error("not_found", 403).is_not_found());
+    assert!(!Error::Io(std::io::ErrorKind::NotFound.into()).is_not_found());
+
+    for code in ["not_found", "NOT_FOUND"] {
+        let without_context = Error::Api {
+            code: ApiErrorCode::from(code.to_string()),
+            message: "No such asset".to_string(),
+            context: None,
+        };
+        assert!(without_context.is_not_found(), "{code}");
+    }
+}
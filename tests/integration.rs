@@ -19,6 +19,12 @@
 //! CANVA_INTEGRATION_TESTS=1 cargo test
 //! ```
 //!
+//! These tests talk to the real API on purpose, to catch differences between
+//! Canva and the crate's fixtures, so they do not use
+//! `canva_connect::testing::MockCanvaServer`. Tests that should run offline
+//! belong in the other suites, which can use the harness with the
+//! `test-util` feature.
+//!
 //! ## Safety
 //!
 //! - Tests only read data or create temporary assets that are cleaned up
@@ -195,6 +201,9 @@ async fn test_get_user_capabilities() {
             canva_connect::endpoints::user::Capability::Resize => {
                 println!("  - Has resize capability");
             }
+            canva_connect::endpoints::user::Capability::Unknown => {
+                println!("  - Has a capability this crate does not know about");
+            }
         }
     }
 
@@ -258,7 +267,8 @@ async fn test_url_asset_upload_workflow() {
                     .unwrap_or_else(|| "Unknown error".to_string());
                 panic!("Upload failed: {error_msg}");
             }
-            canva_connect::models::JobStatus::InProgress => {
+            canva_connect::models::JobStatus::InProgress
+            | canva_connect::models::JobStatus::Unknown => {
                 if attempts >= max_attempts {
                     panic!("Upload timed out after {max_attempts} seconds");
                 }
@@ -330,7 +340,7 @@ async fn test_asset_error_handling() {
     assert!(result.is_err());
 
     match result {
-        Err(canva_connect::Error::Api { code, message }) => {
+        Err(canva_connect::Error::Api { code, message, .. }) => {
             println!("✅ Correct error for non-existent asset: {code} - {message}");
         }
         _ => panic!("Expected API error for non-existent asset"),
@@ -342,6 +352,7 @@ async fn test_asset_error_handling() {
 }
 
 // Designs API integration tests
+use canva_connect::endpoints::designs::ListDesignsRequest;
 use canva_connect::models::{
     CreateDesignRequest, DesignTypeInput, OwnershipType, PresetDesignTypeName, SortByType,
 };
@@ -354,7 +365,7 @@ async fn test_list_designs() {
 
     // Test listing designs without filters
     let designs = designs_api
-        .list(None, None, None, None)
+        .list_with(&ListDesignsRequest::default())
         .await
         .expect("Failed to list designs");
 
@@ -373,11 +384,11 @@ async fn test_list_designs() {
 
     // Test with filters
     let filtered_designs = designs_api
-        .list(
-            None,
-            None,
-            Some(OwnershipType::Any),
-            Some(SortByType::ModifiedDescending),
+        .list_with(
+            &ListDesignsRequest::builder()
+                .ownership(OwnershipType::Any)
+                .sort_by(SortByType::ModifiedDescending)
+                .build(),
         )
         .await
         .expect("Failed to list filtered designs");
@@ -501,7 +512,7 @@ async fn test_design_error_handling() {
     assert!(result.is_err());
 
     match result {
-        Err(canva_connect::Error::Api { code, message }) => {
+        Err(canva_connect::Error::Api { code, message, .. }) => {
             println!("✅ Correct error for non-existent design: {code} - {message}");
         }
         _ => panic!("Expected API error for non-existent design"),
@@ -129,6 +129,24 @@ fn test_asset_deserialization() {
     }
 }
 
+#[test]
+fn test_asset_keeps_unknown_fields() {
+    let json = json!({
+        "id": "asset_123",
+        "name": "test_asset",
+        "tags": [],
+        "type": "image",
+        "thumbnail": null,
+        "created_at": 1640995200,
+        "updated_at": 1640995200,
+        "import_status": { "state": "success" }
+    });
+
+    let asset: Asset = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(asset.extra["import_status"]["state"], "success");
+    assert_eq!(serde_json::to_value(&asset).unwrap(), json);
+}
+
 #[test]
 fn test_thumbnail_deserialization() {
     let json = json!({
@@ -209,3 +227,189 @@ fn test_datetime_deserialization() {
     let timestamp: DateTime<Utc> = serde_json::from_value(json).unwrap();
     assert_eq!(timestamp, DateTime::from_timestamp(1640995200, 0).unwrap());
 }
+
+#[test]
+fn test_page_next_request_carries_continuation() {
+    use canva_connect::endpoints::designs::ListDesignsRequest;
+
+    let page: Page<Design> = serde_json::from_value(json!({
+        "items": [],
+        "continuation": "page_2"
+    }))
+    .unwrap();
+    assert!(page.has_more());
+
+    let request = ListDesignsRequest::builder()
+        .query("cats")
+        .limit(10)
+        .build();
+    let next = page.next_request(&request).unwrap();
+    assert_eq!(next.continuation.as_deref(), Some("page_2"));
+    assert_eq!(next.query.as_deref(), Some("cats"));
+    assert_eq!(next.limit, Some(10));
+
+    let last: Page<Design> = serde_json::from_value(json!({ "items": [] })).unwrap();
+    assert!(!last.has_more());
+    assert!(last.next_request(&next).is_none());
+}
+
+#[test]
+fn test_unknown_enum_values_deserialize() {
+    let status: JobStatus = serde_json::from_value(json!("queued")).unwrap();
+    assert_eq!(status, JobStatus::Unknown);
+
+    let asset_type: AssetType = serde_json::from_value(json!("model_3d")).unwrap();
+    assert!(matches!(asset_type, AssetType::Unknown));
+
+    let code: DesignImportErrorCode = serde_json::from_value(json!("file_too_large")).unwrap();
+    assert_eq!(code, DesignImportErrorCode::Unknown);
+    assert_eq!(code.to_string(), "unknown");
+
+    let field: DataField = serde_json::from_value(json!({
+        "type": "video",
+        "label": "Intro clip"
+    }))
+    .unwrap();
+    assert!(matches!(field, DataField::Unknown));
+
+    let capabilities: canva_connect::endpoints::user::GetUserCapabilitiesResponse =
+        serde_json::from_value(json!({ "capabilities": ["autofill", "teleport"] })).unwrap();
+    assert!(matches!(
+        capabilities.capabilities[1],
+        canva_connect::endpoints::user::Capability::Unknown
+    ));
+}
+
+#[test]
+fn test_unknown_item_types_do_not_break_listings() {
+    let items: Page<FolderItemSummary> = serde_json::from_value(json!({
+        "items": [
+            { "type": "video", "video": { "id": "V1" } },
+            {
+                "type": "folder",
+                "folder": {
+                    "id": "FAF2lZtloor",
+                    "name": "Holiday",
+                    "created_at": 1700000000,
+                    "updated_at": 1700000000
+                }
+            }
+        ]
+    }))
+    .unwrap();
+    assert!(matches!(items.items[0], FolderItemSummary::Unknown));
+    assert!(matches!(items.items[1], FolderItemSummary::Folder { .. }));
+
+    let job: DesignAutofillJob = serde_json::from_value(json!({
+        "id": "job_1",
+        "status": "queued",
+        "result": { "type": "update_design", "design": {} }
+    }))
+    .unwrap();
+    assert!(matches!(job.status, DesignAutofillStatus::Unknown));
+    assert!(matches!(job.result, Some(DesignAutofillJobResult::Unknown)));
+}
+
+#[test]
+fn test_folder_timestamps_are_datetimes() {
+    let folder: Folder = serde_json::from_value(json!({
+        "id": "FAF2lZtloor",
+        "name": "Holiday",
+        "created_at": 1640995200,
+        "updated_at": 1641081600
+    }))
+    .unwrap();
+    assert_eq!(
+        folder.created_at,
+        DateTime::from_timestamp(1640995200, 0).unwrap()
+    );
+    assert_eq!(
+        folder.updated_at - folder.created_at,
+        chrono::Duration::days(1)
+    );
+
+    let json = serde_json::to_value(&folder).unwrap();
+    assert_eq!(json["created_at"], 1640995200);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_timestamps_convert_to_time() {
+    let timestamp = DateTime::from_timestamp(1640995200, 500).unwrap();
+    let converted = timestamp.to_offset_date_time().unwrap();
+    assert_eq!(converted.unix_timestamp(), 1640995200);
+    assert_eq!(converted.nanosecond(), 500);
+    assert_eq!(converted.offset(), time::UtcOffset::UTC);
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_json_schemas_match_serialization() {
+    use canva_connect::endpoints::{exports::CreateDesignExportJobRequest, user::Capability};
+
+    let design = serde_json::to_value(schemars::schema_for!(Design)).unwrap();
+    let properties = &design["properties"];
+    assert_eq!(properties["created_at"]["type"], "integer");
+    assert_eq!(properties["id"]["type"], "string");
+    assert!(design["required"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("owner")));
+
+    let capability = serde_json::to_value(schemars::schema_for!(Capability)).unwrap();
+    assert!(capability.to_string().contains("brand_template"));
+
+    let request =
+        serde_json::to_value(schemars::schema_for!(CreateDesignExportJobRequest)).unwrap();
+    assert!(request["properties"]["design_id"].is_object());
+}
+
+/// Check that every value displays as its wire form and parses back
+fn assert_round_trips<T>(values: &[T])
+where
+    T: std::fmt::Display
+        + std::str::FromStr<Err = canva_connect::Error>
+        + serde::Serialize
+        + PartialEq
+        + std::fmt::Debug,
+{
+    for value in values {
+        let text = value.to_string();
+        assert_eq!(serde_json::to_value(value).unwrap(), json!(text));
+        assert_eq!(&text.parse::<T>().unwrap(), value);
+        assert_eq!(&text.to_uppercase().parse::<T>().unwrap(), value);
+    }
+}
+
+#[test]
+fn test_api_enums_display_and_parse_wire_values() {
+    use canva_connect::endpoints::user::Capability;
+
+    assert_round_trips(&AssetType::ALL);
+    assert_round_trips(&PresetDesignTypeName::ALL);
+    assert_round_trips(&DesignResizeErrorCode::ALL);
+    assert_round_trips(&OwnershipType::ALL);
+    assert_round_trips(&SortByType::ALL);
+    assert_round_trips(&SuggestionStatus::ALL);
+    assert_round_trips(&ExportPageSize::ALL);
+    assert_round_trips(&ExportQuality::ALL);
+    assert_round_trips(&JobStatus::ALL);
+    assert_round_trips(&DesignAutofillStatus::ALL);
+    assert_round_trips(&AutofillErrorCode::ALL);
+    assert_round_trips(&DesignImportErrorCode::ALL);
+    assert_round_trips(&DatasetFilter::ALL);
+    assert_round_trips(&Capability::ALL);
+}
+
+#[test]
+fn test_parsing_unknown_values_lists_the_accepted_ones() {
+    let error = "newest".parse::<SortByType>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unknown sort order \"newest\"; expected one of relevance, modified_descending, \
+         modified_ascending, title_descending, title_ascending"
+    );
+
+    assert!("unknown".parse::<JobStatus>().is_err());
+    assert_eq!(JobStatus::Unknown.to_string(), "unknown");
+}
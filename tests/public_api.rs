@@ -0,0 +1,1508 @@
+//! Semver guard for the public API.
+//!
+//! These tests pin the shape of the crate's public surface in code, in the
+//! spirit of a `cargo public-api` snapshot. The async signature checks are
+//! compiled but never executed; if a method is renamed, gains a parameter or
+//! changes its return type, this file stops compiling. Exhaustive matches and
+//! struct literals catch added enum variants and struct fields, which are
+//! breaking changes for downstream code.
+//!
+//! When a change here is intentional, keep the old signature working through
+//! the `compat` module for a release cycle and update this file in the same
+//! commit.
+
+#![allow(dead_code, deprecated)]
+
+use canva_connect::{
+    auth::{
+        AccessToken, FileTokenStorage, OAuthClient, OAuthConfig, PkceParams, Scope, ScopeSet,
+        TokenProvider, TokenSet, TokenStore,
+    },
+    cache::{CacheConfig, CachedResponse, MemoryCache, ResponseCache, DEFAULT_MAX_ENTRIES},
+    client::{
+        CanvaTransport, HeaderProvider, HttpTransport, OperationClass, RequestInterceptor,
+        ResponseContext, ResponseInterceptor,
+    },
+    config::{CanvaConfig, PollingProfile},
+    endpoints::{
+        assets::{
+            AssetUploadMetadata, BulkDeleteSummary, CreateUrlAssetUploadJobRequest,
+            DirectoryUploadOptions, DirectoryUploadProgress, DirectoryUploadSummary,
+            DownloadedThumbnail, FailedDeletion, FailedUpload, FileUploadStateStore,
+            FileUploadStatus, MemoryUploadStateStore, ResumableUpload, UpdateAssetRequest,
+            UploadStateStore, UploadedFile, DEFAULT_DELETE_CONCURRENCY, DEFAULT_UPLOAD_CONCURRENCY,
+            MAX_ASSET_NAME_CHARS, MAX_ASSET_TAGS, MAX_ASSET_TAG_CHARS,
+        },
+        brand_templates::{ListBrandTemplatesRequest, ListBrandTemplatesRequestBuilder},
+        designs::{ListDesignsRequest, ListDesignsRequestBuilder},
+        exports::{
+            CreateDesignExportJobRequest, DownloadedExport, ExportDownloadOptions,
+            ExportedDocument, ExportedPage,
+        },
+        folders::{
+            BulkMoveSummary, CreateFolderRequest, ListFolderItemsRequest,
+            ListFolderItemsRequestBuilder, MoveFolderItemRequest, UpdateFolderRequest,
+            DEFAULT_MOVE_CONCURRENCY,
+        },
+        user::Capability,
+    },
+    error::{ApiErrorCode, Error, Result},
+    links::DeepLink,
+    models::*,
+    rate_limit::{
+        ApiRateLimiter, EndpointRateLimits, Priority, RateLimitInfo, ThrottleEvent,
+        ThrottleListener, ThrottleReason,
+    },
+    retry::RetryPolicy,
+    uploads::{
+        AssetFormat, AssetKind, UploadCandidate, UploadPrecheck, UploadRejection, UploadSource,
+    },
+    Client, ClientBuilder,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug)]
+struct AllowAll;
+
+#[async_trait::async_trait]
+impl UploadPrecheck for AllowAll {
+    async fn check(
+        &self,
+        _upload: &UploadCandidate<'_>,
+    ) -> std::result::Result<(), UploadRejection> {
+        Ok(())
+    }
+}
+
+#[test]
+fn upload_signatures() {
+    let _: fn(&AssetUploadMetadata) -> Result<String> = AssetUploadMetadata::name;
+    let _: fn(&AssetUploadMetadata) -> Result<String> = AssetUploadMetadata::to_header_value;
+    let _: [usize; 3] = [MAX_ASSET_NAME_CHARS, MAX_ASSET_TAGS, MAX_ASSET_TAG_CHARS];
+    let _: fn(String) -> UploadRejection = UploadRejection::new;
+    let _: fn(UploadRejection, String) -> UploadRejection = UploadRejection::with_policy;
+    let _ = |upload: &UploadCandidate<'_>| {
+        let _: &str = upload.name();
+        let _: &[String] = upload.tags();
+        let _: Option<u64> = upload.size();
+        let _: UploadSource<'_> = upload.source();
+        let _: Vec<&[u8]> = upload.chunks(1024).collect();
+    };
+    let _ = UploadSource::Url("https://example.com/a.png");
+
+    let _: fn(&str, &[u8]) -> Result<AssetFormat> = canva_connect::uploads::validate_asset;
+    let _: fn(&[u8]) -> Option<AssetFormat> = AssetFormat::detect;
+    let _: fn(AssetFormat) -> &'static str = AssetFormat::mime_type;
+    let _: fn(AssetFormat) -> AssetKind = AssetFormat::kind;
+    let _: fn(AssetKind) -> u64 = AssetKind::max_size;
+    let _ = |kind: AssetKind| match kind {
+        AssetKind::Image | AssetKind::Video => {}
+    };
+    let _: fn(ClientBuilder, bool) -> ClientBuilder = ClientBuilder::validate_uploads;
+    let _: fn(ClientBuilder, bool) -> ClientBuilder = ClientBuilder::rate_limit_downloads;
+}
+
+#[cfg(feature = "experimental")]
+async fn experimental_signatures(client: Client) -> Result<()> {
+    use canva_connect::experimental::comments::{
+        CommentMessageBuilder, CreateReplyRequest, CreateThreadRequest, ListRepliesRequest,
+        ListRepliesRequestBuilder, MAX_COMMENT_CHARS,
+    };
+    use canva_connect::experimental::imports::{CreateUrlImportJobRequest, DesignImportMetadata};
+
+    let imports = client.imports();
+    let _: DesignImportJob = imports
+        .create_import_job(Vec::new(), DesignImportMetadata::new("title"))
+        .await?;
+    let _: DesignImportJob = imports.get_import_job("id").await?;
+    let _: DesignImportJob = imports
+        .create_url_import_job(CreateUrlImportJobRequest {
+            title: String::new(),
+            url: String::new(),
+            mime_type: None,
+        })
+        .await?;
+    let _: DesignImportJob = imports.get_url_import_job("id").await?;
+    let _: Vec<DesignSummary> = imports.wait_for_import_job("id").await?;
+    let _: Vec<DesignSummary> = imports.wait_for_url_import_job("id").await?;
+    let _: canva_connect::endpoints::DesignImportApi = imports;
+
+    let comments = client.comments();
+    let thread_request = CreateThreadRequest {
+        message_plaintext: String::new(),
+        assignee_id: None,
+    };
+    let _ = comments.create_thread("design", &thread_request).await?;
+    let _ = comments.get_thread("design", "thread").await?;
+    let reply_request = CreateReplyRequest {
+        message_plaintext: String::new(),
+    };
+    let _ = comments
+        .create_reply("design", "thread", &reply_request)
+        .await?;
+    let _ = comments.get_reply("design", "thread", "reply").await?;
+    let builder: CommentMessageBuilder = CommentMessageBuilder::new()
+        .text("hi ")
+        .mention(&TeamUserSummary {
+            user_id: "u".to_string(),
+            team_id: "t".to_string(),
+        })
+        .mention_ids("u", "t")
+        .assign_to_last_mention();
+    let _: CreateThreadRequest = builder.clone().build_thread()?;
+    let _: CreateReplyRequest = builder.build_reply()?;
+    let _: usize = MAX_COMMENT_CHARS;
+    let list_replies = ListRepliesRequest {
+        limit: None,
+        continuation: None,
+    };
+    let _ = comments
+        .list_replies("design", "thread", &list_replies)
+        .await?;
+    let _: ListRepliesRequestBuilder = ListRepliesRequest::builder();
+    let _: ListRepliesRequest = ListRepliesRequest::builder()
+        .continuation("c")
+        .limit(10)
+        .build();
+    let _: fn(&mut ListRepliesRequest, Option<String>) = PageRequest::set_continuation;
+    let _: canva_connect::endpoints::CommentsApi = comments;
+    Ok(())
+}
+
+#[cfg(feature = "experimental")]
+async fn workflow_signatures(client: Client) -> Result<()> {
+    use canva_connect::workflows::{
+        find_template_and_autofill, render_template_to_file, render_template_to_file_with_progress,
+        AutofillOptions, AutofillOutcome, AutofillReport, RenderOutcome, RenderProgress,
+        SkippedField,
+    };
+
+    let options = AutofillOptions {
+        design_title: None,
+        exact_title: false,
+        strict: false,
+    };
+    let outcome: AutofillOutcome =
+        find_template_and_autofill(&client, "title", HashMap::new(), options).await?;
+    let AutofillOutcome {
+        template: _,
+        design: _,
+        report,
+    } = outcome;
+    let AutofillReport {
+        ignored_fields: _,
+        skipped_fields,
+        unfilled_fields: _,
+    } = report.clone();
+    let _: Vec<SkippedField> = skipped_fields;
+    let _: fn(&AutofillReport) -> bool = AutofillReport::is_clean;
+    let _ = SkippedField {
+        name: String::new(),
+        reason: String::new(),
+    };
+
+    let RenderOutcome {
+        design: _,
+        export: _,
+        paths,
+    } = render_template_to_file(
+        &client,
+        "id",
+        HashMap::new(),
+        ExportFormat::pdf(),
+        "out.pdf",
+    )
+    .await?;
+    let _: Vec<std::path::PathBuf> = paths;
+    let _: RenderOutcome = render_template_to_file_with_progress(
+        &client,
+        "id",
+        HashMap::new(),
+        ExportFormat::png(),
+        "out.png",
+        |progress: &RenderProgress| {
+            let _ = progress;
+        },
+    )
+    .await?;
+    let _: canva_connect::models::DesignSummary =
+        canva_connect::workflows::duplicate_design(&client, "id", "title").await?;
+    Ok(())
+}
+
+async fn observability_signatures() {
+    use canva_connect::observability::{is_tracing_active, try_init_tracing, TracingStatus};
+
+    let _: fn() -> bool = is_tracing_active;
+    #[cfg(feature = "observability")]
+    let _: tracing::Span = canva_connect::observability::workflow_span("asset_upload");
+    let _: TracingStatus = try_init_tracing("service", None).await;
+    let _: fn(&TracingStatus) -> bool = TracingStatus::is_exporting;
+    let _ = |status: TracingStatus| match status {
+        TracingStatus::Otlp(_guard) => {}
+        TracingStatus::Logging { reason } => drop(reason),
+        TracingStatus::External | TracingStatus::Disabled => {}
+        _ => {}
+    };
+}
+
+#[cfg(feature = "oauth-flow")]
+async fn callback_server_signatures() -> Result<()> {
+    use canva_connect::auth::callback_server::{listen_for_callback, CallbackServer};
+
+    let _: String = listen_for_callback("uri", "state", Duration::ZERO).await?;
+    let server: CallbackServer = CallbackServer::bind("uri", "state").await?;
+    let _: std::net::SocketAddr = server.local_addr();
+    let _: String = server.wait(Duration::ZERO).await?;
+    Ok(())
+}
+
+#[cfg(feature = "oauth-flow")]
+async fn authorize_interactive_signatures(client: OAuthClient) -> Result<()> {
+    let _: Duration = canva_connect::auth::DEFAULT_AUTHORIZE_TIMEOUT;
+    let _: TokenSet = client.authorize_interactive(|_url: &str| {}).await?;
+    let _: TokenSet = client
+        .authorize_with(|_url: &str| {}, Duration::ZERO)
+        .await?;
+    Ok(())
+}
+
+async fn authorize_manual_signatures(client: OAuthClient) -> Result<()> {
+    use canva_connect::auth::manual;
+
+    let _: String = manual::parse_authorization_response("code", "state")?;
+    let _: TokenSet = client.authorize_manual(manual::stdin_prompt).await?;
+    let _: TokenSet = client
+        .authorize_manual(|_url: String| async { Ok("code".to_string()) })
+        .await?;
+    Ok(())
+}
+
+#[test]
+fn query_signatures() {
+    use canva_connect::query::{QueryBuilder, QueryValue};
+    use std::borrow::Cow;
+
+    let _: fn() -> QueryBuilder = QueryBuilder::new;
+    let query: QueryBuilder = QueryBuilder::new()
+        .param("k", "v")
+        .param("n", 1u32)
+        .param("s", SortByType::Relevance)
+        .param("o", OwnershipType::Any)
+        .param_opt("c", None::<String>);
+    let _: bool = query.is_empty();
+    let _: &[(String, String)] = query.pairs();
+    let _: String = query.append_to("/v1/path");
+    let _: Cow<'_, str> = "v".to_query_value();
+}
+
+/// Compiled, never run: pins the job polling API.
+async fn jobs_signatures(client: Client) -> Result<()> {
+    use canva_connect::endpoints::{
+        assets::{PendingUploadJob, PendingUrlUploadJob},
+        autofill::PendingAutofillJob,
+        designs::PendingResizeJob,
+        exports::PendingExportJob,
+    };
+    use canva_connect::jobs::{
+        CancellationToken, JobPollConfig, JobPoller, PollableJob, WaitOptions, BATCH_POLL_JITTER,
+        DEFAULT_MAX_POLL_INTERVAL, DEFAULT_POLL_INTERVAL, MAX_POLL_JITTER,
+    };
+
+    let _: Duration = DEFAULT_MAX_POLL_INTERVAL;
+    let _: f64 = MAX_POLL_JITTER;
+    let poller: JobPoller = JobPoller::new(Duration::from_secs(1))
+        .with_backoff(2.0, Duration::from_secs(10))
+        .with_timeout(Duration::from_secs(60))
+        .with_cancellation(CancellationToken::new());
+    let _: Duration = poller.interval();
+    let _: f64 = poller.backoff();
+    let _: Duration = poller.max_interval();
+    let _: f64 = poller.clone().with_jitter(BATCH_POLL_JITTER).jitter();
+    let _: Option<Duration> = poller.timeout();
+    let options: WaitOptions = WaitOptions::new()
+        .with_timeout(Duration::from_secs(60))
+        .with_cancellation(CancellationToken::new())
+        .with_poll_interval(Duration::from_secs(1));
+    let _: Option<Duration> = options.timeout();
+    let _: Option<&CancellationToken> = options.cancellation();
+    let _: Option<Duration> = options.poll_interval();
+    let poller: JobPoller = client.job_poller().with_options(&options);
+    let config: JobPollConfig = JobPollConfig::new()
+        .with_interval(DEFAULT_POLL_INTERVAL)
+        .with_backoff(1.5, Duration::from_secs(30))
+        .with_timeout(Duration::from_secs(600));
+    let _: (Duration, f64, Duration, Option<Duration>) = (
+        config.interval(),
+        config.backoff(),
+        config.max_interval(),
+        config.timeout(),
+    );
+    let _: JobPoller = config.poller();
+    let _: f64 = JobPollConfig::batch().with_jitter(0.1).jitter();
+    let _: &JobPollConfig = client.job_poll_config();
+    let _: ClientBuilder = Client::builder(AccessToken::new("token")).job_poll_config(config);
+
+    {
+        use canva_connect::jobs::{JobEvent, JobKind, JobManager, JobOutput, TrackedJob};
+        let manager: JobManager = JobManager::new(client.clone()).with_poller(poller.clone());
+        let _: tokio::sync::broadcast::Receiver<JobEvent> = manager.subscribe();
+        let job: TrackedJob = manager.track(JobKind::Export, "id");
+        let _: bool = job.is_finished();
+        let _: (String, JobKind, JobStatus, u32) = (job.id, job.kind, job.status, job.checks);
+        let _: Option<TrackedJob> = manager.get("id");
+        manager.resume(manager.jobs());
+        let _: Vec<TrackedJob> = manager.outstanding();
+        let _: Vec<(TrackedJob, Result<JobOutput>)> = manager.await_all(4).await;
+        manager.clear_finished();
+        let _ = [
+            JobKind::AssetUpload,
+            JobKind::UrlAssetUpload,
+            JobKind::Autofill,
+            JobKind::Export,
+            JobKind::Import,
+            JobKind::UrlImport,
+            JobKind::Resize,
+        ];
+    }
+
+    let _: ExportJob = client
+        .exports()
+        .wait_for_export_job_with("id", &options)
+        .await?;
+    let _: Asset = client
+        .assets()
+        .wait_for_upload_job_with("id", &options)
+        .await?;
+    let _: Asset = client
+        .assets()
+        .wait_for_url_upload_job_with("id", &options)
+        .await?;
+    let _: DesignAutofillJob = client
+        .autofill()
+        .wait_for_autofill_job_with("id", &options)
+        .await?;
+    let _: DesignSummary = client
+        .designs()
+        .wait_for_resize_job_with("id", &options)
+        .await?;
+    #[cfg(feature = "experimental")]
+    {
+        let _: Vec<DesignSummary> = client
+            .imports()
+            .wait_for_import_job_with("id", &options)
+            .await?;
+        let _: Vec<DesignSummary> = client
+            .imports()
+            .wait_for_url_import_job_with("id", &options)
+            .await?;
+    }
+
+    let export: PendingExportJob = client.exports().pending_export_job("id");
+    let _: &str = export.id();
+    let _: Option<ExportJob> = export.poll().await?;
+    let _: ExportJob = poller.wait(&export).await?;
+    let _: Asset = poller
+        .wait::<PendingUploadJob>(&client.assets().pending_upload_job("id"))
+        .await?;
+    let _: Asset = poller
+        .wait::<PendingUrlUploadJob>(&client.assets().pending_url_upload_job("id"))
+        .await?;
+    let _: DesignAutofillJob = poller
+        .wait::<PendingAutofillJob>(&client.autofill().pending_autofill_job("id"))
+        .await?;
+    let _: DesignSummary = poller
+        .wait::<PendingResizeJob>(&client.designs().pending_resize_job("id"))
+        .await?;
+    #[cfg(feature = "experimental")]
+    {
+        use canva_connect::endpoints::imports::{PendingImportJob, PendingUrlImportJob};
+        let _: Vec<DesignSummary> = poller
+            .wait::<PendingImportJob>(&client.imports().pending_import_job("id"))
+            .await?;
+        let _: Vec<DesignSummary> = poller
+            .wait::<PendingUrlImportJob>(&client.imports().pending_url_import_job("id"))
+            .await?;
+    }
+    Ok(())
+}
+
+#[test]
+fn debug_log_signatures() {
+    use canva_connect::debug_log::{
+        redact_body, redact_headers, redact_url, DebugLogConfig, DebugLogSink, HttpLogEntry,
+        StderrLogSink, REDACTED,
+    };
+
+    let _: &str = REDACTED;
+    let config: DebugLogConfig = DebugLogConfig::new()
+        .with_bodies(512)
+        .with_sink(StderrLogSink);
+    let _: Option<usize> = config.max_body_bytes();
+    let _: ClientBuilder = Client::builder(AccessToken::new("token")).debug_log(config);
+    let _: fn(&reqwest::header::HeaderMap) -> Vec<(String, String)> = redact_headers;
+    let _: fn(&reqwest::Url) -> String = redact_url;
+    let _: fn(&[u8], usize) -> String = redact_body;
+    let entry = HttpLogEntry {
+        method: String::new(),
+        url: String::new(),
+        attempt: 1,
+        status: None,
+        elapsed: Duration::ZERO,
+        request_id: None,
+        request_headers: Vec::new(),
+        request_body: None,
+        response_body: None,
+        error: None,
+    };
+    let _: String = entry.to_string();
+    let _: fn(&StderrLogSink, &HttpLogEntry) = <StderrLogSink as DebugLogSink>::log;
+}
+
+#[test]
+fn stability_signatures() {
+    use canva_connect::stability::{ApiStability, Stability};
+
+    let _: fn(Stability) -> bool = Stability::is_stable;
+    let _ = |stability: Stability| match stability {
+        Stability::Experimental | Stability::Preview | Stability::Stable => {}
+    };
+    let _: fn(&canva_connect::endpoints::AssetsApi) -> Stability = ApiStability::stability;
+    #[cfg(feature = "experimental")]
+    let _: Stability = <canva_connect::endpoints::CommentsApi as ApiStability>::STABILITY;
+}
+
+#[test]
+fn export_format_signatures() {
+    let _: fn() -> ExportFormat = ExportFormat::pdf;
+    let _: fn(u8) -> ExportFormat = ExportFormat::jpg;
+    let _: fn() -> ExportFormat = ExportFormat::png;
+    let _: fn() -> ExportFormat = ExportFormat::pptx;
+    let _: fn() -> ExportFormat = ExportFormat::gif;
+    let _: fn() -> ExportFormat = ExportFormat::mp4;
+    let _: fn() -> ExportFormat = ExportFormat::pdf_print_a4;
+    let _: fn() -> ExportFormat = ExportFormat::pdf_print_letter;
+    let _: fn() -> ExportFormat = ExportFormat::social_square_jpg;
+    let _: fn() -> ExportFormat = ExportFormat::social_story_jpg;
+    let _: fn() -> ExportFormat = ExportFormat::full_hd_png;
+    let _: fn(ExportFormat, ExportQuality) -> ExportFormat = ExportFormat::export_quality;
+    let _: fn(ExportFormat, Vec<u32>) -> ExportFormat = ExportFormat::pages;
+    let _: fn(ExportFormat, u32) -> ExportFormat = ExportFormat::width;
+    let _: fn(ExportFormat, u32) -> ExportFormat = ExportFormat::height;
+    let _: fn(ExportFormat, ExportPageSize) -> ExportFormat = ExportFormat::size;
+    let _: fn(ExportFormat, u8) -> ExportFormat = ExportFormat::quality;
+}
+
+#[test]
+fn link_signatures() {
+    let _: fn(&str) -> Result<DeepLink> = DeepLink::parse;
+    let _: fn(DeepLink, &str, &str) -> DeepLink = DeepLink::param;
+    let _: fn(DeepLink, &str) -> DeepLink = DeepLink::utm_source;
+    let _: fn(DeepLink, &str) -> DeepLink = DeepLink::utm_medium;
+    let _: fn(DeepLink, &str) -> DeepLink = DeepLink::utm_campaign;
+    let _: fn(DeepLink, &str) -> DeepLink = DeepLink::utm_content;
+    let _: fn(&DeepLink) -> &reqwest::Url = DeepLink::url;
+    let _: fn(&DeepLink) -> &str = DeepLink::as_str;
+    let _: fn(&DesignLinks) -> Result<DeepLink> = DesignLinks::edit_link;
+    let _: fn(&DesignLinks) -> Result<DeepLink> = DesignLinks::view_link;
+    let _: fn(&Design) -> Result<DeepLink> = Design::edit_link;
+    let _: fn(&Design) -> Result<DeepLink> = Design::view_link;
+    let _: fn(&DesignSummary) -> Result<DeepLink> = DesignSummary::edit_link;
+    let _: fn(&DesignSummary) -> Result<DeepLink> = DesignSummary::view_link;
+    let _: fn(&BrandTemplate) -> Result<DeepLink> = BrandTemplate::view_link;
+    let _: fn(&BrandTemplate) -> Result<DeepLink> = BrandTemplate::create_link;
+}
+
+#[test]
+fn config_signatures() {
+    let _: fn() -> CanvaConfig = CanvaConfig::new;
+    let _: fn() -> Result<CanvaConfig> = CanvaConfig::from_env;
+    let _: fn(CanvaConfig) -> Result<CanvaConfig> = CanvaConfig::with_env_overrides;
+    type Lookup = fn(&str) -> Option<String>;
+    let _: fn(CanvaConfig, Lookup) -> Result<CanvaConfig> = CanvaConfig::with_overrides;
+    let _: fn(CanvaConfig, String) -> CanvaConfig = CanvaConfig::with_base_url;
+    let _: fn(CanvaConfig, String) -> CanvaConfig = CanvaConfig::with_api_version;
+    let _: fn(CanvaConfig, Duration) -> CanvaConfig = CanvaConfig::with_timeout;
+    let _: fn(CanvaConfig, Duration) -> CanvaConfig = CanvaConfig::with_connect_timeout;
+    let _: fn(CanvaConfig, RetryPolicy) -> CanvaConfig = CanvaConfig::with_retry_policy;
+    let _: fn(CanvaConfig, u32, Option<u32>) -> CanvaConfig = CanvaConfig::with_rate_limit;
+    let _: fn(CanvaConfig, bool) -> CanvaConfig = CanvaConfig::with_endpoint_limits;
+    let _: fn(CanvaConfig, PollingProfile) -> CanvaConfig = CanvaConfig::with_polling;
+    let _: fn(&CanvaConfig) -> Result<()> = CanvaConfig::validate;
+    let _: fn(&PollingProfile) -> Duration = PollingProfile::interval;
+
+    let config = CanvaConfig::new();
+    let _: &str = config.base_url();
+    let _: &str = config.api_version();
+    let _: Option<Duration> = config.timeout();
+    let _: Option<Duration> = config.connect_timeout();
+    let _: &RetryPolicy = config.retry_policy();
+    let _: u32 = config.requests_per_minute();
+    let _: u32 = config.burst();
+    let _: bool = config.endpoint_limits();
+    let _: PollingProfile = config.polling();
+
+    match PollingProfile::default() {
+        PollingProfile::Fast
+        | PollingProfile::Standard
+        | PollingProfile::Relaxed
+        | PollingProfile::Custom(_) => {}
+    }
+}
+
+#[derive(Debug)]
+struct NoCache;
+
+#[async_trait::async_trait]
+impl ResponseCache for NoCache {
+    async fn get(&self, _key: &str) -> Option<CachedResponse> {
+        None
+    }
+
+    async fn put(&self, _key: &str, _response: CachedResponse) {}
+
+    async fn remove(&self, _key: &str) {}
+}
+
+#[test]
+fn cache_signatures() {
+    let _: fn() -> CacheConfig = CacheConfig::new;
+    let _: fn(CacheConfig, Duration) -> CacheConfig = CacheConfig::with_ttl;
+    let _: fn(CacheConfig, usize) -> CacheConfig = CacheConfig::with_max_entries;
+    let _: fn(CacheConfig, NoCache) -> CacheConfig = CacheConfig::with_store;
+    let _: fn(CacheConfig, std::sync::Arc<MemoryCache>) -> CacheConfig = CacheConfig::with_store;
+    let _: fn(&CacheConfig) -> Duration = CacheConfig::ttl;
+    let _: fn(&CacheConfig) -> usize = CacheConfig::max_entries;
+    let _: fn(usize) -> MemoryCache = MemoryCache::new;
+    let _: fn(&MemoryCache) -> usize = MemoryCache::max_entries;
+    let _: fn(&MemoryCache) -> usize = MemoryCache::len;
+    let _: fn(&MemoryCache) -> bool = MemoryCache::is_empty;
+    let _: fn(&MemoryCache) = MemoryCache::clear;
+    let _: fn(&CachedResponse) -> Option<&str> = CachedResponse::etag;
+    let _: fn(&CachedResponse, Duration) -> bool = CachedResponse::is_fresh;
+    let _: usize = DEFAULT_MAX_ENTRIES;
+    let _: fn(ClientBuilder, CacheConfig) -> ClientBuilder = ClientBuilder::cache;
+    let _: fn(&Client) -> Option<&dyn ResponseCache> = Client::cache;
+    let _: fn(&Client) -> &str = Client::api_version;
+    let _: fn(&Client, &str) -> String = Client::url_for;
+    let _: fn(ClientBuilder, String) -> ClientBuilder = ClientBuilder::api_version;
+    let _: &str = canva_connect::API_VERSION;
+
+    let response = CachedResponse {
+        status: reqwest::StatusCode::OK,
+        headers: reqwest::header::HeaderMap::new(),
+        body: Vec::new(),
+        stored_at: std::time::Instant::now(),
+    };
+    let _: (
+        reqwest::StatusCode,
+        &reqwest::header::HeaderMap,
+        &[u8],
+        std::time::Instant,
+    ) = (
+        response.status,
+        &response.headers,
+        &response.body,
+        response.stored_at,
+    );
+}
+
+#[test]
+fn client_constructor_signatures() {
+    let _: fn(AccessToken) -> Result<Client> = Client::new;
+    let _: fn(AccessToken) -> ClientBuilder = Client::builder;
+    let _: fn(String, AccessToken) -> Result<Client> = Client::with_base_url;
+    let _: fn(AccessToken, ApiRateLimiter) -> Result<Client> = Client::with_rate_limiter;
+    let _: fn(AccessToken, CanvaConfig) -> Result<Client> = Client::from_config;
+    let _: fn(ClientBuilder, CanvaConfig) -> ClientBuilder = ClientBuilder::config;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::poll_interval;
+    let _: fn(ClientBuilder, usize) -> ClientBuilder = ClientBuilder::captured_body_limit;
+    let _: fn(ClientBuilder, usize) -> ClientBuilder = ClientBuilder::pool_max_idle_per_host;
+    let _: fn(ClientBuilder, Option<Duration>) -> ClientBuilder = ClientBuilder::pool_idle_timeout;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::http2_keep_alive;
+    let _: usize = canva_connect::client::DEFAULT_CAPTURED_BODY_LIMIT;
+    let _: fn(ClientBuilder, OAuthClient) -> ClientBuilder = ClientBuilder::token_provider;
+    let _: fn(ClientBuilder, reqwest::Client) -> ClientBuilder = ClientBuilder::transport;
+    let _: fn(ClientBuilder, AllowAll) -> ClientBuilder = ClientBuilder::upload_precheck;
+    let _: fn(ClientBuilder) -> Result<Client> = ClientBuilder::build;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::timeout;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::connect_timeout;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::short_timeout;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::upload_timeout;
+    let _: fn(ClientBuilder, Duration) -> ClientBuilder = ClientBuilder::download_timeout;
+    let _: fn(ClientBuilder, reqwest::Proxy) -> ClientBuilder = ClientBuilder::proxy;
+    let _: fn(ClientBuilder, RetryPolicy) -> ClientBuilder = ClientBuilder::retry_policy;
+    let _: fn(ClientBuilder, ApiRateLimiter) -> ClientBuilder = ClientBuilder::rate_limiter;
+    let _: fn(ClientBuilder, EndpointRateLimits) -> ClientBuilder =
+        ClientBuilder::endpoint_rate_limits;
+    let _: fn(ClientBuilder, reqwest::header::HeaderMap) -> ClientBuilder =
+        ClientBuilder::default_headers;
+    let _: fn(ClientBuilder, usize) -> ClientBuilder = ClientBuilder::event_log;
+    let _: fn(ClientBuilder, bool) -> ClientBuilder = ClientBuilder::scope_preflight;
+}
+
+#[derive(Debug)]
+struct PassThrough;
+
+#[async_trait::async_trait]
+impl RequestInterceptor for PassThrough {
+    async fn on_request(&self, _request: &mut reqwest::Request) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ResponseInterceptor for PassThrough {
+    async fn on_response(
+        &self,
+        context: &ResponseContext<'_>,
+        _response: &mut reqwest::Response,
+    ) -> Result<()> {
+        let _: (&reqwest::Method, &reqwest::Url, u32, Duration) = (
+            context.method,
+            context.url,
+            context.attempt,
+            context.elapsed,
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HeaderProvider for PassThrough {
+    async fn headers(&self, _request: &reqwest::Request) -> Result<reqwest::header::HeaderMap> {
+        Ok(reqwest::header::HeaderMap::new())
+    }
+}
+
+#[test]
+fn interceptor_signatures() {
+    let _ = |builder: ClientBuilder| -> ClientBuilder {
+        builder
+            .header_provider(PassThrough)
+            .request_interceptor(PassThrough)
+            .response_interceptor(PassThrough)
+    };
+}
+
+#[test]
+fn client_accessor_signatures() {
+    let client = Client::new(AccessToken::new("token")).unwrap();
+    let _: &str = client.base_url();
+    let _: &AccessToken = client.access_token();
+    let _: &dyn TokenProvider = client.token_provider();
+    let _: &dyn HttpTransport = client.transport();
+    fn is_http_transport<T: HttpTransport>() {}
+    #[derive(Debug)]
+    struct Fake;
+    #[async_trait::async_trait]
+    impl CanvaTransport for Fake {
+        async fn send(
+            &self,
+            _request: http::Request<Vec<u8>>,
+        ) -> canva_connect::Result<http::Response<Vec<u8>>> {
+            Ok(http::Response::new(Vec::new()))
+        }
+    }
+    is_http_transport::<Fake>();
+    let _: &reqwest::Client = client.http_client();
+    let _: &RetryPolicy = client.retry_policy();
+    let _: Duration = client.poll_interval();
+    let _: Option<Duration> = client.timeout_for(OperationClass::Short);
+    let _ = [OperationClass::Upload, OperationClass::Download];
+    let _: &EndpointRateLimits = client.endpoint_rate_limits();
+    let _: Option<canva_connect::rate_limit::RateLimitInfo> = client.last_rate_limit_info();
+    let _: bool = client.is_shut_down();
+    let _: usize = client.in_flight_requests();
+    let _: Client = client.with_priority(Priority::High);
+    let _: Priority = client.priority();
+    let _: Option<&canva_connect::event_log::EventLog> = client.event_log();
+    let _: Result<String> = client.support_bundle();
+}
+
+#[test]
+fn rate_limit_and_retry_signatures() {
+    let _: fn(u32) -> ApiRateLimiter = ApiRateLimiter::new;
+    let _: fn(u32, u32) -> ApiRateLimiter = ApiRateLimiter::with_burst;
+    let _: fn() -> ApiRateLimiter = ApiRateLimiter::conservative;
+    let _: fn() -> ApiRateLimiter = ApiRateLimiter::permissive;
+    let _: fn(&ApiRateLimiter) -> bool = ApiRateLimiter::can_make_request;
+    let _: fn(&ApiRateLimiter) -> usize = ApiRateLimiter::waiting_requests;
+    let _ = [Priority::Low, Priority::Normal, Priority::High];
+    assert_eq!(Priority::default(), Priority::Normal);
+    let _: fn(&reqwest::header::HeaderMap) -> RateLimitInfo = RateLimitInfo::from_headers;
+    let _: fn() -> EndpointRateLimits = EndpointRateLimits::new;
+    let _: fn() -> EndpointRateLimits = EndpointRateLimits::canva_defaults;
+    let _: fn(EndpointRateLimits, reqwest::Method, &str, u32) -> EndpointRateLimits =
+        EndpointRateLimits::set;
+    let _: fn(EndpointRateLimits, reqwest::Method, &str, ApiRateLimiter) -> EndpointRateLimits =
+        EndpointRateLimits::with_limiter;
+    let _: fn(EndpointRateLimits, &reqwest::Method, &str) -> EndpointRateLimits =
+        EndpointRateLimits::remove;
+    let _: for<'a> fn(
+        &'a EndpointRateLimits,
+        &reqwest::Method,
+        &str,
+    ) -> Option<&'a ApiRateLimiter> = EndpointRateLimits::limiter_for;
+    let _: fn(u32) -> RetryPolicy = RetryPolicy::new;
+    let _: fn() -> RetryPolicy = RetryPolicy::none;
+    let _: fn(&RetryPolicy, u32) -> Duration = RetryPolicy::backoff_for;
+    let _: fn(RetryPolicy, u32) -> RetryPolicy = RetryPolicy::with_max_retries;
+    let _: fn(&RetryPolicy) -> Duration = RetryPolicy::initial_backoff;
+    let _: fn(&RetryPolicy) -> Duration = RetryPolicy::max_backoff;
+    let _: fn(&RetryPolicy, &reqwest::Method, reqwest::StatusCode) -> bool =
+        RetryPolicy::should_retry_status_for;
+    let _: fn(&RetryPolicy, &reqwest::Method, &reqwest::Error) -> bool =
+        RetryPolicy::should_retry_error_for;
+
+    let _ = RateLimitInfo {
+        remaining: None,
+        reset_at: None,
+        limit: None,
+    };
+
+    let _: fn(&ApiRateLimiter, Duration) = ApiRateLimiter::pause_for;
+    let _: fn(&ApiRateLimiter) -> Option<Duration> = ApiRateLimiter::pause_remaining;
+    let _: fn(&reqwest::header::HeaderMap) -> Option<Duration> =
+        canva_connect::rate_limit::retry_after;
+    let _ = |builder: ClientBuilder| -> ClientBuilder { builder.throttle_listener(LogThrottles) };
+    let _ = ThrottleEvent {
+        method: String::new(),
+        path: String::new(),
+        reason: ThrottleReason::RetryAfter,
+        delay: Duration::ZERO,
+        rate_limit: RateLimitInfo {
+            remaining: None,
+            reset_at: None,
+            limit: None,
+        },
+    };
+}
+
+#[derive(Debug)]
+struct LogThrottles;
+
+impl ThrottleListener for LogThrottles {
+    fn on_throttle(&self, event: &ThrottleEvent) {
+        let _ = (event.reason, event.delay);
+    }
+}
+
+#[test]
+fn operation_signatures() {
+    use canva_connect::operations::{scopes_for, Operation};
+
+    let _: [Operation; 39] = Operation::ALL;
+    let _: fn(Operation) -> reqwest::Method = Operation::method;
+    let _: fn(Operation) -> &'static str = Operation::path;
+    let _: fn(Operation) -> ScopeSet = Operation::required_scopes;
+    let _: fn(&reqwest::Method, &str) -> Option<Operation> = Operation::matching;
+    let _: fn(Operation) -> ScopeSet = scopes_for;
+}
+
+#[test]
+fn route_signatures() {
+    use canva_connect::routes;
+
+    let _: &'static str = routes::DESIGNS;
+    let _: fn(&str) -> String = routes::design;
+    let _: fn(&str) -> String = routes::autofill_job;
+    let _: fn(&str, &str, &str) -> String = routes::comment_reply;
+    assert_eq!(routes::folder_items("F1"), "/v1/folders/F1/items");
+}
+
+#[test]
+fn auth_signatures() {
+    let _: fn(String) -> AccessToken = AccessToken::new;
+    let _: fn(&AccessToken) -> &str = AccessToken::as_str;
+    let _: fn() -> PkceParams = PkceParams::new;
+    let _: fn(OAuthConfig) -> OAuthClient = OAuthClient::new;
+    let _: fn(OAuthConfig, TokenStore) -> OAuthClient = OAuthClient::with_token_store;
+    let _: fn(OAuthClient, String) -> OAuthClient = OAuthClient::with_api_base_url;
+    let _: OAuthClient = OAuthClient::new(OAuthConfig::new("id", "secret", "uri", vec![]))
+        .on_store_error(|error: &Error| drop(error.to_string()));
+    let _: fn(&OAuthConfig) -> ScopeSet = OAuthConfig::scope_set;
+    let _: fn(&TokenSet) -> Option<ScopeSet> = TokenSet::granted_scopes;
+    let _: fn(&str) -> ScopeSet = ScopeSet::parse_lossy;
+    let _: fn(&ScopeSet, &ScopeSet) -> ScopeSet = ScopeSet::missing;
+    let _: fn(&ScopeSet, &ScopeSet) -> ScopeSet = ScopeSet::union;
+    let _: fn(&ScopeSet, Scope) -> bool = ScopeSet::contains;
+    let _: Result<ScopeSet> = "asset:read".parse();
+    let _: Result<Scope> = Scope::try_from("asset:read");
+    let _: &'static str = Scope::AssetRead.as_str();
+    let _: fn(OAuthConfig, FileTokenStorage) -> OAuthClient = OAuthClient::with_token_store;
+    let _: fn(FileTokenStorage) -> TokenStore = TokenStore::with_storage;
+    let _: fn(std::path::PathBuf, [u8; 32]) -> FileTokenStorage = FileTokenStorage::new;
+    let _: fn(String, &str) -> FileTokenStorage = FileTokenStorage::with_passphrase;
+    let _: fn(&TokenSet) -> bool = TokenSet::is_expired;
+    let _: fn(&TokenSet) -> Option<chrono::DateTime<chrono::Utc>> = TokenSet::expires_at_utc;
+    let _: fn(&TokenSet) -> Result<String> = TokenSet::to_json;
+    let _: fn(&str) -> Result<TokenSet> = TokenSet::from_json;
+
+    let _ = OAuthConfig {
+        client_id: String::new(),
+        client_secret: String::new(),
+        redirect_uri: String::new(),
+        scopes: vec![Scope::AssetRead],
+    };
+}
+
+/// Compiled, never run: pins the async endpoint surface.
+async fn rate_limit_wait_signatures(limiter: ApiRateLimiter, limits: EndpointRateLimits) {
+    limiter.wait_for_request_with_priority(Priority::Low).await;
+    limits
+        .wait_for_request_with_priority(&reqwest::Method::GET, "/v1/designs", Priority::High)
+        .await;
+}
+
+async fn endpoint_signatures(client: Client) -> Result<()> {
+    let _: Option<ScopeSet> = client.granted_scopes().await?;
+    let _: reqwest::Response = client.download("https://export.example.com/a.pdf").await?;
+    let _: bool = client.shutdown(Duration::from_secs(10)).await;
+    let _: String = client.cache_key("/v1/assets/id").await?;
+    let _: serde_json::Value = client
+        .request_json(reqwest::Method::GET, "/v1/path", &[("k", "v")], None::<&()>)
+        .await?;
+
+    let assets = client.assets();
+    let _: Asset = assets.get("id").await?;
+    let asset = assets.get("id").await?;
+    let DownloadedThumbnail {
+        width: _,
+        height: _,
+        content_type: _,
+        size: _,
+        refreshed: _,
+    } = assets
+        .download_thumbnail(&asset, &mut tokio::io::sink())
+        .await?;
+    let _: Asset = assets
+        .update(
+            "id",
+            UpdateAssetRequest {
+                name: None,
+                tags: None,
+            },
+        )
+        .await?;
+    let _: () = assets.delete("id").await?;
+    let deletions: BulkDeleteSummary = assets.delete_many(vec!["id".to_string()]).await;
+    let filter = canva_connect::endpoints::assets::AssetFilter::ids(["id"])
+        .name_matches(regex::Regex::new("^IMG_").map_err(|e| Error::Generic(e.to_string()))?)
+        .tagged("raw".parse()?)
+        .of_type(AssetType::Image);
+    let _: &[String] = filter.asset_ids();
+    let _: Vec<Asset> = futures::TryStreamExt::try_collect(assets.fetch_matching(&filter)).await?;
+    let plan = canva_connect::endpoints::assets::UpdatePlan::new()
+        .rename(
+            regex::Regex::new("^IMG_").map_err(|e| Error::Generic(e.to_string()))?,
+            "",
+        )
+        .add_tag("done".parse()?)
+        .remove_tag("raw".parse()?)
+        .dry_run(true)
+        .concurrency(canva_connect::endpoints::assets::DEFAULT_UPDATE_CONCURRENCY);
+    let _: (bool, usize) = (plan.is_dry_run(), plan.max_concurrency());
+    let report: canva_connect::endpoints::assets::BulkUpdateReport =
+        assets.bulk_update(&filter, &plan).await;
+    let _: (bool, Vec<String>) = (report.is_success(), report.failed_ids());
+    if let Some(change) = report.changed.first() {
+        let _: UpdateAssetRequest = change.request();
+    }
+    let url_options = canva_connect::endpoints::assets::UrlUploadOptions {
+        name: None,
+        check_remote: true,
+    };
+    let url: url::Url = "https://example.com/a.png".parse()?;
+    let _: AssetUploadJob = assets.upload_from_url(url, &url_options).await?;
+    let _: usize = canva_connect::endpoints::assets::MAX_UPLOAD_URL_CHARS;
+    let _: fn(&str) -> Option<canva_connect::uploads::AssetFormat> =
+        canva_connect::uploads::AssetFormat::from_mime_type;
+    let tag: canva_connect::endpoints::assets::Tag = "holiday".parse()?;
+    let _: Asset = assets.add_tags("id", vec![tag.clone()]).await?;
+    let _: Asset = assets.remove_tags("id", [tag.clone()]).await?;
+    let changes = canva_connect::endpoints::assets::TagChanges {
+        add: vec![tag],
+        remove: vec![],
+    };
+    let _: Asset = assets
+        .change_tags(
+            "id",
+            &changes,
+            canva_connect::endpoints::assets::DEFAULT_TAG_RETRIES,
+        )
+        .await?;
+    let _: (bool, bool) = (deletions.is_success(), deletions.all_gone());
+    let _: (&[String], &[FailedDeletion]) = (&deletions.deleted, &deletions.failed);
+    let _: usize = DEFAULT_DELETE_CONCURRENCY;
+    let _: AssetUploadJob = assets
+        .create_upload_job(Vec::new(), AssetUploadMetadata::new("name", Vec::new()))
+        .await?;
+    let _: AssetUploadJob = assets.get_upload_job("id").await?;
+    let _: AssetUploadJob = assets
+        .create_url_upload_job(CreateUrlAssetUploadJobRequest {
+            url: String::new(),
+            name: String::new(),
+        })
+        .await?;
+    let _: AssetUploadJob = assets.get_url_upload_job("id").await?;
+    let _: Asset = assets.wait_for_upload_job("id").await?;
+    let _: Asset = assets.wait_for_url_upload_job("id").await?;
+    let options = DirectoryUploadOptions::new()
+        .with_concurrency(DEFAULT_UPLOAD_CONCURRENCY)
+        .with_tags(vec![])
+        .with_recursive(true)
+        .with_skip_unsupported(false);
+    let _: (usize, &[String], bool, bool) = (
+        options.concurrency(),
+        options.tags(),
+        options.recursive(),
+        options.skip_unsupported(),
+    );
+    let summary: DirectoryUploadSummary = assets.upload_directory("dir", &options).await?;
+    let _: (bool, usize) = (summary.is_success(), summary.total());
+    let _: (&[UploadedFile], &[FailedUpload], &[std::path::PathBuf]) =
+        (&summary.uploaded, &summary.failed, &summary.skipped);
+    let _: DirectoryUploadSummary = assets
+        .upload_directory_with_progress("dir", &options, |progress: &DirectoryUploadProgress| {
+            let _: (&std::path::Path, FileUploadStatus, usize, usize) = (
+                progress.path,
+                progress.status,
+                progress.completed,
+                progress.total,
+            );
+        })
+        .await?;
+    let store = FileUploadStateStore::new("dir");
+    let _: &std::path::Path = store.dir();
+    let _: MemoryUploadStateStore = MemoryUploadStateStore::new();
+    let _: Asset = assets.upload_resumable("file.mp4", vec![], &store).await?;
+    let pending: Vec<ResumableUpload> = store.list().await?;
+    let _: Option<ResumableUpload> = store.load("id").await?;
+    let _: () = store.remove("id").await?;
+    let upload = ResumableUpload {
+        id: String::new(),
+        path: std::path::PathBuf::new(),
+        name: String::new(),
+        tags: vec![],
+        size: 0,
+        sha256: String::new(),
+        job_id: None,
+    };
+    let _: () = store.save(&upload).await?;
+    let _: Asset = assets.resume_upload_job(upload, &store).await?;
+    drop(pending);
+
+    let autofill = client.autofill();
+    let _: DesignAutofillJob = autofill
+        .create_autofill_job("id", HashMap::new(), None)
+        .await?;
+    let _: DesignAutofillJob = autofill.get_autofill_job("id").await?;
+    let _: DesignAutofillJob = autofill.wait_for_autofill_job("id", None).await?;
+    let _: Vec<Result<DesignAutofillJob>> = autofill
+        .create_autofill_jobs_batch("id", vec![HashMap::new()], 4)
+        .await;
+
+    let templates = client.brand_templates();
+    let _ = templates
+        .list(&ListBrandTemplatesRequest {
+            query: None,
+            continuation: None,
+            limit: None,
+        })
+        .await?;
+    let _ = templates.get("id").await?;
+    let _ = templates.get_dataset("id").await?;
+    let _: Vec<(
+        BrandTemplate,
+        Result<canva_connect::endpoints::brand_templates::BrandTemplateDataset>,
+    )> = futures::TryStreamExt::try_collect(templates.list_with_datasets(4)).await?;
+
+    let designs = client.designs();
+    let list_designs: ListDesignsRequestBuilder = ListDesignsRequest::builder()
+        .query("q")
+        .ownership(OwnershipType::Owned)
+        .sort_by(SortByType::Relevance)
+        .continuation("c")
+        .limit(10);
+    let _: ListDesignsRequest = list_designs.build();
+    let _: ListBrandTemplatesRequestBuilder = ListBrandTemplatesRequest::builder().query("q");
+    let _: ListFolderItemsRequestBuilder = ListFolderItemsRequest::builder().limit(10);
+    let list_designs = ListDesignsRequest {
+        query: None,
+        ownership: None,
+        sort_by: None,
+        continuation: None,
+        limit: None,
+    };
+    let page: Page<Design> = designs.list_with(&list_designs).await?;
+    let _: bool = page.has_more();
+    let _: Option<ListDesignsRequest> = page.next_request(&list_designs);
+    let search = canva_connect::endpoints::designs::DesignSearch::new()
+        .query("q")
+        .ownership(OwnershipType::Owned)
+        .sort_by(SortByType::ModifiedDescending)
+        .page_size(50)
+        .title_matches(|title| title.contains('q'))
+        .updated_since(chrono::Utc::now())
+        .page_count(1..=10);
+    let _: &ListDesignsRequest = search.request();
+    let _: bool = search.matches(&page.items[0]);
+    let _: Option<Design> =
+        futures::TryStreamExt::try_next(&mut Box::pin(designs.search(search))).await?;
+    let body: Vec<u8> = designs.list_raw(&list_designs).await?;
+    let borrowed: canva_connect::models::borrowed::ListDesignsResponseRef<'_> =
+        canva_connect::models::borrowed::ListDesignsResponseRef::from_slice(&body)?;
+    let _: Page<Design> = borrowed.into_owned();
+    let _: Option<ListBrandTemplatesRequest> = Page::<BrandTemplate>::next_request(
+        &Page {
+            items: vec![],
+            continuation: None,
+        },
+        &ListBrandTemplatesRequest::default(),
+    );
+    let _: fn(&mut ListFolderItemsRequest, Option<String>) = PageRequest::set_continuation;
+    let _: GetDesignResponse = designs.get("id").await?;
+    #[cfg(feature = "experimental")]
+    {
+        use canva_connect::endpoints::designs::GetDesignPagesRequest;
+        let pages: GetDesignPagesResponse = designs
+            .get_pages(
+                "id",
+                &GetDesignPagesRequest {
+                    offset: Some(1),
+                    limit: None,
+                },
+            )
+            .await?;
+        let DesignPage {
+            index: _,
+            thumbnail: _,
+        } = pages.items[0].clone();
+    }
+    let _: CreateDesignResponse = designs
+        .create(CreateDesignRequest {
+            design_type: None,
+            asset_id: None,
+            title: None,
+        })
+        .await?;
+    let _: Result<()> = CreateDesignRequest {
+        design_type: None,
+        asset_id: None,
+        title: None,
+    }
+    .validate();
+    let resize = CreateDesignResizeJobRequest::custom("id", 100, 100);
+    let _: DesignResizeJob = designs.create_resize_job(resize).await?;
+    let _: DesignResizeJob = designs.get_resize_job("id").await?;
+    let _: DesignSummary = designs.wait_for_resize_job("id").await?;
+
+    let exports = client.exports();
+    let export_request = CreateDesignExportJobRequest {
+        design_id: String::new(),
+        format: ExportFormat::Pptx {
+            export_quality: None,
+            pages: None,
+        },
+    };
+    let _ = exports.create_design_export_job(&export_request).await?;
+    let _ = exports.get_design_export_job("id").await?;
+    let formats = exports.get_design_export_formats("id").await?.formats;
+    let _: Vec<&'static str> = formats.supported();
+    let _: bool = formats.supports(&ExportFormat::pdf());
+    let _: &'static str = ExportFormat::pdf().name();
+    let _: ExportJob = exports
+        .create_export_checked("id", ExportFormat::pdf())
+        .await?;
+    let job: ExportJob = exports.wait_for_export_job("id").await?;
+    let _: u64 = exports.download_export(&job, &mut Vec::new()).await?;
+    let _: ExportJob = exports.refresh_export(&job.id).await?;
+    let _: bool = job.is_likely_expired();
+    let _: Option<chrono::DateTime<chrono::Utc>> = job.fetched_at;
+    let _: Duration = canva_connect::models::EXPORT_URL_LIFETIME;
+    let _: Vec<std::path::PathBuf> = exports
+        .export_to_path("id", export_request.format.clone(), "design.pdf")
+        .await?;
+    let limits = canva_connect::downloads::DownloadLimits::new(4).with_max_per_host(2);
+    let _: canva_connect::downloads::DownloadReport = exports
+        .download_export_to_path(&job, "design.png", &limits)
+        .await?;
+    let downloader: canva_connect::downloads::Downloader = client
+        .downloader()
+        .with_limits(limits)
+        .with_host_delay(Duration::from_millis(50))
+        .with_resume(false);
+    let _: Duration = downloader.host_delay();
+    let _: &canva_connect::downloads::DownloadLimits = downloader.limits();
+    let download_requests: Vec<canva_connect::downloads::DownloadRequest> =
+        canva_connect::downloads::DownloadRequest::for_export(&job, "deck.png")?;
+    let _: canva_connect::downloads::DownloadReport =
+        downloader.download_all(&download_requests).await?;
+    let _: canva_connect::downloads::DownloadedFile = downloader
+        .download(
+            &canva_connect::downloads::DownloadRequest::new("https://x", "x").with_sha256("ab"),
+        )
+        .await?;
+    let _: &str = canva_connect::downloads::PARTIAL_SUFFIX;
+    let document: ExportedDocument = exports.download_document(&job).await?;
+    let capped = limits.with_max_file_bytes(1 << 20);
+    let _: Option<u64> = capped.max_file_bytes();
+    let _: ExportedDocument = exports.download_document_with(&job, &capped).await?;
+    let _: &[ExportedPage] = document.pages();
+    let _: bool = document.is_single_file();
+    let _: Vec<String> = document.file_names("design");
+    let _: Vec<std::path::PathBuf> = document.write_to_dir("out", "design").await?;
+    let download_options: ExportDownloadOptions = ExportDownloadOptions::new()
+        .with_limits(limits)
+        .with_checksums(true);
+    let _: &canva_connect::downloads::DownloadLimits = download_options.limits();
+    let _: bool = download_options.checksums();
+    let files: Vec<DownloadedExport> = exports.download_all(&job, &download_options).await?;
+    let DownloadedExport {
+        page: _,
+        url: _,
+        content_type: _,
+        extension: _,
+        size: _,
+        sha256: _,
+        bytes: _,
+    } = files[0].clone();
+    let _: Vec<(String, Result<ExportJob>)> = futures::StreamExt::collect(exports.export_designs(
+        vec!["id".to_string()],
+        ExportFormat::pdf(),
+        2,
+    ))
+    .await;
+
+    let folders = client.folders();
+    let _ = folders
+        .create_folder(&CreateFolderRequest {
+            name: String::new(),
+            parent_folder_id: String::new(),
+        })
+        .await?;
+    let _ = folders.get_folder("id").await?;
+    let _ = folders
+        .update_folder(
+            "id",
+            &UpdateFolderRequest {
+                name: String::new(),
+            },
+        )
+        .await?;
+    let _ = folders
+        .list_folder_items(
+            "id",
+            &ListFolderItemsRequest {
+                limit: None,
+                continuation: None,
+            },
+        )
+        .await?;
+    let _: () = folders
+        .move_folder_item(&MoveFolderItemRequest {
+            item_id: String::new(),
+            to_folder_id: String::new(),
+        })
+        .await?;
+    let moves: BulkMoveSummary = folders.move_items(vec!["id".to_string()], "root").await;
+    let _: bool = moves.is_success();
+    let _: Option<&canva_connect::endpoints::folders::FailedMove> = moves.failed.first();
+    let _: usize = DEFAULT_MOVE_CONCURRENCY;
+
+    let user = client.user();
+    let _: canva_connect::endpoints::user::TeamUserSummary = user.get_me().await?;
+    let _ = user.get_profile().await?;
+    let _: Vec<Capability> = user.get_capabilities().await?;
+    let info: canva_connect::endpoints::user::UserInfo = user.get_all().await?;
+    let _: (
+        canva_connect::endpoints::user::TeamUserSummary,
+        canva_connect::endpoints::user::UserProfile,
+        canva_connect::endpoints::user::UserCapabilities,
+    ) = (info.user, info.profile, info.capabilities);
+    let capabilities: canva_connect::endpoints::user::UserCapabilities =
+        client.capabilities().await?;
+    let _: bool = capabilities.can_autofill()
+        && capabilities.can_resize()
+        && capabilities.can_use_brand_templates()
+        && capabilities.contains(Capability::Resize);
+    let _: &[Capability] = capabilities.as_slice();
+    let _ = canva_connect::endpoints::user::UserCapabilities::from(vec![Capability::Autofill]);
+    client.invalidate_capabilities().await;
+
+    Ok(())
+}
+
+/// Compiled, never run: pins the OAuth token flows.
+async fn oauth_signatures(client: OAuthClient, pkce: PkceParams) -> Result<()> {
+    let _: Client = Client::with_oauth(client.clone()).await?;
+    let _: canva_connect::auth::TokenExchangeResponse =
+        client.exchange_code_with_pkce("code", &pkce).await?;
+    let _: canva_connect::auth::TokenExchangeResponse = client.refresh_token().await?;
+    let _: AccessToken = client.get_access_token().await?;
+    let _: () = client.revoke_token("token", None).await?;
+    let store = client.token_store();
+    let _: Option<TokenSet> = store.load().await?;
+    let _: Result<()> = store.try_store(store.get().await.unwrap()).await;
+    let _: Result<()> = store.try_clear().await;
+    let _: Option<ScopeSet> = client.granted_scopes().await?;
+    let refresh_ahead: canva_connect::auth::RefreshAheadHandle =
+        client.spawn_refresh_ahead(Duration::from_secs(300));
+    refresh_ahead.stop();
+    let _: Duration = canva_connect::auth::REFRESH_AHEAD_RETRY_DELAY;
+    Ok(())
+}
+
+/// Compiled, never run: pins deprecated shims kept in `compat`.
+async fn compat_signatures(client: OAuthClient) -> Result<()> {
+    let _ = client.exchange_code("code").await?;
+    let _: String = OAuthConfig::new("id", "secret", "uri", vec![]).scopes_string();
+    let designs = Client::new(AccessToken::new("token"))?.designs();
+    let _: GetListDesignResponse = designs.list(None, None, None, None).await?;
+    Ok(())
+}
+
+#[test]
+fn error_variants_are_stable() {
+    fn describe(error: &Error) -> &'static str {
+        match error {
+            Error::Http(_) => "http",
+            Error::Json(_) => "json",
+            Error::Deserialization { source: _, body: _ } => "deserialization",
+            Error::Api {
+                code: _,
+                message: _,
+                context: _,
+            } => "api",
+            Error::Auth(_) => "auth",
+            Error::RateLimit => "rate_limit",
+            Error::InvalidUrl(_) => "invalid_url",
+            Error::Io(_) => "io",
+            Error::Generic(_) => "generic",
+            Error::InvalidHeader(_) => "invalid_header",
+            Error::ClientBuild(_) => "client_build",
+            Error::Config(_) => "config",
+            Error::UploadRejected {
+                name: _,
+                rejection: _,
+            } => "upload_rejected",
+            Error::InvalidAsset { name: _, reason: _ } => "invalid_asset",
+            Error::JobTimeout {
+                job_id: _,
+                timeout: _,
+            } => "job_timeout",
+            Error::Cancelled { job_id: _ } => "cancelled",
+            Error::Workflow { step: _, source: _ } => "workflow",
+            Error::UnsupportedExportFormat {
+                design_id: _,
+                format: _,
+                supported: _,
+            } => "unsupported_export_format",
+            Error::MissingScope {
+                required: _,
+                granted: _,
+            } => "missing_scope",
+            Error::ShutDown => "shut_down",
+            Error::Download { status: _, url: _ } => "download",
+            _ => "unknown",
+        }
+    }
+
+    assert_eq!(describe(&Error::RateLimit), "rate_limit");
+}
+
+#[test]
+fn error_context_signatures() {
+    let _: fn(&Error) -> Option<&canva_connect::error::RequestContext> = Error::request_context;
+    let _: fn(&Error) -> Option<&reqwest::Method> = Error::method;
+    let _: fn(&Error) -> Option<&str> = Error::path;
+    let _: fn(&Error) -> Option<&str> = Error::request_id;
+    let _: fn(&Error) -> Option<Duration> = Error::elapsed;
+    let _: fn(&Error) -> canva_connect::error::RecoveryHint = Error::recovery_hint;
+    let _: fn(&Error) -> bool = Error::is_retryable;
+    let _: fn(&Error) -> bool = Error::is_not_found;
+    let _: fn(reqwest::StatusCode) -> ApiErrorCode = ApiErrorCode::for_status;
+    let _ = |hint: canva_connect::error::RecoveryHint| match hint {
+        canva_connect::error::RecoveryHint::RefreshToken
+        | canva_connect::error::RecoveryHint::Backoff
+        | canva_connect::error::RecoveryHint::CheckScopes
+        | canva_connect::error::RecoveryHint::Permanent => {}
+    };
+
+    let context = canva_connect::error::RequestContext {
+        method: reqwest::Method::GET,
+        path: "/v1/designs/DAF123".to_string(),
+        status: reqwest::StatusCode::NOT_FOUND,
+        request_id: None,
+        elapsed: Duration::ZERO,
+    };
+    let _: String = context.to_string();
+}
+
+#[test]
+fn api_error_codes_are_stable() {
+    fn describe(code: &ApiErrorCode) -> &'static str {
+        match code {
+            ApiErrorCode::InvalidRequest => "invalid_request",
+            ApiErrorCode::Unauthorized => "unauthorized",
+            ApiErrorCode::Forbidden => "forbidden",
+            ApiErrorCode::NotFound => "not_found",
+            ApiErrorCode::MethodNotAllowed => "method_not_allowed",
+            ApiErrorCode::Conflict => "conflict",
+            ApiErrorCode::UnprocessableEntity => "unprocessable_entity",
+            ApiErrorCode::TooManyRequests => "too_many_requests",
+            ApiErrorCode::InternalServerError => "internal_server_error",
+            ApiErrorCode::ServiceUnavailable => "service_unavailable",
+            ApiErrorCode::Unknown(_) => "unknown",
+        }
+    }
+
+    assert_eq!(describe(&ApiErrorCode::NotFound), "not_found");
+}
+
+#[test]
+fn job_status_variants_are_stable() {
+    fn describe(status: &JobStatus) -> &'static str {
+        match status {
+            JobStatus::InProgress => "in_progress",
+            JobStatus::Success => "success",
+            JobStatus::Failed => "failed",
+            JobStatus::Unknown => "unknown",
+        }
+    }
+
+    assert_eq!(describe(&JobStatus::Success), "success");
+}
+
+#[test]
+fn design_type_presets_are_stable() {
+    fn describe(name: &PresetDesignTypeName) -> &'static str {
+        match name {
+            PresetDesignTypeName::Doc => "doc",
+            PresetDesignTypeName::Whiteboard => "whiteboard",
+            PresetDesignTypeName::Presentation => "presentation",
+            PresetDesignTypeName::Unknown => "unknown",
+        }
+    }
+
+    for name in &PresetDesignTypeName::ALL {
+        assert_eq!(describe(name), name.as_str());
+    }
+    let _: Result<()> = DesignTypeInput::Custom {
+        width: MIN_DESIGN_DIMENSION,
+        height: MAX_DESIGN_DIMENSION,
+    }
+    .validate();
+    let _: Result<()> = CreateDesignResizeJobRequest::custom("id", 1080, 1920).validate();
+    let _: u64 = MAX_RESIZE_AREA;
+}
+
+#[test]
+fn data_table_signatures() {
+    let builder: DataTableBuilder = DataTable::builder();
+    let table: Result<DataTable> = builder
+        .header(["a", "b"])
+        .row(("x", 1.0))
+        .row(vec![Some(true), None])
+        .row([chrono::Utc::now(), chrono::Utc::now()])
+        .build();
+    let _: Result<()> = DataTable { rows: Vec::new() }.validate();
+    let _: DataTableCell = 1u8.into_cell();
+    let _: DataTableCell = <String as DataTableValue>::empty_cell();
+    let _: DatasetValue = DatasetValue::text("t");
+    let _: DatasetValue = DatasetValue::image("asset");
+    if let Ok(table) = table {
+        let _: DatasetValue = DatasetValue::chart(table);
+    }
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn chart_data_import_signatures() {
+    let _: Result<DataTable> = DataTable::from_csv(&b"a,b\n1,2\n"[..]);
+    let _: Result<DataTable> =
+        DataTable::from_serialize::<std::collections::BTreeMap<&str, u8>>(&[]);
+}
+
+#[cfg(feature = "image")]
+async fn image_processing_signatures(client: Client) -> Result<()> {
+    use canva_connect::endpoints::assets::{
+        process_image, ImageOutputFormat, ProcessedImage, ProcessingOptions, DEFAULT_JPEG_QUALITY,
+    };
+
+    let options = ProcessingOptions {
+        max_dimension: Some(4000),
+        format: Some(ImageOutputFormat::Png),
+        quality: DEFAULT_JPEG_QUALITY,
+    };
+    let _: &str = ImageOutputFormat::Jpeg.extension();
+    let processed: ProcessedImage = process_image(&[], &ProcessingOptions::default())?;
+    let _: (Vec<u8>, u32, u32, Option<ImageOutputFormat>, bool) = (
+        processed.bytes.clone(),
+        processed.width,
+        processed.height,
+        processed.format,
+        processed.is_unchanged(),
+    );
+    let _: Asset = client
+        .assets()
+        .upload_image_with_processing("photo.jpg", &options)
+        .await?;
+    Ok(())
+}
+
+#[test]
+fn api_enum_string_signatures() {
+    let _: [SortByType; 5] = SortByType::ALL;
+    let _: &'static str = OwnershipType::Owned.as_str();
+    let _: String = ExportQuality::Pro.to_string();
+    let _: Result<DatasetFilter> = "non_empty".parse();
+    let _: Result<canva_connect::endpoints::user::Capability> = "resize".parse();
+}
+
+#[test]
+fn scope_variants_are_stable() {
+    let all = [
+        Scope::AssetRead,
+        Scope::AssetWrite,
+        Scope::BrandTemplateMetaRead,
+        Scope::BrandTemplateContentRead,
+        Scope::CommentRead,
+        Scope::CommentWrite,
+        Scope::DesignMetaRead,
+        Scope::DesignContentRead,
+        Scope::DesignContentWrite,
+        Scope::FolderRead,
+        Scope::FolderWrite,
+        Scope::ProfileRead,
+    ];
+    for scope in &all {
+        match scope {
+            Scope::AssetRead
+            | Scope::AssetWrite
+            | Scope::BrandTemplateMetaRead
+            | Scope::BrandTemplateContentRead
+            | Scope::CommentRead
+            | Scope::CommentWrite
+            | Scope::DesignMetaRead
+            | Scope::DesignContentRead
+            | Scope::DesignContentWrite
+            | Scope::FolderRead
+            | Scope::FolderWrite
+            | Scope::ProfileRead => {}
+        }
+    }
+    assert_eq!(all.len(), 12);
+}
+
+#[test]
+fn signature_checks_are_linked() {
+    // Reference the compile-only functions so they are type-checked in full
+    let _ = endpoint_signatures;
+    let _ = oauth_signatures;
+    let _ = compat_signatures;
+    let _ = jobs_signatures;
+    let _ = rate_limit_wait_signatures;
+}
@@ -1,4 +1,8 @@
-use canva_connect::rate_limit::*;
+use canva_connect::{auth::AccessToken, rate_limit::*, Client};
+use reqwest::Method;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
 
 #[test]
 fn test_api_rate_limiter_creation() {
@@ -90,3 +94,410 @@ fn test_rate_limit_info_with_past_reset_time() {
     assert_eq!(info.remaining, Some(75));
     assert!(info.reset_at.unwrap() < chrono::Utc::now());
 }
+
+// Timing tests run under a paused tokio clock, so sleeps advance virtual time
+// instantly and elapsed durations are exact.
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_allows_full_burst_immediately() {
+    let limiter = ApiRateLimiter::new(30);
+    let start = Instant::now();
+
+    for _ in 0..30 {
+        limiter.wait_for_request().await;
+    }
+
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_spaces_requests_after_burst() {
+    let limiter = ApiRateLimiter::new(30);
+    let start = Instant::now();
+
+    for _ in 0..30 {
+        limiter.wait_for_request().await;
+    }
+    limiter.wait_for_request().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(2));
+
+    limiter.wait_for_request().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_sustained_throughput() {
+    let limiter = ApiRateLimiter::with_burst(10, 1);
+    let start = Instant::now();
+
+    for _ in 0..21 {
+        limiter.wait_for_request().await;
+    }
+
+    // One immediate request plus twenty spaced six seconds apart
+    assert_eq!(start.elapsed(), Duration::from_secs(120));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_refills_while_idle() {
+    let limiter = ApiRateLimiter::with_burst(60, 5);
+
+    for _ in 0..5 {
+        limiter.wait_for_request().await;
+    }
+    assert!(!limiter.can_make_request());
+
+    tokio::time::advance(Duration::from_secs(3)).await;
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.wait_for_request().await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert!(!limiter.can_make_request());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_idle_time_does_not_exceed_burst() {
+    let limiter = ApiRateLimiter::with_burst(60, 2);
+
+    tokio::time::advance(Duration::from_secs(600)).await;
+
+    assert!(limiter.can_make_request());
+    assert!(limiter.can_make_request());
+    assert!(!limiter.can_make_request());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_can_make_request_consumes_capacity() {
+    let limiter = ApiRateLimiter::with_burst(60, 1);
+
+    assert!(limiter.can_make_request());
+    assert!(!limiter.can_make_request());
+
+    tokio::time::advance(Duration::from_secs(1)).await;
+    assert!(limiter.can_make_request());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_serves_waiters_in_arrival_order() {
+    let limiter = Arc::new(ApiRateLimiter::with_burst(60, 1));
+    let order = Arc::new(Mutex::new(Vec::new()));
+    limiter.wait_for_request().await;
+
+    let mut handles = Vec::new();
+    for id in 0..5 {
+        let limiter = Arc::clone(&limiter);
+        let order = Arc::clone(&order);
+        handles.push(tokio::spawn(async move {
+            limiter.wait_for_request().await;
+            order.lock().unwrap().push((id, Instant::now()));
+        }));
+        // Let the task start waiting before the next one is spawned
+        tokio::task::yield_now().await;
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    let order = order.lock().unwrap();
+    let ids: Vec<_> = order.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    for pair in order.windows(2) {
+        assert_eq!(pair[1].1 - pair[0].1, Duration::from_secs(1));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_releases_higher_priority_first() {
+    let limiter = Arc::new(ApiRateLimiter::with_burst(60, 1));
+    let order = Arc::new(Mutex::new(Vec::new()));
+    limiter.wait_for_request().await;
+
+    let mut handles = Vec::new();
+    for (id, priority) in [
+        (0, Priority::Low),
+        (1, Priority::Normal),
+        (2, Priority::Low),
+        (3, Priority::High),
+        (4, Priority::Normal),
+        (5, Priority::High),
+    ] {
+        let limiter = Arc::clone(&limiter);
+        let order = Arc::clone(&order);
+        handles.push(tokio::spawn(async move {
+            limiter.wait_for_request_with_priority(priority).await;
+            order.lock().unwrap().push(id);
+        }));
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(limiter.waiting_requests(), 6);
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    // Same-priority requests keep their arrival order
+    assert_eq!(*order.lock().unwrap(), vec![3, 5, 1, 4, 0, 2]);
+    assert_eq!(limiter.waiting_requests(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_cancelled_high_priority_wait_unblocks_others() {
+    let limiter = ApiRateLimiter::with_burst(60, 1);
+    limiter.wait_for_request().await;
+
+    let cancelled = tokio::time::timeout(
+        Duration::from_millis(500),
+        limiter.wait_for_request_with_priority(Priority::High),
+    )
+    .await;
+    assert!(cancelled.is_err());
+
+    let start = Instant::now();
+    limiter.wait_for_request_with_priority(Priority::Low).await;
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_cancelled_wait_consumes_nothing() {
+    let limiter = ApiRateLimiter::with_burst(60, 1);
+    limiter.wait_for_request().await;
+
+    let cancelled =
+        tokio::time::timeout(Duration::from_millis(500), limiter.wait_for_request()).await;
+    assert!(cancelled.is_err());
+
+    // The next slot is still available one second after the first request
+    let start = Instant::now();
+    limiter.wait_for_request().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+}
+
+#[test]
+fn test_rate_limiter_configuration() {
+    let limiter = ApiRateLimiter::new(120);
+    assert_eq!(limiter.requests_per_minute(), 120);
+    assert_eq!(limiter.burst(), 120);
+
+    let limiter = ApiRateLimiter::with_burst(120, 0);
+    assert_eq!(limiter.burst(), 1);
+
+    let limiter = ApiRateLimiter::new(0);
+    assert_eq!(limiter.requests_per_minute(), 60);
+
+    assert_eq!(ApiRateLimiter::default().requests_per_minute(), 30);
+    assert_eq!(ApiRateLimiter::permissive().requests_per_minute(), 100);
+}
+
+#[test]
+fn test_endpoint_limits_match_route_patterns() {
+    let limits = EndpointRateLimits::canva_defaults();
+
+    let per_minute = |method: Method, path: &str| {
+        limits
+            .limiter_for(&method, path)
+            .map(ApiRateLimiter::requests_per_minute)
+    };
+    assert_eq!(per_minute(Method::POST, "/v1/autofills"), Some(10));
+    assert_eq!(per_minute(Method::GET, "/v1/autofills/job_123"), Some(60));
+    assert_eq!(per_minute(Method::GET, "/v1/designs?query=cats"), Some(100));
+    assert_eq!(
+        per_minute(Method::GET, "/v1/designs/DAF123/export-formats"),
+        Some(100)
+    );
+    assert_eq!(per_minute(Method::POST, "/v1/folders/move"), Some(100));
+    assert_eq!(per_minute(Method::PUT, "/v1/autofills"), None);
+    assert_eq!(per_minute(Method::GET, "/v1/unknown"), None);
+}
+
+#[test]
+fn test_endpoint_limits_prefer_literal_segments() {
+    let limits = EndpointRateLimits::new()
+        .set(Method::GET, "/v1/users/{userId}", 50)
+        .set(Method::GET, "/v1/users/me", 5);
+
+    let per_minute = |path: &str| {
+        limits
+            .limiter_for(&Method::GET, path)
+            .map(ApiRateLimiter::requests_per_minute)
+    };
+    assert_eq!(per_minute("/v1/users/me"), Some(5));
+    assert_eq!(per_minute("/v1/users/someone"), Some(50));
+}
+
+#[test]
+fn test_endpoint_limits_can_be_overridden_and_removed() {
+    let limits = EndpointRateLimits::canva_defaults()
+        .set(Method::POST, "/v1/autofills", 30)
+        .set(Method::GET, "/v1/autofills/{id}", 90)
+        .remove(&Method::POST, "/v1/exports");
+
+    let autofill = limits.limiter_for(&Method::POST, "/v1/autofills").unwrap();
+    assert_eq!(autofill.requests_per_minute(), 30);
+    let status = limits
+        .limiter_for(&Method::GET, "/v1/autofills/job_1")
+        .unwrap();
+    assert_eq!(status.requests_per_minute(), 90);
+    assert!(limits.limiter_for(&Method::POST, "/v1/exports").is_none());
+    assert_eq!(
+        limits
+            .routes()
+            .filter(
+                |(method, route, _)| **method == Method::GET && route.starts_with("/v1/autofills/")
+            )
+            .count(),
+        1
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_endpoint_buckets_are_independent() {
+    let limits = EndpointRateLimits::new()
+        .with_limiter(
+            Method::POST,
+            "/v1/autofills",
+            ApiRateLimiter::with_burst(10, 1),
+        )
+        .set(Method::GET, "/v1/autofills/{jobId}", 60);
+    let start = Instant::now();
+
+    limits
+        .wait_for_request(&Method::POST, "/v1/autofills")
+        .await;
+    for _ in 0..10 {
+        limits
+            .wait_for_request(&Method::GET, "/v1/autofills/job")
+            .await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    limits
+        .wait_for_request(&Method::POST, "/v1/autofills")
+        .await;
+    assert_eq!(start.elapsed(), Duration::from_secs(6));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_client_waits_on_endpoint_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/users/me")
+        .with_body(r#"{"team_user":{"user_id":"u","team_id":"t"}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = Client::builder(AccessToken::new("token"))
+        .base_url(server.url())
+        .rate_limiter(ApiRateLimiter::new(1000))
+        .endpoint_rate_limits(EndpointRateLimits::new().with_limiter(
+            Method::GET,
+            "/v1/users/me",
+            ApiRateLimiter::with_burst(6, 1),
+        ))
+        .build()
+        .unwrap();
+    let start = Instant::now();
+
+    client.user().get_me().await.unwrap();
+    client.user().get_me().await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_secs(10));
+    mock.assert_async().await;
+}
+
+fn rate_limit_headers(remaining: &str, limit: &str, reset: &str) -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-ratelimit-remaining", remaining.parse().unwrap());
+    headers.insert("x-ratelimit-limit", limit.parse().unwrap());
+    headers.insert("x-ratelimit-reset", reset.parse().unwrap());
+    headers
+}
+
+#[test]
+fn test_rate_limit_info_from_headers() {
+    let info = RateLimitInfo::from_headers(&rate_limit_headers("10", "100", "1700000000"));
+
+    assert_eq!(info.remaining, Some(10));
+    assert_eq!(info.limit, Some(100));
+    assert_eq!(info.reset_at.unwrap().timestamp(), 1_700_000_000);
+    assert!(info.is_near_limit());
+    assert_eq!(info.time_until_reset(), None);
+}
+
+#[test]
+fn test_rate_limit_info_ignores_malformed_headers() {
+    let info = RateLimitInfo::from_headers(&rate_limit_headers("lots", "-1", "soon"));
+
+    assert_eq!(info.remaining, None);
+    assert_eq!(info.limit, None);
+    assert_eq!(info.reset_at, None);
+    assert!(!info.is_near_limit());
+}
+
+#[test]
+fn test_rate_limit_info_near_limit_threshold() {
+    let info = |remaining| RateLimitInfo {
+        remaining: Some(remaining),
+        reset_at: None,
+        limit: Some(100),
+    };
+
+    assert!(!info(100).is_near_limit());
+    assert!(!info(20).is_near_limit());
+    assert!(info(19).is_near_limit());
+    assert!(info(0).is_near_limit());
+    // Servers reporting more remaining than the limit must not panic
+    assert!(!info(150).is_near_limit());
+}
+
+#[test]
+fn test_rate_limit_info_time_until_reset() {
+    let info = RateLimitInfo {
+        remaining: Some(0),
+        reset_at: Some(chrono::Utc::now() + chrono::Duration::seconds(30)),
+        limit: Some(100),
+    };
+
+    let until_reset = info.time_until_reset().unwrap();
+    assert!(until_reset <= Duration::from_secs(30));
+    assert!(until_reset > Duration::from_secs(25));
+}
+
+#[test]
+fn test_retry_after_parses_seconds_and_dates() {
+    let mut headers = reqwest::header::HeaderMap::new();
+    assert_eq!(retry_after(&headers), None);
+
+    headers.insert("retry-after", "7".parse().unwrap());
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+    let at = (chrono::Utc::now() + chrono::Duration::seconds(60)).to_rfc2822();
+    headers.insert("retry-after", at.parse().unwrap());
+    let delay = retry_after(&headers).unwrap();
+    assert!(delay <= Duration::from_secs(60) && delay > Duration::from_secs(55));
+
+    headers.insert(
+        "retry-after",
+        "Thu, 01 Jan 1970 00:00:00 +0000".parse().unwrap(),
+    );
+    assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+    headers.insert("retry-after", "later".parse().unwrap());
+    assert_eq!(retry_after(&headers), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_pause_holds_back_requests() {
+    let limiter = ApiRateLimiter::new(600);
+    limiter.pause_for(Duration::from_secs(3));
+    // A shorter pause does not cut the current one short
+    limiter.pause_for(Duration::from_secs(1));
+
+    assert!(!limiter.can_make_request());
+    assert_eq!(limiter.pause_remaining(), Some(Duration::from_secs(3)));
+
+    let start = Instant::now();
+    limiter.wait_for_request().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+    assert_eq!(limiter.pause_remaining(), None);
+    assert!(limiter.can_make_request());
+}
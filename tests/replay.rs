@@ -0,0 +1,29 @@
+//! Runs a workflow against a recorded cassette, without network access.
+//!
+//! Re-record with `CANVA_RECORD=1 CANVA_ACCESS_TOKEN=... cargo test
+//! --features test-util --test replay`.
+
+#![cfg(feature = "test-util")]
+
+use canva_connect::{auth::AccessToken, testing::CassetteTransport, Client, Error};
+
+#[tokio::test]
+async fn user_workflow_replays_from_cassette() -> Result<(), Error> {
+    let transport = CassetteTransport::from_env(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/cassettes/user_workflow.json"
+    ))?;
+    let token = std::env::var("CANVA_ACCESS_TOKEN").unwrap_or_else(|_| "replay".to_string());
+    let client = Client::builder(AccessToken::new(token))
+        .transport(transport)
+        .build()?;
+
+    let me = client.user().get_me().await?;
+    assert_eq!(me.user_id, "oUnPjZ2k2yuhftbWF7873o");
+    let profile = client.user().get_profile().await?;
+    assert_eq!(profile.display_name, "Jane Doe");
+
+    let missing = client.designs().get("DAFmissing").await;
+    assert!(matches!(missing, Err(Error::Api { .. })));
+    Ok(())
+}
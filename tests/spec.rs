@@ -0,0 +1,407 @@
+//! Checks the models against Canva's published OpenAPI document.
+//!
+//! Example payloads are generated from every schema in
+//! `components.schemas` that a crate type corresponds to, and each payload
+//! must deserialize into that type. Payloads come in two shapes, one with
+//! every property and one with only the required ones, and enough of each
+//! to use every enum value and `oneOf` branch once. A model that needs a
+//! field Canva made optional, or an enum with the wrong casing, fails here.
+//!
+//! The check runs against the copy of the spec vendored at
+//! `public-api.json`. To check against a newer one instead:
+//!
+//! ```text
+//! curl -o /tmp/canva.yml https://www.canva.dev/sources/connect/api/latest/api.yml
+//! CANVA_OPENAPI_SPEC=/tmp/canva.yml cargo test --test spec -- --nocapture
+//! ```
+//!
+//! `tests/spec/sample.yml` is a small hand-written document used to test the
+//! generator itself.
+
+use canva_connect::{
+    endpoints::{
+        assets::{GetAssetResponse, UpdateAssetResponse},
+        brand_templates::{GetBrandTemplateDatasetResponse, GetBrandTemplateResponse},
+        exports::{
+            CreateDesignExportJobResponse, GetDesignExportFormatsResponse,
+            GetDesignExportJobResponse,
+        },
+        folders::{CreateFolderResponse, GetFolderResponse, UpdateFolderResponse},
+        user::{GetUserCapabilitiesResponse, UserProfileResponse, UsersMeResponse},
+    },
+    models::*,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// Nesting depth after which the generator stops following `$ref`s
+const MAX_DEPTH: usize = 12;
+
+type Check = fn(&Value) -> Result<(), String>;
+
+/// Schemas whose spec shape the crate deliberately does not accept, and why
+///
+/// Failures for these are reported but do not fail the check. Each entry
+/// must still fail, so the list shrinks when the spec or the model changes.
+const KNOWN_GAPS: &[(&str, &str)] = &[
+    (
+        "CreateFolderResponse",
+        "`folder` is optional in the spec but always returned; callers get a Folder, not an Option",
+    ),
+    (
+        "UpdateFolderResponse",
+        "`folder` is optional in the spec but always returned; callers get a Folder, not an Option",
+    ),
+];
+
+fn check<T: DeserializeOwned>(payload: &Value) -> Result<(), String> {
+    serde_json::from_value::<T>(payload.clone())
+        .map(drop)
+        .map_err(|e| e.to_string())
+}
+
+/// Spec schema names and the crate types they deserialize into
+fn models() -> Vec<(&'static str, Check)> {
+    vec![
+        ("Asset", check::<Asset>),
+        ("AssetType", check::<AssetType>),
+        ("Thumbnail", check::<Thumbnail>),
+        ("Design", check::<Design>),
+        ("DesignSummary", check::<DesignSummary>),
+        ("DesignLinks", check::<DesignLinks>),
+        ("TeamUserSummary", check::<TeamUserSummary>),
+        ("OwnershipType", check::<OwnershipType>),
+        ("SortByType", check::<SortByType>),
+        ("BrandTemplate", check::<BrandTemplate>),
+        ("Folder", check::<Folder>),
+        ("FolderItemSummary", check::<FolderItemSummary>),
+        ("CommentThread", check::<CommentThread>),
+        ("CommentReply", check::<CommentReply>),
+        ("ExportJob", check::<ExportJob>),
+        ("ExportError", check::<JobError>),
+        ("JobStatus", check::<JobStatus>),
+        ("DesignAutofillJob", check::<DesignAutofillJob>),
+        ("DesignImportJob", check::<DesignImportJob>),
+        ("DesignResizeJob", check::<DesignResizeJob>),
+        ("GetDesignResponse", check::<GetDesignResponse>),
+        ("CreateDesignResponse", check::<CreateDesignResponse>),
+        ("GetListDesignResponse", check::<GetListDesignResponse>),
+        ("GetDesignPagesResponse", check::<GetDesignPagesResponse>),
+        (
+            "CreateDesignResizeJobResponse",
+            check::<CreateDesignResizeJobResponse>,
+        ),
+        (
+            "GetDesignResizeJobResponse",
+            check::<GetDesignResizeJobResponse>,
+        ),
+        ("GetAssetResponse", check::<GetAssetResponse>),
+        ("UpdateAssetResponse", check::<UpdateAssetResponse>),
+        (
+            "GetBrandTemplateResponse",
+            check::<GetBrandTemplateResponse>,
+        ),
+        (
+            "GetBrandTemplateDatasetResponse",
+            check::<GetBrandTemplateDatasetResponse>,
+        ),
+        (
+            "CreateDesignAutofillJobResponse",
+            check::<CreateDesignAutofillJobResponse>,
+        ),
+        (
+            "GetDesignAutofillJobResponse",
+            check::<GetDesignAutofillJobResponse>,
+        ),
+        (
+            "CreateDesignExportJobResponse",
+            check::<CreateDesignExportJobResponse>,
+        ),
+        (
+            "GetDesignExportJobResponse",
+            check::<GetDesignExportJobResponse>,
+        ),
+        (
+            "GetDesignExportFormatsResponse",
+            check::<GetDesignExportFormatsResponse>,
+        ),
+        ("CreateFolderResponse", check::<CreateFolderResponse>),
+        ("GetFolderResponse", check::<GetFolderResponse>),
+        ("UpdateFolderResponse", check::<UpdateFolderResponse>),
+        ("UsersMeResponse", check::<UsersMeResponse>),
+        ("UserProfileResponse", check::<UserProfileResponse>),
+        (
+            "GetUserCapabilitiesResponse",
+            check::<GetUserCapabilitiesResponse>,
+        ),
+    ]
+}
+
+/// Builds example payloads from the schemas of one OpenAPI document
+struct Generator<'a> {
+    schemas: &'a Map<String, Value>,
+    variant: usize,
+    required_only: bool,
+}
+
+impl Generator<'_> {
+    fn resolve(&self, reference: &str) -> Option<&Value> {
+        self.schemas
+            .get(reference.strip_prefix("#/components/schemas/")?)
+    }
+
+    /// Largest number of enum values or `oneOf`/`anyOf` branches reachable from `schema`
+    fn widest(&self, schema: &Value, depth: usize) -> usize {
+        if depth > MAX_DEPTH {
+            return 1;
+        }
+        if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+            return self
+                .resolve(target)
+                .map_or(1, |target| self.widest(target, depth + 1));
+        }
+        let own = ["enum", "oneOf", "anyOf"]
+            .iter()
+            .filter_map(|key| schema.get(key).and_then(Value::as_array))
+            .map(Vec::len)
+            .max()
+            .unwrap_or(1);
+        let nested = ["oneOf", "anyOf", "allOf"]
+            .iter()
+            .filter_map(|key| schema.get(key).and_then(Value::as_array))
+            .flatten()
+            .chain(
+                schema
+                    .get("properties")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flat_map(Map::values),
+            )
+            .chain(schema.get("items"))
+            .map(|child| self.widest(child, depth + 1))
+            .max()
+            .unwrap_or(1);
+        own.max(nested)
+    }
+
+    fn example(&self, schema: &Value, depth: usize) -> Value {
+        if depth > MAX_DEPTH {
+            return Value::Null;
+        }
+        if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+            return self
+                .resolve(target)
+                .map_or(Value::Null, |target| self.example(target, depth + 1));
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            if !values.is_empty() {
+                return values[self.variant % values.len()].clone();
+            }
+        }
+        if let Some(example) = schema.get("example") {
+            return example.clone();
+        }
+        for key in ["oneOf", "anyOf"] {
+            if let Some(branches) = schema.get(key).and_then(Value::as_array) {
+                if !branches.is_empty() {
+                    return self.example(&branches[self.variant % branches.len()], depth + 1);
+                }
+            }
+        }
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+            let mut merged = Map::new();
+            for part in parts {
+                if let Value::Object(fields) = self.example(part, depth + 1) {
+                    merged.extend(fields);
+                }
+            }
+            return Value::Object(merged);
+        }
+
+        match schema.get("type").and_then(Value::as_str) {
+            Some("array") => Value::Array(
+                schema
+                    .get("items")
+                    .map(|items| vec![self.example(items, depth + 1)])
+                    .unwrap_or_default(),
+            ),
+            Some("string") => Value::from(match schema.get("format").and_then(Value::as_str) {
+                Some("uri" | "url") => "https://example.com/resource",
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("email") => "user@example.com",
+                _ => "example",
+            }),
+            Some("integer") => schema
+                .get("minimum")
+                .cloned()
+                .unwrap_or_else(|| Value::from(1)),
+            Some("number") => Value::from(1.5),
+            Some("boolean") => Value::Bool(true),
+            _ => self.object(schema, depth),
+        }
+    }
+
+    fn object(&self, schema: &Value, depth: usize) -> Value {
+        let required: BTreeSet<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let fields = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter(|(name, _)| !self.required_only || required.contains(name.as_str()))
+            .map(|(name, property)| (name.clone(), self.example(property, depth + 1)))
+            .collect();
+        Value::Object(fields)
+    }
+}
+
+/// Every payload generated for `schema`, without duplicates
+fn payloads(schemas: &Map<String, Value>, schema: &Value) -> Vec<Value> {
+    let probe = Generator {
+        schemas,
+        variant: 0,
+        required_only: false,
+    };
+    let variants = probe.widest(schema, 0);
+    let mut seen = BTreeSet::new();
+    let mut payloads = Vec::new();
+    for required_only in [false, true] {
+        for variant in 0..variants {
+            let generator = Generator {
+                schemas,
+                variant,
+                required_only,
+            };
+            let payload = generator.example(schema, 0);
+            if seen.insert(payload.to_string()) {
+                payloads.push(payload);
+            }
+        }
+    }
+    payloads
+}
+
+/// Results of checking one OpenAPI document
+#[derive(Debug, Default)]
+struct Report {
+    checked: Vec<&'static str>,
+    failures: Vec<String>,
+    known_gaps: BTreeSet<&'static str>,
+    missing: Vec<&'static str>,
+    unmapped: Vec<String>,
+}
+
+fn check_spec(spec: &Value) -> Report {
+    let empty = Map::new();
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut report = Report::default();
+    let models = models();
+
+    for (name, check) in &models {
+        let Some(schema) = schemas.get(*name) else {
+            report.missing.push(name);
+            continue;
+        };
+        report.checked.push(name);
+        for payload in payloads(schemas, schema) {
+            if let Err(error) = check(&payload) {
+                if KNOWN_GAPS.iter().any(|(gap, _)| gap == name) {
+                    report.known_gaps.insert(name);
+                } else {
+                    report
+                        .failures
+                        .push(format!("{name}: {error}\n  payload: {payload}"));
+                }
+            }
+        }
+    }
+
+    // Response schemas the crate has no counterpart for
+    let mapped: BTreeSet<&str> = models.iter().map(|(name, _)| *name).collect();
+    let responses = spec
+        .get("paths")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(Map::values)
+        .filter_map(Value::as_object)
+        .flat_map(Map::values)
+        .filter_map(|operation| {
+            operation.pointer("/responses/200/content/application~1json/schema/$ref")
+        })
+        .filter_map(Value::as_str)
+        .filter_map(|target| target.strip_prefix("#/components/schemas/"))
+        .filter(|name| !mapped.contains(name))
+        .map(str::to_string)
+        .collect::<BTreeSet<_>>();
+    report.unmapped = responses.into_iter().collect();
+    report
+}
+
+fn load(path: &str) -> Value {
+    let text = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("reading {path}: {e}"));
+    serde_yaml::from_str(&text).unwrap_or_else(|e| panic!("parsing {path}: {e}"))
+}
+
+#[test]
+fn generator_covers_every_enum_value_and_required_only_shape() {
+    let spec = load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/spec/sample.yml"
+    ));
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .expect("sample has schemas");
+
+    let jobs = payloads(schemas, &schemas["ExportJob"]);
+    let statuses: BTreeSet<&str> = jobs
+        .iter()
+        .filter_map(|job| job["status"].as_str())
+        .collect();
+    assert_eq!(
+        statuses,
+        BTreeSet::from(["failed", "in_progress", "success"])
+    );
+    assert!(jobs.iter().any(|job| job.get("urls").is_none()));
+}
+
+#[test]
+fn published_spec_models_deserialize() {
+    let path = std::env::var("CANVA_OPENAPI_SPEC")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/public-api.json").to_string());
+
+    let report = check_spec(&load(&path));
+
+    eprintln!("Checked {} schemas in {path}", report.checked.len());
+    if !report.missing.is_empty() {
+        eprintln!("Not in the spec (renamed?): {:?}", report.missing);
+    }
+    if !report.unmapped.is_empty() {
+        eprintln!("Response schemas without a model: {:?}", report.unmapped);
+    }
+    assert!(
+        report.failures.is_empty(),
+        "{} payloads failed:\n{}",
+        report.failures.len(),
+        report.failures.join("\n")
+    );
+    let stale: Vec<_> = KNOWN_GAPS
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| !report.known_gaps.contains(name))
+        .collect();
+    assert!(
+        stale.is_empty(),
+        "known gaps that now deserialize, remove them: {stale:?}"
+    );
+}
@@ -101,3 +101,35 @@ fn test_capabilities_list() {
     assert!(matches!(parsed[1], Capability::BrandTemplate));
     assert!(matches!(parsed[2], Capability::Resize));
 }
+
+#[cfg(feature = "test-util")]
+#[tokio::test]
+async fn test_get_all_combines_identity_profile_and_capabilities() {
+    use canva_connect::operations::Operation;
+    use canva_connect::testing::{fixtures, MockCanvaServer, MockResponse};
+
+    let mut server = MockCanvaServer::start().await;
+    server.on(
+        Operation::GetUserCapabilities,
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "capabilities": ["resize", "teleport"] }),
+        ),
+    );
+    let client = server.client().unwrap();
+
+    let info = client.user().get_all().await.unwrap();
+
+    assert_eq!(info.user.team_id, fixtures::TEAM_ID);
+    assert_eq!(info.profile.display_name, "Jane Doe");
+    assert!(info.capabilities.can_resize());
+    assert!(!info.capabilities.can_autofill());
+    assert_eq!(info.capabilities.as_slice().len(), 2);
+    for operation in [
+        Operation::GetMe,
+        Operation::GetUserProfile,
+        Operation::GetUserCapabilities,
+    ] {
+        server.assert_called(operation, 1);
+    }
+}